@@ -0,0 +1,42 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use google_trends::TimeSeriesData;
+
+// roughly the size of an hourly five-keyword timeline over a week
+fn timeline(points: usize, keywords: usize) -> String {
+    let entries: Vec<String> = (0..points)
+        .map(|i| {
+            let values: Vec<String> = (0..keywords).map(|k| ((i * 7 + k * 13) % 101).to_string()).collect();
+            format!(
+                r#"{{"time":"{}","formattedTime":"Mar {}, 2021 at {}:00","formattedAxisTime":"Mar {}","value":[{}],"formattedValue":["{}"],"hasData":[{}]}}"#,
+                1_614_556_800 + i * 3600,
+                1 + i / 24,
+                i % 24,
+                1 + i / 24,
+                values.join(","),
+                values.join("\",\""),
+                vec!["true"; keywords].join(",")
+            )
+        })
+        .collect();
+    format!(r#"{{"timelineData":[{}],"averages":[]}}"#, entries.join(","))
+}
+
+fn parse(c: &mut Criterion) {
+    let json = timeline(24 * 7, 5);
+    let mut group = c.benchmark_group("timeline");
+
+    group.bench_function("serde_json", |b| {
+        b.iter(|| serde_json::from_str::<TimeSeriesData>(black_box(&json)).unwrap())
+    });
+    group.bench_function("simd_json", |b| {
+        b.iter_batched(
+            || json.as_bytes().to_vec(),
+            |mut buffer| simd_json::serde::from_slice::<TimeSeriesData>(black_box(&mut buffer)).unwrap(),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
@@ -0,0 +1,213 @@
+use chrono::{DateTime, Utc};
+
+use crate::{LabeledSeries, SeriesPoint, TrendValue};
+
+/// Brings both series onto the buckets of the coarser one, returning them in the original order.
+/// Joining e.g. a daily and a weekly series by timestamp would only match the few points that happen
+/// to coincide and compare values on different scales, so the finer series is averaged instead.
+///
+/// The coarser series is the one with the longer median step between points and is returned as is.
+/// The finer one is downsampled with `downsample`. Series with fewer than two points have no known
+/// step and are treated as the coarser one.
+pub fn to_common_granularity(a: &LabeledSeries, b: &LabeledSeries) -> (LabeledSeries, LabeledSeries) {
+    let times = |series: &LabeledSeries| series.points.iter().map(|point| point.time).collect::<Vec<_>>();
+    match (median_step(a), median_step(b)) {
+        (Some(step_a), Some(step_b)) if step_a < step_b => (downsample(a, &times(b)), b.clone()),
+        (Some(_), None) => (downsample(a, &times(b)), b.clone()),
+        _ => (a.clone(), downsample(b, &times(a))),
+    }
+}
+
+/// Averages the points of `series` into the buckets starting at `times`, which must be sorted.
+///
+/// Each bucket runs until the next timestamp, the last one is as long as the one before it. Points
+/// before the first bucket are dropped. Missing points are skipped and a bucket without any data is
+/// `Missing`. `LessThanOne` counts as zero, a bucket that only holds such points stays `LessThanOne`.
+pub fn downsample(series: &LabeledSeries, times: &[DateTime<Utc>]) -> LabeledSeries {
+    let points = times
+        .iter()
+        .enumerate()
+        .map(|(index, start)| {
+            let end = bucket_end(times, index);
+            let values: Vec<TrendValue> = series
+                .points
+                .iter()
+                .filter(|point| point.time >= *start && end.map_or(true, |end| point.time < end))
+                .map(|point| point.value)
+                .filter(|value| !value.is_missing())
+                .collect();
+            SeriesPoint {
+                time: *start,
+                value: mean(&values),
+            }
+        })
+        .collect();
+    LabeledSeries {
+        label: series.label.clone(),
+        points,
+    }
+}
+
+/// Spreads the points of `series` over the finer `times`, which must be sorted. Every timestamp takes
+/// the value of the bucket it falls into, so the result is a step function carrying no more detail
+/// than the input. Timestamps outside of the series are `Missing`.
+pub fn upsample(series: &LabeledSeries, times: &[DateTime<Utc>]) -> LabeledSeries {
+    let starts: Vec<DateTime<Utc>> = series.points.iter().map(|point| point.time).collect();
+    let points = times
+        .iter()
+        .map(|time| {
+            let value = starts
+                .iter()
+                .enumerate()
+                .rev()
+                .find(|(_, start)| *start <= time)
+                .filter(|(index, _)| bucket_end(&starts, *index).map_or(true, |end| *time < end))
+                .map_or(TrendValue::Missing, |(index, _)| series.points[index].value);
+            SeriesPoint { time: *time, value }
+        })
+        .collect();
+    LabeledSeries {
+        label: series.label.clone(),
+        points,
+    }
+}
+
+fn bucket_end(times: &[DateTime<Utc>], index: usize) -> Option<DateTime<Utc>> {
+    match times.get(index + 1) {
+        Some(next) => Some(*next),
+        None if index > 0 => Some(times[index] + (times[index] - times[index - 1])),
+        None => None,
+    }
+}
+
+fn median_step(series: &LabeledSeries) -> Option<chrono::Duration> {
+    let mut steps: Vec<chrono::Duration> = series
+        .points
+        .windows(2)
+        .map(|pair| pair[1].time - pair[0].time)
+        .collect();
+    steps.sort();
+    steps.get(steps.len() / 2).copied()
+}
+
+fn mean(values: &[TrendValue]) -> TrendValue {
+    if values.is_empty() {
+        return TrendValue::Missing;
+    }
+    if values.iter().all(|value| *value == TrendValue::LessThanOne) {
+        return TrendValue::LessThanOne;
+    }
+    let sum: f32 = values.iter().filter_map(TrendValue::as_f32).sum();
+    TrendValue::Value(sum / values.len() as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn day(day: i64) -> DateTime<Utc> {
+        Utc.ymd(2021, 1, 3).and_hms(0, 0, 0) + Duration::days(day)
+    }
+
+    fn series(label: &str, points: &[(DateTime<Utc>, TrendValue)]) -> LabeledSeries {
+        LabeledSeries {
+            label: label.to_owned(),
+            points: points
+                .iter()
+                .map(|&(time, value)| SeriesPoint { time, value })
+                .collect(),
+        }
+    }
+
+    fn values(series: &LabeledSeries) -> Vec<(DateTime<Utc>, TrendValue)> {
+        series.points.iter().map(|point| (point.time, point.value)).collect()
+    }
+
+    #[test]
+    fn daily_series_are_averaged_onto_weekly_buckets() {
+        let daily: Vec<_> = (0..14).map(|d| (day(d), TrendValue::Value(d as f32 + 1.0))).collect();
+        let daily = series("daily", &daily);
+        let weekly = series(
+            "weekly",
+            &[(day(0), TrendValue::Value(50.0)), (day(7), TrendValue::Value(60.0))],
+        );
+
+        let (a, b) = to_common_granularity(&daily, &weekly);
+        assert_eq!(a.label, "daily");
+        assert_eq!(
+            values(&a),
+            [(day(0), TrendValue::Value(4.0)), (day(7), TrendValue::Value(11.0))]
+        );
+        assert_eq!(values(&b), values(&weekly));
+
+        // the coarser series can come first as well
+        let (a, b) = to_common_granularity(&weekly, &daily);
+        assert_eq!(values(&a), values(&weekly));
+        assert_eq!(b.label, "daily");
+        assert_eq!(b.points.len(), 2);
+    }
+
+    #[test]
+    fn the_last_bucket_is_as_long_as_the_one_before() {
+        let points = series(
+            "daily",
+            &[
+                (day(-1), TrendValue::Value(90.0)),
+                (day(13), TrendValue::Value(10.0)),
+                (day(14), TrendValue::Value(90.0)),
+            ],
+        );
+
+        let downsampled = downsample(&points, &[day(0), day(7)]);
+        assert_eq!(
+            values(&downsampled),
+            [(day(0), TrendValue::Missing), (day(7), TrendValue::Value(10.0))]
+        );
+    }
+
+    #[test]
+    fn buckets_of_only_less_than_one_stay_less_than_one() {
+        let points = series(
+            "daily",
+            &[
+                (day(0), TrendValue::LessThanOne),
+                (day(1), TrendValue::Missing),
+                (day(7), TrendValue::LessThanOne),
+                (day(8), TrendValue::Value(3.0)),
+                (day(14), TrendValue::Missing),
+            ],
+        );
+
+        let downsampled = downsample(&points, &[day(0), day(7), day(14)]);
+        assert_eq!(
+            values(&downsampled),
+            [
+                (day(0), TrendValue::LessThanOne),
+                (day(7), TrendValue::Value(1.5)),
+                (day(14), TrendValue::Missing)
+            ]
+        );
+    }
+
+    #[test]
+    fn upsampling_repeats_the_value_of_the_bucket() {
+        let weekly = series(
+            "weekly",
+            &[(day(0), TrendValue::Value(50.0)), (day(7), TrendValue::Value(60.0))],
+        );
+
+        let upsampled = upsample(&weekly, &[day(-1), day(0), day(6), day(7), day(13), day(14)]);
+        assert_eq!(
+            values(&upsampled),
+            [
+                (day(-1), TrendValue::Missing),
+                (day(0), TrendValue::Value(50.0)),
+                (day(6), TrendValue::Value(50.0)),
+                (day(7), TrendValue::Value(60.0)),
+                (day(13), TrendValue::Value(60.0)),
+                (day(14), TrendValue::Missing)
+            ]
+        );
+    }
+}
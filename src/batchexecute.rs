@@ -0,0 +1,109 @@
+use serde_json::Value;
+
+use crate::Error;
+
+/// One call of a batchexecute request, `args` are the positional arguments of the RPC.
+#[derive(Debug, Clone)]
+pub struct RpcCall {
+    pub rpc_id: String,
+    pub args: Value,
+}
+
+impl RpcCall {
+    pub fn new<S: Into<String>>(rpc_id: S, args: Value) -> RpcCall {
+        RpcCall {
+            rpc_id: rpc_id.into(),
+            args,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RpcResponse {
+    pub rpc_id: String,
+    /// The index of the call in its batch, starting from one. Google leaves it out when the batch has a
+    /// single call, it is `1` then.
+    pub index: usize,
+    /// The decoded payload, `None` when the RPC failed (`error` then usually holds Google's status).
+    pub data: Option<Value>,
+    pub error: Option<Value>,
+}
+
+/// Encodes `calls` as the `f.req` form field. Arguments travel as JSON strings inside the JSON envelope.
+pub fn encode(calls: &[RpcCall]) -> Result<String, Error> {
+    let envelope = calls
+        .iter()
+        .enumerate()
+        .map(|(index, call)| {
+            let tag = if calls.len() == 1 {
+                "generic".to_owned()
+            } else {
+                (index + 1).to_string()
+            };
+            Ok(Value::Array(vec![
+                Value::String(call.rpc_id.clone()),
+                Value::String(serde_json::to_string(&call.args)?),
+                Value::Null,
+                Value::String(tag),
+            ]))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    Ok(serde_json::to_string(&Value::Array(vec![Value::Array(envelope)]))?)
+}
+
+/// The rpc ids of `calls` as expected by the `rpcids` query parameter.
+pub fn rpc_ids(calls: &[RpcCall]) -> String {
+    let mut ids: Vec<&str> = vec![];
+    for call in calls {
+        if !ids.contains(&call.rpc_id.as_str()) {
+            ids.push(&call.rpc_id);
+        }
+    }
+    ids.join(",")
+}
+
+/// Decodes a batchexecute response body: the `)]}'` prefix followed by chunks, each a length line and
+/// a JSON array of entries. The lengths aren't reliable (they count UTF-16 units), so chunks are read
+/// as consecutive JSON values instead. Entries other than results (`wrb.fr`) are skipped.
+pub fn decode(body: &str) -> Result<Vec<RpcResponse>, Error> {
+    let body = body.strip_prefix(")]}'").unwrap_or(body);
+    let mut responses = vec![];
+    for chunk in serde_json::Deserializer::from_str(body).into_iter::<Value>() {
+        let entries = match chunk? {
+            Value::Array(entries) => entries,
+            _ => continue,
+        };
+        for entry in entries {
+            if let Some(response) = decode_entry(&entry)? {
+                responses.push(response);
+            }
+        }
+    }
+    Ok(responses)
+}
+
+fn decode_entry(entry: &Value) -> Result<Option<RpcResponse>, Error> {
+    let fields = match entry.as_array() {
+        Some(fields) if fields.first().and_then(Value::as_str) == Some("wrb.fr") => fields,
+        _ => return Ok(None),
+    };
+    let rpc_id = fields
+        .get(1)
+        .and_then(Value::as_str)
+        .ok_or_else(|| Error::UnexpectedResponse(format!("batchexecute entry without an rpc id: {}", entry)))?;
+    let data = match fields.get(2).and_then(Value::as_str) {
+        Some(payload) => Some(serde_json::from_str(payload)?),
+        None => None,
+    };
+    let index = fields
+        .get(6)
+        .and_then(Value::as_str)
+        .and_then(|tag| tag.parse().ok())
+        .unwrap_or(1);
+    Ok(Some(RpcResponse {
+        rpc_id: rpc_id.to_owned(),
+        index,
+        data,
+        error: fields.get(5).filter(|error| !error.is_null()).cloned(),
+    }))
+}
@@ -0,0 +1,403 @@
+use std::process;
+use std::time::Duration;
+
+use chrono::{Date, NaiveDate, TimeZone, Utc};
+use futures_util::TryStreamExt;
+use google_trends::export::jsonl;
+use google_trends::{
+    Category, Error, Query, QueryItem, RealtimeStory, RegionOptions, Resolution, RisingValue, Source, Timeframe,
+    TokenCache, TrendValue, TrendsClient,
+};
+
+const USAGE: &str = "Usage: trends <interest|regions|related|daily|realtime|stories|suggest> [KEYWORD...] [OPTIONS]
+
+Options:
+    --geo CODE              restrict to a region, e.g. US or US-CA (daily, realtime and stories default to US)
+    --timeframe RANGE       \"YYYY-MM-DD YYYY-MM-DD\", defaults to 2014-01-01 until today
+    --date YYYY-MM-DD       day to fetch daily trends for, defaults to today
+    --category ID           category id, defaults to 0 (all categories), a topic id for realtime
+    --hours HOURS           4, 24 (default), 48 or 168, how far back realtime looks
+    --limit COUNT           how many realtime stories to list, all of them by default
+    --resolution LEVEL      country, region, city or dma (regions only)
+    --locale LOCALE         defaults to en-US
+    --format FORMAT         json, jsonl, csv or table (default)
+    --no-cache              don't reuse explore tokens of recent runs";
+
+const TOKEN_TTL: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Debug)]
+struct Args {
+    command: String,
+    keywords: Vec<String>,
+    geo: Option<String>,
+    timeframe: Option<Timeframe>,
+    date: Option<Date<Utc>>,
+    category: Option<Category>,
+    resolution: Option<Resolution>,
+    hours: Option<u32>,
+    limit: Option<usize>,
+    locale: Option<String>,
+    format: Format,
+    no_cache: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Format {
+    Json,
+    JsonLines,
+    Csv,
+    Table,
+}
+
+struct Output {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+    /// The records of `export::jsonl` for the commands with data it can export, one per keyword and
+    /// data point, used by `--format jsonl` instead of the rows.
+    lines: Option<String>,
+}
+
+#[tokio::main]
+async fn main() {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    match run(&args).await {
+        Ok(output) => print!("{}", render(&output, args.format)),
+        Err(err) => {
+            eprintln!("Request failed: {:?}", err);
+            process::exit(1);
+        }
+    }
+}
+
+async fn run(args: &Args) -> Result<Output, Error> {
+    let mut builder = TrendsClient::builder().locale(args.locale.clone().unwrap_or_else(|| "en-US".to_owned()));
+    if let (false, Some(cache)) = (args.no_cache, TokenCache::in_cache_dir(TOKEN_TTL)) {
+        builder = builder.token_cache(cache);
+    }
+    let client = builder.build()?;
+    let category = args.category.unwrap_or(Category::All);
+    let time = args.timeframe.clone().unwrap_or_else(Timeframe::default);
+    let items = args
+        .keywords
+        .iter()
+        .map(|keyword| match &args.geo {
+            Some(geo) => QueryItem::by_keyword_with_geo(keyword.as_str(), geo.as_str(), time.clone()),
+            None => QueryItem::by_keyword(keyword.as_str(), time.clone()),
+        })
+        .collect();
+    let query = Query::new(items);
+
+    match args.command.as_str() {
+        "interest" => {
+            let data = client.interest_by_time(&query, Source::Search, category).await?;
+            let rows = data
+                .entries
+                .iter()
+                .map(|entry| {
+                    let mut row = vec![entry.time.format("%Y-%m-%d").to_string()];
+                    row.extend(entry.value.iter().map(TrendValue::to_string));
+                    row
+                })
+                .collect();
+            Ok(Output {
+                headers: headers("date", &args.keywords),
+                rows,
+                lines: json_lines(args.format, &query, &data)?,
+            })
+        }
+        "regions" => {
+            let opts = RegionOptions::default()
+                .resolution(args.resolution.unwrap_or(Resolution::Country))
+                .category(category);
+            let data = client.interest_by_region_with_opts(&query, &opts).await?;
+            let rows = data
+                .entries
+                .iter()
+                .map(|entry| {
+                    let mut row = vec![entry.geo_code.clone(), entry.geo_name.clone()];
+                    row.extend(entry.value.iter().map(TrendValue::to_string));
+                    row
+                })
+                .collect();
+            let mut headers = headers("geo", &args.keywords);
+            headers.insert(1, "name".to_owned());
+            Ok(Output {
+                headers,
+                rows,
+                lines: json_lines(args.format, &query, &data)?,
+            })
+        }
+        "related" => {
+            let related = client.related_queries(&query, Source::Search, category).await?;
+            let top = related
+                .top
+                .iter()
+                .map(|query| vec!["top".to_owned(), query.query.clone(), query.value.to_string()]);
+            let rising = related.rising.iter().map(|query| {
+                let value = match query.value {
+                    RisingValue::Breakout => "breakout".to_owned(),
+                    RisingValue::Percent(percent) => format!("+{}%", percent),
+                };
+                vec!["rising".to_owned(), query.query.clone(), value]
+            });
+            Ok(Output {
+                headers: vec!["list".to_owned(), "query".to_owned(), "value".to_owned()],
+                rows: top.chain(rising).collect(),
+                lines: None,
+            })
+        }
+        "daily" => {
+            let geo = args.geo.as_deref().unwrap_or("US");
+            let date = args.date.unwrap_or_else(|| Utc::now().date());
+            let days = client.daily_trends(geo, date).await?;
+            let rows = days
+                .iter()
+                .flat_map(|day| {
+                    day.trending_searches.iter().map(move |search| {
+                        vec![
+                            day.date.clone(),
+                            search.title.query.clone(),
+                            search.formatted_traffic.clone(),
+                        ]
+                    })
+                })
+                .collect();
+            Ok(Output {
+                headers: vec!["date".to_owned(), "query".to_owned(), "traffic".to_owned()],
+                rows,
+                lines: None,
+            })
+        }
+        "realtime" => {
+            let geo = args.geo.as_deref().unwrap_or("US");
+            let topic = args.category.map(u32::from).unwrap_or(0);
+            let searches = client.trending_now(geo, args.hours.unwrap_or(24), topic).await?;
+            let rows = searches
+                .iter()
+                .map(|search| {
+                    vec![
+                        search.started.format("%Y-%m-%d %H:%M").to_string(),
+                        search.title.clone(),
+                        search.search_volume.to_string(),
+                        search.related_queries.join(", "),
+                    ]
+                })
+                .collect();
+            Ok(Output {
+                headers: vec![
+                    "started".to_owned(),
+                    "query".to_owned(),
+                    "volume".to_owned(),
+                    "related".to_owned(),
+                ],
+                rows,
+                lines: None,
+            })
+        }
+        "stories" => {
+            let geo = args.geo.as_deref().unwrap_or("US");
+            let stories: Vec<RealtimeStory> = client
+                .realtime_trends_stream(geo, "all", args.limit)
+                .try_collect()
+                .await?;
+            let rows = stories
+                .into_iter()
+                .map(|story| {
+                    let source = story.articles.first().map(|article| article.source.clone());
+                    vec![story.id, story.title, source.unwrap_or_default()]
+                })
+                .collect();
+            Ok(Output {
+                headers: vec!["id".to_owned(), "title".to_owned(), "source".to_owned()],
+                rows,
+                lines: None,
+            })
+        }
+        "suggest" => {
+            let mut rows = vec![];
+            for keyword in &args.keywords {
+                for topic in client.suggestions(keyword).await? {
+                    rows.push(vec![keyword.clone(), topic.mid, topic.title, topic.kind]);
+                }
+            }
+            Ok(Output {
+                headers: vec![
+                    "keyword".to_owned(),
+                    "mid".to_owned(),
+                    "title".to_owned(),
+                    "type".to_owned(),
+                ],
+                rows,
+                lines: None,
+            })
+        }
+        _ => unreachable!("commands are checked while parsing"),
+    }
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Args, String> {
+    let command = args.next().ok_or("Missing command")?;
+    if ![
+        "interest", "regions", "related", "daily", "realtime", "stories", "suggest",
+    ]
+    .contains(&command.as_str())
+    {
+        return Err(format!("Unknown command '{}'", command));
+    }
+    let mut parsed = Args {
+        command,
+        keywords: vec![],
+        geo: None,
+        timeframe: None,
+        date: None,
+        category: None,
+        resolution: None,
+        hours: None,
+        limit: None,
+        locale: None,
+        format: Format::Table,
+        no_cache: false,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("Missing value for {}", arg));
+        match arg.as_str() {
+            "--geo" => parsed.geo = Some(value()?),
+            "--timeframe" => parsed.timeframe = Some(parse_timeframe(&value()?)?),
+            "--date" => parsed.date = Some(parse_date(&value()?)?),
+            "--category" => {
+                let id = value()?.parse().map_err(|_| "Category must be a numeric id")?;
+                parsed.category = Some(Category::from_id(id));
+            }
+            "--resolution" => {
+                parsed.resolution = Some(match value()?.as_str() {
+                    "country" => Resolution::Country,
+                    "region" => Resolution::Region,
+                    "city" => Resolution::City,
+                    "dma" => Resolution::Dma,
+                    other => return Err(format!("Unknown resolution '{}'", other)),
+                })
+            }
+            "--hours" => parsed.hours = Some(value()?.parse().map_err(|_| "Hours must be a number")?),
+            "--limit" => parsed.limit = Some(value()?.parse().map_err(|_| "Limit must be a number")?),
+            "--locale" => parsed.locale = Some(value()?),
+            "--format" => {
+                parsed.format = match value()?.as_str() {
+                    "json" => Format::Json,
+                    "jsonl" => Format::JsonLines,
+                    "csv" => Format::Csv,
+                    "table" => Format::Table,
+                    other => return Err(format!("Unknown format '{}'", other)),
+                }
+            }
+            "--no-cache" => parsed.no_cache = true,
+            flag if flag.starts_with("--") => return Err(format!("Unknown option '{}'", flag)),
+            _ => parsed.keywords.push(arg.clone()),
+        }
+    }
+
+    if parsed.keywords.is_empty() && !["daily", "realtime", "stories"].contains(&parsed.command.as_str()) {
+        return Err(format!("'{}' needs at least one keyword", parsed.command));
+    }
+    Ok(parsed)
+}
+
+fn parse_timeframe(range: &str) -> Result<Timeframe, String> {
+    let mut dates = range.split_whitespace();
+    match (dates.next(), dates.next(), dates.next()) {
+        (Some(start), Some(end), None) => Ok(Timeframe::new(parse_date(start)?, parse_date(end)?)),
+        _ => Err(format!("Invalid timeframe '{}'", range)),
+    }
+}
+
+fn parse_date(date: &str) -> Result<Date<Utc>, String> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map(|date| Utc.from_utc_date(&date))
+        .map_err(|_| format!("Invalid date '{}'", date))
+}
+
+fn headers(first: &str, keywords: &[String]) -> Vec<String> {
+    std::iter::once(first.to_owned())
+        .chain(keywords.iter().cloned())
+        .collect()
+}
+
+fn json_lines<T: jsonl::Records>(format: Format, query: &Query, data: &T) -> Result<Option<String>, Error> {
+    if let Format::JsonLines = format {
+        let mut buf = vec![];
+        jsonl::write(&mut buf, std::iter::once((query, data)))?;
+        Ok(Some(String::from_utf8_lossy(&buf).into_owned()))
+    } else {
+        Ok(None)
+    }
+}
+
+fn records(output: &Output) -> Vec<serde_json::Map<String, serde_json::Value>> {
+    output
+        .rows
+        .iter()
+        .map(|row| {
+            output
+                .headers
+                .iter()
+                .cloned()
+                .zip(row.iter().map(|cell| serde_json::Value::String(cell.clone())))
+                .collect()
+        })
+        .collect()
+}
+
+fn render(output: &Output, format: Format) -> String {
+    match format {
+        Format::Json => serde_json::to_string_pretty(&records(output)).unwrap_or_default() + "\n",
+        Format::JsonLines => match &output.lines {
+            Some(lines) => lines.clone(),
+            None => records(output)
+                .iter()
+                .map(|record| serde_json::to_string(record).unwrap_or_default() + "\n")
+                .collect(),
+        },
+        Format::Csv => std::iter::once(&output.headers)
+            .chain(&output.rows)
+            .map(|row| {
+                let cells: Vec<String> = row
+                    .iter()
+                    .map(|cell| {
+                        if cell.contains(|c: char| c == ',' || c == '"' || c == '\n') {
+                            format!("\"{}\"", cell.replace('"', "\"\""))
+                        } else {
+                            cell.clone()
+                        }
+                    })
+                    .collect();
+                cells.join(",") + "\n"
+            })
+            .collect(),
+        Format::Table => {
+            let mut widths: Vec<usize> = output.headers.iter().map(|header| header.chars().count()).collect();
+            for row in &output.rows {
+                for (width, cell) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(cell.chars().count());
+                }
+            }
+            std::iter::once(&output.headers)
+                .chain(&output.rows)
+                .map(|row| {
+                    let cells: Vec<String> = row
+                        .iter()
+                        .zip(&widths)
+                        .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                        .collect();
+                    cells.join("  ").trim_end().to_owned() + "\n"
+                })
+                .collect()
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use std::borrow::Cow;
+
+use chrono::{DateTime, Utc};
+use serde::de::{self, Deserializer, Visitor};
+use serde::Deserialize;
+
+use crate::types::trends_time_format;
+use crate::{Coordinates, Error, RegionData, RegionEntry, TimeSeriesData, TimeSeriesEntry, TrendValue};
+
+/// A widget response body kept around so the data can be parsed into types borrowing from it.
+/// Strings without escape sequences point into the body instead of being copied.
+#[derive(Debug, Clone)]
+pub struct RawResponse {
+    body: String,
+    #[cfg_attr(not(feature = "strict"), allow(dead_code))]
+    strict: bool,
+}
+
+#[derive(Deserialize)]
+struct Wrapper<T> {
+    default: T,
+}
+
+impl RawResponse {
+    pub(crate) fn new(body: String, strict: bool) -> RawResponse {
+        RawResponse { body, strict }
+    }
+
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Fails with `Error::SchemaDrift` on unknown fields when the client was in strict mode.
+    pub fn time_series(&self) -> Result<TimeSeriesDataRef<'_>, Error> {
+        let wrapper: Wrapper<TimeSeriesDataRef> = self.parse()?;
+        Ok(wrapper.default)
+    }
+
+    pub fn regions(&self) -> Result<RegionDataRef<'_>, Error> {
+        let wrapper: Wrapper<RegionDataRef> = self.parse()?;
+        Ok(wrapper.default)
+    }
+
+    fn parse<'a, A: Deserialize<'a>>(&'a self) -> Result<A, Error> {
+        #[cfg(feature = "strict")]
+        if self.strict {
+            return crate::client::from_str_strict(&self.body);
+        }
+        Ok(serde_json::from_str(&self.body)?)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TimeSeriesDataRef<'a> {
+    #[serde(rename = "timelineData", borrow)]
+    pub entries: Vec<TimeSeriesEntryRef<'a>>,
+    #[serde(default)]
+    pub averages: Vec<u8>,
+}
+
+impl TimeSeriesDataRef<'_> {
+    pub fn into_owned(self) -> TimeSeriesData {
+        TimeSeriesData {
+            entries: self.entries.into_iter().map(TimeSeriesEntryRef::into_owned).collect(),
+            averages: self.averages,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeSeriesEntryRef<'a> {
+    #[serde(with = "trends_time_format")]
+    pub time: DateTime<Utc>,
+    #[serde(borrow)]
+    pub formatted_time: Cow<'a, str>,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub formatted_axis_time: Option<Cow<'a, str>>,
+    pub value: Vec<u8>,
+    #[serde(borrow, default, deserialize_with = "strs")]
+    pub formatted_value: Vec<Cow<'a, str>>,
+    #[serde(default)]
+    pub has_data: Vec<bool>,
+    pub is_partial: Option<bool>,
+}
+
+impl TimeSeriesEntryRef<'_> {
+    pub fn into_owned(self) -> TimeSeriesEntry {
+        TimeSeriesEntry {
+            time: self.time,
+            formatted_time: self.formatted_time.into_owned(),
+            formatted_axis_time: self.formatted_axis_time.map(Cow::into_owned),
+            value: TrendValue::from_raw(&self.value, &self.has_data, &self.formatted_value),
+            formatted_value: self.formatted_value.into_iter().map(Cow::into_owned).collect(),
+            has_data: self.has_data,
+            is_partial: self.is_partial,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RegionDataRef<'a> {
+    #[serde(rename = "geoMapData", borrow)]
+    pub entries: Vec<RegionEntryRef<'a>>,
+}
+
+impl RegionDataRef<'_> {
+    pub fn into_owned(self) -> RegionData {
+        RegionData {
+            entries: self.entries.into_iter().map(RegionEntryRef::into_owned).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegionEntryRef<'a> {
+    pub coordinates: Option<Coordinates>,
+    #[serde(borrow, default)]
+    pub geo_code: Cow<'a, str>,
+    #[serde(borrow)]
+    pub geo_name: Cow<'a, str>,
+    pub value: Vec<u8>,
+    #[serde(borrow, default, deserialize_with = "strs")]
+    pub formatted_value: Vec<Cow<'a, str>>,
+    pub max_value_index: Option<usize>,
+    #[serde(default)]
+    pub has_data: Vec<bool>,
+}
+
+impl RegionEntryRef<'_> {
+    pub fn into_owned(self) -> RegionEntry {
+        RegionEntry {
+            coordinates: self.coordinates,
+            geo_code: self.geo_code.into_owned(),
+            geo_name: self.geo_name.into_owned(),
+            value: TrendValue::from_raw(&self.value, &self.has_data, &self.formatted_value),
+            formatted_value: self.formatted_value.into_iter().map(Cow::into_owned).collect(),
+            max_value_index: self.max_value_index,
+            has_data: self.has_data,
+        }
+    }
+}
+
+// serde only borrows a `Cow` field on its own, nested in an `Option` or a `Vec` it always copies
+struct BorrowedStr<'a>(Cow<'a, str>);
+
+impl<'de> Deserialize<'de> for BorrowedStr<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct StrVisitor;
+
+        impl<'de> Visitor<'de> for StrVisitor {
+            type Value = BorrowedStr<'de>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E: de::Error>(self, value: &'de str) -> Result<Self::Value, E> {
+                Ok(BorrowedStr(Cow::Borrowed(value)))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(BorrowedStr(Cow::Owned(value.to_owned())))
+            }
+
+            fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+                Ok(BorrowedStr(Cow::Owned(value)))
+            }
+        }
+
+        deserializer.deserialize_str(StrVisitor)
+    }
+}
+
+fn optional_str<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Cow<'de, str>>, D::Error> {
+    let str: Option<BorrowedStr> = Deserialize::deserialize(deserializer)?;
+    Ok(str.map(|str| str.0))
+}
+
+fn strs<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Cow<'de, str>>, D::Error> {
+    let strs: Vec<BorrowedStr> = Deserialize::deserialize(deserializer)?;
+    Ok(strs.into_iter().map(|str| str.0).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_borrowed(str: &Cow<str>) -> bool {
+        matches!(str, Cow::Borrowed(_))
+    }
+
+    #[test]
+    fn strings_point_into_the_body() {
+        let body = r#"{"default":{"timelineData":[{"time":"1609632000","formattedTime":"Jan 3, 2021",
+            "formattedAxisTime":"Jan 3","value":[64],"formattedValue":["64"],"hasData":[true]}]}}"#;
+        let response = RawResponse::new(body.to_owned(), false);
+        let data = response.time_series().unwrap();
+        let entry = &data.entries[0];
+
+        assert!(is_borrowed(&entry.formatted_time));
+        assert!(entry.formatted_axis_time.as_ref().map_or(false, is_borrowed));
+        assert!(entry.formatted_value.iter().all(is_borrowed));
+    }
+
+    #[test]
+    fn escaped_strings_are_copied() {
+        let body = r#"{"default":{"geoMapData":[{"geoCode":"US-NY","geoName":"New \u0059ork",
+            "value":[100],"formattedValue":["\u0031\u0030\u0030"],"hasData":[true]}]}}"#;
+        let response = RawResponse::new(body.to_owned(), false);
+        let data = response.regions().unwrap();
+        let entry = &data.entries[0];
+
+        assert!(is_borrowed(&entry.geo_code));
+        assert_eq!(entry.geo_name, "New York");
+        assert!(!is_borrowed(&entry.geo_name));
+        assert_eq!(entry.formatted_value, ["100"]);
+        assert!(!is_borrowed(&entry.formatted_value[0]));
+    }
+}
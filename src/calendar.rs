@@ -0,0 +1,107 @@
+use chrono::{Date, DateTime, Utc};
+
+use crate::{TimeSeriesData, TimeSeriesEntry};
+
+/// A source of labels for stretches of time, e.g. holidays or marketing campaigns, used to explain
+/// what happened around a point of a series.
+pub trait Calendar: Send + Sync {
+    /// Labels of the events overlapping `start..end`.
+    fn labels(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<String>;
+}
+
+/// An event spanning whole days, `start` to `end` inclusive.
+#[derive(Debug, Clone)]
+pub struct CalendarEvent {
+    pub label: String,
+    pub start: Date<Utc>,
+    pub end: Date<Utc>,
+}
+
+impl CalendarEvent {
+    pub fn new<S: Into<String>>(label: S, start: Date<Utc>, end: Date<Utc>) -> CalendarEvent {
+        CalendarEvent {
+            label: label.into(),
+            start,
+            end,
+        }
+    }
+
+    pub fn on<S: Into<String>>(label: S, date: Date<Utc>) -> CalendarEvent {
+        CalendarEvent::new(label, date, date)
+    }
+
+    fn overlaps(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> bool {
+        self.start.and_hms(0, 0, 0) < end && start < self.end.succ().and_hms(0, 0, 0)
+    }
+}
+
+impl Calendar for [CalendarEvent] {
+    fn labels(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<String> {
+        self.iter()
+            .filter(|event| event.overlaps(start, end))
+            .map(|event| event.label.clone())
+            .collect()
+    }
+}
+
+impl Calendar for Vec<CalendarEvent> {
+    fn labels(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<String> {
+        self.as_slice().labels(start, end)
+    }
+}
+
+/// A series along with the calendar labels of each of its points, `labels[i]` belongs to
+/// `data.entries[i]`.
+#[derive(Debug, Clone)]
+pub struct AnnotatedTimeSeries {
+    pub data: TimeSeriesData,
+    pub labels: Vec<Vec<String>>,
+}
+
+impl AnnotatedTimeSeries {
+    /// The annotated points only.
+    pub fn annotated(&self) -> impl Iterator<Item = (&TimeSeriesEntry, &[String])> {
+        self.data
+            .entries
+            .iter()
+            .zip(&self.labels)
+            .filter(|(_, labels)| !labels.is_empty())
+            .map(|(entry, labels)| (entry, labels.as_slice()))
+    }
+
+    /// Labels of the point at `time`, empty if there is no such point.
+    pub fn labels_at(&self, time: DateTime<Utc>) -> &[String] {
+        self.data
+            .entries
+            .iter()
+            .position(|entry| entry.time == time)
+            .map_or(&[][..], |index| self.labels[index].as_slice())
+    }
+}
+
+impl TimeSeriesData {
+    /// Labels every point with the events of `calendar` that overlap its bucket, which runs until the
+    /// next point (the last one is as long as the one before it).
+    pub fn annotate<C: Calendar + ?Sized>(&self, calendar: &C) -> AnnotatedTimeSeries {
+        let labels = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| calendar.labels(entry.time, bucket_end(&self.entries, index)))
+            .collect();
+        AnnotatedTimeSeries {
+            data: self.clone(),
+            labels,
+        }
+    }
+}
+
+/// End of the bucket of `entries[index]`, see `TimeSeriesData::annotate`.
+pub(crate) fn bucket_end(entries: &[TimeSeriesEntry], index: usize) -> DateTime<Utc> {
+    let time = entries[index].time;
+    match (entries.get(index + 1), index.checked_sub(1)) {
+        (Some(next), _) => next.time,
+        (None, Some(prev)) => time + (time - entries[prev].time),
+        (None, None) => time + chrono::Duration::days(1),
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use futures_util::future::{self, Either};
+use futures_util::pin_mut;
+
+use crate::Error;
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    next_key: AtomicU64,
+    // one slot per pending `Cancelled`, removed again when it's dropped
+    wakers: Mutex<HashMap<u64, Waker>>,
+}
+
+/// A runtime-agnostic shutdown signal. Clones share the same state, cancelling any of them
+/// makes requests in flight and all later requests of the client it's installed on fail
+/// with `Error::Cancelled`.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        for (_, waker) in self.inner.wakers.lock().unwrap().drain() {
+            waker.wake();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Resolves once the token is cancelled.
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled { token: self, key: None }
+    }
+
+    pub(crate) async fn run<T, F: Future<Output = Result<T, Error>>>(&self, fut: F) -> Result<T, Error> {
+        if self.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+        let cancelled = self.cancelled();
+        pin_mut!(fut);
+        match future::select(fut, cancelled).await {
+            Either::Left((result, _)) => result,
+            Either::Right(_) => Err(Error::Cancelled),
+        }
+    }
+}
+
+pub struct Cancelled<'a> {
+    token: &'a CancellationToken,
+    key: Option<u64>,
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let token = self.token;
+        let inner = &token.inner;
+        if token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        let key = match self.key {
+            Some(key) => key,
+            None => inner.next_key.fetch_add(1, Ordering::Relaxed),
+        };
+        let mut wakers = inner.wakers.lock().unwrap();
+        if !wakers.get(&key).map_or(false, |waker| waker.will_wake(cx.waker())) {
+            wakers.insert(key, cx.waker().clone());
+        }
+        drop(wakers);
+        self.key = Some(key);
+        // the token might have been cancelled between the check and registering the waker
+        if token.is_cancelled() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for Cancelled<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.token.inner.wakers.lock().unwrap().remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::future::FutureExt;
+    use futures_util::task::noop_waker_ref;
+
+    #[test]
+    fn run_fails_fast_once_cancelled() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let result = token.run(future::ready(Ok::<_, Error>(1))).now_or_never();
+        assert!(matches!(result, Some(Err(Error::Cancelled))));
+    }
+
+    #[test]
+    fn run_interrupts_a_pending_future() {
+        let token = CancellationToken::new();
+        let fut = token.run(future::pending::<Result<(), Error>>());
+        pin_mut!(fut);
+        let mut cx = Context::from_waker(noop_waker_ref());
+
+        assert!(fut.as_mut().poll(&mut cx).is_pending());
+        token.cancel();
+        assert!(matches!(fut.poll(&mut cx), Poll::Ready(Err(Error::Cancelled))));
+    }
+
+    #[test]
+    fn finished_runs_leave_no_wakers_behind() {
+        let token = CancellationToken::new();
+        let mut cx = Context::from_waker(noop_waker_ref());
+        for _ in 0..100 {
+            let fut = token.run(future::pending::<Result<(), Error>>());
+            pin_mut!(fut);
+            assert!(fut.as_mut().poll(&mut cx).is_pending());
+            assert!(fut.as_mut().poll(&mut cx).is_pending());
+        }
+        assert!(token.inner.wakers.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn run_passes_results_through() {
+        let token = CancellationToken::new();
+        let result = token.run(future::ready(Ok::<_, Error>(1))).now_or_never();
+        assert!(matches!(result, Some(Ok(1))));
+        assert!(!token.is_cancelled());
+    }
+}
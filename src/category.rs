@@ -0,0 +1,180 @@
+use serde::Deserialize;
+
+use crate::Category;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CategoryNode {
+    pub name: String,
+    pub id: u32,
+    #[serde(default)]
+    pub children: Vec<CategoryNode>,
+}
+
+impl CategoryNode {
+    pub fn category(&self) -> Category {
+        Category::from_id(self.id)
+    }
+
+    fn descendants(&self) -> Vec<(&CategoryNode, Vec<&str>)> {
+        let mut result = vec![];
+        let mut stack = vec![(self, vec![])];
+        while let Some((node, path)) = stack.pop() {
+            for child in node.children.iter().rev() {
+                let mut child_path: Vec<&str> = path.clone();
+                child_path.push(&child.name);
+                stack.push((child, child_path));
+            }
+            result.push((node, path));
+        }
+        result
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(transparent)]
+pub struct CategoryTree {
+    pub root: CategoryNode,
+}
+
+impl CategoryTree {
+    /// Looks a category up by name, ignoring case and punctuation. A path such as
+    /// "Autos & Vehicles > Motorcycles" is resolved one segment at a time. When there is no
+    /// exact match the closest name by edit distance is returned, if it's reasonably close. Names without
+    /// any letters or digits match nothing.
+    pub fn find(&self, name: &str) -> Option<&CategoryNode> {
+        if name.contains('>') {
+            return name.split('>').try_fold(&self.root, |node, segment| {
+                Self::best_match(node.children.iter(), segment)
+            });
+        }
+        let nodes = self.root.descendants().into_iter().map(|(node, _)| node);
+        Self::best_match(nodes, name)
+    }
+
+    pub fn find_by_id(&self, id: u32) -> Option<&CategoryNode> {
+        self.root
+            .descendants()
+            .into_iter()
+            .map(|(node, _)| node)
+            .find(|node| node.id == id)
+    }
+
+    pub fn path(&self, id: u32) -> Option<Vec<&str>> {
+        self.root
+            .descendants()
+            .into_iter()
+            .find(|(node, _)| node.id == id)
+            .map(|(_, path)| path)
+    }
+
+    fn best_match<'a, I: Iterator<Item = &'a CategoryNode>>(nodes: I, name: &str) -> Option<&'a CategoryNode> {
+        let needle = normalize(name);
+        // every name would contain it
+        if needle.is_empty() {
+            return None;
+        }
+        let mut best: Option<(usize, &CategoryNode)> = None;
+        for node in nodes {
+            let candidate = normalize(&node.name);
+            let score = if candidate == needle {
+                0
+            } else if candidate.contains(&needle) {
+                1
+            } else {
+                2 + levenshtein(&candidate, &needle)
+            };
+            if best.map_or(true, |(best_score, _)| score < best_score) {
+                best = Some((score, node));
+            }
+        }
+        let max_distance = 2 + needle.chars().count() / 3;
+        best.filter(|(score, _)| *score <= max_distance).map(|(_, node)| node)
+    }
+}
+
+fn normalize(str: &str) -> String {
+    str.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                prev
+            } else {
+                1 + prev.min(row[j]).min(row[j + 1])
+            };
+            prev = current;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree() -> CategoryTree {
+        serde_json::from_str(
+            r#"{"name":"All categories","id":0,"children":[
+                {"name":"Autos & Vehicles","id":47,"children":[
+                    {"name":"Motorcycles","id":273},
+                    {"name":"Vehicle Brands","id":815}
+                ]},
+                {"name":"Computers & Electronics","id":5,"children":[{"name":"Programming","id":31}]}
+            ]}"#,
+        )
+        .unwrap()
+    }
+
+    fn find(name: &str) -> Option<u32> {
+        tree().find(name).map(|node| node.id)
+    }
+
+    #[test]
+    fn find_ignores_case_and_punctuation() {
+        assert_eq!(find("AUTOS & VEHICLES"), Some(47));
+        assert_eq!(find("autos, vehicles"), Some(47));
+        assert_eq!(find("Program"), Some(31));
+    }
+
+    #[test]
+    fn find_tolerates_typos() {
+        assert_eq!(find("Motorcyles"), Some(273));
+        assert_eq!(find("vehicle brand"), Some(815));
+    }
+
+    #[test]
+    fn find_resolves_paths_one_segment_at_a_time() {
+        assert_eq!(find("Autos & Vehicles > Motorcycles"), Some(273));
+        assert_eq!(find("computers>programming"), Some(31));
+        assert_eq!(find("Computers > Motorcycles"), None);
+        assert_eq!(find("Autos & Vehicles >"), None);
+    }
+
+    #[test]
+    fn find_rejects_distant_and_empty_names() {
+        assert_eq!(find("Gardening"), None);
+        assert_eq!(find("zzz"), None);
+        assert_eq!(find(""), None);
+        assert_eq!(find(" & "), None);
+    }
+
+    #[test]
+    fn ids_resolve_to_nodes_and_paths() {
+        let tree = tree();
+        assert_eq!(tree.find_by_id(815).unwrap().name, "Vehicle Brands");
+        assert_eq!(tree.path(273).unwrap(), ["Autos & Vehicles", "Motorcycles"]);
+        assert_eq!(tree.path(0).unwrap(), Vec::<&str>::new());
+        assert!(tree.find_by_id(1).is_none());
+    }
+}
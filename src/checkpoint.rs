@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::hash::Fnv;
+use crate::Error;
+
+/// The progress of a long pull, saved to `path` after every window so that a run that crashed or got
+/// banned picks up where it stopped. Calls on a client from `TrendsClient::with_checkpoint` take the
+/// widgets they fetched already from it, explore request included, which makes the fan-out methods
+/// (`interest_by_time_per_geo`, `interest_by_time_anchored`, `compare_sources`, `seasonality`,
+/// `rising_digest`, `drill_down_regions`) resumable: a repeated call only fetches what is missing.
+///
+/// Windows stay in the checkpoint until it's removed, a window from an earlier pull is never fetched
+/// again. Remove the checkpoint once the pull is through, or when its data should be refreshed.
+#[derive(Debug)]
+pub struct Checkpoint {
+    path: PathBuf,
+    state: Mutex<CheckpointState>,
+}
+
+/// What a `Checkpoint` keeps on disk: the response bodies of the finished windows, keyed by a hash
+/// of the query, locale, timezone, widget and its settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CheckpointState {
+    pub windows: BTreeMap<String, String>,
+}
+
+impl Checkpoint {
+    /// Resumes the checkpoint at `path`, or starts an empty one when there's no file yet.
+    pub fn open<P: Into<PathBuf>>(path: P) -> Result<Checkpoint, Error> {
+        let path = path.into();
+        let state = match File::open(&path) {
+            Ok(file) => serde_json::from_reader(file)?,
+            Err(err) if err.kind() == ErrorKind::NotFound => CheckpointState::default(),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Checkpoint {
+            path,
+            state: Mutex::new(state),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// How many windows are done.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().windows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn state(&self) -> CheckpointState {
+        self.state.lock().unwrap().clone()
+    }
+
+    /// Forgets every window and deletes the file.
+    pub fn remove(&self) -> Result<(), Error> {
+        self.state.lock().unwrap().windows.clear();
+        match fs::remove_file(&self.path) {
+            Err(err) if err.kind() != ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn window(parts: &[&str]) -> String {
+        let mut hasher = Fnv::new();
+        for part in parts {
+            hasher.str(part);
+        }
+        format!("{:016x}", hasher.finish())
+    }
+
+    pub(crate) fn get(&self, window: &str) -> Option<String> {
+        self.state.lock().unwrap().windows.get(window).cloned()
+    }
+
+    pub(crate) fn insert(&self, window: String, body: String) -> Result<(), Error> {
+        let mut state = self.state.lock().unwrap();
+        state.windows.insert(window, body);
+        save(&self.path, &*state)
+    }
+}
+
+// the previous file stays in place until the new one is complete
+pub(crate) fn save<T: Serialize>(path: &Path, value: &T) -> Result<(), Error> {
+    let tmp = path.with_extension("tmp");
+    serde_json::to_writer(File::create(&tmp)?, value)?;
+    fs::rename(tmp, path)?;
+    Ok(())
+}
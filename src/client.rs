@@ -1,63 +1,136 @@
-use reqwest::header::HeaderValue;
-use reqwest::{Client, Method, Request, Response, StatusCode};
-use serde::de::DeserializeOwned;
-use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
 
-use crate::*;
+use chrono::{Date, DateTime, TimeZone, Utc};
+use futures_util::future::{self, Either};
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+use reqwest::header::{self, HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Client, ClientBuilder, Method, Request, Response, StatusCode};
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(untagged)]
-enum Feature {
-    DataRequest(RequestParameters),
-    Other { id: String },
-}
+use crate::batchexecute::{self, RpcCall, RpcResponse};
+use crate::clock::{Clock, Sleeper, SystemClock};
+use crate::progress::Tracker;
+use crate::session::{Session, Sessions};
+#[cfg(feature = "testing")]
+use crate::testing::{Cassette, CassetteMode, Interaction};
+use crate::*;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone)]
 struct RequestParameters {
     token: String,
     id: String,
-    request: serde_json::Value,
+    request: WidgetRequest,
+    // the locale of the explore request when it differs from the client's
+    locale: Option<String>,
 }
 
 impl RequestParameters {
-    fn resolution(&mut self, resolution: Resolution) -> Result<(), serde_json::Error> {
-        self.request["resolution"] = serde_json::to_value(resolution)?;
-        Ok(())
+    fn from_descriptor(desc: &WidgetDescriptor) -> Result<RequestParameters, Error> {
+        let missing = || Error::UnexpectedResponse(format!("Widget {} has no request", desc.id));
+        let token = desc.token.clone().ok_or_else(missing)?;
+        let request = desc.request.clone().ok_or_else(missing)?;
+        Ok(RequestParameters {
+            token,
+            id: desc.id.clone(),
+            request: serde_json::from_value(request)?,
+            locale: None,
+        })
+    }
+
+    fn resolution(&mut self, resolution: Resolution) {
+        self.request.resolution = Some(WidgetResolution::Geo(resolution));
+    }
+
+    fn source(&mut self, source: Source) {
+        self.request.request_options.property = Some(source);
     }
 
-    fn source(&mut self, source: Source) -> Result<(), serde_json::Error> {
-        self.request["requestOptions"]["property"] = serde_json::to_value(source)?;
-        Ok(())
+    fn category(&mut self, category: Category) {
+        self.request.request_options.category = Some(category);
     }
 
-    fn category(&mut self, category: Category) -> Result<(), serde_json::Error> {
-        self.request["requestOptions"]["category"] = serde_json::to_value(category)?;
-        Ok(())
+    fn include_low_volume_geos(&mut self, include: bool) {
+        self.request.include_low_search_volume_geos = Some(include);
     }
 
-    fn include_low_volume_geos(&mut self, include: bool) -> Result<(), serde_json::Error> {
-        self.request["includeLowSearchVolumeGeos"] = serde_json::to_value(include)?;
-        Ok(())
+    // related searches widgets are restricted to a single keyword instead of comparing items
+    fn keyword_count(&self) -> usize {
+        match self.request.rest.get("comparisonItem") {
+            Some(serde_json::Value::Array(items)) => items.len(),
+            _ => 1,
+        }
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+// The widget request is echoed back to Google, so anything not modelled here is carried over untouched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WidgetRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    resolution: Option<WidgetResolution>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    include_low_search_volume_geos: Option<bool>,
+    #[serde(default)]
+    request_options: RequestOptions,
+    #[serde(flatten)]
+    rest: serde_json::Map<String, serde_json::Value>,
+}
+
+// Time series widgets use the same field for their time granularity (e.g. "WEEK").
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum WidgetResolution {
+    Geo(Resolution),
+    Other(String),
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct RequestOptions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    property: Option<Source>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    category: Option<Category>,
+    #[serde(flatten)]
+    rest: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct ExploreResponse {
-    widgets: Vec<Feature>,
+    widgets: Vec<WidgetDescriptor>,
+    #[serde(skip)]
+    locale: Option<String>,
+    // the rest of the explore page, named so strict mode only flags fields Google adds
+    #[serde(default)]
+    keywords: IgnoredAny,
+    #[serde(default)]
+    time_ranges: IgnoredAny,
+    #[serde(default)]
+    examples: IgnoredAny,
+    #[serde(default)]
+    share_text: IgnoredAny,
+    #[serde(default)]
+    should_show_multi_heat_map_message: IgnoredAny,
 }
 
 impl ExploreResponse {
-    fn get_request(&self, search: SearchType) -> Option<&RequestParameters> {
-        let id = match search {
-            SearchType::TimeSeries => "TIMESERIES",
-            SearchType::Region => "GEO_MAP",
-            SearchType::RelatedTopics => "RELATED_TOPICS",
-            SearchType::RelatedQueries => "RELATED_QUERIES",
-        };
-        self.widgets.iter().find_map(|item| match item {
-            Feature::DataRequest(desc) if id == desc.id => Some(desc),
-            _ => None,
-        })
+    fn get_request(&self, id: &str) -> Result<RequestParameters, Error> {
+        let desc = self
+            .widgets
+            .iter()
+            .find(|desc| desc.id == id && desc.token.is_some() && desc.request.is_some())
+            .ok_or_else(|| Error::UnexpectedResponse("Search feature unavailable".to_owned()))?;
+        let mut params = RequestParameters::from_descriptor(desc)?;
+        params.locale = self.locale.clone();
+        Ok(params)
     }
 }
 
@@ -66,123 +139,2573 @@ struct TimeSeriesResponse {
     default: TimeSeriesData,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+struct MultirangeResponse {
+    default: MultirangeData,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 struct GeoDataResponse {
     default: RegionData,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+struct RelatedSearchesResponse<A> {
+    default: RankedLists<A>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RankedLists<A> {
+    ranked_list: Vec<RankedList<A>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RankedList<A> {
+    ranked_keyword: Vec<A>,
+}
+
+impl<A> RankedLists<A> {
+    fn into_top_and_rising(self) -> (Vec<A>, Vec<A>) {
+        let mut lists = self.ranked_list.into_iter().map(|list| list.ranked_keyword);
+        let top = lists.next().unwrap_or_default();
+        let rising = lists.next().unwrap_or_default();
+        (top, rising)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct DailyTrendsResponse {
+    default: DailyTrendsData,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RealtimeTrendsResponse {
+    trending_story_ids: Vec<String>,
+    story_summaries: RealtimeStories,
+    #[serde(default)]
+    featured_story_ids: IgnoredAny,
+    #[serde(default)]
+    date: IgnoredAny,
+    #[serde(default)]
+    hide_all_images: IgnoredAny,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RealtimeStories {
+    trending_stories: Vec<RealtimeStory>,
+    #[serde(default)]
+    featured_stories: IgnoredAny,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StorySummaryResponse {
+    trending_stories: Vec<RealtimeStory>,
+}
+
+// What `realtime_trends_stream` still has to fetch, and what it has handed out.
+#[derive(Default)]
+struct RealtimePages {
+    started: bool,
+    pending: VecDeque<String>,
+    seen: HashSet<String>,
+    refreshed: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct AutocompleteResponse {
+    default: AutocompleteData,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct AutocompleteData {
+    topics: Vec<Topic>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DailyTrendsData {
+    trending_searches_days: Vec<TrendingDay>,
+    #[serde(default)]
+    end_date_for_next_request: IgnoredAny,
+    #[serde(default)]
+    rss_feed_page_url: IgnoredAny,
+}
+
+const DEFAULT_BASE_URL: &str = "https://trends.google.com";
+
+pub(crate) const MAX_ERROR_BODY: usize = 1024;
+
+const DEFAULT_CONSENT_COOKIE: &str = "SOCS=CAESEwgDEgk0ODE3Nzk3MjQaAmVuIAEaBgiA_LyaBg; CONSENT=YES+";
+
+const TRENDING_NOW_RPC: &str = "i0OFE";
+// stories summarized per page of `realtime_trends_stream`, the first page included
+const REALTIME_PAGE_SIZE: usize = 20;
+
+// Trending Now entries are positional: title, _, geo, start, end, _, volume, _, growth, related queries.
+fn trending_now_entry(entry: &serde_json::Value) -> Option<TrendingNow> {
+    let timestamp = |index: usize| {
+        let seconds = entry.get(index)?.get(0)?.as_i64()?;
+        Some(Utc.timestamp(seconds, 0))
+    };
+    Some(TrendingNow {
+        title: entry.get(0)?.as_str()?.to_owned(),
+        search_volume: entry.get(6).and_then(serde_json::Value::as_u64).unwrap_or(0),
+        started: timestamp(3)?,
+        ended: timestamp(4),
+        related_queries: entry
+            .get(9)
+            .and_then(serde_json::Value::as_array)
+            .map(|queries| {
+                queries
+                    .iter()
+                    .filter_map(|query| query.as_str().map(str::to_owned))
+                    .collect()
+            })
+            .unwrap_or_default(),
+    })
+}
+
+/// Anti-XSSI prefixes Google puts in front of JSON responses.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Prefix {
+    /// `)]}'`, sent by explore and realtimetrends.
+    Quote,
+    /// `)]}',`, sent by widgets, pickers, dailytrends, story summaries and autocomplete.
+    QuoteComma,
+}
+
+impl Prefix {
+    fn as_str(self) -> &'static str {
+        match self {
+            Prefix::Quote => ")]}'",
+            Prefix::QuoteComma => ")]}',",
+        }
+    }
+}
+
+/// Strips the prefix an endpoint is expected to send. Endpoints have switched between the two before,
+/// so the other one is accepted as well, and so is a body without a prefix as long as it is JSON.
+fn strip_prefix(body: &str, expected: Prefix) -> Result<&str, Error> {
+    let json = match body.strip_prefix(expected.as_str()) {
+        // `)]}'` followed by a comma is the other prefix
+        Some(rest) if !(expected == Prefix::Quote && rest.starts_with(',')) => rest,
+        _ => {
+            let found = [Prefix::QuoteComma, Prefix::Quote]
+                .iter()
+                .find_map(|prefix| body.strip_prefix(prefix.as_str()));
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                expected = expected.as_str(),
+                found = found.is_some(),
+                "unexpected response prefix"
+            );
+            found.unwrap_or(body)
+        }
+    };
+    match json.trim_start().chars().next() {
+        Some('{') | Some('[') => Ok(json),
+        _ => Err(Error::UnexpectedResponse(format!(
+            "Expected JSON after {}, got {:?}",
+            expected.as_str(),
+            body.chars().take(32).collect::<String>()
+        ))),
+    }
+}
+
+/// Spaces requests evenly, each one takes the next free slot and sleeps until it comes up.
+struct RateLimit {
+    interval: chrono::Duration,
+    sleeper: Arc<dyn Sleeper>,
+    next: Mutex<Option<DateTime<Utc>>>,
+}
+
+impl RateLimit {
+    async fn wait(&self, now: DateTime<Utc>) {
+        let slot = {
+            let mut next = self.next.lock().unwrap();
+            let slot = next.map_or(now, |next| next.max(now));
+            *next = Some(slot + self.interval);
+            slot
+        };
+        if let Ok(delay) = (slot - now).to_std() {
+            if delay > Duration::from_secs(0) {
+                self.sleeper.sleep(delay).await;
+            }
+        }
+    }
+}
+
+/// Opens after `threshold` consecutive blocked (429/403) responses and stays open for `cooldown`. After
+/// that it's half-open: a single request probes whether Google still blocks, a blocked probe opens it
+/// again and any other response closes it.
+struct CircuitBreaker {
+    threshold: u32,
+    cooldown: chrono::Duration,
+    state: Mutex<CircuitState>,
+}
+
+#[derive(Default)]
+struct CircuitState {
+    failures: u32,
+    open_until: Option<DateTime<Utc>>,
+    probing: bool,
+}
+
+// Held by the request probing a half-open breaker. A probe that fails before a response or is dropped
+// releases the breaker for the next request to probe.
+struct Probe<'a> {
+    breaker: &'a CircuitBreaker,
+}
+
+impl Drop for Probe<'_> {
+    fn drop(&mut self) {
+        self.breaker.state.lock().unwrap().probing = false;
+    }
+}
+
+impl CircuitBreaker {
+    fn check(&self, now: DateTime<Utc>) -> Result<Option<Probe<'_>>, Error> {
+        let mut state = self.state.lock().unwrap();
+        let open_until = state.open_until;
+        match open_until {
+            Some(until) if now < until || state.probing => Err(Error::CircuitOpen { until }),
+            Some(_) => {
+                state.probing = true;
+                Ok(Some(Probe { breaker: self }))
+            }
+            None => Ok(None),
+        }
+    }
+
+    // `status` is `None` for requests that failed without a response
+    fn record(&self, status: Option<StatusCode>, probe: Option<Probe<'_>>, now: DateTime<Utc>) {
+        let mut state = self.state.lock().unwrap();
+        // responses to requests sent before the breaker opened don't change it
+        if probe.is_none() && state.open_until.is_some() {
+            return;
+        }
+        match status {
+            Some(StatusCode::TOO_MANY_REQUESTS) | Some(StatusCode::FORBIDDEN) => {
+                state.failures += 1;
+                if state.failures >= self.threshold || probe.is_some() {
+                    state.failures = 0;
+                    state.open_until = Some(now + self.cooldown);
+                }
+            }
+            Some(_) => {
+                state.failures = 0;
+                state.open_until = None;
+            }
+            None => {}
+        }
+    }
+}
+
+struct Hedge {
+    delay: Duration,
+    sleeper: Arc<dyn Sleeper>,
+}
+
+struct RateLimitedBackoff {
+    backoff: Duration,
+    sleeper: Arc<dyn Sleeper>,
+}
+
+struct TransientRetry {
+    attempts: u32,
+    backoff: Duration,
+    sleeper: Arc<dyn Sleeper>,
+}
+
+struct Config {
+    locale: String,
+    locale_set: bool,
+    tz: String,
+    base_url: String,
+    max_concurrency: usize,
+    retry_rate_limited: bool,
+    consent_cookie: Option<HeaderValue>,
+    consent_required: AtomicBool,
+    #[cfg(not(target_arch = "wasm32"))]
+    timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    deadline: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    max_body_size: Option<usize>,
+    #[cfg(not(target_arch = "wasm32"))]
+    token_cache: Option<TokenCache>,
+    metrics: Option<Arc<dyn MetricsRecorder>>,
+    debug_dump: Option<Arc<dyn DebugSink>>,
+    clock: Arc<dyn Clock>,
+    cancellation: Option<CancellationToken>,
+    default_source: Source,
+    default_category: Category,
+    rate_limit: Option<RateLimit>,
+    rate_limited_backoff: Option<RateLimitedBackoff>,
+    transient_retry: Option<TransientRetry>,
+    circuit_breaker: Option<CircuitBreaker>,
+    hedge: Option<Hedge>,
+    request_counter: AtomicU64,
+    #[cfg(feature = "strict")]
+    strict: bool,
+    #[cfg(feature = "testing")]
+    cassette: Option<Arc<Cassette>>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            locale: "en-US".to_owned(),
+            locale_set: false,
+            tz: "0".to_owned(),
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            max_concurrency: 4,
+            retry_rate_limited: true,
+            consent_cookie: Some(HeaderValue::from_static(DEFAULT_CONSENT_COOKIE)),
+            consent_required: AtomicBool::new(false),
+            #[cfg(not(target_arch = "wasm32"))]
+            timeout: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            deadline: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            max_body_size: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            token_cache: None,
+            metrics: None,
+            debug_dump: None,
+            clock: Arc::new(SystemClock),
+            cancellation: None,
+            default_source: Source::Search,
+            default_category: Category::All,
+            rate_limit: None,
+            rate_limited_backoff: None,
+            transient_retry: None,
+            circuit_breaker: None,
+            hedge: None,
+            request_counter: AtomicU64::new(0),
+            #[cfg(feature = "strict")]
+            strict: false,
+            #[cfg(feature = "testing")]
+            cassette: None,
+        }
+    }
+}
+
+const STEALTH_USER_AGENT: &str =
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/90.0.4430.93 Safari/537.36";
+
+type HttpOption = Box<dyn Fn(ClientBuilder) -> ClientBuilder + Send + Sync>;
+
+pub struct TrendsClientBuilder {
+    http: Vec<HttpOption>,
+    headers: HeaderMap,
+    sessions: Option<SessionPool>,
+    config: Config,
+}
+
+impl Default for TrendsClientBuilder {
+    fn default() -> Self {
+        TrendsClientBuilder {
+            http: vec![],
+            headers: HeaderMap::new(),
+            sessions: None,
+            config: Config::default(),
+        }
+    }
+}
+
+impl TrendsClientBuilder {
+    /// The `hl` sent with requests, `en-US` by default. With the `detect-language` feature a client whose
+    /// locale isn't set picks it per query from the language of the keywords, when that is clear.
+    pub fn locale<S: Into<String>>(mut self, locale: S) -> Self {
+        self.config.locale = locale.into();
+        self.config.locale_set = true;
+        self
+    }
+
+    /// Timezone offset in minutes the way JavaScript's `getTimezoneOffset` reports it, e.g. 300 for
+    /// US Eastern. It only affects the formatted times, timestamps are always UTC.
+    pub fn timezone_offset(mut self, minutes: i32) -> Self {
+        self.config.tz = minutes.to_string();
+        self
+    }
+
+    pub fn base_url<S: Into<String>>(mut self, base_url: S) -> Self {
+        self.config.base_url = base_url.into();
+        self
+    }
+
+    /// Adds a header sent with every request.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Makes requests look like they come from a desktop browser on the Trends page: a fixed Chrome
+    /// user agent plus the accept, language and referer headers it would send, in that order.
+    /// Headers set with `header` take precedence over the preset.
+    pub fn stealth(mut self) -> Self {
+        let language = HeaderValue::from_str(&self.config.locale).unwrap_or_else(|_| HeaderValue::from_static("en-US"));
+        let mut headers = HeaderMap::new();
+        headers.insert(header::USER_AGENT, HeaderValue::from_static(STEALTH_USER_AGENT));
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/json, text/plain, */*"),
+        );
+        headers.insert(header::ACCEPT_LANGUAGE, language);
+        headers.insert(
+            header::REFERER,
+            HeaderValue::from_static("https://trends.google.com/trends/explore"),
+        );
+        headers.extend(self.headers.drain());
+        self.headers = headers;
+        self
+    }
+
+    /// When disabled, a 429 response fails with `Error::RateLimited` carrying the session cookie Google
+    /// handed out and its `Retry-After` hint, instead of being retried once with that cookie.
+    pub fn retry_rate_limited(mut self, retry: bool) -> Self {
+        self.config.retry_rate_limited = retry;
+        self
+    }
+
+    /// Waits before retrying a 429 response, for as long as its `Retry-After` asks or `backoff` when it
+    /// doesn't say. Without it the retry goes out right away.
+    pub fn rate_limited_backoff(mut self, backoff: Duration, sleeper: Arc<dyn Sleeper>) -> Self {
+        self.config.rate_limited_backoff = Some(RateLimitedBackoff { backoff, sleeper });
+        self
+    }
+
+    /// The cookies sent once Google redirects a request to its consent page, as it does for EU IPs. By
+    /// default they reject all optional cookies. `None` turns the handling off, consent redirects then
+    /// end up as unparseable responses.
+    pub fn consent_cookie(mut self, cookie: Option<HeaderValue>) -> Self {
+        self.config.consent_cookie = cookie;
+        self
+    }
+
+    /// Maximum number of requests issued at once by the fan-out methods.
+    pub fn max_concurrency(mut self, max: usize) -> Self {
+        self.config.max_concurrency = max.max(1);
+        self
+    }
+
+    /// Sends all requests through `proxy`, sessions from a `SessionPool` with a proxy of their own
+    /// keep using theirs.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.http.push(Box::new(move |http| http.proxy(proxy.clone())));
+        self
+    }
+
+    /// Keeps outgoing requests (retries included) under `requests_per_minute`, waiting with `sleeper`.
+    pub fn rate_limit(mut self, requests_per_minute: u32, sleeper: Arc<dyn Sleeper>) -> Self {
+        self.config.rate_limit = Some(RateLimit {
+            interval: chrono::Duration::milliseconds(60_000 / i64::from(requests_per_minute.max(1))),
+            sleeper,
+            next: Mutex::new(None),
+        });
+        self
+    }
+
+    /// Retries requests failing with a transient network error (`Error::is_transient`) up to `attempts`
+    /// times, waiting `backoff` before the first retry and twice as long before each one after that.
+    pub fn retry_transient(mut self, attempts: u32, backoff: Duration, sleeper: Arc<dyn Sleeper>) -> Self {
+        self.config.transient_retry = Some(TransientRetry {
+            attempts,
+            backoff,
+            sleeper,
+        });
+        self
+    }
+
+    /// After `threshold` consecutive 429 or 403 responses, fail every request with `Error::CircuitOpen`
+    /// for `cooldown` instead of sending it, so a blocked IP isn't kept hammering Google. Then one request
+    /// is let through to probe: if it's blocked too the breaker opens for another `cooldown`, otherwise it
+    /// closes. Requests made while the probe is out fail with `Error::CircuitOpen` as well.
+    pub fn circuit_breaker(mut self, threshold: u32, cooldown: Duration) -> Self {
+        self.config.circuit_breaker = Some(CircuitBreaker {
+            threshold: threshold.max(1),
+            cooldown: chrono::Duration::from_std(cooldown).unwrap_or_else(|_| chrono::Duration::max_value()),
+            state: Mutex::new(CircuitState::default()),
+        });
+        self
+    }
+
+    /// Sends a duplicate of every widget request that hasn't completed after `delay` and takes whichever
+    /// succeeds first. Cuts the tail latency of slow Google edges at the price of extra requests. Requests
+    /// that fail before `delay` aren't duplicated, and once both are out only a transient failure of the
+    /// first one waits for the duplicate.
+    pub fn hedge_requests(mut self, delay: Duration, sleeper: Arc<dyn Sleeper>) -> Self {
+        self.config.hedge = Some(Hedge { delay, sleeper });
+        self
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http.push(Box::new(move |http| http.timeout(timeout)));
+        self.config.timeout = Some(timeout);
+        self
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http.push(Box::new(move |http| http.connect_timeout(timeout)));
+        self
+    }
+
+    /// How long idle connections are kept in the pool, `None` keeps them until the server closes them.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.http.push(Box::new(move |http| http.pool_idle_timeout(timeout)));
+        self
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.http.push(Box::new(move |http| http.pool_max_idle_per_host(max)));
+        self
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.http.push(Box::new(move |http| http.tcp_keepalive(interval)));
+        self
+    }
+
+    /// Talks HTTP/2 right away instead of negotiating it, Google's frontends support it.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.http.push(Box::new(|http| http.http2_prior_knowledge()));
+        self
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn http1_only(mut self) -> Self {
+        self.http.push(Box::new(|http| http.http1_only()));
+        self
+    }
+
+    /// Upper bound on the time spent on a single request, including the retry after a 429.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn deadline(mut self, deadline: Duration) -> Self {
+        self.config.deadline = Some(deadline);
+        self
+    }
+
+    #[cfg(all(feature = "gzip", not(target_arch = "wasm32")))]
+    pub fn gzip(mut self, enable: bool) -> Self {
+        self.http.push(Box::new(move |http| http.gzip(enable)));
+        self
+    }
+
+    #[cfg(all(feature = "brotli", not(target_arch = "wasm32")))]
+    pub fn brotli(mut self, enable: bool) -> Self {
+        self.http.push(Box::new(move |http| http.brotli(enable)));
+        self
+    }
+
+    /// Responses with a body larger than `limit` bytes fail with `Error::ResponseTooLarge`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn max_body_size(mut self, limit: usize) -> Self {
+        self.config.max_body_size = Some(limit);
+        self
+    }
+
+    /// Reuses the widgets of explore requests made by earlier processes, see `TokenCache`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn token_cache(mut self, cache: TokenCache) -> Self {
+        self.config.token_cache = Some(cache);
+        self
+    }
+
+    /// Spreads requests over the sessions of `pool` instead of a single HTTP client.
+    /// Every session is built with the HTTP options configured on this builder.
+    pub fn session_pool(mut self, pool: SessionPool) -> Self {
+        self.sessions = Some(pool);
+        self
+    }
+
+    pub fn metrics_recorder<M: MetricsRecorder + 'static>(mut self, recorder: M) -> Self {
+        self.config.metrics = Some(Arc::new(recorder));
+        self
+    }
+
+    /// Sends the URL (with the widget token removed) and body of every successful response to `sink`.
+    pub fn debug_dump<D: DebugSink + 'static>(mut self, sink: D) -> Self {
+        self.config.debug_dump = Some(Arc::new(sink));
+        self
+    }
+
+    /// Replaces the system clock used for benching sessions and measuring latency, mostly useful
+    /// together with `testing::MockClock`.
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.config.clock = clock;
+        self
+    }
+
+    /// Makes every request fail with `Error::Cancelled` once `token` is cancelled, requests in flight
+    /// are dropped. Batch calls then return the error instead of partial results, streams yield it
+    /// as their last item.
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.config.cancellation = Some(token);
+        self
+    }
+
+    /// Source used by calls that don't pick one: the `*_with_opts` methods and `full_report` when their
+    /// options leave it unset. Methods taking a `source` argument, like `related_queries`, always send that.
+    pub fn default_source(mut self, source: Source) -> Self {
+        self.config.default_source = source;
+        self
+    }
+
+    /// Category used by calls that don't pick one, e.g. `Category::Health` for a health analytics product.
+    /// Like `default_source`, it doesn't override a `category` argument.
+    pub fn default_category(mut self, category: Category) -> Self {
+        self.config.default_category = category;
+        self
+    }
+
+    /// Fail with `Error::SchemaDrift` when a response contains fields this crate doesn't know about, meant for
+    /// canary jobs that should notice format changes early. This covers explore, widgets, the category picker,
+    /// autocomplete, daily trends and `RawResponse`.
+    #[cfg(feature = "strict")]
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.config.strict = strict;
+        self
+    }
+
+    #[cfg(feature = "testing")]
+    pub fn cassette(mut self, cassette: Arc<Cassette>) -> Self {
+        self.config.cassette = Some(cassette);
+        self
+    }
+
+    /// Starts from the environment:
+    ///
+    /// * `GOOGLE_TRENDS_LOCALE`, e.g. `en-GB`
+    /// * `GOOGLE_TRENDS_TZ`, the offset in minutes as for `timezone_offset`
+    /// * `GOOGLE_TRENDS_PROXY`, a proxy url for all requests
+    /// * `GOOGLE_TRENDS_RPM`, a limit of requests per minute, waiting requires the `timer` feature
+    /// * `GOOGLE_TRENDS_TIMEOUT`, the request timeout in seconds
+    /// * `GOOGLE_TRENDS_BASE_URL`
+    ///
+    /// Unset variables keep the defaults, malformed ones fail with `Error::InvalidConfig`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_env() -> Result<TrendsClientBuilder, Error> {
+        fn var(name: &str) -> Option<String> {
+            std::env::var(name).ok().filter(|value| !value.trim().is_empty())
+        }
+        fn parse<T: std::str::FromStr>(name: &str) -> Result<Option<T>, Error> {
+            var(name)
+                .map(|value| {
+                    value
+                        .trim()
+                        .parse()
+                        .map_err(|_| Error::InvalidConfig(format!("{} has an invalid value '{}'", name, value)))
+                })
+                .transpose()
+        }
+
+        let mut builder = TrendsClientBuilder::default();
+        if let Some(locale) = var("GOOGLE_TRENDS_LOCALE") {
+            builder = builder.locale(locale);
+        }
+        if let Some(minutes) = parse("GOOGLE_TRENDS_TZ")? {
+            builder = builder.timezone_offset(minutes);
+        }
+        if let Some(proxy) = var("GOOGLE_TRENDS_PROXY") {
+            builder = builder.proxy(reqwest::Proxy::all(&proxy)?);
+        }
+        if let Some(rpm) = parse::<u32>("GOOGLE_TRENDS_RPM")? {
+            #[cfg(feature = "timer")]
+            {
+                builder = builder.rate_limit(rpm, Arc::new(SystemClock));
+            }
+            #[cfg(not(feature = "timer"))]
+            return Err(Error::InvalidConfig(format!(
+                "GOOGLE_TRENDS_RPM={} needs the timer feature",
+                rpm
+            )));
+        }
+        if let Some(secs) = parse("GOOGLE_TRENDS_TIMEOUT")? {
+            builder = builder.timeout(Duration::from_secs(secs));
+        }
+        if let Some(base_url) = var("GOOGLE_TRENDS_BASE_URL") {
+            builder = builder.base_url(base_url);
+        }
+        Ok(builder)
+    }
+
+    pub fn build(mut self) -> Result<TrendsClient, Error> {
+        self.config.base_url = self.config.base_url.trim_end_matches('/').to_owned();
+        let sessions = match self.sessions.take() {
+            Some(pool) => Some(pool.build(|| self.http_builder())?),
+            None => None,
+        };
+        Ok(TrendsClient {
+            client: self.http_builder().build()?,
+            sessions: sessions.map(Arc::new),
+            config: Arc::new(self.config),
+            progress: None,
+            checkpoint: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            operation_deadline: None,
+        })
+    }
+
+    fn http_builder(&self) -> ClientBuilder {
+        let builder = Client::builder().default_headers(self.headers.clone());
+        self.http.iter().fold(builder, |builder, option| option(builder))
+    }
+}
+
+/// The client is `Clone + Send + Sync` and cheap to clone, so a service can keep one in its state and use
+/// it from any number of tasks. Clones share the connection pool, sessions and their cookies, the rate
+/// limit, circuit breaker, consent state and token cache of the original, so limits apply to all of them
+/// together. Only the progress handler (`with_progress`), the checkpoint (`with_checkpoint`) and the
+/// deadline of a running operation belong to a single clone.
+///
+/// None of it is tied to an async runtime: the client spawns no tasks and waits with `futures-timer` or
+/// the configured `Sleeper`, tokio is only a dependency of the CLI. The transport is reqwest's though,
+/// whose connections are driven by tokio's reactor, so network requests have to be polled inside a tokio
+/// runtime context. Under async-std or smol enter one, for instance with `async-compat`. Replayed
+/// cassettes don't touch the network and run on any executor.
+#[derive(Clone)]
 pub struct TrendsClient {
     client: Client,
-    locale: String,
+    sessions: Option<Arc<Sessions>>,
+    config: Arc<Config>,
+    progress: Option<Arc<dyn ProgressHandler>>,
+    checkpoint: Option<Arc<Checkpoint>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    operation_deadline: Option<Instant>,
 }
 
+// fails to compile if a field ever stops the client from being shared across threads
+const _: fn() = || {
+    fn shared<T: Clone + Send + Sync + 'static>() {}
+    shared::<TrendsClient>();
+};
+
 impl TrendsClient {
     pub fn new(locale: String) -> TrendsClient {
+        TrendsClient::builder()
+            .locale(locale)
+            .build()
+            .expect("TrendsClient::new()")
+    }
+
+    /// A clone that reports the progress of its calls to `handler`, see `ProgressHandler`.
+    pub fn with_progress(&self, handler: Arc<dyn ProgressHandler>) -> TrendsClient {
+        TrendsClient {
+            progress: Some(handler),
+            ..self.clone()
+        }
+    }
+
+    /// A clone that takes the time series, regions and related searches it fetched before from
+    /// `checkpoint` and records the ones it fetches in it, see `Checkpoint`.
+    pub fn with_checkpoint(&self, checkpoint: Arc<Checkpoint>) -> TrendsClient {
         TrendsClient {
-            client: Client::new(),
-            locale,
+            checkpoint: Some(checkpoint),
+            ..self.clone()
+        }
+    }
+
+    /// This client, or a clone of it whose requests all have to finish `timeout` from now, so
+    /// that the explore and widget requests of one call share a single deadline.
+    fn within(&self, timeout: Option<Duration>) -> Cow<'_, TrendsClient> {
+        match timeout {
+            #[cfg(not(target_arch = "wasm32"))]
+            Some(timeout) => Cow::Owned(TrendsClient {
+                operation_deadline: Some(Instant::now() + timeout),
+                ..self.clone()
+            }),
+            _ => Cow::Borrowed(self),
         }
     }
 
+    pub(crate) fn progress(&self) -> Option<&dyn ProgressHandler> {
+        self.progress.as_deref()
+    }
+
+    pub(crate) fn clock(&self) -> &dyn Clock {
+        self.config.clock.as_ref()
+    }
+
+    pub(crate) fn default_source(&self) -> Source {
+        self.config.default_source
+    }
+
+    pub(crate) fn default_category(&self) -> Category {
+        self.config.default_category
+    }
+
+    /// A client configured from the `GOOGLE_TRENDS_*` variables, see `TrendsClientBuilder::from_env`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_env() -> Result<TrendsClient, Error> {
+        TrendsClientBuilder::from_env()?.build()
+    }
+
+    pub fn builder() -> TrendsClientBuilder {
+        TrendsClientBuilder::default()
+    }
+
     pub async fn interest_by_time(
         &self,
         query: &Query<'_>,
         source: Source,
         category: Category,
     ) -> Result<TimeSeriesData, Error> {
-        let search = SearchType::TimeSeries;
-        let mut item = self.explore(query, search).await?;
-        item.source(source)?;
-        item.category(category)?;
+        let opts = TimeSeriesOptions::default().source(source).category(category);
+        self.interest_by_time_with_opts(query, &opts).await
+    }
 
-        let resp: TimeSeriesResponse = self.query(&item, search).await?;
+    pub async fn interest_by_time_with_opts(
+        &self,
+        query: &Query<'_>,
+        opts: &TimeSeriesOptions,
+    ) -> Result<TimeSeriesData, Error> {
+        let client = self.within(opts.get_deadline());
+        let settings = Self::window_settings(
+            opts.source_or(self.config.default_source),
+            opts.category_or(self.config.default_category),
+        );
+        let request = client.time_series_request(query, opts);
+        let resp: TimeSeriesResponse = client
+            .query_checkpointed(query, SearchType::TimeSeries, &settings, request)
+            .await?;
         Ok(resp.default)
     }
 
-    pub async fn interest_by_region(
+    /// Interest over time for queries whose items cover different timeframes, like the ones built by
+    /// `Query::by_keyword_across_timeframes`.
+    pub async fn interest_by_time_multirange(
         &self,
         query: &Query<'_>,
-        resolution: Resolution,
-        source: Source,
-        category: Category,
-        include_low_volume_regions: bool,
-    ) -> Result<RegionData, Error> {
-        let search = SearchType::Region;
-        let mut item = self.explore(query, search).await?;
-        item.resolution(resolution)?;
-        item.source(source)?;
-        item.category(category)?;
-        item.include_low_volume_geos(include_low_volume_regions)?;
-
-        let resp: GeoDataResponse = self.query(&item, search).await?;
+        opts: &TimeSeriesOptions,
+    ) -> Result<MultirangeData, Error> {
+        let client = self.within(opts.get_deadline());
+        let item = client.time_series_request(query, opts).await?;
+        let resp: MultirangeResponse = client
+            .query_path(
+                &item,
+                "widgetdata/multirange",
+                Operation::Widget(SearchType::TimeSeries),
+            )
+            .await?;
         Ok(resp.default)
     }
 
-    async fn query<A: DeserializeOwned>(&self, params: &RequestParameters, search: SearchType) -> Result<A, Error> {
-        let req = self
-            .client
-            .request(Method::GET, Self::endpoint(search))
-            .query(&[
-                ("hl", self.locale.as_str()),
-                ("tz", "0"),
-                ("token", &params.token),
-                ("req", &serde_json::to_string(&params.request)?),
+    /// Like `interest_by_time_with_opts`, but hands out the response body for borrowed parsing
+    /// through `RawResponse::time_series`.
+    pub async fn interest_by_time_raw(
+        &self,
+        query: &Query<'_>,
+        opts: &TimeSeriesOptions,
+    ) -> Result<RawResponse, Error> {
+        let client = self.within(opts.get_deadline());
+        let search = SearchType::TimeSeries;
+        let item = client.time_series_request(query, opts).await?;
+        let body = client
+            .query_body(&item, Self::widget_path(search), Operation::Widget(search))
+            .await?;
+        Ok(RawResponse::new(body, self.strict()))
+    }
+
+    async fn time_series_request(
+        &self,
+        query: &Query<'_>,
+        opts: &TimeSeriesOptions,
+    ) -> Result<RequestParameters, Error> {
+        let mut item = self.explore(query, SearchType::TimeSeries.widget_id()).await?;
+        item.source(opts.source_or(self.config.default_source));
+        item.category(opts.category_or(self.config.default_category));
+        Ok(item)
+    }
+
+    /// Fetches the points after `last_seen` for a collector that already holds `known`. The request
+    /// covers a window overlapping the known data by a few buckets, long enough for Google to return
+    /// buckets as far apart as the stored ones, and the new points are rescaled against that overlap
+    /// so they line up with what was stored before. Fails when `last_seen` lies too far back for the
+    /// stored granularity, or when none of the returned buckets overlap the stored ones.
+    pub async fn interest_by_time_since(
+        &self,
+        query: &Query<'_>,
+        last_seen: DateTime<Utc>,
+        known: &LabeledTimeSeries,
+        source: Source,
+        category: Category,
+    ) -> Result<LabeledTimeSeries, Error> {
+        const OVERLAP_BUCKETS: i32 = 4;
+
+        let spacing = known
+            .series
+            .iter()
+            .filter_map(|series| match series.points.as_slice() {
+                [.., prev, last] => Some(last.time - prev.time),
+                _ => None,
+            })
+            .max()
+            .unwrap_or_else(|| chrono::Duration::days(1));
+        let granularity = Granularity::from_step(spacing);
+        let now = self.clock().now();
+        let span = std::cmp::max(now - last_seen + spacing * OVERLAP_BUCKETS, granularity.min_window());
+        if span > granularity.max_window() {
+            return Err(Error::InvalidQuery(format!(
+                "{:?} data last seen at {} can't be updated with one request, fetch it again",
+                granularity, last_seen
+            )));
+        }
+        let window = Timeframe::new(granularity.align((now - span).date()), now.date());
+
+        let fresh = self
+            .interest_by_time(&query.with_timeframe(window), source, category)
+            .await?
+            .labeled(query);
+        let mut series = Vec::with_capacity(fresh.series.len());
+        for fresh in &fresh.series {
+            let mut fresh = match known.get(&fresh.label) {
+                Some(reference) if !fresh.overlaps(reference) => {
+                    return Err(Error::UnexpectedResponse(format!(
+                        "No bucket of '{}' overlaps the stored data",
+                        fresh.label
+                    )))
+                }
+                Some(reference) => fresh.rescaled_to(reference),
+                None => fresh.clone(),
+            };
+            fresh.points.retain(|point| point.time > last_seen);
+            series.push(fresh);
+        }
+        Ok(LabeledTimeSeries { series })
+    }
+
+    pub async fn interest_by_region(
+        &self,
+        query: &Query<'_>,
+        resolution: Resolution,
+        source: Source,
+        category: Category,
+        include_low_volume_regions: bool,
+    ) -> Result<RegionData, Error> {
+        let opts = RegionOptions::default()
+            .resolution(resolution)
+            .source(source)
+            .category(category)
+            .include_low_volume_regions(include_low_volume_regions);
+        self.interest_by_region_with_opts(query, &opts).await
+    }
+
+    pub async fn interest_by_region_with_opts(
+        &self,
+        query: &Query<'_>,
+        opts: &RegionOptions,
+    ) -> Result<RegionData, Error> {
+        let client = self.within(opts.get_deadline());
+        let settings = format!(
+            "{}/{:?}/{}",
+            Self::window_settings(
+                opts.source_or(self.config.default_source),
+                opts.category_or(self.config.default_category)
+            ),
+            opts.get_resolution(),
+            opts.get_include_low_volume_regions()
+        );
+        let request = client.region_request(query, opts);
+        let mut resp: GeoDataResponse = client
+            .query_checkpointed(query, SearchType::Region, &settings, request)
+            .await?;
+        resp.default.sort(opts.get_order());
+        Ok(resp.default)
+    }
+
+    /// Like `interest_by_region_with_opts`, but hands out the response body for borrowed parsing
+    /// through `RawResponse::regions`.
+    pub async fn interest_by_region_raw(&self, query: &Query<'_>, opts: &RegionOptions) -> Result<RawResponse, Error> {
+        let client = self.within(opts.get_deadline());
+        let search = SearchType::Region;
+        let item = client.region_request(query, opts).await?;
+        let body = client
+            .query_body(&item, Self::widget_path(search), Operation::Widget(search))
+            .await?;
+        Ok(RawResponse::new(body, self.strict()))
+    }
+
+    async fn region_request(&self, query: &Query<'_>, opts: &RegionOptions) -> Result<RequestParameters, Error> {
+        for item in query.items() {
+            opts.get_resolution().check_supported(query.geo_of(item))?;
+        }
+
+        let mut item = self.explore(query, SearchType::Region.widget_id()).await?;
+        item.resolution(opts.get_resolution());
+        item.source(opts.source_or(self.config.default_source));
+        item.category(opts.category_or(self.config.default_category));
+        item.include_low_volume_geos(opts.get_include_low_volume_regions());
+        Ok(item)
+    }
+
+    pub async fn interest_by_time_per_geo(
+        &self,
+        keyword: &str,
+        geos: &[&str],
+        time: &Timeframe,
+        source: Source,
+        category: Category,
+    ) -> Result<HashMap<String, TimeSeriesData>, Error> {
+        let requests = geos.iter().map(|geo| async move {
+            let query = Query::new(vec![QueryItem::by_keyword_with_geo(keyword, *geo, time.clone())]);
+            let data = self.interest_by_time(&query, source, category).await?;
+            Ok::<_, Error>((geo.to_string(), data))
+        });
+        let progress = Tracker::start(self.progress(), geos.len());
+        let results: HashMap<String, TimeSeriesData> = stream::iter(requests)
+            .buffer_unordered(self.config.max_concurrency)
+            .inspect_ok(|_| progress.window_done())
+            .try_collect()
+            .await?;
+        progress.finish();
+        Ok(results)
+    }
+
+    /// Fetches any number of `keywords` on one scale: they are split with `Query::chunked_with_anchor`,
+    /// the chunks are fetched concurrently and joined with `LabeledTimeSeries::joined_on`. Without any
+    /// keywords besides the anchor nothing is fetched and the result is empty.
+    pub async fn interest_by_time_anchored(
+        &self,
+        anchor: &str,
+        keywords: &[&str],
+        time: &Timeframe,
+        source: Source,
+        category: Category,
+    ) -> Result<LabeledTimeSeries, Error> {
+        let queries = Query::chunked_with_anchor(anchor, keywords.iter().copied(), time.clone());
+        let requests = queries.iter().map(|query| async move {
+            let data = self.interest_by_time(query, source, category).await?;
+            Ok::<_, Error>(data.labeled(query))
+        });
+        let progress = Tracker::start(self.progress(), queries.len());
+        // in order, the first chunk sets the scale
+        let chunks: Vec<LabeledTimeSeries> = stream::iter(requests)
+            .buffered(self.config.max_concurrency)
+            .inspect_ok(|_| progress.window_done())
+            .try_collect()
+            .await?;
+        progress.finish();
+        LabeledTimeSeries::joined_on(anchor, &chunks)
+    }
+
+    /// Compares `keyword` over two periods in a single request, so both windows share one scale.
+    /// The periods have to be of the same length.
+    pub async fn compare_periods(
+        &self,
+        keyword: &str,
+        period_a: &Timeframe,
+        period_b: &Timeframe,
+        source: Source,
+        category: Category,
+    ) -> Result<PeriodComparison, Error> {
+        if period_a.end() - period_a.start() != period_b.end() - period_b.start() {
+            return Err(Error::InvalidQuery(format!(
+                "Compared periods have to be of the same length, got {} and {}",
+                period_a.formatted(),
+                period_b.formatted()
+            )));
+        }
+        let query = Query::by_keyword_across_timeframes(keyword, &[period_a.clone(), period_b.clone()]);
+        let opts = TimeSeriesOptions::default().source(source).category(category);
+        let data = self.interest_by_time_multirange(&query, &opts).await?;
+        Ok(PeriodComparison::new(&data))
+    }
+
+    /// Fetches `keyword` from every source in `sources` and returns one series per source, labeled
+    /// with `Source::name` and aligned on the timestamps of the first. Each source is scaled to 0-100
+    /// on its own, so the series compare in shape rather than absolute volume.
+    pub async fn compare_sources(
+        &self,
+        keyword: &str,
+        sources: &[Source],
+        geo: Option<&str>,
+        time: &Timeframe,
+        category: Category,
+    ) -> Result<LabeledTimeSeries, Error> {
+        let item = match geo {
+            Some(geo) => QueryItem::by_keyword_with_geo(keyword, geo, time.clone()),
+            None => QueryItem::by_keyword(keyword, time.clone()),
+        };
+        let query = Query::new(vec![item]);
+        let requests = sources.iter().map(|source| {
+            let query = &query;
+            async move {
+                let data = self.interest_by_time(query, *source, category).await?;
+                let mut series = data.labeled(query).series;
+                let points = series.pop().map(|series| series.points).unwrap_or_default();
+                Ok::<_, Error>(LabeledSeries {
+                    label: source.name().to_owned(),
+                    points,
+                })
+            }
+        });
+        let progress = Tracker::start(self.progress(), sources.len());
+        let series = stream::iter(requests)
+            .buffered(self.config.max_concurrency)
+            .inspect_ok(|_| progress.window_done())
+            .try_collect()
+            .await?;
+        progress.finish();
+        Ok(LabeledTimeSeries { series }.aligned())
+    }
+
+    pub async fn youtube_interest_by_time(
+        &self,
+        query: &Query<'_>,
+        category: Category,
+    ) -> Result<TimeSeriesData, Error> {
+        query.check_source(Source::Videos)?;
+        self.interest_by_time(query, Source::Videos, category).await
+    }
+
+    pub async fn youtube_interest_by_region(
+        &self,
+        query: &Query<'_>,
+        resolution: Resolution,
+        category: Category,
+    ) -> Result<RegionData, Error> {
+        query.check_source(Source::Videos)?;
+        self.interest_by_region(query, resolution, Source::Videos, category, false)
+            .await
+    }
+
+    pub async fn youtube_related_queries(
+        &self,
+        query: &Query<'_>,
+        category: Category,
+    ) -> Result<RelatedQueries, Error> {
+        query.check_source(Source::Videos)?;
+        self.related_queries(query, Source::Videos, category).await
+    }
+
+    /// Fetches the last `years` years of shopping interest one year at a time and folds them into
+    /// an average curve by week of the year, along with the strongest weeks.
+    pub async fn seasonality(&self, keyword: &str, geo: Option<&str>, years: u32) -> Result<Seasonality, Error> {
+        let today = self.clock().now().date();
+        let requests = (0..years.max(1)).map(|year| async move {
+            let end = today - chrono::Duration::days(365 * i64::from(year));
+            let time = Timeframe::new(end - chrono::Duration::days(364), end);
+            let item = match geo {
+                Some(geo) => QueryItem::by_keyword_with_geo(keyword, geo, time),
+                None => QueryItem::by_keyword(keyword, time),
+            };
+            self.interest_by_time(&Query::new(vec![item]), Source::Shopping, Category::All)
+                .await
+        });
+        let progress = Tracker::start(self.progress(), years.max(1) as usize);
+        let years: Vec<TimeSeriesData> = stream::iter(requests)
+            .buffered(self.config.max_concurrency)
+            .inspect_ok(|_| progress.window_done())
+            .try_collect()
+            .await?;
+        progress.finish();
+        Ok(Seasonality::from_years(&years))
+    }
+
+    /// The topics Google's autocomplete suggests for `keyword`, best match first.
+    pub async fn suggestions(&self, keyword: &str) -> Result<Vec<Topic>, Error> {
+        self.suggestions_in(keyword, &self.config.locale).await
+    }
+
+    /// Like `suggestions`, with the titles in `locale` instead of the client's locale.
+    pub async fn suggestions_in(&self, keyword: &str, locale: &str) -> Result<Vec<Topic>, Error> {
+        let mut url = reqwest::Url::parse(&self.url("autocomplete"))
+            .map_err(|err| Error::InvalidConfig(format!("Invalid base url: {}", err)))?;
+        url.path_segments_mut()
+            .map_err(|_| Error::InvalidConfig("Invalid base url".to_owned()))?
+            .push(keyword);
+        let req = self
+            .client
+            .request(Method::GET, url)
+            .query(&[("hl", locale), ("tz", self.config.tz.as_str())])
+            .build()?;
+
+        let context = self.request_context(Operation::Autocomplete, 1);
+        async {
+            let body = self.fetch(req, Operation::Autocomplete).await?;
+            let resp: AutocompleteResponse = self.parse_widget(strip_prefix(&body, Prefix::QuoteComma)?)?;
+            Ok(resp.default.topics)
+        }
+        .await
+        .map_err(|err: Error| err.with_context(context))
+    }
+
+    /// Sends `calls` in one request to the batchexecute endpoint of the Trends UI, which newer features
+    /// are served from. Responses come back in the order Google sends them, see `RpcResponse::index`.
+    pub async fn batch_execute(&self, calls: &[RpcCall]) -> Result<Vec<RpcResponse>, Error> {
+        let req = self
+            .client
+            .request(
+                Method::POST,
+                format!("{}/_/TrendsUi/data/batchexecute", self.config.base_url),
+            )
+            .query(&[
+                ("rpcids", batchexecute::rpc_ids(calls).as_str()),
+                ("hl", self.config.locale.as_str()),
+                ("rt", "c"),
+            ])
+            .form(&[("f.req", batchexecute::encode(calls)?)])
+            .build()?;
+
+        let context = self.request_context(Operation::BatchExecute, 0);
+        async {
+            let body = self.fetch(req, Operation::BatchExecute).await?;
+            batchexecute::decode(&body)
+        }
+        .await
+        .map_err(|err: Error| err.with_context(context))
+    }
+
+    /// The searches trending in `geo` over the past `hours` (4, 24, 48 or 168), as on the Trending Now
+    /// page which replaces `daily_trends`. `category` is a Trending Now topic id, these are not the ids
+    /// of `Category`, 0 means all topics.
+    pub async fn trending_now(&self, geo: &str, hours: u32, category: u32) -> Result<Vec<TrendingNow>, Error> {
+        if ![4, 24, 48, 168].contains(&hours) {
+            return Err(Error::InvalidQuery(format!(
+                "Trending Now covers the past 4, 24, 48 or 168 hours, not {}",
+                hours
+            )));
+        }
+        let args = serde_json::json!([null, null, geo, category, self.config.locale, hours, 1]);
+        let responses = self.batch_execute(&[RpcCall::new(TRENDING_NOW_RPC, args)]).await?;
+        let data = responses
+            .into_iter()
+            .find(|response| response.rpc_id == TRENDING_NOW_RPC)
+            .and_then(|response| response.data)
+            .ok_or_else(|| Error::UnexpectedResponse("Trending Now returned no data".to_owned()))?;
+        let entries = data
+            .get(1)
+            .and_then(serde_json::Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        Ok(entries.iter().filter_map(trending_now_entry).collect())
+    }
+
+    /// Sends a HEAD request to the Trends site and classifies the outcome, for readiness probes. It goes
+    /// through the rate limit and circuit breaker like any other request, but not through sessions.
+    pub async fn ping(&self) -> PingStatus {
+        let req = match self
+            .client
+            .request(Method::HEAD, format!("{}/trends/", self.config.base_url))
+            .build()
+        {
+            Ok(req) => req,
+            Err(err) => return PingStatus::NetworkError(err.into()),
+        };
+        let started = self.config.clock.now();
+        match self.execute(None, req, Operation::Ping, 0).await {
+            Ok(resp) => match resp.status() {
+                status if status.is_success() => PingStatus::Ok {
+                    latency: (self.config.clock.now() - started).to_std().unwrap_or_default(),
+                },
+                StatusCode::TOO_MANY_REQUESTS => PingStatus::RateLimited {
+                    retry_after: self.retry_after(&resp),
+                },
+                StatusCode::FORBIDDEN => PingStatus::Blocked,
+                status => PingStatus::Unexpected(status.as_u16()),
+            },
+            Err(Error::CircuitOpen { .. }) => PingStatus::Blocked,
+            Err(err) => PingStatus::NetworkError(err),
+        }
+    }
+
+    /// Suggestions whose entity type contains one of `kinds`, ignoring case. Google's types are
+    /// descriptive ("Technology company", "American singer"), so `&["company"]` matches any company.
+    pub async fn suggestions_of_kind(&self, keyword: &str, kinds: &[&str]) -> Result<Vec<Topic>, Error> {
+        let kinds: Vec<String> = kinds.iter().map(|kind| kind.to_lowercase()).collect();
+        let mut topics = self.suggestions(keyword).await?;
+        topics.retain(|topic| {
+            let kind = topic.kind.to_lowercase();
+            kinds.iter().any(|wanted| kind.contains(wanted.as_str()))
+        });
+        Ok(topics)
+    }
+
+    /// The best matching topic for `keyword`, plain search term suggestions don't count.
+    pub async fn resolve_topic(&self, keyword: &str) -> Result<Option<Topic>, Error> {
+        let topics = self.suggestions(keyword).await?;
+        Ok(topics
+            .into_iter()
+            .find(|topic| !topic.kind.eq_ignore_ascii_case("Search term")))
+    }
+
+    /// Looks `keyword` up in every locale and keeps the topics suggested in all of them, ranked as in
+    /// the first locale. Comparing such a topic instead of a translated string lines countries up on
+    /// the same entity.
+    pub async fn suggestions_multi_locale(&self, keyword: &str, locales: &[&str]) -> Result<Vec<SharedTopic>, Error> {
+        let requests = locales.iter().map(|locale| self.suggestions_in(keyword, locale));
+        let per_locale: Vec<Vec<Topic>> = stream::iter(requests)
+            .buffered(self.config.max_concurrency)
+            .try_collect()
+            .await?;
+
+        let (first, rest) = match per_locale.split_first() {
+            Some(split) => split,
+            None => return Ok(vec![]),
+        };
+        let shared = first
+            .iter()
+            .filter_map(|topic| {
+                let mut titles = vec![(locales[0].to_owned(), topic.title.clone())];
+                for (locale, topics) in locales[1..].iter().zip(rest) {
+                    let other = topics.iter().find(|other| other.mid == topic.mid)?;
+                    titles.push((locale.to_string(), other.title.clone()));
+                }
+                Some(SharedTopic {
+                    mid: topic.mid.clone(),
+                    kind: topic.kind.clone(),
+                    titles,
+                })
+            })
+            .collect();
+        Ok(shared)
+    }
+
+    pub async fn categories(&self) -> Result<CategoryTree, Error> {
+        let req = self
+            .client
+            .request(Method::GET, self.url("explore/pickers/category"))
+            .query(&[("hl", self.config.locale.as_str()), ("tz", self.config.tz.as_str())])
+            .build()?;
+
+        let context = self.request_context(Operation::Explore, 0);
+        async {
+            let body = self.fetch(req, Operation::Explore).await?;
+            self.parse_widget(strip_prefix(&body, Prefix::QuoteComma)?)
+        }
+        .await
+        .map_err(|err: Error| err.with_context(context))
+    }
+
+    /// Fetches the daily trending searches published up to `date`, the API usually returns
+    /// that day along with the one before it.
+    pub async fn daily_trends(&self, geo: &str, date: Date<Utc>) -> Result<Vec<TrendingDay>, Error> {
+        let req = self
+            .client
+            .request(Method::GET, self.url("dailytrends"))
+            .query(&[
+                ("hl", self.config.locale.as_str()),
+                ("tz", self.config.tz.as_str()),
+                ("geo", geo),
+                ("ed", &date.format("%Y%m%d").to_string()),
+                ("ns", "15"),
             ])
             .build()?;
 
-        let body = self.run_with_retry(req).await?.text().await?;
-        Ok(serde_json::from_str(&body[5..])?)
+        let context = self.request_context(Operation::DailyTrends, 0);
+        async {
+            let body = self.fetch(req, Operation::DailyTrends).await?;
+            let resp: DailyTrendsResponse = self.parse_widget(strip_prefix(&body, Prefix::QuoteComma)?)?;
+            Ok(resp.default.trending_searches_days)
+        }
+        .await
+        .map_err(|err: Error| err.with_context(context))
     }
 
-    async fn explore(&self, query: &Query<'_>, search: SearchType) -> Result<RequestParameters, Error> {
+    /// Walks `start..=end` one day at a time, yielding each day once in ascending order.
+    /// Searches that already trended on an earlier day of the range are left out.
+    /// The stream ends after the first error.
+    pub fn daily_trends_range<'a>(
+        &'a self,
+        geo: &'a str,
+        start: Date<Utc>,
+        end: Date<Utc>,
+    ) -> impl Stream<Item = Result<TrendingDay, Error>> + 'a {
+        let range = (start.format("%Y%m%d").to_string(), end.format("%Y%m%d").to_string());
+        let state = Some((start, HashSet::new(), HashSet::new()));
+
+        stream::unfold(state, move |state| {
+            let range = range.clone();
+            async move {
+                let (date, mut seen_days, mut seen_searches) = state?;
+                if date > end {
+                    return None;
+                }
+                let days = match self.daily_trends(geo, date).await {
+                    Ok(days) => days,
+                    Err(err) => return Some((vec![Err(err)], None)),
+                };
+
+                let mut fresh = vec![];
+                for mut day in days.into_iter().rev() {
+                    if day.date < range.0 || day.date > range.1 || !seen_days.insert(day.date.clone()) {
+                        continue;
+                    }
+                    day.trending_searches
+                        .retain(|search| seen_searches.insert(search.title.query.to_lowercase()));
+                    fresh.push(Ok(day));
+                }
+                Some((fresh, Some((date.succ(), seen_days, seen_searches))))
+            }
+        })
+        .flat_map(stream::iter)
+    }
+
+    /// Walks the stories trending in `geo` right now, most trending first. The first request lists the
+    /// ids of up to 300 stories along with the first page of them, the rest are fetched a page at a
+    /// time until every story was yielded or `limit` were. `category` is `all`, or `b` (business),
+    /// `e` (entertainment), `m` (health), `t` (sci/tech), `s` (sports) or `h` (top stories).
+    ///
+    /// Ids go stale as stories drop out of the list. When a page fails, the list is fetched once more
+    /// and the walk goes on with the stories not yielded yet, a failure after that ends the stream.
+    pub fn realtime_trends_stream<'a>(
+        &'a self,
+        geo: &'a str,
+        category: &'a str,
+        limit: Option<usize>,
+    ) -> impl Stream<Item = Result<RealtimeStory, Error>> + 'a {
+        let limit = limit.unwrap_or(usize::MAX);
+
+        stream::unfold(Some(RealtimePages::default()), move |state| async move {
+            let mut state = state?;
+            if state.seen.len() >= limit || (state.started && state.pending.is_empty()) {
+                return None;
+            }
+            let stories = if state.started {
+                let ids: Vec<String> = state.pending.iter().take(REALTIME_PAGE_SIZE).cloned().collect();
+                match self.realtime_stories(category, &ids).await {
+                    Ok(stories) => {
+                        state.pending.drain(..ids.len());
+                        stories
+                    }
+                    Err(err) if state.refreshed => return Some((vec![Err(err)], None)),
+                    Err(err) => {
+                        state.refreshed = true;
+                        match self.realtime_trends(geo, category).await {
+                            Ok(resp) => {
+                                let seen = &state.seen;
+                                state.pending = resp
+                                    .trending_story_ids
+                                    .into_iter()
+                                    .filter(|id| !seen.contains(id))
+                                    .collect();
+                                resp.story_summaries.trending_stories
+                            }
+                            Err(_) => return Some((vec![Err(err)], None)),
+                        }
+                    }
+                }
+            } else {
+                state.started = true;
+                match self.realtime_trends(geo, category).await {
+                    Ok(resp) => {
+                        state.pending = resp.trending_story_ids.into();
+                        resp.story_summaries.trending_stories
+                    }
+                    Err(err) => return Some((vec![Err(err)], None)),
+                }
+            };
+
+            let mut fresh = vec![];
+            for story in stories {
+                state.pending.retain(|id| *id != story.id);
+                if state.seen.len() < limit && state.seen.insert(story.id.clone()) {
+                    fresh.push(Ok(story));
+                }
+            }
+            Some((fresh, Some(state)))
+        })
+        .flat_map(stream::iter)
+    }
+
+    async fn realtime_trends(&self, geo: &str, category: &str) -> Result<RealtimeTrendsResponse, Error> {
+        let rs = REALTIME_PAGE_SIZE.to_string();
         let req = self
             .client
-            .request(Method::GET, "https://trends.google.com/trends/api/explore")
+            .request(Method::GET, self.url("realtimetrends"))
             .query(&[
-                ("hl", self.locale.as_str()),
-                ("tz", "0"),
-                ("req", &serde_json::to_string(query)?),
+                ("hl", self.config.locale.as_str()),
+                ("tz", self.config.tz.as_str()),
+                ("cat", category),
+                ("fi", "0"),
+                ("fs", "0"),
+                ("geo", geo),
+                ("ri", "300"),
+                ("rs", &rs),
+                ("sort", "0"),
             ])
             .build()?;
 
-        let body = self.run_with_retry(req).await?.text().await?;
-        let resp: ExploreResponse = serde_json::from_str(&body[4..])?;
+        let context = self.request_context(Operation::RealtimeTrends, 0);
+        async {
+            let body = self.fetch(req, Operation::RealtimeTrends).await?;
+            self.parse_widget(strip_prefix(&body, Prefix::Quote)?)
+        }
+        .await
+        .map_err(|err: Error| err.with_context(context))
+    }
 
-        let item = resp
-            .get_request(search)
-            .ok_or_else(|| Error::UnexpectedResponse("Search feature unavailable".to_owned()))?;
-        Ok(item.clone())
+    async fn realtime_stories(&self, category: &str, ids: &[String]) -> Result<Vec<RealtimeStory>, Error> {
+        let mut params = vec![
+            ("hl", self.config.locale.as_str()),
+            ("tz", self.config.tz.as_str()),
+            ("cat", category),
+        ];
+        params.extend(ids.iter().map(|id| ("id", id.as_str())));
+        let req = self
+            .client
+            .request(Method::GET, self.url("stories/summary"))
+            .query(&params)
+            .build()?;
+
+        let context = self.request_context(Operation::RealtimeTrends, 0);
+        async {
+            let body = self.fetch(req, Operation::RealtimeTrends).await?;
+            let resp: StorySummaryResponse = self.parse_widget(strip_prefix(&body, Prefix::QuoteComma)?)?;
+            Ok(resp.trending_stories)
+        }
+        .await
+        .map_err(|err: Error| err.with_context(context))
     }
 
-    async fn run_with_retry(&self, req: Request) -> Result<Response, Error> {
-        let mut req_copy = Request::new(req.method().clone(), req.url().clone());
-        *req_copy.headers_mut() = req.headers().clone();
+    /// Top and rising queries searched together with the query's keywords, for exactly the `source` and
+    /// `category` given. `TrendsClientBuilder::default_source` and `default_category` don't apply here,
+    /// `full_report` with unset options picks them up.
+    pub async fn related_queries(
+        &self,
+        query: &Query<'_>,
+        source: Source,
+        category: Category,
+    ) -> Result<RelatedQueries, Error> {
+        let search = SearchType::RelatedQueries;
+        let request = self.related_request(query, search, source, category);
+        let resp: RelatedSearchesResponse<RankedQuery> = self
+            .query_checkpointed(query, search, &Self::window_settings(source, category), request)
+            .await?;
+        let (top, rising) = resp.default.into_top_and_rising();
+        let mut related = RelatedQueries {
+            top,
+            rising: rising.into_iter().map(RisingQuery::from).collect(),
+        };
+        related.sort_by_value_desc();
+        Ok(related)
+    }
 
-        let resp = self.client.execute(req).await?;
+    /// Like `related_queries` for topics, `source` and `category` are sent as given.
+    pub async fn related_topics(
+        &self,
+        query: &Query<'_>,
+        source: Source,
+        category: Category,
+    ) -> Result<RelatedTopics, Error> {
+        let search = SearchType::RelatedTopics;
+        let request = self.related_request(query, search, source, category);
+        let resp: RelatedSearchesResponse<RankedTopic> = self
+            .query_checkpointed(query, search, &Self::window_settings(source, category), request)
+            .await?;
+        let (top, rising) = resp.default.into_top_and_rising();
+        let mut related = RelatedTopics { top, rising };
+        related.sort_by_value_desc();
+        Ok(related)
+    }
+
+    async fn related_request(
+        &self,
+        query: &Query<'_>,
+        search: SearchType,
+        source: Source,
+        category: Category,
+    ) -> Result<RequestParameters, Error> {
+        let mut item = self.explore(query, search.widget_id()).await?;
+        item.source(source);
+        item.category(category);
+        Ok(item)
+    }
+
+    /// Fetches every widget of the explore page off a single explore request, running the widget
+    /// requests concurrently. Source and category of `opts` apply to all of them.
+    pub async fn full_report(&self, query: &Query<'_>, opts: &RegionOptions) -> Result<TrendsReport, Error> {
+        let client = self.within(opts.get_deadline());
+        let requests = client
+            .report_requests(query, opts)
+            .await?
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (time_series, regions, topics, queries) = future::try_join4(
+            client.query::<TimeSeriesResponse>(&requests[0], SearchType::TimeSeries),
+            client.query::<GeoDataResponse>(&requests[1], SearchType::Region),
+            client.query::<RelatedSearchesResponse<RankedTopic>>(&requests[2], SearchType::RelatedTopics),
+            client.query::<RelatedSearchesResponse<RankedQuery>>(&requests[3], SearchType::RelatedQueries),
+        )
+        .await?;
+
+        Ok(TrendsReport {
+            time_series: time_series.default,
+            regions: Self::report_regions(regions, opts),
+            related_topics: Self::report_topics(topics),
+            related_queries: Self::report_queries(queries),
+        })
+    }
+
+    /// Like `full_report`, but a widget that is missing or fails only fails its own section. The call
+    /// itself fails when the explore request does.
+    pub async fn full_report_partial(
+        &self,
+        query: &Query<'_>,
+        opts: &RegionOptions,
+    ) -> Result<PartialTrendsReport, Error> {
+        let client = self.within(opts.get_deadline());
+        let mut requests = client.report_requests(query, opts).await?.into_iter();
+        let mut next = || requests.next().expect("one request per widget");
+
+        let (time_series, regions, topics, queries) = future::join4(
+            client.report_section::<TimeSeriesResponse>(next(), SearchType::TimeSeries),
+            client.report_section::<GeoDataResponse>(next(), SearchType::Region),
+            client.report_section::<RelatedSearchesResponse<RankedTopic>>(next(), SearchType::RelatedTopics),
+            client.report_section::<RelatedSearchesResponse<RankedQuery>>(next(), SearchType::RelatedQueries),
+        )
+        .await;
+
+        Ok(PartialTrendsReport {
+            time_series: time_series.map(|resp| resp.default),
+            regions: regions.map(|regions| Self::report_regions(regions, opts)),
+            related_topics: topics.map(Self::report_topics),
+            related_queries: queries.map(Self::report_queries),
+        })
+    }
+
+    // one request per widget in the order of TrendsReport, a widget missing from explore fails its own
+    async fn report_requests(
+        &self,
+        query: &Query<'_>,
+        opts: &RegionOptions,
+    ) -> Result<Vec<Result<RequestParameters, Error>>, Error> {
+        for item in query.items() {
+            opts.get_resolution().check_supported(query.geo_of(item))?;
+        }
+
+        let explore = self.explore_raw(query).await?;
+        let requests = SearchType::ALL
+            .iter()
+            .map(|search| {
+                let mut item = explore.get_request(search.widget_id())?;
+                item.source(opts.source_or(self.config.default_source));
+                item.category(opts.category_or(self.config.default_category));
+                if *search == SearchType::Region {
+                    item.resolution(opts.get_resolution());
+                    item.include_low_volume_geos(opts.get_include_low_volume_regions());
+                }
+                Ok(item)
+            })
+            .collect();
+        Ok(requests)
+    }
+
+    async fn report_section<A: DeserializeOwned>(
+        &self,
+        request: Result<RequestParameters, Error>,
+        search: SearchType,
+    ) -> Result<A, Error> {
+        self.query(&request?, search).await
+    }
+
+    fn report_regions(resp: GeoDataResponse, opts: &RegionOptions) -> RegionData {
+        let mut regions = resp.default;
+        regions.sort(opts.get_order());
+        regions
+    }
+
+    fn report_topics(resp: RelatedSearchesResponse<RankedTopic>) -> RelatedTopics {
+        let (top, rising) = resp.default.into_top_and_rising();
+        let mut related = RelatedTopics { top, rising };
+        related.sort_by_value_desc();
+        related
+    }
+
+    fn report_queries(resp: RelatedSearchesResponse<RankedQuery>) -> RelatedQueries {
+        let (top, rising) = resp.default.into_top_and_rising();
+        let mut related = RelatedQueries {
+            top,
+            rising: rising.into_iter().map(RisingQuery::from).collect(),
+        };
+        related.sort_by_value_desc();
+        related
+    }
+
+    /// Collects the rising queries of every seed keyword within `category` and merges them
+    /// into a single list ranked by growth, breakouts first.
+    pub async fn rising_digest(
+        &self,
+        category: Category,
+        geo: Option<&str>,
+        timeframe: &Timeframe,
+        seeds: &[&str],
+    ) -> Result<Vec<DigestEntry>, Error> {
+        let requests = seeds.iter().map(|seed| async move {
+            let item = match geo {
+                Some(geo) => QueryItem::by_keyword_with_geo(*seed, geo, timeframe.clone()),
+                None => QueryItem::by_keyword(*seed, timeframe.clone()),
+            };
+            let related = self
+                .related_queries(&Query::new(vec![item]), self.config.default_source, category)
+                .await?;
+            Ok::<_, Error>((seed.to_string(), related.rising))
+        });
+        let progress = Tracker::start(self.progress(), seeds.len());
+        let results = stream::iter(requests)
+            .buffered(self.config.max_concurrency)
+            .inspect_ok(|_| progress.window_done())
+            .try_collect()
+            .await?;
+        progress.finish();
+        Ok(DigestEntry::collect(results))
+    }
+
+    /// Fetches country-level interest and then, for the `top` countries by interest,
+    /// issues a follow-up request at `resolution` restricted to that country.
+    pub async fn drill_down_regions(
+        &self,
+        query: &Query<'_>,
+        top: usize,
+        resolution: Resolution,
+        source: Source,
+        category: Category,
+    ) -> Result<GeoTree, Error> {
+        let countries = self
+            .interest_by_region(query, Resolution::Country, source, category, false)
+            .await?;
+
+        let mut entries: Vec<RegionEntry> = countries
+            .entries
+            .into_iter()
+            .filter(|entry| entry.has_data.iter().any(|has_data| *has_data))
+            .collect();
+        entries.sort_by(|a, b| b.total_value().cmp(&a.total_value()));
+        entries.truncate(top);
+
+        let progress = Tracker::start(self.progress(), entries.len());
+        let mut nodes = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let local = query.with_geo(entry.geo_code.clone());
+            let regions = self
+                .interest_by_region(&local, resolution, source, category, false)
+                .await?;
+            let children = regions
+                .entries
+                .into_iter()
+                .map(|entry| GeoNode {
+                    entry,
+                    children: vec![],
+                })
+                .collect();
+            nodes.push(GeoNode { entry, children });
+            progress.window_done();
+        }
+        progress.finish();
+        Ok(GeoTree { nodes })
+    }
+
+    pub async fn fetch_widget<W: Widget + Default>(&self, query: &Query<'_>) -> Result<W::Output, Error> {
+        self.fetch_widget_with(query, &W::default()).await
+    }
+
+    pub async fn fetch_widget_with<W: Widget>(&self, query: &Query<'_>, widget: &W) -> Result<W::Output, Error> {
+        let mut item = self.explore(query, W::ID).await?;
+        let mut request = serde_json::to_value(&item.request)?;
+        widget.prepare(&mut request)?;
+        item.request = serde_json::from_value(request)?;
+        self.query_path(&item, W::PATH, Operation::CustomWidget(W::ID)).await
+    }
+
+    async fn query<A: DeserializeOwned>(&self, params: &RequestParameters, search: SearchType) -> Result<A, Error> {
+        self.query_path(params, Self::widget_path(search), Operation::Widget(search))
+            .await
+    }
+
+    // Like `query`, with the body taken from the checkpoint when an earlier call fetched the same window.
+    // `request` is only awaited when the widget has to be fetched, so a window from the checkpoint
+    // doesn't cost an explore request either.
+    async fn query_checkpointed<A, F>(
+        &self,
+        query: &Query<'_>,
+        search: SearchType,
+        settings: &str,
+        request: F,
+    ) -> Result<A, Error>
+    where
+        A: DeserializeOwned,
+        F: Future<Output = Result<RequestParameters, Error>>,
+    {
+        let checkpoint = match &self.checkpoint {
+            Some(checkpoint) => checkpoint,
+            None => return self.query(&request.await?, search).await,
+        };
+        let req = serde_json::to_string(query)?;
+        let window = Checkpoint::window(&[search.widget_id(), &self.config.locale, &self.config.tz, &req, settings]);
+        let context = self.request_context(Operation::Widget(search), query.items().len());
+        let body = match checkpoint.get(&window) {
+            Some(body) => body,
+            None => {
+                let operation = Operation::Widget(search);
+                let body = self
+                    .query_body(&request.await?, Self::widget_path(search), operation)
+                    .await?;
+                checkpoint.insert(window, body.clone())?;
+                body
+            }
+        };
+        self.parse_widget(&body).map_err(|err| err.with_context(context))
+    }
+
+    fn window_settings(source: Source, category: Category) -> String {
+        format!("{}/{}", source.name(), category.id())
+    }
+
+    async fn query_path<A: DeserializeOwned>(
+        &self,
+        params: &RequestParameters,
+        path: &str,
+        operation: Operation,
+    ) -> Result<A, Error> {
+        let context = self.request_context(operation, params.keyword_count());
+        async {
+            let body = self.hedged_widget_body(params, path, operation).await?;
+            self.parse_widget(&body)
+        }
+        .await
+        .map_err(|err| err.with_context(context))
+    }
+
+    async fn query_body(&self, params: &RequestParameters, path: &str, operation: Operation) -> Result<String, Error> {
+        let context = self.request_context(operation, params.keyword_count());
+        self.hedged_widget_body(params, path, operation)
+            .await
+            .map_err(|err| err.with_context(context))
+    }
+
+    async fn hedged_widget_body(
+        &self,
+        params: &RequestParameters,
+        path: &str,
+        operation: Operation,
+    ) -> Result<String, Error> {
+        let hedge = match &self.config.hedge {
+            Some(hedge) => hedge,
+            None => return self.widget_body(params, path, operation).await,
+        };
+        let primary = Box::pin(self.widget_body(params, path, operation));
+        let primary = match future::select(primary, hedge.sleeper.sleep(hedge.delay)).await {
+            Either::Left((result, _)) => return result,
+            Either::Right(((), primary)) => primary,
+        };
+        let backup = Box::pin(self.widget_body(params, path, operation));
+        match future::select(primary, backup).await {
+            Either::Left((Ok(body), _)) | Either::Right((Ok(body), _)) => Ok(body),
+            // a bad request or a block would fail the duplicate the same way
+            Either::Left((Err(err), _)) if !err.is_transient() => Err(err),
+            Either::Left((Err(_), other)) => other.await,
+            Either::Right((Err(_), other)) => other.await,
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self, params), fields(widget = %params.id)))]
+    async fn widget_body(&self, params: &RequestParameters, path: &str, operation: Operation) -> Result<String, Error> {
+        let req = self
+            .client
+            .request(Method::GET, self.url(path))
+            .query(&[
+                ("hl", params.locale.as_deref().unwrap_or(&self.config.locale)),
+                ("tz", self.config.tz.as_str()),
+                ("token", &params.token),
+                ("req", &serde_json::to_string(&params.request)?),
+            ])
+            .build()?;
+
+        let mut body = self.fetch(req, operation).await?;
+        let start = body.len() - strip_prefix(&body, Prefix::QuoteComma)?.len();
+        body.drain(..start);
+        Ok(body)
+    }
+
+    pub async fn explore_widgets(&self, query: &Query<'_>) -> Result<Vec<WidgetDescriptor>, Error> {
+        Ok(self.explore_raw(query).await?.widgets)
+    }
+
+    /// The widgets explore offers for `query`, e.g. related topics are left out for rare terms.
+    pub async fn available_widgets(&self, query: &Query<'_>) -> Result<Vec<SearchType>, Error> {
+        let explore = self.explore_raw(query).await?;
+        Ok(SearchType::ALL
+            .iter()
+            .copied()
+            .filter(|search| explore.get_request(search.widget_id()).is_ok())
+            .collect())
+    }
+
+    /// Issues the request of a widget returned by `explore_widgets` against the endpoint at `path`
+    /// (relative to `/trends/api/`) and returns the response as untyped JSON.
+    pub async fn widget_data(&self, widget: &WidgetDescriptor, path: &str) -> Result<serde_json::Value, Error> {
+        let params = RequestParameters::from_descriptor(widget)?;
+        self.query_path(&params, path, Operation::RawWidget).await
+    }
+
+    async fn explore(&self, query: &Query<'_>, widget: &str) -> Result<RequestParameters, Error> {
+        self.explore_raw(query).await?.get_request(widget)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, query), fields(keywords = query.items().len()))
+    )]
+    async fn explore_raw(&self, query: &Query<'_>) -> Result<ExploreResponse, Error> {
+        let context = self.request_context(Operation::Explore, query.items().len());
+        self.explore_body(query).await.map_err(|err| err.with_context(context))
+    }
+
+    async fn explore_body(&self, query: &Query<'_>) -> Result<ExploreResponse, Error> {
+        query.validate()?;
+        let req = serde_json::to_string(query)?;
+        let locale = self.detected_locale(query);
+        let hl = locale.unwrap_or(self.config.locale.as_str());
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(cache) = &self.config.token_cache {
+            let key = TokenCache::key(&[&self.config.base_url, hl, &self.config.tz, &req]);
+            let now = self.config.clock.now();
+            if let Some(widgets) = cache.get(&key, now) {
+                return Ok(ExploreResponse {
+                    widgets,
+                    locale: locale.map(str::to_owned),
+                    ..ExploreResponse::default()
+                });
+            }
+            let explore = self.explore_request(&req, hl).await?;
+            cache.put(&key, &explore.widgets, now);
+            return Ok(ExploreResponse {
+                locale: locale.map(str::to_owned),
+                ..explore
+            });
+        }
+        let explore = self.explore_request(&req, hl).await?;
+        Ok(ExploreResponse {
+            locale: locale.map(str::to_owned),
+            ..explore
+        })
+    }
+
+    // only when the caller left the locale alone, see the detect-language feature
+    #[cfg(feature = "detect-language")]
+    fn detected_locale(&self, query: &Query<'_>) -> Option<&'static str> {
+        if self.config.locale_set {
+            return None;
+        }
+        let keywords: Vec<&str> = query.items().iter().map(QueryItem::keyword).collect();
+        crate::language::detect_locale(&keywords.join(" ")).filter(|locale| *locale != self.config.locale)
+    }
+
+    #[cfg(not(feature = "detect-language"))]
+    fn detected_locale(&self, _query: &Query<'_>) -> Option<&'static str> {
+        None
+    }
+
+    async fn explore_request(&self, req: &str, hl: &str) -> Result<ExploreResponse, Error> {
+        let req = self
+            .client
+            .request(Method::GET, self.url("explore"))
+            .query(&[("hl", hl), ("tz", self.config.tz.as_str()), ("req", req)])
+            .build()?;
+
+        let body = self.fetch(req, Operation::Explore).await?;
+        self.parse_widget(strip_prefix(&body, Prefix::Quote)?)
+    }
+
+    fn request_context(&self, operation: Operation, keywords: usize) -> ErrorContext {
+        let sequence = self.config.request_counter.fetch_add(1, Ordering::Relaxed);
+        ErrorContext {
+            request_id: format!("{:x}-{:x}", self.config.clock.now().timestamp_millis(), sequence),
+            operation,
+            keywords,
+        }
+    }
+
+    #[cfg(feature = "strict")]
+    fn strict(&self) -> bool {
+        self.config.strict
+    }
+
+    #[cfg(not(feature = "strict"))]
+    fn strict(&self) -> bool {
+        false
+    }
+
+    fn parse_widget<A: DeserializeOwned>(&self, json: &str) -> Result<A, Error> {
+        #[cfg(feature = "strict")]
+        if self.config.strict {
+            return from_str_strict(json);
+        }
+        #[cfg(feature = "simd-json")]
+        {
+            // simd-json parses in place, so it needs a buffer of its own
+            let mut buffer = json.as_bytes().to_vec();
+            Ok(simd_json::serde::from_slice(&mut buffer)?)
+        }
+        #[cfg(not(feature = "simd-json"))]
+        Ok(serde_json::from_str(json)?)
+    }
+
+    async fn fetch(&self, req: Request, operation: Operation) -> Result<String, Error> {
+        let dump_url = self.config.debug_dump.as_ref().map(|_| debug::redact_token(req.url()));
+        let body = match &self.config.cancellation {
+            Some(token) => token.run(self.fetch_body(req, operation)).await?,
+            None => self.fetch_body(req, operation).await?,
+        };
+
+        if let (Some(sink), Some(url)) = (&self.config.debug_dump, dump_url) {
+            sink.dump(&url, &body);
+        }
+        Ok(body)
+    }
+
+    async fn fetch_body(&self, req: Request, operation: Operation) -> Result<String, Error> {
+        let resp = self.run_with_retry(req, operation).await?;
+        self.read_body(resp).await
+    }
+
+    async fn read_body(&self, resp: Response) -> Result<String, Error> {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(limit) = self.config.max_body_size {
+            return Self::read_limited(resp, limit).await;
+        }
+        Ok(resp.text().await?)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn read_limited(mut resp: Response, limit: usize) -> Result<String, Error> {
+        if resp.content_length().map_or(false, |len| len > limit as u64) {
+            return Err(Error::ResponseTooLarge(limit));
+        }
+        let mut body = Vec::new();
+        while let Some(chunk) = resp.chunk().await? {
+            if body.len() + chunk.len() > limit {
+                return Err(Error::ResponseTooLarge(limit));
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(String::from_utf8_lossy(&body).into_owned())
+    }
+
+    async fn run_with_retry(&self, req: Request, operation: Operation) -> Result<Response, Error> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let deadline = match (self.config.deadline, self.operation_deadline) {
+            (Some(timeout), Some(shared)) => Some(shared.min(Instant::now() + timeout)),
+            (Some(timeout), None) => Some(Instant::now() + timeout),
+            (None, shared) => shared,
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        let req = self.with_deadline(req, deadline)?;
+
+        let req_copy = req.try_clone();
+
+        let session = match &self.sessions {
+            Some(sessions) => Some(sessions.checkout(self.config.clock.now())?),
+            None => None,
+        };
+        let mut resp = self.execute_transient(session, req, operation).await?;
+        if Self::is_consent_redirect(&resp) && self.config.consent_cookie.is_some() {
+            // from now on every request carries the consent cookie, see `execute`
+            self.config.consent_required.store(true, Ordering::Relaxed);
+            let retry = req_copy
+                .as_ref()
+                .and_then(Request::try_clone)
+                .ok_or(Error::RetryNotPossible)?;
+            resp = self.execute(session, retry, operation, 1).await?;
+        }
         match resp.status() {
             StatusCode::TOO_MANY_REQUESTS => {
-                if let Some(val) = resp
-                    .headers()
-                    .get("set-cookie")
-                    .and_then(|val| val.to_str().ok())
-                    .and_then(|str| str.split(';').next())
-                {
-                    let header = HeaderValue::from_str(val).unwrap();
-                    req_copy.headers_mut().insert("cookie", header);
+                let cookie = Self::session_cookie(&resp);
+                if !self.config.retry_rate_limited {
+                    if let (Some(sessions), Some(session)) = (&self.sessions, session) {
+                        sessions.bench(session, None, self.config.clock.now());
+                    }
+                    return Err(Error::RateLimited {
+                        cookie: cookie.map(str::to_owned),
+                        retry_after: self.retry_after(&resp),
+                    });
+                }
+
+                #[cfg(feature = "tracing")]
+                tracing::info!("rate limited, retrying with a session cookie");
+                if let Some(progress) = &self.progress {
+                    progress.on_retry(operation);
+                }
+                if let Some(policy) = &self.config.rate_limited_backoff {
+                    let wait = self.retry_after(&resp).unwrap_or(policy.backoff);
+                    policy.sleeper.sleep(wait).await;
                 }
-                Ok(self.client.execute(req_copy).await?)
+
+                // a cookie that isn't a valid header value is dropped, the retry then goes out without it
+                let cookie = cookie.and_then(cookie_header);
+
+                let mut req_copy = req_copy.ok_or(Error::RetryNotPossible)?;
+                let retry_session = match (&self.sessions, session) {
+                    (Some(sessions), Some(session)) => {
+                        let now = self.config.clock.now();
+                        sessions.bench(session, cookie, now);
+                        Some(sessions.checkout(now)?)
+                    }
+                    _ => {
+                        if let Some(cookie) = cookie {
+                            req_copy.headers_mut().insert("cookie", cookie);
+                        }
+                        None
+                    }
+                };
+
+                #[cfg(not(target_arch = "wasm32"))]
+                let req_copy = self.with_deadline(req_copy, deadline)?;
+                let resp = self.execute(retry_session, req_copy, operation, 1).await?;
+                self.check_status(resp).await
             }
-            StatusCode::OK => Ok(resp),
-            _ => Err(Error::UnexpectedResponse(resp.text().await?)),
+            _ => self.check_status(resp).await,
         }
     }
 
-    fn endpoint<'a>(search: SearchType) -> &'a str {
+    async fn execute_transient(
+        &self,
+        session: Option<&Session>,
+        mut req: Request,
+        operation: Operation,
+    ) -> Result<Response, Error> {
+        let policy = match &self.config.transient_retry {
+            Some(policy) => policy,
+            None => return self.execute(session, req, operation, 0).await,
+        };
+        let mut attempt = 0;
+        loop {
+            let retry = req.try_clone();
+            match (self.execute(session, req, operation, attempt).await, retry) {
+                (Err(err), Some(retry)) if err.is_transient() && attempt < policy.attempts => {
+                    #[cfg(feature = "tracing")]
+                    tracing::info!(error = ?err, attempt, "transient network error, retrying");
+                    if let Some(progress) = &self.progress {
+                        progress.on_retry(operation);
+                    }
+                    policy.sleeper.sleep(policy.backoff * 2u32.pow(attempt.min(16))).await;
+                    attempt += 1;
+                    req = retry;
+                }
+                (result, _) => return result,
+            }
+        }
+    }
+
+    async fn check_status(&self, resp: Response) -> Result<Response, Error> {
+        match resp.status() {
+            status if status.is_success() => Ok(resp),
+            StatusCode::TOO_MANY_REQUESTS => Err(Error::RateLimited {
+                cookie: Self::session_cookie(&resp).map(str::to_owned),
+                retry_after: self.retry_after(&resp),
+            }),
+            status => {
+                let header = |name| {
+                    resp.headers()
+                        .get(name)
+                        .and_then(|val| val.to_str().ok())
+                        .map(str::to_owned)
+                };
+                let (retry_after, content_type) = (header(header::RETRY_AFTER), header(header::CONTENT_TYPE));
+                let mut body = resp.text().await?;
+                if let Some((end, _)) = body.char_indices().nth(MAX_ERROR_BODY) {
+                    body.truncate(end);
+                }
+                Err(Error::UnexpectedStatus {
+                    status: status.as_u16(),
+                    retry_after,
+                    content_type,
+                    body,
+                })
+            }
+        }
+    }
+
+    /// Reads `Retry-After`, given either in seconds or as an HTTP date.
+    fn retry_after(&self, resp: &Response) -> Option<Duration> {
+        let value = resp.headers().get(header::RETRY_AFTER)?.to_str().ok()?.trim();
+        if let Ok(secs) = value.parse() {
+            return Some(Duration::from_secs(secs));
+        }
+        let date = DateTime::parse_from_rfc2822(value).ok()?;
+        Some(
+            (date.with_timezone(&Utc) - self.config.clock.now())
+                .to_std()
+                .unwrap_or_default(),
+        )
+    }
+
+    fn is_consent_redirect(resp: &Response) -> bool {
+        resp.url().host_str().map_or(false, |host| host.starts_with("consent."))
+    }
+
+    fn session_cookie(resp: &Response) -> Option<&str> {
+        set_cookie_value(resp.headers())
+    }
+
+    // a timeout once the operation deadline has passed is reported as the operation running out of time
+    #[cfg(not(target_arch = "wasm32"))]
+    fn timeout_error(&self) -> Error {
+        match self.operation_deadline {
+            Some(deadline) if Instant::now() >= deadline => Error::DeadlineExceeded,
+            _ => Error::Timeout,
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn with_deadline(&self, mut req: Request, deadline: Option<Instant>) -> Result<Request, Error> {
+        if let Some(deadline) = deadline {
+            let remaining = deadline
+                .checked_duration_since(Instant::now())
+                .ok_or_else(|| self.timeout_error())?;
+            *req.timeout_mut() = Some(self.config.timeout.map_or(remaining, |timeout| timeout.min(remaining)));
+        }
+        Ok(req)
+    }
+
+    async fn execute(
+        &self,
+        session: Option<&Session>,
+        mut req: Request,
+        operation: Operation,
+        attempt: u32,
+    ) -> Result<Response, Error> {
+        let client = match session {
+            Some(session) => {
+                if let Some(cookie) = session.cookie() {
+                    req.headers_mut().insert(header::COOKIE, cookie);
+                }
+                session.client()
+            }
+            None => &self.client,
+        };
+        if let (Some(consent), true) = (
+            &self.config.consent_cookie,
+            self.config.consent_required.load(Ordering::Relaxed),
+        ) {
+            // merged into a session cookie, unless the caller already sends consent cookies of their own
+            let cookie = match req.headers().get(header::COOKIE).and_then(|val| val.to_str().ok()) {
+                Some(cookie) if cookie.contains("SOCS=") => None,
+                Some(cookie) => {
+                    HeaderValue::from_str(&format!("{}; {}", cookie, consent.to_str().unwrap_or_default())).ok()
+                }
+                None => Some(consent.clone()),
+            };
+            if let Some(cookie) = cookie {
+                req.headers_mut().insert(header::COOKIE, cookie);
+            }
+        }
+
+        let probe = match &self.config.circuit_breaker {
+            Some(breaker) => breaker.check(self.config.clock.now())?,
+            None => None,
+        };
+        if let Some(limit) = &self.config.rate_limit {
+            limit.wait(self.config.clock.now()).await;
+        }
+        let started = self.config.clock.now();
+        let resp = self.send(client, req).await;
+        let latency = (self.config.clock.now() - started).to_std().unwrap_or_default();
+
+        if let Some(breaker) = &self.config.circuit_breaker {
+            let status = resp.as_ref().ok().map(Response::status);
+            breaker.record(status, probe, self.config.clock.now());
+        }
+
+        #[cfg(feature = "tracing")]
+        match &resp {
+            Ok(resp) => tracing::debug!(
+                status = resp.status().as_u16(),
+                attempt,
+                latency_ms = latency.as_millis() as u64,
+                "request completed"
+            ),
+            Err(err) => {
+                tracing::warn!(error = ?err, attempt, latency_ms = latency.as_millis() as u64, "request failed")
+            }
+        }
+
+        if let Some(metrics) = &self.config.metrics {
+            metrics.record(&RequestOutcome {
+                operation,
+                status: resp.as_ref().ok().map(Response::status),
+                attempt,
+                latency,
+            });
+        }
+        resp
+    }
+
+    // Everything about a request but the network goes through `execute`, so with a cassette rate limits,
+    // the circuit breaker, metrics and retries behave as they would against Google.
+    async fn send(&self, client: &Client, req: Request) -> Result<Response, Error> {
+        #[cfg(feature = "testing")]
+        if let Some(cassette) = &self.config.cassette {
+            match cassette.mode() {
+                CassetteMode::Replay => return cassette.find(&req),
+                CassetteMode::Record => {
+                    let copy = req.try_clone().ok_or(Error::RetryNotPossible)?;
+                    let resp = self.send_network(client, req).await?;
+                    // consent redirects are followed up with a retry, which gets recorded instead
+                    if Self::is_consent_redirect(&resp) {
+                        return Ok(resp);
+                    }
+                    let (status, headers) = (resp.status().as_u16(), resp.headers().clone());
+                    let interaction = Interaction::new(&copy, status, &headers, self.read_body(resp).await?);
+                    let resp = interaction.response()?;
+                    cassette.push(interaction)?;
+                    return Ok(resp);
+                }
+            }
+        }
+        self.send_network(client, req).await
+    }
+
+    async fn send_network(&self, client: &Client, req: Request) -> Result<Response, Error> {
+        let resp = client.execute(req).await;
+        #[cfg(not(target_arch = "wasm32"))]
+        let resp = resp.map_err(|err| match Error::from(err) {
+            Error::Timeout => self.timeout_error(),
+            err => err,
+        });
+        Ok(resp?)
+    }
+
+    fn widget_path(search: SearchType) -> &'static str {
         match search {
-            SearchType::TimeSeries => "https://trends.google.com/trends/api/widgetdata/multiline",
-            SearchType::Region => "https://trends.google.com/trends/api/widgetdata/comparedgeo",
-            SearchType::RelatedTopics => "https://trends.google.com/trends/api/widgetdata/relatedsearches",
-            SearchType::RelatedQueries => "https://trends.google.com/trends/api/widgetdata/relatedsearches",
+            SearchType::TimeSeries => "widgetdata/multiline",
+            SearchType::Region => "widgetdata/comparedgeo",
+            SearchType::RelatedTopics => "widgetdata/relatedsearches",
+            SearchType::RelatedQueries => "widgetdata/relatedsearches",
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/trends/api/{}", self.config.base_url, path)
+    }
+}
+
+// fails with `Error::SchemaDrift` naming every field of `json` that nothing was deserialized into
+#[cfg(feature = "strict")]
+pub(crate) fn from_str_strict<'de, A: Deserialize<'de>>(json: &'de str) -> Result<A, Error> {
+    let mut ignored = vec![];
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let result = serde_ignored::deserialize(&mut deserializer, |path| ignored.push(path.to_string()))?;
+    if ignored.is_empty() {
+        Ok(result)
+    } else {
+        Err(Error::SchemaDrift(ignored))
+    }
+}
+
+// the `name=value` part of a Set-Cookie header, `None` when it isn't text or has no cookie before its attributes
+fn set_cookie_value(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::SET_COOKIE)
+        .and_then(|val| val.to_str().ok())
+        .and_then(|str| str.split(';').next())
+        .map(str::trim)
+        .filter(|cookie| !cookie.is_empty())
+}
+
+fn cookie_header(cookie: &str) -> Option<HeaderValue> {
+    HeaderValue::from_str(cookie).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_cookie(value: &[u8]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::SET_COOKIE, HeaderValue::from_bytes(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn set_cookie_value_drops_attributes() {
+        let headers = set_cookie(b"NID=511=abc; expires=Sat, 01-Jan-2022 00:00:00 GMT; path=/; HttpOnly");
+        assert_eq!(set_cookie_value(&headers), Some("NID=511=abc"));
+    }
+
+    #[test]
+    fn set_cookie_value_skips_malformed_headers() {
+        assert_eq!(set_cookie_value(&HeaderMap::new()), None);
+        // obs-text is a valid header value, but not a string
+        assert_eq!(set_cookie_value(&set_cookie(b"NID=\xff\xfe; path=/")), None);
+        assert_eq!(set_cookie_value(&set_cookie(b"; path=/")), None);
+        assert_eq!(set_cookie_value(&set_cookie(b"")), None);
+    }
+
+    #[test]
+    fn cookie_header_rejects_invalid_values() {
+        assert!(cookie_header("NID=abc\r\nX-Injected: 1").is_none());
+        assert!(cookie_header("NID=\u{7f}").is_none());
+        assert_eq!(cookie_header("NID=abc").unwrap(), "NID=abc");
+    }
+
+    fn breaker() -> CircuitBreaker {
+        CircuitBreaker {
+            threshold: 2,
+            cooldown: chrono::Duration::seconds(60),
+            state: Mutex::new(CircuitState::default()),
+        }
+    }
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp(1_614_556_800 + secs, 0)
+    }
+
+    fn open(breaker: &CircuitBreaker) {
+        for _ in 0..2 {
+            breaker.record(Some(StatusCode::TOO_MANY_REQUESTS), None, at(0));
+        }
+    }
+
+    #[test]
+    fn circuit_breaker_opens_after_consecutive_blocks() {
+        let breaker = breaker();
+        breaker.record(Some(StatusCode::FORBIDDEN), None, at(0));
+        breaker.record(Some(StatusCode::OK), None, at(0));
+        breaker.record(Some(StatusCode::FORBIDDEN), None, at(0));
+        assert!(matches!(breaker.check(at(1)), Ok(None)));
+
+        breaker.record(Some(StatusCode::TOO_MANY_REQUESTS), None, at(0));
+        assert!(matches!(breaker.check(at(59)), Err(Error::CircuitOpen { until }) if until == at(60)));
+    }
+
+    #[test]
+    fn circuit_breaker_lets_one_probe_through_and_closes_on_success() {
+        let breaker = breaker();
+        open(&breaker);
+
+        let probe = breaker.check(at(60)).unwrap();
+        assert!(probe.is_some());
+        assert!(matches!(breaker.check(at(61)), Err(Error::CircuitOpen { .. })));
+        // a response to a request sent before the breaker opened doesn't close it
+        breaker.record(Some(StatusCode::OK), None, at(61));
+        assert!(matches!(breaker.check(at(61)), Err(Error::CircuitOpen { .. })));
+
+        breaker.record(Some(StatusCode::OK), probe, at(62));
+        assert!(matches!(breaker.check(at(62)), Ok(None)));
+    }
+
+    #[test]
+    fn circuit_breaker_reopens_on_a_blocked_probe() {
+        let breaker = breaker();
+        open(&breaker);
+
+        let probe = breaker.check(at(60)).unwrap();
+        breaker.record(Some(StatusCode::TOO_MANY_REQUESTS), probe, at(65));
+        assert!(matches!(breaker.check(at(124)), Err(Error::CircuitOpen { until }) if until == at(125)));
+        assert!(matches!(breaker.check(at(125)), Ok(Some(_))));
+    }
+
+    #[test]
+    fn circuit_breaker_probes_again_after_a_failed_or_dropped_probe() {
+        let breaker = breaker();
+        open(&breaker);
+
+        let probe = breaker.check(at(60)).unwrap();
+        breaker.record(None, probe, at(61));
+        let probe = breaker.check(at(61)).unwrap();
+        assert!(probe.is_some());
+
+        drop(probe);
+        assert!(matches!(breaker.check(at(62)), Ok(Some(_))));
+    }
+
+    #[test]
+    fn strip_prefix_accepts_either_prefix() {
+        for &expected in &[Prefix::Quote, Prefix::QuoteComma] {
+            assert_eq!(strip_prefix(")]}'\n{\"a\":1}", expected).unwrap(), "\n{\"a\":1}");
+            assert_eq!(strip_prefix(")]}',{\"a\":1}", expected).unwrap(), "{\"a\":1}");
+            assert_eq!(strip_prefix(")]}',\n[1]", expected).unwrap(), "\n[1]");
+            assert_eq!(strip_prefix("{\"a\":1}", expected).unwrap(), "{\"a\":1}");
+        }
+    }
+
+    #[test]
+    fn strip_prefix_rejects_malformed_bodies() {
+        let bodies = [
+            "",
+            ")",
+            ")]}",
+            ")]}'",
+            ")]}',",
+            ")]}'x{}",
+            ")]}',,{}",
+            ")]}' ,{}",
+            "]}',{}",
+            "<!DOCTYPE html><html></html>",
+            "Too many requests",
+        ];
+        for body in &bodies {
+            for &expected in &[Prefix::Quote, Prefix::QuoteComma] {
+                assert!(
+                    matches!(strip_prefix(body, expected), Err(Error::UnexpectedResponse(_))),
+                    "{:?} with {:?}",
+                    body,
+                    expected
+                );
+            }
+        }
+    }
+
+    mod fuzz {
+        use super::*;
+        use proptest::prelude::*;
+
+        fn prefix() -> impl Strategy<Value = Prefix> {
+            prop_oneof![Just(Prefix::Quote), Just(Prefix::QuoteComma)]
+        }
+
+        // bodies built from fragments of the prefixes so malformed ones come up often
+        fn body() -> impl Strategy<Value = String> {
+            let fragment = prop_oneof![
+                Just(")".to_owned()),
+                Just("]".to_owned()),
+                Just("}".to_owned()),
+                Just("'".to_owned()),
+                Just(",".to_owned()),
+                Just(")]}'".to_owned()),
+                Just("{".to_owned()),
+                Just("[".to_owned()),
+                Just(" ".to_owned()),
+                "\\PC{0,4}",
+            ];
+            prop::collection::vec(fragment, 0..8).prop_map(|parts| parts.concat())
+        }
+
+        proptest! {
+            #[test]
+            fn never_panics(body in "\\PC*", expected in prefix()) {
+                let _ = strip_prefix(&body, expected);
+            }
+
+            #[test]
+            fn returns_a_json_suffix(body in body(), expected in prefix()) {
+                if let Ok(json) = strip_prefix(&body, expected) {
+                    prop_assert!(body.ends_with(json));
+                    prop_assert!(json.trim_start().starts_with(|c: char| c == '{' || c == '['));
+                }
+            }
+
+            #[test]
+            fn strips_any_known_prefix(json in "[\\[{]\\PC*", sent in prefix(), expected in prefix()) {
+                let body = format!("{}{}", sent.as_str(), json);
+                prop_assert_eq!(strip_prefix(&body, expected).unwrap(), json.as_str());
+            }
         }
     }
 }
@@ -0,0 +1,50 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
+use reqwest::Url;
+
+use crate::Error;
+
+pub trait DebugSink: Send + Sync {
+    fn dump(&self, url: &str, body: &str);
+}
+
+impl<F: Fn(&str, &str) + Send + Sync> DebugSink for F {
+    fn dump(&self, url: &str, body: &str) {
+        self(url, body)
+    }
+}
+
+#[derive(Debug)]
+pub struct FileDump {
+    file: Mutex<File>,
+}
+
+impl FileDump {
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<FileDump, Error> {
+        Ok(FileDump {
+            file: Mutex::new(File::create(path)?),
+        })
+    }
+}
+
+impl DebugSink for FileDump {
+    fn dump(&self, url: &str, body: &str) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = writeln!(file, "{}\n{}\n", url, body);
+        }
+    }
+}
+
+pub(crate) fn redact_token(url: &Url) -> String {
+    let mut url = url.clone();
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(key, _)| key != "token")
+        .map(|(key, val)| (key.into_owned(), val.into_owned()))
+        .collect();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+    url.to_string()
+}
@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+
+use chrono::NaiveDate;
+
+use crate::keywords;
+use crate::{TrendingDay, TrendingSearch};
+
+/// One story as it trended over one or more days, in the order it was seen.
+#[derive(Debug, Clone)]
+pub struct StoryCluster {
+    /// `(date, search)` pairs, the date formatted as in `TrendingDay::date`.
+    pub searches: Vec<(String, TrendingSearch)>,
+}
+
+impl StoryCluster {
+    /// Title of the first search of the story.
+    pub fn title(&self) -> &str {
+        self.searches
+            .first()
+            .map_or("", |(_, search)| search.title.query.as_str())
+    }
+
+    pub fn dates(&self) -> Vec<&str> {
+        let mut dates: Vec<&str> = self.searches.iter().map(|(date, _)| date.as_str()).collect();
+        dates.dedup();
+        dates
+    }
+}
+
+struct Cluster {
+    story: StoryCluster,
+    last_date: Option<NaiveDate>,
+    words: Vec<HashSet<String>>,
+    urls: HashSet<String>,
+}
+
+/// Groups the trending searches of `days` into stories. A search joins a story seen on the same or
+/// the previous day when it shares an article URL with it, or when the word overlap (Jaccard index of
+/// the canonicalized titles) with one of its searches is at least `threshold`, e.g. 0.5. The days are
+/// processed in date order regardless of the order they are given in, and a gap of missing days ends
+/// a story. Days whose date can't be parsed only start stories of their own.
+pub fn cluster_stories(days: &[TrendingDay], threshold: f64) -> Vec<StoryCluster> {
+    let mut days: Vec<(Option<NaiveDate>, &TrendingDay)> = days
+        .iter()
+        .map(|day| (NaiveDate::parse_from_str(&day.date, "%Y%m%d").ok(), day))
+        .collect();
+    days.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut clusters: Vec<Cluster> = vec![];
+    for (date, day) in days {
+        let continues = |last: Option<NaiveDate>| match (last, date) {
+            (Some(last), Some(date)) => date.signed_duration_since(last).num_days() <= 1,
+            _ => false,
+        };
+        for search in &day.trending_searches {
+            let words = words(&search.title.query);
+            let urls: HashSet<String> = search
+                .articles
+                .iter()
+                .map(|article| article.url.clone())
+                .filter(|url| !url.is_empty())
+                .collect();
+
+            let matching = clusters.iter_mut().find(|cluster| {
+                continues(cluster.last_date)
+                    && (!cluster.urls.is_disjoint(&urls)
+                        || cluster.words.iter().any(|other| jaccard(other, &words) >= threshold))
+            });
+            match matching {
+                Some(cluster) => {
+                    cluster.story.searches.push((day.date.clone(), search.clone()));
+                    cluster.last_date = date;
+                    cluster.words.push(words);
+                    cluster.urls.extend(urls);
+                }
+                None => clusters.push(Cluster {
+                    story: StoryCluster {
+                        searches: vec![(day.date.clone(), search.clone())],
+                    },
+                    last_date: date,
+                    words: vec![words],
+                    urls,
+                }),
+            }
+        }
+    }
+    clusters.into_iter().map(|cluster| cluster.story).collect()
+}
+
+fn words(title: &str) -> HashSet<String> {
+    keywords::canonicalize(title)
+        .split(' ')
+        .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()).to_owned())
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(date: &str, queries: &[&str]) -> TrendingDay {
+        let searches: Vec<_> = queries
+            .iter()
+            .map(|query| serde_json::json!({ "title": { "query": query } }))
+            .collect();
+        serde_json::from_value(serde_json::json!({ "date": date, "trendingSearches": searches })).unwrap()
+    }
+
+    #[test]
+    fn stories_continue_over_consecutive_days_only() {
+        // the 2nd of March is missing, so the story seen on the 3rd is a new one
+        let days = [
+            day("20210303", &["grammys winners"]),
+            day("20210228", &["grammys 2021"]),
+            day("20210301", &["grammys 2021 winners"]),
+        ];
+        let stories = cluster_stories(&days, 0.5);
+        let dates: Vec<Vec<&str>> = stories.iter().map(StoryCluster::dates).collect();
+        assert_eq!(dates, [vec!["20210228", "20210301"], vec!["20210303"]]);
+    }
+}
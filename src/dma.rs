@@ -0,0 +1,277 @@
+use std::fmt;
+
+use crate::{RegionData, RegionEntry};
+
+/// A Nielsen Designated Market Area, the metro areas of `Resolution::Dma` results.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Dma {
+    pub code: u16,
+    /// The state the market is filed under by Google, that of its first city.
+    pub state: &'static str,
+    pub name: &'static str,
+}
+
+impl Dma {
+    pub fn from_code(code: u16) -> Option<Dma> {
+        DMAS.binary_search_by_key(&code, |(code, _, _)| *code).ok().map(Dma::at)
+    }
+
+    /// Parses the code of a region entry or a geo like `US-NY-501`.
+    pub fn from_geo_code(geo: &str) -> Option<Dma> {
+        Dma::from_code(geo.rsplit('-').next()?.parse().ok()?)
+    }
+
+    pub fn all() -> impl Iterator<Item = Dma> {
+        (0..DMAS.len()).map(Dma::at)
+    }
+
+    /// The geo to restrict a query to this market.
+    pub fn geo(&self) -> String {
+        format!("US-{}-{}", self.state, self.code)
+    }
+
+    fn at(index: usize) -> Dma {
+        let (code, state, name) = DMAS[index];
+        Dma { code, state, name }
+    }
+}
+
+impl fmt::Display for Dma {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl RegionEntry {
+    pub fn dma(&self) -> Option<Dma> {
+        Dma::from_geo_code(&self.geo_code)
+    }
+}
+
+impl RegionData {
+    /// Names the entries of DMA results that came back with a bare code (or nothing) as their name.
+    pub fn with_dma_names(mut self) -> RegionData {
+        for entry in &mut self.entries {
+            if entry.geo_name.is_empty() || entry.geo_name == entry.geo_code {
+                if let Some(dma) = entry.dma() {
+                    entry.geo_name = dma.name.to_owned();
+                }
+            }
+        }
+        self
+    }
+}
+
+// sorted by code, for the binary search
+const DMAS: &[(u16, &str, &str)] = &[
+    (500, "ME", "Portland-Auburn ME"),
+    (501, "NY", "New York NY"),
+    (502, "NY", "Binghamton NY"),
+    (503, "GA", "Macon GA"),
+    (504, "PA", "Philadelphia PA"),
+    (505, "MI", "Detroit MI"),
+    (506, "MA", "Boston MA-Manchester NH"),
+    (507, "GA", "Savannah GA"),
+    (508, "PA", "Pittsburgh PA"),
+    (509, "IN", "Ft. Wayne IN"),
+    (510, "OH", "Cleveland-Akron (Canton) OH"),
+    (511, "DC", "Washington DC (Hagerstown MD)"),
+    (512, "MD", "Baltimore MD"),
+    (513, "MI", "Flint-Saginaw-Bay City MI"),
+    (514, "NY", "Buffalo NY"),
+    (515, "OH", "Cincinnati OH"),
+    (516, "PA", "Erie PA"),
+    (517, "NC", "Charlotte NC"),
+    (518, "NC", "Greensboro-High Point-Winston Salem NC"),
+    (519, "SC", "Charleston SC"),
+    (520, "GA", "Augusta GA"),
+    (521, "RI", "Providence RI-New Bedford MA"),
+    (522, "GA", "Columbus GA"),
+    (523, "VT", "Burlington VT-Plattsburgh NY"),
+    (524, "GA", "Atlanta GA"),
+    (525, "GA", "Albany GA"),
+    (526, "NY", "Utica NY"),
+    (527, "IN", "Indianapolis IN"),
+    (528, "FL", "Miami-Ft. Lauderdale FL"),
+    (529, "KY", "Louisville KY"),
+    (530, "FL", "Tallahassee FL-Thomasville GA"),
+    (531, "TN", "Tri-Cities TN-VA"),
+    (532, "NY", "Albany-Schenectady-Troy NY"),
+    (533, "CT", "Hartford & New Haven CT"),
+    (534, "FL", "Orlando-Daytona Beach-Melbourne FL"),
+    (535, "OH", "Columbus OH"),
+    (536, "OH", "Youngstown OH"),
+    (537, "ME", "Bangor ME"),
+    (538, "NY", "Rochester NY"),
+    (539, "FL", "Tampa-St. Petersburg (Sarasota) FL"),
+    (540, "MI", "Traverse City-Cadillac MI"),
+    (541, "KY", "Lexington KY"),
+    (542, "OH", "Dayton OH"),
+    (543, "MA", "Springfield-Holyoke MA"),
+    (544, "VA", "Norfolk-Portsmouth-Newport News VA"),
+    (545, "NC", "Greenville-New Bern-Washington NC"),
+    (546, "SC", "Columbia SC"),
+    (547, "OH", "Toledo OH"),
+    (548, "FL", "West Palm Beach-Ft. Pierce FL"),
+    (549, "NY", "Watertown NY"),
+    (550, "NC", "Wilmington NC"),
+    (551, "MI", "Lansing MI"),
+    (552, "ME", "Presque Isle ME"),
+    (553, "MI", "Marquette MI"),
+    (554, "WV", "Wheeling WV-Steubenville OH"),
+    (555, "NY", "Syracuse NY"),
+    (556, "VA", "Richmond-Petersburg VA"),
+    (557, "TN", "Knoxville TN"),
+    (558, "OH", "Lima OH"),
+    (559, "WV", "Bluefield-Beckley-Oak Hill WV"),
+    (560, "NC", "Raleigh-Durham (Fayetteville) NC"),
+    (561, "FL", "Jacksonville FL"),
+    (563, "MI", "Grand Rapids-Kalamazoo-Battle Creek MI"),
+    (564, "WV", "Charleston-Huntington WV"),
+    (565, "NY", "Elmira (Corning) NY"),
+    (566, "PA", "Harrisburg-Lancaster-Lebanon-York PA"),
+    (567, "SC", "Greenville-Spartanburg SC-Asheville NC-Anderson SC"),
+    (569, "VA", "Harrisonburg VA"),
+    (570, "SC", "Florence-Myrtle Beach SC"),
+    (571, "FL", "Ft. Myers-Naples FL"),
+    (573, "VA", "Roanoke-Lynchburg VA"),
+    (574, "PA", "Johnstown-Altoona PA"),
+    (575, "TN", "Chattanooga TN"),
+    (576, "MD", "Salisbury MD"),
+    (577, "PA", "Wilkes Barre-Scranton PA"),
+    (581, "IN", "Terre Haute IN"),
+    (582, "IN", "Lafayette IN"),
+    (583, "MI", "Alpena MI"),
+    (584, "VA", "Charlottesville VA"),
+    (588, "IN", "South Bend-Elkhart IN"),
+    (592, "FL", "Gainesville FL"),
+    (596, "OH", "Zanesville OH"),
+    (597, "WV", "Parkersburg WV"),
+    (598, "WV", "Clarksburg-Weston WV"),
+    (600, "TX", "Corpus Christi TX"),
+    (602, "IL", "Chicago IL"),
+    (603, "MO", "Joplin MO-Pittsburg KS"),
+    (604, "MO", "Columbia-Jefferson City MO"),
+    (605, "KS", "Topeka KS"),
+    (606, "AL", "Dothan AL"),
+    (609, "MO", "St. Louis MO"),
+    (610, "IL", "Rockford IL"),
+    (611, "MN", "Rochester MN-Mason City IA-Austin MN"),
+    (612, "LA", "Shreveport LA"),
+    (613, "MN", "Minneapolis-St. Paul MN"),
+    (616, "MO", "Kansas City MO"),
+    (617, "WI", "Milwaukee WI"),
+    (618, "TX", "Houston TX"),
+    (619, "MO", "Springfield MO"),
+    (622, "LA", "New Orleans LA"),
+    (623, "TX", "Dallas-Ft. Worth TX"),
+    (624, "IA", "Sioux City IA"),
+    (625, "TX", "Waco-Temple-Bryan TX"),
+    (626, "TX", "Victoria TX"),
+    (627, "TX", "Wichita Falls TX & Lawton OK"),
+    (628, "LA", "Monroe LA-El Dorado AR"),
+    (630, "AL", "Birmingham AL"),
+    (631, "IA", "Ottumwa IA-Kirksville MO"),
+    (632, "KY", "Paducah KY-Cape Girardeau MO-Harrisburg-Mount Vernon IL"),
+    (633, "TX", "Odessa-Midland TX"),
+    (634, "TX", "Amarillo TX"),
+    (635, "TX", "Austin TX"),
+    (636, "TX", "Harlingen-Weslaco-Brownsville-McAllen TX"),
+    (637, "IA", "Cedar Rapids-Waterloo-Iowa City & Dubuque IA"),
+    (638, "MO", "St. Joseph MO"),
+    (639, "TN", "Jackson TN"),
+    (640, "TN", "Memphis TN"),
+    (641, "TX", "San Antonio TX"),
+    (642, "LA", "Lafayette LA"),
+    (643, "LA", "Lake Charles LA"),
+    (644, "LA", "Alexandria LA"),
+    (647, "MS", "Greenwood-Greenville MS"),
+    (648, "IL", "Champaign & Springfield-Decatur IL"),
+    (649, "IN", "Evansville IN"),
+    (650, "OK", "Oklahoma City OK"),
+    (651, "TX", "Lubbock TX"),
+    (652, "NE", "Omaha NE"),
+    (656, "FL", "Panama City FL"),
+    (657, "TX", "Sherman TX-Ada OK"),
+    (658, "WI", "Green Bay-Appleton WI"),
+    (659, "TN", "Nashville TN"),
+    (661, "TX", "San Angelo TX"),
+    (662, "TX", "Abilene-Sweetwater TX"),
+    (669, "WI", "Madison WI"),
+    (670, "AR", "Ft. Smith-Fayetteville-Springdale-Rogers AR"),
+    (671, "OK", "Tulsa OK"),
+    (673, "MS", "Columbus-Tupelo-West Point MS"),
+    (675, "IL", "Peoria-Bloomington IL"),
+    (676, "MN", "Duluth MN-Superior WI"),
+    (678, "KS", "Wichita-Hutchinson KS"),
+    (679, "IA", "Des Moines-Ames IA"),
+    (682, "IA", "Davenport IA-Rock Island-Moline IL"),
+    (686, "AL", "Mobile AL-Pensacola (Ft. Walton Beach) FL"),
+    (687, "ND", "Minot-Bismarck-Dickinson (Williston) ND"),
+    (691, "AL", "Huntsville-Decatur (Florence) AL"),
+    (692, "TX", "Beaumont-Port Arthur TX"),
+    (693, "AR", "Little Rock-Pine Bluff AR"),
+    (698, "AL", "Montgomery (Selma) AL"),
+    (702, "WI", "La Crosse-Eau Claire WI"),
+    (705, "WI", "Wausau-Rhinelander WI"),
+    (709, "TX", "Tyler-Longview (Lufkin & Nacogdoches) TX"),
+    (710, "MS", "Hattiesburg-Laurel MS"),
+    (711, "MS", "Meridian MS"),
+    (716, "LA", "Baton Rouge LA"),
+    (717, "IL", "Quincy IL-Hannibal MO-Keokuk IA"),
+    (718, "MS", "Jackson MS"),
+    (722, "NE", "Lincoln & Hastings-Kearney NE"),
+    (724, "ND", "Fargo-Valley City ND"),
+    (725, "SD", "Sioux Falls (Mitchell) SD"),
+    (734, "AR", "Jonesboro AR"),
+    (736, "KY", "Bowling Green KY"),
+    (737, "MN", "Mankato MN"),
+    (740, "NE", "North Platte NE"),
+    (743, "AK", "Anchorage AK"),
+    (744, "HI", "Honolulu HI"),
+    (745, "AK", "Fairbanks AK"),
+    (746, "MS", "Biloxi-Gulfport MS"),
+    (747, "AK", "Juneau AK"),
+    (749, "TX", "Laredo TX"),
+    (751, "CO", "Denver CO"),
+    (752, "CO", "Colorado Springs-Pueblo CO"),
+    (753, "AZ", "Phoenix AZ"),
+    (754, "MT", "Butte-Bozeman MT"),
+    (755, "MT", "Great Falls MT"),
+    (756, "MT", "Billings MT"),
+    (757, "ID", "Boise ID"),
+    (758, "ID", "Idaho Falls-Pocatello ID"),
+    (759, "WY", "Cheyenne WY-Scottsbluff NE"),
+    (760, "ID", "Twin Falls ID"),
+    (762, "MT", "Missoula MT"),
+    (764, "SD", "Rapid City SD"),
+    (765, "TX", "El Paso TX"),
+    (766, "MT", "Helena MT"),
+    (767, "WY", "Casper-Riverton WY"),
+    (770, "UT", "Salt Lake City UT"),
+    (771, "AZ", "Yuma AZ-El Centro CA"),
+    (773, "CO", "Grand Junction-Montrose CO"),
+    (789, "AZ", "Tucson (Sierra Vista) AZ"),
+    (790, "NM", "Albuquerque-Santa Fe NM"),
+    (798, "MT", "Glendive MT"),
+    (800, "CA", "Bakersfield CA"),
+    (801, "OR", "Eugene OR"),
+    (802, "CA", "Eureka CA"),
+    (803, "CA", "Los Angeles CA"),
+    (804, "CA", "Palm Springs CA"),
+    (807, "CA", "San Francisco-Oakland-San Jose CA"),
+    (810, "WA", "Yakima-Pasco-Richland-Kennewick WA"),
+    (811, "NV", "Reno NV"),
+    (813, "OR", "Medford-Klamath Falls OR"),
+    (819, "WA", "Seattle-Tacoma WA"),
+    (820, "OR", "Portland OR"),
+    (821, "OR", "Bend OR"),
+    (825, "CA", "San Diego CA"),
+    (828, "CA", "Monterey-Salinas CA"),
+    (839, "NV", "Las Vegas NV"),
+    (855, "CA", "Santa Barbara-Santa Maria-San Luis Obispo CA"),
+    (862, "CA", "Sacramento-Stockton-Modesto CA"),
+    (866, "CA", "Fresno-Visalia CA"),
+    (868, "CA", "Chico-Redding CA"),
+    (881, "WA", "Spokane WA"),
+];
@@ -0,0 +1,163 @@
+use std::fmt::Write;
+
+use reqwest::header::{self, HeaderValue};
+
+use crate::client::MAX_ERROR_BODY;
+use crate::{Error, Query, TimeSeriesData};
+
+/// Formats `series` as InfluxDB line protocol, one line per point that has a value. Each line carries
+/// `tags` plus a `column` tag with the label `Query::labels` gives the point's comparison item, a
+/// `value` field and a `partial` field, with the timestamp in nanoseconds. Tags are written sorted by
+/// key, the order Influx ingests fastest.
+pub fn to_line_protocol(series: &TimeSeriesData, query: &Query, measurement: &str, tags: &[(&str, &str)]) -> String {
+    let prefixes: Vec<String> = query
+        .labels()
+        .iter()
+        .map(|label| {
+            let mut tags: Vec<(&str, &str)> = tags.iter().copied().filter(|(key, _)| *key != "column").collect();
+            tags.push(("column", label.as_str()));
+            tags.sort_by_key(|(key, _)| *key);
+
+            let mut prefix = escape(measurement, &[',', ' ']);
+            for (key, value) in tags {
+                let _ = write!(prefix, ",{}={}", escape_tag(key), escape_tag(value));
+            }
+            prefix
+        })
+        .collect();
+
+    let mut lines = String::new();
+    for entry in &series.entries {
+        for (prefix, value) in prefixes.iter().zip(entry.values()) {
+            if let Some(value) = value.as_f32() {
+                let _ = writeln!(
+                    lines,
+                    "{} value={},partial={} {}",
+                    prefix,
+                    value,
+                    entry.is_partial.unwrap_or(false),
+                    entry.time.timestamp_nanos()
+                );
+            }
+        }
+    }
+    lines
+}
+
+fn escape_tag(str: &str) -> String {
+    escape(str, &[',', '=', ' '])
+}
+
+fn escape(str: &str, special: &[char]) -> String {
+    let mut escaped = String::with_capacity(str.len());
+    for c in str.chars() {
+        if special.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Writes line protocol to the v2 HTTP API (`/api/v2/write`) of an InfluxDB server.
+#[derive(Debug, Clone)]
+pub struct InfluxWriter {
+    client: reqwest::Client,
+    url: String,
+    org: String,
+    bucket: String,
+    token: Option<String>,
+}
+
+impl InfluxWriter {
+    /// `url` is the server's base URL, e.g. `http://localhost:8086`.
+    pub fn new<S: Into<String>>(url: S, org: S, bucket: S) -> InfluxWriter {
+        InfluxWriter {
+            client: reqwest::Client::new(),
+            url: url.into(),
+            org: org.into(),
+            bucket: bucket.into(),
+            token: None,
+        }
+    }
+
+    pub fn token<S: Into<String>>(mut self, token: S) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Sends `lines` as produced by `to_line_protocol`, nothing is sent when they are empty.
+    pub async fn write(&self, lines: &str) -> Result<(), Error> {
+        if lines.is_empty() {
+            return Ok(());
+        }
+        let mut req = self
+            .client
+            .post(&format!("{}/api/v2/write", self.url.trim_end_matches('/')))
+            .query(&[
+                ("org", &self.org),
+                ("bucket", &self.bucket),
+                ("precision", &"ns".to_owned()),
+            ])
+            .header(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("text/plain; charset=utf-8"),
+            )
+            .body(lines.to_owned());
+        if let Some(token) = &self.token {
+            req = req.header(header::AUTHORIZATION, format!("Token {}", token));
+        }
+
+        let resp = req.send().await?;
+        let status = resp.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let content_type = resp
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|val| val.to_str().ok())
+            .map(str::to_owned);
+        let mut body = resp.text().await?;
+        if let Some((end, _)) = body.char_indices().nth(MAX_ERROR_BODY) {
+            body.truncate(end);
+        }
+        Err(Error::UnexpectedStatus {
+            status: status.as_u16(),
+            retry_after: None,
+            content_type,
+            body,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{QueryItem, Timeframe};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn column_tags_are_labels_in_sorted_position() {
+        let time = Timeframe::new(Utc.ymd(2021, 1, 1), Utc.ymd(2021, 3, 1));
+        let query = Query::new(vec![
+            QueryItem::by_keyword("rust lang", time.clone()),
+            QueryItem::by_keyword("go", time),
+        ]);
+        let series: TimeSeriesData = serde_json::from_str(
+            r#"{"timelineData":[{"time":"1609632000","formattedTime":"Jan 3","value":[64,0],"hasData":[true,false]}]}"#,
+        )
+        .unwrap();
+
+        let lines = to_line_protocol(&series, &query, "trends", &[("source", "web"), ("app", "collector")]);
+        assert_eq!(
+            lines,
+            "trends,app=collector,column=rust\\ lang,source=web value=64,partial=false 1609632000000000000\n"
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use std::io::Write;
+
+use serde_json::{json, Value};
+
+use crate::{Error, Query, RegionData, TimeSeriesData};
+
+/// Data that can be flattened into one JSON record per data point, annotated with the
+/// comparison item (keyword, geo and timeframe) the point belongs to.
+pub trait Records {
+    fn records(&self, query: &Query) -> Vec<Value>;
+}
+
+impl Records for TimeSeriesData {
+    fn records(&self, query: &Query) -> Vec<Value> {
+        let mut records = vec![];
+        for entry in &self.entries {
+            for (item, value) in query.items().iter().zip(entry.values()) {
+                records.push(json!({
+                    "keyword": item.keyword(),
+                    "geo": query.geo_of(item),
+                    "timeframe": item.time().formatted(),
+                    "time": entry.time.to_rfc3339(),
+                    "value": value.as_f32(),
+                    "partial": entry.is_partial.unwrap_or(false),
+                }));
+            }
+        }
+        records
+    }
+}
+
+impl Records for RegionData {
+    fn records(&self, query: &Query) -> Vec<Value> {
+        let mut records = vec![];
+        for entry in &self.entries {
+            for (item, value) in query.items().iter().zip(entry.values()) {
+                records.push(json!({
+                    "keyword": item.keyword(),
+                    "geo": query.geo_of(item),
+                    "timeframe": item.time().formatted(),
+                    "region": entry.geo_code,
+                    "region_name": entry.geo_name,
+                    "value": value.as_f32(),
+                }));
+            }
+        }
+        records
+    }
+}
+
+/// Writes every record of `results` as a line of JSON, flushing once at the end.
+pub fn write<'a, W, T, I>(mut sink: W, results: I) -> Result<(), Error>
+where
+    W: Write,
+    T: Records + 'a,
+    I: IntoIterator<Item = (&'a Query<'a>, &'a T)>,
+{
+    for (query, data) in results {
+        for record in data.records(query) {
+            serde_json::to_writer(&mut sink, &record)?;
+            sink.write_all(b"\n")?;
+        }
+    }
+    sink.flush()?;
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::{Coordinates, Error, RegionData, RegionEntry};
+
+/// Country or region shapes to place coordinates in, read from a GeoJSON `FeatureCollection` of
+/// polygons such as the Natural Earth admin-0 (countries, code in `ISO_A2`) or admin-1 (regions, code in
+/// `iso_3166_2`) files. No shapes ship with the crate, boundaries precise enough for cities near a
+/// border run to megabytes and the choice of dataset and its licence is left to the application.
+#[derive(Debug, Clone)]
+pub struct Boundaries {
+    shapes: Vec<Shape>,
+}
+
+#[derive(Debug, Clone)]
+struct Shape {
+    code: String,
+    // min lng, min lat, max lng, max lat
+    bbox: [f64; 4],
+    // each polygon is its outer ring followed by its holes, points as (lng, lat)
+    polygons: Vec<Vec<Vec<(f64, f64)>>>,
+}
+
+impl Boundaries {
+    /// Reads the features with a `Polygon` or `MultiPolygon` geometry and a string `code_property`,
+    /// others are skipped.
+    pub fn from_geojson(json: &str, code_property: &str) -> Result<Boundaries, Error> {
+        let collection: Value = serde_json::from_str(json)?;
+        let features = collection["features"]
+            .as_array()
+            .ok_or_else(|| Error::InvalidConfig("GeoJSON without a features array".to_owned()))?;
+
+        let mut shapes = vec![];
+        for feature in features {
+            let code = match feature["properties"][code_property].as_str() {
+                Some(code) => code.to_owned(),
+                None => continue,
+            };
+            let geometry = &feature["geometry"];
+            let polygons = match geometry["type"].as_str() {
+                Some("Polygon") => vec![polygon(&geometry["coordinates"])?],
+                Some("MultiPolygon") => geometry["coordinates"]
+                    .as_array()
+                    .map_or(&[][..], Vec::as_slice)
+                    .iter()
+                    .map(polygon)
+                    .collect::<Result<_, _>>()?,
+                _ => continue,
+            };
+            let bbox = polygons
+                .iter()
+                .flatten()
+                .flatten()
+                .fold([f64::MAX, f64::MAX, f64::MIN, f64::MIN], |[x0, y0, x1, y1], &(x, y)| {
+                    [x0.min(x), y0.min(y), x1.max(x), y1.max(y)]
+                });
+            shapes.push(Shape { code, bbox, polygons });
+        }
+        Ok(Boundaries { shapes })
+    }
+
+    pub fn from_path<P: AsRef<Path>>(path: P, code_property: &str) -> Result<Boundaries, Error> {
+        Boundaries::from_geojson(&fs::read_to_string(path)?, code_property)
+    }
+
+    /// The code of the first shape containing `coordinates`.
+    pub fn locate(&self, coordinates: &Coordinates) -> Option<&str> {
+        let (x, y) = (coordinates.lng, coordinates.lat);
+        self.shapes
+            .iter()
+            .filter(|shape| x >= shape.bbox[0] && y >= shape.bbox[1] && x <= shape.bbox[2] && y <= shape.bbox[3])
+            .find(|shape| shape.polygons.iter().any(|rings| contains(rings, x, y)))
+            .map(|shape| shape.code.as_str())
+    }
+}
+
+fn polygon(coordinates: &Value) -> Result<Vec<Vec<(f64, f64)>>, Error> {
+    let invalid = || Error::InvalidConfig("GeoJSON polygon with malformed coordinates".to_owned());
+    coordinates
+        .as_array()
+        .ok_or_else(invalid)?
+        .iter()
+        .map(|ring| {
+            ring.as_array()
+                .ok_or_else(invalid)?
+                .iter()
+                .map(|point| match (point[0].as_f64(), point[1].as_f64()) {
+                    (Some(lng), Some(lat)) => Ok((lng, lat)),
+                    _ => Err(invalid()),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// even-odd ray casting over all rings, so a point in a hole crosses the outer ring and the hole
+fn contains(rings: &[Vec<(f64, f64)>], x: f64, y: f64) -> bool {
+    let mut inside = false;
+    for ring in rings {
+        for (index, &(x1, y1)) in ring.iter().enumerate() {
+            let (x2, y2) = ring[(index + 1) % ring.len()];
+            if (y1 > y) != (y2 > y) && x < (x2 - x1) * (y - y1) / (y2 - y1) + x1 {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+impl RegionEntry {
+    /// The code of the boundary the entry's coordinates fall in, `None` without coordinates.
+    pub fn locate<'a>(&self, boundaries: &'a Boundaries) -> Option<&'a str> {
+        boundaries.locate(self.coordinates.as_ref()?)
+    }
+}
+
+impl RegionData {
+    /// Groups the entries, typically of `Resolution::City` results, by the boundary their coordinates fall
+    /// in. Entries without coordinates or outside every boundary are left out.
+    pub fn group_by_boundary(&self, boundaries: &Boundaries) -> BTreeMap<String, Vec<&RegionEntry>> {
+        let mut groups: BTreeMap<String, Vec<&RegionEntry>> = BTreeMap::new();
+        for entry in &self.entries {
+            if let Some(code) = entry.locate(boundaries) {
+                groups.entry(code.to_owned()).or_default().push(entry);
+            }
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a square around the origin with a hole in the middle and a separate unit square to its east
+    const JSON: &str = r#"{"type":"FeatureCollection","features":[
+        {"type":"Feature","properties":{"code":"AA"},"geometry":{"type":"Polygon","coordinates":[
+            [[-2,-2],[2,-2],[2,2],[-2,2],[-2,-2]],[[-1,-1],[1,-1],[1,1],[-1,1],[-1,-1]]]}},
+        {"type":"Feature","properties":{"code":"BB"},"geometry":{"type":"MultiPolygon","coordinates":[
+            [[[5,0],[6,0],[6,1],[5,1],[5,0]]]]}},
+        {"type":"Feature","properties":{},"geometry":{"type":"Polygon","coordinates":[
+            [[-10,-10],[10,-10],[10,10],[-10,10],[-10,-10]]]}}]}"#;
+
+    fn at(lng: f64, lat: f64) -> Coordinates {
+        Coordinates { lat, lng }
+    }
+
+    #[test]
+    fn locates_points_in_polygons_and_not_in_holes() {
+        let boundaries = Boundaries::from_geojson(JSON, "code").unwrap();
+        assert_eq!(boundaries.locate(&at(-1.5, 0.0)), Some("AA"));
+        assert_eq!(boundaries.locate(&at(0.0, 0.0)), None);
+        assert_eq!(boundaries.locate(&at(5.5, 0.5)), Some("BB"));
+        assert_eq!(boundaries.locate(&at(8.0, 8.0)), None);
+    }
+
+    #[test]
+    fn rejects_malformed_geometries() {
+        let json =
+            r#"{"features":[{"properties":{"code":"AA"},"geometry":{"type":"Polygon","coordinates":[[["x",0]]]}}]}"#;
+        assert!(matches!(
+            Boundaries::from_geojson(json, "code"),
+            Err(Error::InvalidConfig(_))
+        ));
+    }
+}
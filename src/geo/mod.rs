@@ -0,0 +1,11 @@
+#[cfg(feature = "geo-boundaries")]
+mod boundaries;
+#[cfg(feature = "geo-names")]
+mod names;
+#[cfg(feature = "geo-names")]
+mod tables;
+
+#[cfg(feature = "geo-boundaries")]
+pub use boundaries::Boundaries;
+#[cfg(feature = "geo-names")]
+pub use names::localize;
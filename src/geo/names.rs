@@ -0,0 +1,87 @@
+use super::tables::{COUNTRIES, SUBDIVISIONS, TRANSLATIONS};
+use crate::RegionData;
+
+/// The name of a country (`DE`) or subdivision (`US-CA`) code in `locale`, which takes the same form as
+/// the `hl` of a client (`de`, `pt-BR`, `zh-TW`). Countries are translated into the larger Trends
+/// locales and fall back to English, subdivisions are named the way ISO 3166-2 names them whatever the
+/// locale. `None` for codes that aren't ISO 3166, like those of metro areas.
+pub fn localize(code: &str, locale: &str) -> Option<&'static str> {
+    let code = code.to_ascii_uppercase();
+    if code.contains('-') {
+        return SUBDIVISIONS
+            .binary_search_by_key(&code.as_str(), |(code, _)| *code)
+            .ok()
+            .map(|index| SUBDIVISIONS[index].1);
+    }
+    let english = COUNTRIES
+        .binary_search_by_key(&code.as_str(), |(code, _)| *code)
+        .ok()
+        .map(|index| COUNTRIES[index].1)?;
+    let translated = candidates(locale).into_iter().find_map(|locale| {
+        TRANSLATIONS
+            .binary_search_by_key(&(code.as_str(), locale.as_str()), |(code, locale, _)| (*code, *locale))
+            .ok()
+            .map(|index| TRANSLATIONS[index].2)
+    });
+    Some(translated.unwrap_or(english))
+}
+
+// the table keys to try for a locale, most specific first
+fn candidates(locale: &str) -> Vec<String> {
+    let locale = locale.to_ascii_lowercase().replace('_', "-");
+    let language = locale.split('-').next().unwrap_or_default();
+    let language = match language {
+        "iw" => "he",
+        other => other,
+    };
+    match language {
+        "zh" if locale == "zh-tw" || locale == "zh-hk" => vec!["zh-tw".to_owned()],
+        "zh" => vec!["zh-cn".to_owned()],
+        language => vec![locale.clone(), language.to_owned()],
+    }
+}
+
+impl RegionData {
+    /// Renames the entries with an ISO 3166 code after it in `locale`, so results fetched under
+    /// different client locales can be displayed side by side.
+    pub fn with_localized_names(mut self, locale: &str) -> RegionData {
+        for entry in &mut self.entries {
+            if let Some(name) = localize(&entry.geo_code, locale) {
+                entry.geo_name = name.to_owned();
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_sorted<K: Ord, T>(table: &[T], key: impl Fn(&T) -> K) -> bool {
+        table.windows(2).all(|pair| key(&pair[0]) < key(&pair[1]))
+    }
+
+    #[test]
+    fn tables_are_sorted_for_the_binary_search() {
+        assert!(is_sorted(COUNTRIES, |(code, _)| *code));
+        assert!(is_sorted(SUBDIVISIONS, |(code, _)| *code));
+        assert!(is_sorted(TRANSLATIONS, |(code, locale, _)| (*code, *locale)));
+    }
+
+    #[test]
+    fn localizes_countries_with_an_english_fallback() {
+        assert_eq!(localize("DE", "de"), Some("Deutschland"));
+        assert_eq!(localize("de", "fr-FR"), Some("Allemagne"));
+        assert_eq!(localize("DE", "en-US"), Some("Germany"));
+        assert_eq!(localize("DE", "xx"), Some("Germany"));
+        assert_eq!(localize("IL", "iw"), localize("IL", "he"));
+    }
+
+    #[test]
+    fn names_subdivisions_and_skips_other_codes() {
+        assert_eq!(localize("US-CA", "de"), Some("California"));
+        assert_eq!(localize("US-NY-501", "en-US"), None);
+        assert_eq!(localize("XX", "en-US"), None);
+    }
+}
@@ -0,0 +1,10127 @@
+// Generated from the iso-codes project (ISO 3166-1 and 3166-2 names and their translations),
+// https://salsa.debian.org/iso-codes-team/iso-codes, LGPL-2.1-or-later.
+
+// sorted by code, for the binary search
+pub(super) const COUNTRIES: &[(&str, &str)] = &[
+    ("AD", "Andorra"),
+    ("AE", "United Arab Emirates"),
+    ("AF", "Afghanistan"),
+    ("AG", "Antigua and Barbuda"),
+    ("AI", "Anguilla"),
+    ("AL", "Albania"),
+    ("AM", "Armenia"),
+    ("AO", "Angola"),
+    ("AQ", "Antarctica"),
+    ("AR", "Argentina"),
+    ("AS", "American Samoa"),
+    ("AT", "Austria"),
+    ("AU", "Australia"),
+    ("AW", "Aruba"),
+    ("AX", "Åland Islands"),
+    ("AZ", "Azerbaijan"),
+    ("BA", "Bosnia and Herzegovina"),
+    ("BB", "Barbados"),
+    ("BD", "Bangladesh"),
+    ("BE", "Belgium"),
+    ("BF", "Burkina Faso"),
+    ("BG", "Bulgaria"),
+    ("BH", "Bahrain"),
+    ("BI", "Burundi"),
+    ("BJ", "Benin"),
+    ("BL", "Saint Barthélemy"),
+    ("BM", "Bermuda"),
+    ("BN", "Brunei Darussalam"),
+    ("BO", "Bolivia"),
+    ("BQ", "Bonaire, Sint Eustatius and Saba"),
+    ("BR", "Brazil"),
+    ("BS", "Bahamas"),
+    ("BT", "Bhutan"),
+    ("BV", "Bouvet Island"),
+    ("BW", "Botswana"),
+    ("BY", "Belarus"),
+    ("BZ", "Belize"),
+    ("CA", "Canada"),
+    ("CC", "Cocos (Keeling) Islands"),
+    ("CD", "Congo, The Democratic Republic of the"),
+    ("CF", "Central African Republic"),
+    ("CG", "Congo"),
+    ("CH", "Switzerland"),
+    ("CI", "Côte d'Ivoire"),
+    ("CK", "Cook Islands"),
+    ("CL", "Chile"),
+    ("CM", "Cameroon"),
+    ("CN", "China"),
+    ("CO", "Colombia"),
+    ("CR", "Costa Rica"),
+    ("CU", "Cuba"),
+    ("CV", "Cabo Verde"),
+    ("CW", "Curaçao"),
+    ("CX", "Christmas Island"),
+    ("CY", "Cyprus"),
+    ("CZ", "Czechia"),
+    ("DE", "Germany"),
+    ("DJ", "Djibouti"),
+    ("DK", "Denmark"),
+    ("DM", "Dominica"),
+    ("DO", "Dominican Republic"),
+    ("DZ", "Algeria"),
+    ("EC", "Ecuador"),
+    ("EE", "Estonia"),
+    ("EG", "Egypt"),
+    ("EH", "Western Sahara"),
+    ("ER", "Eritrea"),
+    ("ES", "Spain"),
+    ("ET", "Ethiopia"),
+    ("FI", "Finland"),
+    ("FJ", "Fiji"),
+    ("FK", "Falkland Islands (Malvinas)"),
+    ("FM", "Micronesia, Federated States of"),
+    ("FO", "Faroe Islands"),
+    ("FR", "France"),
+    ("GA", "Gabon"),
+    ("GB", "United Kingdom"),
+    ("GD", "Grenada"),
+    ("GE", "Georgia"),
+    ("GF", "French Guiana"),
+    ("GG", "Guernsey"),
+    ("GH", "Ghana"),
+    ("GI", "Gibraltar"),
+    ("GL", "Greenland"),
+    ("GM", "Gambia"),
+    ("GN", "Guinea"),
+    ("GP", "Guadeloupe"),
+    ("GQ", "Equatorial Guinea"),
+    ("GR", "Greece"),
+    ("GS", "South Georgia and the South Sandwich Islands"),
+    ("GT", "Guatemala"),
+    ("GU", "Guam"),
+    ("GW", "Guinea-Bissau"),
+    ("GY", "Guyana"),
+    ("HK", "Hong Kong"),
+    ("HM", "Heard Island and McDonald Islands"),
+    ("HN", "Honduras"),
+    ("HR", "Croatia"),
+    ("HT", "Haiti"),
+    ("HU", "Hungary"),
+    ("ID", "Indonesia"),
+    ("IE", "Ireland"),
+    ("IL", "Israel"),
+    ("IM", "Isle of Man"),
+    ("IN", "India"),
+    ("IO", "British Indian Ocean Territory"),
+    ("IQ", "Iraq"),
+    ("IR", "Iran"),
+    ("IS", "Iceland"),
+    ("IT", "Italy"),
+    ("JE", "Jersey"),
+    ("JM", "Jamaica"),
+    ("JO", "Jordan"),
+    ("JP", "Japan"),
+    ("KE", "Kenya"),
+    ("KG", "Kyrgyzstan"),
+    ("KH", "Cambodia"),
+    ("KI", "Kiribati"),
+    ("KM", "Comoros"),
+    ("KN", "Saint Kitts and Nevis"),
+    ("KP", "North Korea"),
+    ("KR", "South Korea"),
+    ("KW", "Kuwait"),
+    ("KY", "Cayman Islands"),
+    ("KZ", "Kazakhstan"),
+    ("LA", "Laos"),
+    ("LB", "Lebanon"),
+    ("LC", "Saint Lucia"),
+    ("LI", "Liechtenstein"),
+    ("LK", "Sri Lanka"),
+    ("LR", "Liberia"),
+    ("LS", "Lesotho"),
+    ("LT", "Lithuania"),
+    ("LU", "Luxembourg"),
+    ("LV", "Latvia"),
+    ("LY", "Libya"),
+    ("MA", "Morocco"),
+    ("MC", "Monaco"),
+    ("MD", "Moldova"),
+    ("ME", "Montenegro"),
+    ("MF", "Saint Martin (French part)"),
+    ("MG", "Madagascar"),
+    ("MH", "Marshall Islands"),
+    ("MK", "North Macedonia"),
+    ("ML", "Mali"),
+    ("MM", "Myanmar"),
+    ("MN", "Mongolia"),
+    ("MO", "Macao"),
+    ("MP", "Northern Mariana Islands"),
+    ("MQ", "Martinique"),
+    ("MR", "Mauritania"),
+    ("MS", "Montserrat"),
+    ("MT", "Malta"),
+    ("MU", "Mauritius"),
+    ("MV", "Maldives"),
+    ("MW", "Malawi"),
+    ("MX", "Mexico"),
+    ("MY", "Malaysia"),
+    ("MZ", "Mozambique"),
+    ("NA", "Namibia"),
+    ("NC", "New Caledonia"),
+    ("NE", "Niger"),
+    ("NF", "Norfolk Island"),
+    ("NG", "Nigeria"),
+    ("NI", "Nicaragua"),
+    ("NL", "Netherlands"),
+    ("NO", "Norway"),
+    ("NP", "Nepal"),
+    ("NR", "Nauru"),
+    ("NU", "Niue"),
+    ("NZ", "New Zealand"),
+    ("OM", "Oman"),
+    ("PA", "Panama"),
+    ("PE", "Peru"),
+    ("PF", "French Polynesia"),
+    ("PG", "Papua New Guinea"),
+    ("PH", "Philippines"),
+    ("PK", "Pakistan"),
+    ("PL", "Poland"),
+    ("PM", "Saint Pierre and Miquelon"),
+    ("PN", "Pitcairn"),
+    ("PR", "Puerto Rico"),
+    ("PS", "Palestine, State of"),
+    ("PT", "Portugal"),
+    ("PW", "Palau"),
+    ("PY", "Paraguay"),
+    ("QA", "Qatar"),
+    ("RE", "Réunion"),
+    ("RO", "Romania"),
+    ("RS", "Serbia"),
+    ("RU", "Russian Federation"),
+    ("RW", "Rwanda"),
+    ("SA", "Saudi Arabia"),
+    ("SB", "Solomon Islands"),
+    ("SC", "Seychelles"),
+    ("SD", "Sudan"),
+    ("SE", "Sweden"),
+    ("SG", "Singapore"),
+    ("SH", "Saint Helena, Ascension and Tristan da Cunha"),
+    ("SI", "Slovenia"),
+    ("SJ", "Svalbard and Jan Mayen"),
+    ("SK", "Slovakia"),
+    ("SL", "Sierra Leone"),
+    ("SM", "San Marino"),
+    ("SN", "Senegal"),
+    ("SO", "Somalia"),
+    ("SR", "Suriname"),
+    ("SS", "South Sudan"),
+    ("ST", "Sao Tome and Principe"),
+    ("SV", "El Salvador"),
+    ("SX", "Sint Maarten (Dutch part)"),
+    ("SY", "Syria"),
+    ("SZ", "Eswatini"),
+    ("TC", "Turks and Caicos Islands"),
+    ("TD", "Chad"),
+    ("TF", "French Southern Territories"),
+    ("TG", "Togo"),
+    ("TH", "Thailand"),
+    ("TJ", "Tajikistan"),
+    ("TK", "Tokelau"),
+    ("TL", "Timor-Leste"),
+    ("TM", "Turkmenistan"),
+    ("TN", "Tunisia"),
+    ("TO", "Tonga"),
+    ("TR", "Türkiye"),
+    ("TT", "Trinidad and Tobago"),
+    ("TV", "Tuvalu"),
+    ("TW", "Taiwan"),
+    ("TZ", "Tanzania"),
+    ("UA", "Ukraine"),
+    ("UG", "Uganda"),
+    ("UM", "United States Minor Outlying Islands"),
+    ("US", "United States"),
+    ("UY", "Uruguay"),
+    ("UZ", "Uzbekistan"),
+    ("VA", "Holy See (Vatican City State)"),
+    ("VC", "Saint Vincent and the Grenadines"),
+    ("VE", "Venezuela"),
+    ("VG", "Virgin Islands, British"),
+    ("VI", "Virgin Islands, U.S."),
+    ("VN", "Vietnam"),
+    ("VU", "Vanuatu"),
+    ("WF", "Wallis and Futuna"),
+    ("WS", "Samoa"),
+    ("YE", "Yemen"),
+    ("YT", "Mayotte"),
+    ("ZA", "South Africa"),
+    ("ZM", "Zambia"),
+    ("ZW", "Zimbabwe"),
+];
+
+// sorted by code, for the binary search
+pub(super) const SUBDIVISIONS: &[(&str, &str)] = &[
+    ("AD-02", "Canillo"),
+    ("AD-03", "Encamp"),
+    ("AD-04", "La Massana"),
+    ("AD-05", "Ordino"),
+    ("AD-06", "Sant Julià de Lòria"),
+    ("AD-07", "Andorra la Vella"),
+    ("AD-08", "Escaldes-Engordany"),
+    ("AE-AJ", "‘Ajmān"),
+    ("AE-AZ", "Abū Z̧aby"),
+    ("AE-DU", "Dubayy"),
+    ("AE-FU", "Al Fujayrah"),
+    ("AE-RK", "Ra’s al Khaymah"),
+    ("AE-SH", "Ash Shāriqah"),
+    ("AE-UQ", "Umm al Qaywayn"),
+    ("AF-BAL", "Balkh"),
+    ("AF-BAM", "Bāmyān"),
+    ("AF-BDG", "Bādghīs"),
+    ("AF-BDS", "Badakhshān"),
+    ("AF-BGL", "Baghlān"),
+    ("AF-DAY", "Dāykundī"),
+    ("AF-FRA", "Farāh"),
+    ("AF-FYB", "Fāryāb"),
+    ("AF-GHA", "Ghaznī"),
+    ("AF-GHO", "Ghōr"),
+    ("AF-HEL", "Helmand"),
+    ("AF-HER", "Herāt"),
+    ("AF-JOW", "Jowzjān"),
+    ("AF-KAB", "Kābul"),
+    ("AF-KAN", "Kandahār"),
+    ("AF-KAP", "Kāpīsā"),
+    ("AF-KDZ", "Kunduz"),
+    ("AF-KHO", "Khōst"),
+    ("AF-KNR", "Kunaṟ"),
+    ("AF-LAG", "Laghmān"),
+    ("AF-LOG", "Lōgar"),
+    ("AF-NAN", "Nangarhār"),
+    ("AF-NIM", "Nīmrōz"),
+    ("AF-NUR", "Nūristān"),
+    ("AF-PAN", "Panjshayr"),
+    ("AF-PAR", "Parwān"),
+    ("AF-PIA", "Paktiyā"),
+    ("AF-PKA", "Paktīkā"),
+    ("AF-SAM", "Samangān"),
+    ("AF-SAR", "Sar-e Pul"),
+    ("AF-TAK", "Takhār"),
+    ("AF-URU", "Uruzgān"),
+    ("AF-WAR", "Wardak"),
+    ("AF-ZAB", "Zābul"),
+    ("AG-03", "Saint George"),
+    ("AG-04", "Saint John"),
+    ("AG-05", "Saint Mary"),
+    ("AG-06", "Saint Paul"),
+    ("AG-07", "Saint Peter"),
+    ("AG-08", "Saint Philip"),
+    ("AG-10", "Barbuda"),
+    ("AG-11", "Redonda"),
+    ("AL-01", "Berat"),
+    ("AL-02", "Durrës"),
+    ("AL-03", "Elbasan"),
+    ("AL-04", "Fier"),
+    ("AL-05", "Gjirokastër"),
+    ("AL-06", "Korçë"),
+    ("AL-07", "Kukës"),
+    ("AL-08", "Lezhë"),
+    ("AL-09", "Dibër"),
+    ("AL-10", "Shkodër"),
+    ("AL-11", "Tiranë"),
+    ("AL-12", "Vlorë"),
+    ("AM-AG", "Aragac̣otn"),
+    ("AM-AR", "Ararat"),
+    ("AM-AV", "Armavir"),
+    ("AM-ER", "Erevan"),
+    ("AM-GR", "Geġark'unik'"),
+    ("AM-KT", "Kotayk'"),
+    ("AM-LO", "Loṙi"),
+    ("AM-SH", "Širak"),
+    ("AM-SU", "Syunik'"),
+    ("AM-TV", "Tavuš"),
+    ("AM-VD", "Vayoć Jor"),
+    ("AO-BGO", "Bengo"),
+    ("AO-BGU", "Benguela"),
+    ("AO-BIE", "Bié"),
+    ("AO-CAB", "Cabinda"),
+    ("AO-CCU", "Cuando Cubango"),
+    ("AO-CNN", "Cunene"),
+    ("AO-CNO", "Cuanza-Norte"),
+    ("AO-CUS", "Cuanza-Sul"),
+    ("AO-HUA", "Huambo"),
+    ("AO-HUI", "Huíla"),
+    ("AO-LNO", "Lunda-Norte"),
+    ("AO-LSU", "Lunda-Sul"),
+    ("AO-LUA", "Luanda"),
+    ("AO-MAL", "Malange"),
+    ("AO-MOX", "Moxico"),
+    ("AO-NAM", "Namibe"),
+    ("AO-UIG", "Uíge"),
+    ("AO-ZAI", "Zaire"),
+    ("AR-A", "Salta"),
+    ("AR-B", "Buenos Aires"),
+    ("AR-C", "Ciudad Autónoma de Buenos Aires"),
+    ("AR-D", "San Luis"),
+    ("AR-E", "Entre Ríos"),
+    ("AR-F", "La Rioja"),
+    ("AR-G", "Santiago del Estero"),
+    ("AR-H", "Chaco"),
+    ("AR-J", "San Juan"),
+    ("AR-K", "Catamarca"),
+    ("AR-L", "La Pampa"),
+    ("AR-M", "Mendoza"),
+    ("AR-N", "Misiones"),
+    ("AR-P", "Formosa"),
+    ("AR-Q", "Neuquén"),
+    ("AR-R", "Río Negro"),
+    ("AR-S", "Santa Fe"),
+    ("AR-T", "Tucumán"),
+    ("AR-U", "Chubut"),
+    ("AR-V", "Tierra del Fuego"),
+    ("AR-W", "Corrientes"),
+    ("AR-X", "Córdoba"),
+    ("AR-Y", "Jujuy"),
+    ("AR-Z", "Santa Cruz"),
+    ("AT-1", "Burgenland"),
+    ("AT-2", "Kärnten"),
+    ("AT-3", "Niederösterreich"),
+    ("AT-4", "Oberösterreich"),
+    ("AT-5", "Salzburg"),
+    ("AT-6", "Steiermark"),
+    ("AT-7", "Tirol"),
+    ("AT-8", "Vorarlberg"),
+    ("AT-9", "Wien"),
+    ("AU-ACT", "Australian Capital Territory"),
+    ("AU-NSW", "New South Wales"),
+    ("AU-NT", "Northern Territory"),
+    ("AU-QLD", "Queensland"),
+    ("AU-SA", "South Australia"),
+    ("AU-TAS", "Tasmania"),
+    ("AU-VIC", "Victoria"),
+    ("AU-WA", "Western Australia"),
+    ("AZ-ABS", "Abşeron"),
+    ("AZ-AGA", "Ağstafa"),
+    ("AZ-AGC", "Ağcabədi"),
+    ("AZ-AGM", "Ağdam"),
+    ("AZ-AGS", "Ağdaş"),
+    ("AZ-AGU", "Ağsu"),
+    ("AZ-AST", "Astara"),
+    ("AZ-BA", "Bakı"),
+    ("AZ-BAB", "Babək"),
+    ("AZ-BAL", "Balakən"),
+    ("AZ-BAR", "Bərdə"),
+    ("AZ-BEY", "Beyləqan"),
+    ("AZ-BIL", "Biləsuvar"),
+    ("AZ-CAB", "Cəbrayıl"),
+    ("AZ-CAL", "Cəlilabad"),
+    ("AZ-CUL", "Culfa"),
+    ("AZ-DAS", "Daşkəsən"),
+    ("AZ-FUZ", "Füzuli"),
+    ("AZ-GA", "Gəncə"),
+    ("AZ-GAD", "Gədəbəy"),
+    ("AZ-GOR", "Goranboy"),
+    ("AZ-GOY", "Göyçay"),
+    ("AZ-GYG", "Göygöl"),
+    ("AZ-HAC", "Hacıqabul"),
+    ("AZ-IMI", "İmişli"),
+    ("AZ-ISM", "İsmayıllı"),
+    ("AZ-KAL", "Kəlbəcər"),
+    ("AZ-KAN", "Kǝngǝrli"),
+    ("AZ-KUR", "Kürdəmir"),
+    ("AZ-LA", "Lənkəran"),
+    ("AZ-LAC", "Laçın"),
+    ("AZ-LAN", "Lənkəran"),
+    ("AZ-LER", "Lerik"),
+    ("AZ-MAS", "Masallı"),
+    ("AZ-MI", "Mingəçevir"),
+    ("AZ-NA", "Naftalan"),
+    ("AZ-NEF", "Neftçala"),
+    ("AZ-NV", "Naxçıvan"),
+    ("AZ-NX", "Naxçıvan"),
+    ("AZ-OGU", "Oğuz"),
+    ("AZ-ORD", "Ordubad"),
+    ("AZ-QAB", "Qəbələ"),
+    ("AZ-QAX", "Qax"),
+    ("AZ-QAZ", "Qazax"),
+    ("AZ-QBA", "Quba"),
+    ("AZ-QBI", "Qubadlı"),
+    ("AZ-QOB", "Qobustan"),
+    ("AZ-QUS", "Qusar"),
+    ("AZ-SA", "Şəki"),
+    ("AZ-SAB", "Sabirabad"),
+    ("AZ-SAD", "Sədərək"),
+    ("AZ-SAH", "Şahbuz"),
+    ("AZ-SAK", "Şəki"),
+    ("AZ-SAL", "Salyan"),
+    ("AZ-SAR", "Şərur"),
+    ("AZ-SAT", "Saatlı"),
+    ("AZ-SBN", "Şabran"),
+    ("AZ-SIY", "Siyəzən"),
+    ("AZ-SKR", "Şəmkir"),
+    ("AZ-SM", "Sumqayıt"),
+    ("AZ-SMI", "Şamaxı"),
+    ("AZ-SMX", "Samux"),
+    ("AZ-SR", "Şirvan"),
+    ("AZ-SUS", "Şuşa"),
+    ("AZ-TAR", "Tərtər"),
+    ("AZ-TOV", "Tovuz"),
+    ("AZ-UCA", "Ucar"),
+    ("AZ-XA", "Xankəndi"),
+    ("AZ-XAC", "Xaçmaz"),
+    ("AZ-XCI", "Xocalı"),
+    ("AZ-XIZ", "Xızı"),
+    ("AZ-XVD", "Xocavənd"),
+    ("AZ-YAR", "Yardımlı"),
+    ("AZ-YE", "Yevlax"),
+    ("AZ-YEV", "Yevlax"),
+    ("AZ-ZAN", "Zəngilan"),
+    ("AZ-ZAQ", "Zaqatala"),
+    ("AZ-ZAR", "Zərdab"),
+    ("BA-BIH", "Federacija Bosne i Hercegovine"),
+    ("BA-BRC", "Brčko distrikt"),
+    ("BA-SRP", "Republika Srpska"),
+    ("BB-01", "Christ Church"),
+    ("BB-02", "Saint Andrew"),
+    ("BB-03", "Saint George"),
+    ("BB-04", "Saint James"),
+    ("BB-05", "Saint John"),
+    ("BB-06", "Saint Joseph"),
+    ("BB-07", "Saint Lucy"),
+    ("BB-08", "Saint Michael"),
+    ("BB-09", "Saint Peter"),
+    ("BB-10", "Saint Philip"),
+    ("BB-11", "Saint Thomas"),
+    ("BD-01", "Bandarban"),
+    ("BD-02", "Barguna"),
+    ("BD-03", "Bogura"),
+    ("BD-04", "Brahmanbaria"),
+    ("BD-05", "Bagerhat"),
+    ("BD-06", "Barishal"),
+    ("BD-07", "Bhola"),
+    ("BD-08", "Cumilla"),
+    ("BD-09", "Chandpur"),
+    ("BD-10", "Chattogram"),
+    ("BD-11", "Cox's Bazar"),
+    ("BD-12", "Chuadanga"),
+    ("BD-13", "Dhaka"),
+    ("BD-14", "Dinajpur"),
+    ("BD-15", "Faridpur"),
+    ("BD-16", "Feni"),
+    ("BD-17", "Gopalganj"),
+    ("BD-18", "Gazipur"),
+    ("BD-19", "Gaibandha"),
+    ("BD-20", "Habiganj"),
+    ("BD-21", "Jamalpur"),
+    ("BD-22", "Jashore"),
+    ("BD-23", "Jhenaidah"),
+    ("BD-24", "Joypurhat"),
+    ("BD-25", "Jhalakathi"),
+    ("BD-26", "Kishoreganj"),
+    ("BD-27", "Khulna"),
+    ("BD-28", "Kurigram"),
+    ("BD-29", "Khagrachhari"),
+    ("BD-30", "Kushtia"),
+    ("BD-31", "Lakshmipur"),
+    ("BD-32", "Lalmonirhat"),
+    ("BD-33", "Manikganj"),
+    ("BD-34", "Mymensingh"),
+    ("BD-35", "Munshiganj"),
+    ("BD-36", "Madaripur"),
+    ("BD-37", "Magura"),
+    ("BD-38", "Moulvibazar"),
+    ("BD-39", "Meherpur"),
+    ("BD-40", "Narayanganj"),
+    ("BD-41", "Netrakona"),
+    ("BD-42", "Narsingdi"),
+    ("BD-43", "Narail"),
+    ("BD-44", "Natore"),
+    ("BD-45", "Chapai Nawabganj"),
+    ("BD-46", "Nilphamari"),
+    ("BD-47", "Noakhali"),
+    ("BD-48", "Naogaon"),
+    ("BD-49", "Pabna"),
+    ("BD-50", "Pirojpur"),
+    ("BD-51", "Patuakhali"),
+    ("BD-52", "Panchagarh"),
+    ("BD-53", "Rajbari"),
+    ("BD-54", "Rajshahi"),
+    ("BD-55", "Rangpur"),
+    ("BD-56", "Rangamati"),
+    ("BD-57", "Sherpur"),
+    ("BD-58", "Satkhira"),
+    ("BD-59", "Sirajganj"),
+    ("BD-60", "Sylhet"),
+    ("BD-61", "Sunamganj"),
+    ("BD-62", "Shariatpur"),
+    ("BD-63", "Tangail"),
+    ("BD-64", "Thakurgaon"),
+    ("BD-A", "Barishal"),
+    ("BD-B", "Chattogram"),
+    ("BD-C", "Dhaka"),
+    ("BD-D", "Khulna"),
+    ("BD-E", "Rajshahi"),
+    ("BD-F", "Rangpur"),
+    ("BD-G", "Sylhet"),
+    ("BD-H", "Mymensingh"),
+    ("BE-BRU", "Brussels Hoofdstedelijk Gewest"),
+    ("BE-VAN", "Antwerpen"),
+    ("BE-VBR", "Vlaams-Brabant"),
+    ("BE-VLG", "Vlaams Gewest"),
+    ("BE-VLI", "Limburg"),
+    ("BE-VOV", "Oost-Vlaanderen"),
+    ("BE-VWV", "West-Vlaanderen"),
+    ("BE-WAL", "wallonne, Région"),
+    ("BE-WBR", "Brabant wallon"),
+    ("BE-WHT", "Hainaut"),
+    ("BE-WLG", "Liège"),
+    ("BE-WLX", "Luxembourg"),
+    ("BE-WNA", "Namur"),
+    ("BF-01", "Boucle du Mouhoun"),
+    ("BF-02", "Cascades"),
+    ("BF-03", "Centre"),
+    ("BF-04", "Centre-Est"),
+    ("BF-05", "Centre-Nord"),
+    ("BF-06", "Centre-Ouest"),
+    ("BF-07", "Centre-Sud"),
+    ("BF-08", "Est"),
+    ("BF-09", "Hauts-Bassins"),
+    ("BF-10", "Nord"),
+    ("BF-11", "Plateau-Central"),
+    ("BF-12", "Sahel"),
+    ("BF-13", "Sud-Ouest"),
+    ("BF-BAL", "Balé"),
+    ("BF-BAM", "Bam"),
+    ("BF-BAN", "Banwa"),
+    ("BF-BAZ", "Bazèga"),
+    ("BF-BGR", "Bougouriba"),
+    ("BF-BLG", "Boulgou"),
+    ("BF-BLK", "Boulkiemdé"),
+    ("BF-COM", "Comoé"),
+    ("BF-GAN", "Ganzourgou"),
+    ("BF-GNA", "Gnagna"),
+    ("BF-GOU", "Gourma"),
+    ("BF-HOU", "Houet"),
+    ("BF-IOB", "Ioba"),
+    ("BF-KAD", "Kadiogo"),
+    ("BF-KEN", "Kénédougou"),
+    ("BF-KMD", "Komondjari"),
+    ("BF-KMP", "Kompienga"),
+    ("BF-KOP", "Koulpélogo"),
+    ("BF-KOS", "Kossi"),
+    ("BF-KOT", "Kouritenga"),
+    ("BF-KOW", "Kourwéogo"),
+    ("BF-LER", "Léraba"),
+    ("BF-LOR", "Loroum"),
+    ("BF-MOU", "Mouhoun"),
+    ("BF-NAM", "Namentenga"),
+    ("BF-NAO", "Nahouri"),
+    ("BF-NAY", "Nayala"),
+    ("BF-NOU", "Noumbiel"),
+    ("BF-OUB", "Oubritenga"),
+    ("BF-OUD", "Oudalan"),
+    ("BF-PAS", "Passoré"),
+    ("BF-PON", "Poni"),
+    ("BF-SEN", "Séno"),
+    ("BF-SIS", "Sissili"),
+    ("BF-SMT", "Sanmatenga"),
+    ("BF-SNG", "Sanguié"),
+    ("BF-SOM", "Soum"),
+    ("BF-SOR", "Sourou"),
+    ("BF-TAP", "Tapoa"),
+    ("BF-TUI", "Tuy"),
+    ("BF-YAG", "Yagha"),
+    ("BF-YAT", "Yatenga"),
+    ("BF-ZIR", "Ziro"),
+    ("BF-ZON", "Zondoma"),
+    ("BF-ZOU", "Zoundwéogo"),
+    ("BG-01", "Blagoevgrad"),
+    ("BG-02", "Burgas"),
+    ("BG-03", "Varna"),
+    ("BG-04", "Veliko Tarnovo"),
+    ("BG-05", "Vidin"),
+    ("BG-06", "Vratsa"),
+    ("BG-07", "Gabrovo"),
+    ("BG-08", "Dobrich"),
+    ("BG-09", "Kardzhali"),
+    ("BG-10", "Kyustendil"),
+    ("BG-11", "Lovech"),
+    ("BG-12", "Montana"),
+    ("BG-13", "Pazardzhik"),
+    ("BG-14", "Pernik"),
+    ("BG-15", "Pleven"),
+    ("BG-16", "Plovdiv"),
+    ("BG-17", "Razgrad"),
+    ("BG-18", "Ruse"),
+    ("BG-19", "Silistra"),
+    ("BG-20", "Sliven"),
+    ("BG-21", "Smolyan"),
+    ("BG-22", "Sofia (stolitsa)"),
+    ("BG-23", "Sofia"),
+    ("BG-24", "Stara Zagora"),
+    ("BG-25", "Targovishte"),
+    ("BG-26", "Haskovo"),
+    ("BG-27", "Shumen"),
+    ("BG-28", "Yambol"),
+    ("BH-13", "Al ‘Āşimah"),
+    ("BH-14", "Al Janūbīyah"),
+    ("BH-15", "Al Muḩarraq"),
+    ("BH-17", "Ash Shamālīyah"),
+    ("BI-BB", "Bubanza"),
+    ("BI-BL", "Bujumbura Rural"),
+    ("BI-BM", "Bujumbura Mairie"),
+    ("BI-BR", "Bururi"),
+    ("BI-CA", "Cankuzo"),
+    ("BI-CI", "Cibitoke"),
+    ("BI-GI", "Gitega"),
+    ("BI-KI", "Kirundo"),
+    ("BI-KR", "Karuzi"),
+    ("BI-KY", "Kayanza"),
+    ("BI-MA", "Makamba"),
+    ("BI-MU", "Muramvya"),
+    ("BI-MW", "Mwaro"),
+    ("BI-MY", "Muyinga"),
+    ("BI-NG", "Ngozi"),
+    ("BI-RM", "Rumonge"),
+    ("BI-RT", "Rutana"),
+    ("BI-RY", "Ruyigi"),
+    ("BJ-AK", "Atacora"),
+    ("BJ-AL", "Alibori"),
+    ("BJ-AQ", "Atlantique"),
+    ("BJ-BO", "Borgou"),
+    ("BJ-CO", "Collines"),
+    ("BJ-DO", "Donga"),
+    ("BJ-KO", "Couffo"),
+    ("BJ-LI", "Littoral"),
+    ("BJ-MO", "Mono"),
+    ("BJ-OU", "Ouémé"),
+    ("BJ-PL", "Plateau"),
+    ("BJ-ZO", "Zou"),
+    ("BN-BE", "Belait"),
+    ("BN-BM", "Brunei-Muara"),
+    ("BN-TE", "Temburong"),
+    ("BN-TU", "Tutong"),
+    ("BO-B", "El Beni"),
+    ("BO-C", "Cochabamba"),
+    ("BO-H", "Chuquisaca"),
+    ("BO-L", "La Paz"),
+    ("BO-N", "Pando"),
+    ("BO-O", "Oruro"),
+    ("BO-P", "Potosí"),
+    ("BO-S", "Santa Cruz"),
+    ("BO-T", "Tarija"),
+    ("BQ-BO", "Bonaire"),
+    ("BQ-SA", "Saba"),
+    ("BQ-SE", "Sint Eustatius"),
+    ("BR-AC", "Acre"),
+    ("BR-AL", "Alagoas"),
+    ("BR-AM", "Amazonas"),
+    ("BR-AP", "Amapá"),
+    ("BR-BA", "Bahia"),
+    ("BR-CE", "Ceará"),
+    ("BR-DF", "Distrito Federal"),
+    ("BR-ES", "Espírito Santo"),
+    ("BR-GO", "Goiás"),
+    ("BR-MA", "Maranhão"),
+    ("BR-MG", "Minas Gerais"),
+    ("BR-MS", "Mato Grosso do Sul"),
+    ("BR-MT", "Mato Grosso"),
+    ("BR-PA", "Pará"),
+    ("BR-PB", "Paraíba"),
+    ("BR-PE", "Pernambuco"),
+    ("BR-PI", "Piauí"),
+    ("BR-PR", "Paraná"),
+    ("BR-RJ", "Rio de Janeiro"),
+    ("BR-RN", "Rio Grande do Norte"),
+    ("BR-RO", "Rondônia"),
+    ("BR-RR", "Roraima"),
+    ("BR-RS", "Rio Grande do Sul"),
+    ("BR-SC", "Santa Catarina"),
+    ("BR-SE", "Sergipe"),
+    ("BR-SP", "São Paulo"),
+    ("BR-TO", "Tocantins"),
+    ("BS-AK", "Acklins"),
+    ("BS-BI", "Bimini"),
+    ("BS-BP", "Black Point"),
+    ("BS-BY", "Berry Islands"),
+    ("BS-CE", "Central Eleuthera"),
+    ("BS-CI", "Cat Island"),
+    ("BS-CK", "Crooked Island and Long Cay"),
+    ("BS-CO", "Central Abaco"),
+    ("BS-CS", "Central Andros"),
+    ("BS-EG", "East Grand Bahama"),
+    ("BS-EX", "Exuma"),
+    ("BS-FP", "City of Freeport"),
+    ("BS-GC", "Grand Cay"),
+    ("BS-HI", "Harbour Island"),
+    ("BS-HT", "Hope Town"),
+    ("BS-IN", "Inagua"),
+    ("BS-LI", "Long Island"),
+    ("BS-MC", "Mangrove Cay"),
+    ("BS-MG", "Mayaguana"),
+    ("BS-MI", "Moore's Island"),
+    ("BS-NE", "North Eleuthera"),
+    ("BS-NO", "North Abaco"),
+    ("BS-NP", "New Providence"),
+    ("BS-NS", "North Andros"),
+    ("BS-RC", "Rum Cay"),
+    ("BS-RI", "Ragged Island"),
+    ("BS-SA", "South Andros"),
+    ("BS-SE", "South Eleuthera"),
+    ("BS-SO", "South Abaco"),
+    ("BS-SS", "San Salvador"),
+    ("BS-SW", "Spanish Wells"),
+    ("BS-WG", "West Grand Bahama"),
+    ("BT-11", "Paro"),
+    ("BT-12", "Chhukha"),
+    ("BT-13", "Haa"),
+    ("BT-14", "Samtse"),
+    ("BT-15", "Thimphu"),
+    ("BT-21", "Tsirang"),
+    ("BT-22", "Dagana"),
+    ("BT-23", "Punakha"),
+    ("BT-24", "Wangdue Phodrang"),
+    ("BT-31", "Sarpang"),
+    ("BT-32", "Trongsa"),
+    ("BT-33", "Bumthang"),
+    ("BT-34", "Zhemgang"),
+    ("BT-41", "Trashigang"),
+    ("BT-42", "Monggar"),
+    ("BT-43", "Pema Gatshel"),
+    ("BT-44", "Lhuentse"),
+    ("BT-45", "Samdrup Jongkhar"),
+    ("BT-GA", "Gasa"),
+    ("BT-TY", "Trashi Yangtse"),
+    ("BW-CE", "Central"),
+    ("BW-CH", "Chobe"),
+    ("BW-FR", "Francistown"),
+    ("BW-GA", "Gaborone"),
+    ("BW-GH", "Ghanzi"),
+    ("BW-JW", "Jwaneng"),
+    ("BW-KG", "Kgalagadi"),
+    ("BW-KL", "Kgatleng"),
+    ("BW-KW", "Kweneng"),
+    ("BW-LO", "Lobatse"),
+    ("BW-NE", "North East"),
+    ("BW-NW", "North West"),
+    ("BW-SE", "South East"),
+    ("BW-SO", "Southern"),
+    ("BW-SP", "Selibe Phikwe"),
+    ("BW-ST", "Sowa Town"),
+    ("BY-BR", "Bresckaja voblasć"),
+    ("BY-HM", "Gorod Minsk"),
+    ("BY-HO", "Gomel'skaja oblast'"),
+    ("BY-HR", "Grodnenskaja oblast'"),
+    ("BY-MA", "Mahilioŭskaja voblasć"),
+    ("BY-MI", "Minskaja oblast'"),
+    ("BY-VI", "Viciebskaja voblasć"),
+    ("BZ-BZ", "Belize"),
+    ("BZ-CY", "Cayo"),
+    ("BZ-CZL", "Corozal"),
+    ("BZ-OW", "Orange Walk"),
+    ("BZ-SC", "Stann Creek"),
+    ("BZ-TOL", "Toledo"),
+    ("CA-AB", "Alberta"),
+    ("CA-BC", "British Columbia"),
+    ("CA-MB", "Manitoba"),
+    ("CA-NB", "New Brunswick"),
+    ("CA-NL", "Newfoundland and Labrador"),
+    ("CA-NS", "Nova Scotia"),
+    ("CA-NT", "Northwest Territories"),
+    ("CA-NU", "Nunavut"),
+    ("CA-ON", "Ontario"),
+    ("CA-PE", "Prince Edward Island"),
+    ("CA-QC", "Quebec"),
+    ("CA-SK", "Saskatchewan"),
+    ("CA-YT", "Yukon"),
+    ("CD-BC", "Kongo Central"),
+    ("CD-BU", "Bas-Uélé"),
+    ("CD-EQ", "Équateur"),
+    ("CD-HK", "Haut-Katanga"),
+    ("CD-HL", "Haut-Lomami"),
+    ("CD-HU", "Haut-Uélé"),
+    ("CD-IT", "Ituri"),
+    ("CD-KC", "Kasaï Central"),
+    ("CD-KE", "Kasaï Oriental"),
+    ("CD-KG", "Kwango"),
+    ("CD-KL", "Kwilu"),
+    ("CD-KN", "Kinshasa"),
+    ("CD-KS", "Kasaï"),
+    ("CD-LO", "Lomami"),
+    ("CD-LU", "Lualaba"),
+    ("CD-MA", "Maniema"),
+    ("CD-MN", "Mai-Ndombe"),
+    ("CD-MO", "Mongala"),
+    ("CD-NK", "Nord-Kivu"),
+    ("CD-NU", "Nord-Ubangi"),
+    ("CD-SA", "Sankuru"),
+    ("CD-SK", "Sud-Kivu"),
+    ("CD-SU", "Sud-Ubangi"),
+    ("CD-TA", "Tanganyika"),
+    ("CD-TO", "Tshopo"),
+    ("CD-TU", "Tshuapa"),
+    ("CF-AC", "Ouham"),
+    ("CF-BB", "Bamingui-Bangoran"),
+    ("CF-BGF", "Bangui"),
+    ("CF-BK", "Basse-Kotto"),
+    ("CF-HK", "Haute-Kotto"),
+    ("CF-HM", "Haut-Mbomou"),
+    ("CF-HS", "Haute-Sangha / Mambéré-Kadéï"),
+    ("CF-KB", "Gribingui"),
+    ("CF-KG", "Kemö-Gïrïbïngï"),
+    ("CF-LB", "Lobaye"),
+    ("CF-MB", "Mbomou"),
+    ("CF-MP", "Ombella-Mpoko"),
+    ("CF-NM", "Nana-Mambéré"),
+    ("CF-OP", "Ouham-Pendé"),
+    ("CF-SE", "Sangha"),
+    ("CF-UK", "Ouaka"),
+    ("CF-VK", "Vakaga"),
+    ("CG-11", "Bouenza"),
+    ("CG-12", "Pool"),
+    ("CG-13", "Sangha"),
+    ("CG-14", "Plateaux"),
+    ("CG-15", "Cuvette-Ouest"),
+    ("CG-16", "Pointe-Noire"),
+    ("CG-2", "Lékoumou"),
+    ("CG-5", "Kouilou"),
+    ("CG-7", "Likouala"),
+    ("CG-8", "Cuvette"),
+    ("CG-9", "Niari"),
+    ("CG-BZV", "Brazzaville"),
+    ("CH-AG", "Aargau"),
+    ("CH-AI", "Appenzell Innerrhoden"),
+    ("CH-AR", "Appenzell Ausserrhoden"),
+    ("CH-BE", "Bern"),
+    ("CH-BL", "Basel-Landschaft"),
+    ("CH-BS", "Basel-Stadt"),
+    ("CH-FR", "Freiburg"),
+    ("CH-GE", "Genève"),
+    ("CH-GL", "Glarus"),
+    ("CH-GR", "Graubünden"),
+    ("CH-JU", "Jura"),
+    ("CH-LU", "Luzern"),
+    ("CH-NE", "Neuchâtel"),
+    ("CH-NW", "Nidwalden"),
+    ("CH-OW", "Obwalden"),
+    ("CH-SG", "Sankt Gallen"),
+    ("CH-SH", "Schaffhausen"),
+    ("CH-SO", "Solothurn"),
+    ("CH-SZ", "Schwyz"),
+    ("CH-TG", "Thurgau"),
+    ("CH-TI", "Ticino"),
+    ("CH-UR", "Uri"),
+    ("CH-VD", "Vaud"),
+    ("CH-VS", "Valais"),
+    ("CH-ZG", "Zug"),
+    ("CH-ZH", "Zürich"),
+    ("CI-AB", "Abidjan"),
+    ("CI-BS", "Bas-Sassandra"),
+    ("CI-CM", "Comoé"),
+    ("CI-DN", "Denguélé"),
+    ("CI-GD", "Gôh-Djiboua"),
+    ("CI-LC", "Lacs"),
+    ("CI-LG", "Lagunes"),
+    ("CI-MG", "Montagnes"),
+    ("CI-SM", "Sassandra-Marahoué"),
+    ("CI-SV", "Savanes"),
+    ("CI-VB", "Vallée du Bandama"),
+    ("CI-WR", "Woroba"),
+    ("CI-YM", "Yamoussoukro"),
+    ("CI-ZZ", "Zanzan"),
+    ("CL-AI", "Aisén del General Carlos Ibañez del Campo"),
+    ("CL-AN", "Antofagasta"),
+    ("CL-AP", "Arica y Parinacota"),
+    ("CL-AR", "La Araucanía"),
+    ("CL-AT", "Atacama"),
+    ("CL-BI", "Biobío"),
+    ("CL-CO", "Coquimbo"),
+    ("CL-LI", "Libertador General Bernardo O'Higgins"),
+    ("CL-LL", "Los Lagos"),
+    ("CL-LR", "Los Ríos"),
+    ("CL-MA", "Magallanes"),
+    ("CL-ML", "Maule"),
+    ("CL-NB", "Ñuble"),
+    ("CL-RM", "Región Metropolitana de Santiago"),
+    ("CL-TA", "Tarapacá"),
+    ("CL-VS", "Valparaíso"),
+    ("CM-AD", "Adamaoua"),
+    ("CM-CE", "Centre"),
+    ("CM-EN", "Far North"),
+    ("CM-ES", "East"),
+    ("CM-LT", "Littoral"),
+    ("CM-NO", "North"),
+    ("CM-NW", "North-West"),
+    ("CM-OU", "West"),
+    ("CM-SU", "South"),
+    ("CM-SW", "South-West"),
+    ("CN-AH", "Anhui Sheng"),
+    ("CN-BJ", "Beijing Shi"),
+    ("CN-CQ", "Chongqing Shi"),
+    ("CN-FJ", "Fujian Sheng"),
+    ("CN-GD", "Guangdong Sheng"),
+    ("CN-GS", "Gansu Sheng"),
+    ("CN-GX", "Guangxi Zhuangzu Zizhiqu"),
+    ("CN-GZ", "Guizhou Sheng"),
+    ("CN-HA", "Henan Sheng"),
+    ("CN-HB", "Hubei Sheng"),
+    ("CN-HE", "Hebei Sheng"),
+    ("CN-HI", "Hainan Sheng"),
+    ("CN-HK", "Hong Kong SAR"),
+    ("CN-HL", "Heilongjiang Sheng"),
+    ("CN-HN", "Hunan Sheng"),
+    ("CN-JL", "Jilin Sheng"),
+    ("CN-JS", "Jiangsu Sheng"),
+    ("CN-JX", "Jiangxi Sheng"),
+    ("CN-LN", "Liaoning Sheng"),
+    ("CN-MO", "Macao SAR"),
+    ("CN-NM", "Nei Mongol Zizhiqu"),
+    ("CN-NX", "Ningxia Huizi Zizhiqu"),
+    ("CN-QH", "Qinghai Sheng"),
+    ("CN-SC", "Sichuan Sheng"),
+    ("CN-SD", "Shandong Sheng"),
+    ("CN-SH", "Shanghai Shi"),
+    ("CN-SN", "Shaanxi Sheng"),
+    ("CN-SX", "Shanxi Sheng"),
+    ("CN-TJ", "Tianjin Shi"),
+    ("CN-TW", "Taiwan Sheng"),
+    ("CN-XJ", "Xinjiang Uygur Zizhiqu"),
+    ("CN-XZ", "Xizang Zizhiqu"),
+    ("CN-YN", "Yunnan Sheng"),
+    ("CN-ZJ", "Zhejiang Sheng"),
+    ("CO-AMA", "Amazonas"),
+    ("CO-ANT", "Antioquia"),
+    ("CO-ARA", "Arauca"),
+    ("CO-ATL", "Atlántico"),
+    ("CO-BOL", "Bolívar"),
+    ("CO-BOY", "Boyacá"),
+    ("CO-CAL", "Caldas"),
+    ("CO-CAQ", "Caquetá"),
+    ("CO-CAS", "Casanare"),
+    ("CO-CAU", "Cauca"),
+    ("CO-CES", "Cesar"),
+    ("CO-CHO", "Chocó"),
+    ("CO-COR", "Córdoba"),
+    ("CO-CUN", "Cundinamarca"),
+    ("CO-DC", "Distrito Capital de Bogotá"),
+    ("CO-GUA", "Guainía"),
+    ("CO-GUV", "Guaviare"),
+    ("CO-HUI", "Huila"),
+    ("CO-LAG", "La Guajira"),
+    ("CO-MAG", "Magdalena"),
+    ("CO-MET", "Meta"),
+    ("CO-NAR", "Nariño"),
+    ("CO-NSA", "Norte de Santander"),
+    ("CO-PUT", "Putumayo"),
+    ("CO-QUI", "Quindío"),
+    ("CO-RIS", "Risaralda"),
+    ("CO-SAN", "Santander"),
+    ("CO-SAP", "San Andrés, Providencia y Santa Catalina"),
+    ("CO-SUC", "Sucre"),
+    ("CO-TOL", "Tolima"),
+    ("CO-VAC", "Valle del Cauca"),
+    ("CO-VAU", "Vaupés"),
+    ("CO-VID", "Vichada"),
+    ("CR-A", "Alajuela"),
+    ("CR-C", "Cartago"),
+    ("CR-G", "Guanacaste"),
+    ("CR-H", "Heredia"),
+    ("CR-L", "Limón"),
+    ("CR-P", "Puntarenas"),
+    ("CR-SJ", "San José"),
+    ("CU-01", "Pinar del Río"),
+    ("CU-03", "La Habana"),
+    ("CU-04", "Matanzas"),
+    ("CU-05", "Villa Clara"),
+    ("CU-06", "Cienfuegos"),
+    ("CU-07", "Sancti Spíritus"),
+    ("CU-08", "Ciego de Ávila"),
+    ("CU-09", "Camagüey"),
+    ("CU-10", "Las Tunas"),
+    ("CU-11", "Holguín"),
+    ("CU-12", "Granma"),
+    ("CU-13", "Santiago de Cuba"),
+    ("CU-14", "Guantánamo"),
+    ("CU-15", "Artemisa"),
+    ("CU-16", "Mayabeque"),
+    ("CU-99", "Isla de la Juventud"),
+    ("CV-B", "Ilhas de Barlavento"),
+    ("CV-BR", "Brava"),
+    ("CV-BV", "Boa Vista"),
+    ("CV-CA", "Santa Catarina"),
+    ("CV-CF", "Santa Catarina do Fogo"),
+    ("CV-CR", "Santa Cruz"),
+    ("CV-MA", "Maio"),
+    ("CV-MO", "Mosteiros"),
+    ("CV-PA", "Paul"),
+    ("CV-PN", "Porto Novo"),
+    ("CV-PR", "Praia"),
+    ("CV-RB", "Ribeira Brava"),
+    ("CV-RG", "Ribeira Grande"),
+    ("CV-RS", "Ribeira Grande de Santiago"),
+    ("CV-S", "Ilhas de Sotavento"),
+    ("CV-SD", "São Domingos"),
+    ("CV-SF", "São Filipe"),
+    ("CV-SL", "Sal"),
+    ("CV-SM", "São Miguel"),
+    ("CV-SO", "São Lourenço dos Órgãos"),
+    ("CV-SS", "São Salvador do Mundo"),
+    ("CV-SV", "São Vicente"),
+    ("CV-TA", "Tarrafal"),
+    ("CV-TS", "Tarrafal de São Nicolau"),
+    ("CY-01", "Lefkosia"),
+    ("CY-02", "Lemesos"),
+    ("CY-03", "Larnaka"),
+    ("CY-04", "Ammochostos"),
+    ("CY-05", "Baf"),
+    ("CY-06", "Girne"),
+    ("CZ-10", "Praha, Hlavní město"),
+    ("CZ-20", "Středočeský kraj"),
+    ("CZ-201", "Benešov"),
+    ("CZ-202", "Beroun"),
+    ("CZ-203", "Kladno"),
+    ("CZ-204", "Kolín"),
+    ("CZ-205", "Kutná Hora"),
+    ("CZ-206", "Mělník"),
+    ("CZ-207", "Mladá Boleslav"),
+    ("CZ-208", "Nymburk"),
+    ("CZ-209", "Praha-východ"),
+    ("CZ-20A", "Praha-západ"),
+    ("CZ-20B", "Příbram"),
+    ("CZ-20C", "Rakovník"),
+    ("CZ-31", "Jihočeský kraj"),
+    ("CZ-311", "České Budějovice"),
+    ("CZ-312", "Český Krumlov"),
+    ("CZ-313", "Jindřichův Hradec"),
+    ("CZ-314", "Písek"),
+    ("CZ-315", "Prachatice"),
+    ("CZ-316", "Strakonice"),
+    ("CZ-317", "Tábor"),
+    ("CZ-32", "Plzeňský kraj"),
+    ("CZ-321", "Domažlice"),
+    ("CZ-322", "Klatovy"),
+    ("CZ-323", "Plzeň-město"),
+    ("CZ-324", "Plzeň-jih"),
+    ("CZ-325", "Plzeň-sever"),
+    ("CZ-326", "Rokycany"),
+    ("CZ-327", "Tachov"),
+    ("CZ-41", "Karlovarský kraj"),
+    ("CZ-411", "Cheb"),
+    ("CZ-412", "Karlovy Vary"),
+    ("CZ-413", "Sokolov"),
+    ("CZ-42", "Ústecký kraj"),
+    ("CZ-421", "Děčín"),
+    ("CZ-422", "Chomutov"),
+    ("CZ-423", "Litoměřice"),
+    ("CZ-424", "Louny"),
+    ("CZ-425", "Most"),
+    ("CZ-426", "Teplice"),
+    ("CZ-427", "Ústí nad Labem"),
+    ("CZ-51", "Liberecký kraj"),
+    ("CZ-511", "Česká Lípa"),
+    ("CZ-512", "Jablonec nad Nisou"),
+    ("CZ-513", "Liberec"),
+    ("CZ-514", "Semily"),
+    ("CZ-52", "Královéhradecký kraj"),
+    ("CZ-521", "Hradec Králové"),
+    ("CZ-522", "Jičín"),
+    ("CZ-523", "Náchod"),
+    ("CZ-524", "Rychnov nad Kněžnou"),
+    ("CZ-525", "Trutnov"),
+    ("CZ-53", "Pardubický kraj"),
+    ("CZ-531", "Chrudim"),
+    ("CZ-532", "Pardubice"),
+    ("CZ-533", "Svitavy"),
+    ("CZ-534", "Ústí nad Orlicí"),
+    ("CZ-63", "Kraj Vysočina"),
+    ("CZ-631", "Havlíčkův Brod"),
+    ("CZ-632", "Jihlava"),
+    ("CZ-633", "Pelhřimov"),
+    ("CZ-634", "Třebíč"),
+    ("CZ-635", "Žďár nad Sázavou"),
+    ("CZ-64", "Jihomoravský kraj"),
+    ("CZ-641", "Blansko"),
+    ("CZ-642", "Brno-město"),
+    ("CZ-643", "Brno-venkov"),
+    ("CZ-644", "Břeclav"),
+    ("CZ-645", "Hodonín"),
+    ("CZ-646", "Vyškov"),
+    ("CZ-647", "Znojmo"),
+    ("CZ-71", "Olomoucký kraj"),
+    ("CZ-711", "Jeseník"),
+    ("CZ-712", "Olomouc"),
+    ("CZ-713", "Prostějov"),
+    ("CZ-714", "Přerov"),
+    ("CZ-715", "Šumperk"),
+    ("CZ-72", "Zlínský kraj"),
+    ("CZ-721", "Kroměříž"),
+    ("CZ-722", "Uherské Hradiště"),
+    ("CZ-723", "Vsetín"),
+    ("CZ-724", "Zlín"),
+    ("CZ-80", "Moravskoslezský kraj"),
+    ("CZ-801", "Bruntál"),
+    ("CZ-802", "Frýdek-Místek"),
+    ("CZ-803", "Karviná"),
+    ("CZ-804", "Nový Jičín"),
+    ("CZ-805", "Opava"),
+    ("CZ-806", "Ostrava-město"),
+    ("DE-BB", "Brandenburg"),
+    ("DE-BE", "Berlin"),
+    ("DE-BW", "Baden-Württemberg"),
+    ("DE-BY", "Bayern"),
+    ("DE-HB", "Bremen"),
+    ("DE-HE", "Hessen"),
+    ("DE-HH", "Hamburg"),
+    ("DE-MV", "Mecklenburg-Vorpommern"),
+    ("DE-NI", "Niedersachsen"),
+    ("DE-NW", "Nordrhein-Westfalen"),
+    ("DE-RP", "Rheinland-Pfalz"),
+    ("DE-SH", "Schleswig-Holstein"),
+    ("DE-SL", "Saarland"),
+    ("DE-SN", "Sachsen"),
+    ("DE-ST", "Sachsen-Anhalt"),
+    ("DE-TH", "Thüringen"),
+    ("DJ-AR", "Arta"),
+    ("DJ-AS", "Ali Sabieh"),
+    ("DJ-DI", "Dikhil"),
+    ("DJ-DJ", "Djibouti"),
+    ("DJ-OB", "Awbūk"),
+    ("DJ-TA", "Tadjourah"),
+    ("DK-81", "Nordjylland"),
+    ("DK-82", "Midtjylland"),
+    ("DK-83", "Syddanmark"),
+    ("DK-84", "Hovedstaden"),
+    ("DK-85", "Sjælland"),
+    ("DM-02", "Saint Andrew"),
+    ("DM-03", "Saint David"),
+    ("DM-04", "Saint George"),
+    ("DM-05", "Saint John"),
+    ("DM-06", "Saint Joseph"),
+    ("DM-07", "Saint Luke"),
+    ("DM-08", "Saint Mark"),
+    ("DM-09", "Saint Patrick"),
+    ("DM-10", "Saint Paul"),
+    ("DM-11", "Saint Peter"),
+    ("DO-01", "Distrito Nacional (Santo Domingo)"),
+    ("DO-02", "Azua"),
+    ("DO-03", "Baoruco"),
+    ("DO-04", "Barahona"),
+    ("DO-05", "Dajabón"),
+    ("DO-06", "Duarte"),
+    ("DO-07", "Elías Piña"),
+    ("DO-08", "El Seibo"),
+    ("DO-09", "Espaillat"),
+    ("DO-10", "Independencia"),
+    ("DO-11", "La Altagracia"),
+    ("DO-12", "La Romana"),
+    ("DO-13", "La Vega"),
+    ("DO-14", "María Trinidad Sánchez"),
+    ("DO-15", "Monte Cristi"),
+    ("DO-16", "Pedernales"),
+    ("DO-17", "Peravia"),
+    ("DO-18", "Puerto Plata"),
+    ("DO-19", "Hermanas Mirabal"),
+    ("DO-20", "Samaná"),
+    ("DO-21", "San Cristóbal"),
+    ("DO-22", "San Juan"),
+    ("DO-23", "San Pedro de Macorís"),
+    ("DO-24", "Sánchez Ramírez"),
+    ("DO-25", "Santiago"),
+    ("DO-26", "Santiago Rodríguez"),
+    ("DO-27", "Valverde"),
+    ("DO-28", "Monseñor Nouel"),
+    ("DO-29", "Monte Plata"),
+    ("DO-30", "Hato Mayor"),
+    ("DO-31", "San José de Ocoa"),
+    ("DO-32", "Santo Domingo"),
+    ("DO-33", "Cibao Nordeste"),
+    ("DO-34", "Cibao Noroeste"),
+    ("DO-35", "Cibao Norte"),
+    ("DO-36", "Cibao Sur"),
+    ("DO-37", "El Valle"),
+    ("DO-38", "Enriquillo"),
+    ("DO-39", "Higuamo"),
+    ("DO-40", "Ozama"),
+    ("DO-41", "Valdesia"),
+    ("DO-42", "Yuma"),
+    ("DZ-01", "Adrar"),
+    ("DZ-02", "Chlef"),
+    ("DZ-03", "Laghouat"),
+    ("DZ-04", "Oum el Bouaghi"),
+    ("DZ-05", "Batna"),
+    ("DZ-06", "Béjaïa"),
+    ("DZ-07", "Biskra"),
+    ("DZ-08", "Béchar"),
+    ("DZ-09", "Blida"),
+    ("DZ-10", "Bouira"),
+    ("DZ-11", "Tamanrasset"),
+    ("DZ-12", "Tébessa"),
+    ("DZ-13", "Tlemcen"),
+    ("DZ-14", "Tiaret"),
+    ("DZ-15", "Tizi Ouzou"),
+    ("DZ-16", "Alger"),
+    ("DZ-17", "Djelfa"),
+    ("DZ-18", "Jijel"),
+    ("DZ-19", "Sétif"),
+    ("DZ-20", "Saïda"),
+    ("DZ-21", "Skikda"),
+    ("DZ-22", "Sidi Bel Abbès"),
+    ("DZ-23", "Annaba"),
+    ("DZ-24", "Guelma"),
+    ("DZ-25", "Constantine"),
+    ("DZ-26", "Médéa"),
+    ("DZ-27", "Mostaganem"),
+    ("DZ-28", "M'sila"),
+    ("DZ-29", "Mascara"),
+    ("DZ-30", "Ouargla"),
+    ("DZ-31", "Oran"),
+    ("DZ-32", "El Bayadh"),
+    ("DZ-33", "Illizi"),
+    ("DZ-34", "Bordj Bou Arréridj"),
+    ("DZ-35", "Boumerdès"),
+    ("DZ-36", "El Tarf"),
+    ("DZ-37", "Tindouf"),
+    ("DZ-38", "Tissemsilt"),
+    ("DZ-39", "El Oued"),
+    ("DZ-40", "Khenchela"),
+    ("DZ-41", "Souk Ahras"),
+    ("DZ-42", "Tipaza"),
+    ("DZ-43", "Mila"),
+    ("DZ-44", "Aïn Defla"),
+    ("DZ-45", "Naama"),
+    ("DZ-46", "Aïn Témouchent"),
+    ("DZ-47", "Ghardaïa"),
+    ("DZ-48", "Relizane"),
+    ("EC-A", "Azuay"),
+    ("EC-B", "Bolívar"),
+    ("EC-C", "Carchi"),
+    ("EC-D", "Orellana"),
+    ("EC-E", "Esmeraldas"),
+    ("EC-F", "Cañar"),
+    ("EC-G", "Guayas"),
+    ("EC-H", "Chimborazo"),
+    ("EC-I", "Imbabura"),
+    ("EC-L", "Loja"),
+    ("EC-M", "Manabí"),
+    ("EC-N", "Napo"),
+    ("EC-O", "El Oro"),
+    ("EC-P", "Pichincha"),
+    ("EC-R", "Los Ríos"),
+    ("EC-S", "Morona Santiago"),
+    ("EC-SD", "Santo Domingo de los Tsáchilas"),
+    ("EC-SE", "Santa Elena"),
+    ("EC-T", "Tungurahua"),
+    ("EC-U", "Sucumbíos"),
+    ("EC-W", "Galápagos"),
+    ("EC-X", "Cotopaxi"),
+    ("EC-Y", "Pastaza"),
+    ("EC-Z", "Zamora Chinchipe"),
+    ("EE-130", "Alutaguse"),
+    ("EE-141", "Anija"),
+    ("EE-142", "Antsla"),
+    ("EE-171", "Elva"),
+    ("EE-184", "Haapsalu"),
+    ("EE-191", "Haljala"),
+    ("EE-198", "Harku"),
+    ("EE-205", "Hiiumaa"),
+    ("EE-214", "Häädemeeste"),
+    ("EE-245", "Jõelähtme"),
+    ("EE-247", "Jõgeva"),
+    ("EE-251", "Jõhvi"),
+    ("EE-255", "Järva"),
+    ("EE-272", "Kadrina"),
+    ("EE-283", "Kambja"),
+    ("EE-284", "Kanepi"),
+    ("EE-291", "Kastre"),
+    ("EE-293", "Kehtna"),
+    ("EE-296", "Keila"),
+    ("EE-303", "Kihnu"),
+    ("EE-305", "Kiili"),
+    ("EE-317", "Kohila"),
+    ("EE-321", "Kohtla-Järve"),
+    ("EE-338", "Kose"),
+    ("EE-353", "Kuusalu"),
+    ("EE-37", "Harjumaa"),
+    ("EE-39", "Hiiumaa"),
+    ("EE-424", "Loksa"),
+    ("EE-430", "Lääneranna"),
+    ("EE-431", "Lääne-Harju"),
+    ("EE-432", "Luunja"),
+    ("EE-441", "Lääne-Nigula"),
+    ("EE-442", "Lüganuse"),
+    ("EE-446", "Maardu"),
+    ("EE-45", "Ida-Virumaa"),
+    ("EE-478", "Muhu"),
+    ("EE-480", "Mulgi"),
+    ("EE-486", "Mustvee"),
+    ("EE-50", "Jõgevamaa"),
+    ("EE-503", "Märjamaa"),
+    ("EE-511", "Narva"),
+    ("EE-514", "Narva-Jõesuu"),
+    ("EE-52", "Järvamaa"),
+    ("EE-528", "Nõo"),
+    ("EE-557", "Otepää"),
+    ("EE-56", "Läänemaa"),
+    ("EE-567", "Paide"),
+    ("EE-586", "Peipsiääre"),
+    ("EE-60", "Lääne-Virumaa"),
+    ("EE-615", "Põhja-Sakala"),
+    ("EE-618", "Põltsamaa"),
+    ("EE-622", "Põlva"),
+    ("EE-624", "Pärnu"),
+    ("EE-638", "Põhja-Pärnumaa"),
+    ("EE-64", "Põlvamaa"),
+    ("EE-651", "Raasiku"),
+    ("EE-653", "Rae"),
+    ("EE-661", "Rakvere"),
+    ("EE-663", "Rakvere"),
+    ("EE-668", "Rapla"),
+    ("EE-68", "Pärnumaa"),
+    ("EE-689", "Ruhnu"),
+    ("EE-698", "Rõuge"),
+    ("EE-708", "Räpina"),
+    ("EE-71", "Raplamaa"),
+    ("EE-712", "Saarde"),
+    ("EE-714", "Saaremaa"),
+    ("EE-719", "Saku"),
+    ("EE-726", "Saue"),
+    ("EE-732", "Setomaa"),
+    ("EE-735", "Sillamäe"),
+    ("EE-74", "Saaremaa"),
+    ("EE-784", "Tallinn"),
+    ("EE-79", "Tartumaa"),
+    ("EE-792", "Tapa"),
+    ("EE-793", "Tartu"),
+    ("EE-796", "Tartu"),
+    ("EE-803", "Toila"),
+    ("EE-809", "Tori"),
+    ("EE-81", "Valgamaa"),
+    ("EE-824", "Tõrva"),
+    ("EE-834", "Türi"),
+    ("EE-84", "Viljandimaa"),
+    ("EE-855", "Valga"),
+    ("EE-87", "Võrumaa"),
+    ("EE-890", "Viimsi"),
+    ("EE-897", "Viljandi"),
+    ("EE-899", "Viljandi"),
+    ("EE-901", "Vinni"),
+    ("EE-903", "Viru-Nigula"),
+    ("EE-907", "Vormsi"),
+    ("EE-917", "Võru"),
+    ("EE-919", "Võru"),
+    ("EE-928", "Väike-Maarja"),
+    ("EG-ALX", "Al Iskandarīyah"),
+    ("EG-ASN", "Aswān"),
+    ("EG-AST", "Asyūţ"),
+    ("EG-BA", "Al Baḩr al Aḩmar"),
+    ("EG-BH", "Al Buḩayrah"),
+    ("EG-BNS", "Banī Suwayf"),
+    ("EG-C", "Al Qāhirah"),
+    ("EG-DK", "Ad Daqahlīyah"),
+    ("EG-DT", "Dumyāţ"),
+    ("EG-FYM", "Al Fayyūm"),
+    ("EG-GH", "Al Gharbīyah"),
+    ("EG-GZ", "Al Jīzah"),
+    ("EG-IS", "Al Ismā'īlīyah"),
+    ("EG-JS", "Janūb Sīnā'"),
+    ("EG-KB", "Al Qalyūbīyah"),
+    ("EG-KFS", "Kafr ash Shaykh"),
+    ("EG-KN", "Qinā"),
+    ("EG-LX", "Al Uqşur"),
+    ("EG-MN", "Al Minyā"),
+    ("EG-MNF", "Al Minūfīyah"),
+    ("EG-MT", "Maţrūḩ"),
+    ("EG-PTS", "Būr Sa‘īd"),
+    ("EG-SHG", "Sūhāj"),
+    ("EG-SHR", "Ash Sharqīyah"),
+    ("EG-SIN", "Shamāl Sīnā'"),
+    ("EG-SUZ", "As Suways"),
+    ("EG-WAD", "Al Wādī al Jadīd"),
+    ("ER-AN", "Ansabā"),
+    ("ER-DK", "Debubawi K’eyyĭḥ Baḥri"),
+    ("ER-DU", "Al Janūbī"),
+    ("ER-GB", "Gash-Barka"),
+    ("ER-MA", "Al Awsaţ"),
+    ("ER-SK", "Semienawi K’eyyĭḥ Baḥri"),
+    ("ES-A", "Alacant*"),
+    ("ES-AB", "Albacete"),
+    ("ES-AL", "Almería"),
+    ("ES-AN", "Andalucía"),
+    ("ES-AR", "Aragón"),
+    ("ES-AS", "Asturias, Principado de"),
+    ("ES-AV", "Ávila"),
+    ("ES-B", "Barcelona [Barcelona]"),
+    ("ES-BA", "Badajoz"),
+    ("ES-BI", "Bizkaia"),
+    ("ES-BU", "Burgos"),
+    ("ES-C", "A Coruña [La Coruña]"),
+    ("ES-CA", "Cádiz"),
+    ("ES-CB", "Cantabria"),
+    ("ES-CC", "Cáceres"),
+    ("ES-CE", "Ceuta"),
+    ("ES-CL", "Castilla y León"),
+    ("ES-CM", "Castilla-La Mancha"),
+    ("ES-CN", "Canarias"),
+    ("ES-CO", "Córdoba"),
+    ("ES-CR", "Ciudad Real"),
+    ("ES-CS", "Castelló*"),
+    ("ES-CT", "Catalunya [Cataluña]"),
+    ("ES-CU", "Cuenca"),
+    ("ES-EX", "Extremadura"),
+    ("ES-GA", "Galicia [Galicia]"),
+    ("ES-GC", "Las Palmas"),
+    ("ES-GI", "Girona [Gerona]"),
+    ("ES-GR", "Granada"),
+    ("ES-GU", "Guadalajara"),
+    ("ES-H", "Huelva"),
+    ("ES-HU", "Huesca"),
+    ("ES-IB", "Illes Balears [Islas Baleares]"),
+    ("ES-J", "Jaén"),
+    ("ES-L", "Lleida [Lérida]"),
+    ("ES-LE", "León"),
+    ("ES-LO", "La Rioja"),
+    ("ES-LU", "Lugo [Lugo]"),
+    ("ES-M", "Madrid"),
+    ("ES-MA", "Málaga"),
+    ("ES-MC", "Murcia, Región de"),
+    ("ES-MD", "Madrid, Comunidad de"),
+    ("ES-ML", "Melilla"),
+    ("ES-MU", "Murcia"),
+    ("ES-NA", "Nafarroa*"),
+    ("ES-NC", "Nafarroako Foru Komunitatea*"),
+    ("ES-O", "Asturias"),
+    ("ES-OR", "Ourense [Orense]"),
+    ("ES-P", "Palencia"),
+    ("ES-PM", "Illes Balears [Islas Baleares]"),
+    ("ES-PO", "Pontevedra [Pontevedra]"),
+    ("ES-PV", "Euskal Herria"),
+    ("ES-RI", "La Rioja"),
+    ("ES-S", "Cantabria"),
+    ("ES-SA", "Salamanca"),
+    ("ES-SE", "Sevilla"),
+    ("ES-SG", "Segovia"),
+    ("ES-SO", "Soria"),
+    ("ES-SS", "Gipuzkoa"),
+    ("ES-T", "Tarragona [Tarragona]"),
+    ("ES-TE", "Teruel"),
+    ("ES-TF", "Santa Cruz de Tenerife"),
+    ("ES-TO", "Toledo"),
+    ("ES-V", "Valencia"),
+    ("ES-VA", "Valladolid"),
+    ("ES-VC", "Valenciana, Comunidad"),
+    ("ES-VI", "Araba*"),
+    ("ES-Z", "Zaragoza"),
+    ("ES-ZA", "Zamora"),
+    ("ET-AA", "Addis Ababa"),
+    ("ET-AF", "Afar"),
+    ("ET-AM", "Amara"),
+    ("ET-BE", "Benshangul-Gumaz"),
+    ("ET-DD", "Dire Dawa"),
+    ("ET-GA", "Gambela Peoples"),
+    ("ET-HA", "Harari People"),
+    ("ET-OR", "Oromia"),
+    ("ET-SN", "Southern Nations, Nationalities and Peoples"),
+    ("ET-SO", "Somali"),
+    ("ET-TI", "Tigrai"),
+    ("FI-01", "Åland"),
+    ("FI-02", "Etelä-Karjala"),
+    ("FI-03", "Etelä-Pohjanmaa"),
+    ("FI-04", "Etelä-Savo"),
+    ("FI-05", "Kainuu"),
+    ("FI-06", "Kanta-Häme"),
+    ("FI-07", "Keski-Pohjanmaa"),
+    ("FI-08", "Keski-Suomi"),
+    ("FI-09", "Kymenlaakso"),
+    ("FI-10", "Lappi"),
+    ("FI-11", "Pirkanmaa"),
+    ("FI-12", "Pohjanmaa"),
+    ("FI-13", "Pohjois-Karjala"),
+    ("FI-14", "Pohjois-Pohjanmaa"),
+    ("FI-15", "Pohjois-Savo"),
+    ("FI-16", "Päijät-Häme"),
+    ("FI-17", "Satakunta"),
+    ("FI-18", "Uusimaa"),
+    ("FI-19", "Varsinais-Suomi"),
+    ("FJ-01", "Ba"),
+    ("FJ-02", "Bua"),
+    ("FJ-03", "Cakaudrove"),
+    ("FJ-04", "Kadavu"),
+    ("FJ-05", "Lau"),
+    ("FJ-06", "Lomaiviti"),
+    ("FJ-07", "Macuata"),
+    ("FJ-08", "Nadroga and Navosa"),
+    ("FJ-09", "Naitasiri"),
+    ("FJ-10", "Namosi"),
+    ("FJ-11", "Ra"),
+    ("FJ-12", "Rewa"),
+    ("FJ-13", "Serua"),
+    ("FJ-14", "Tailevu"),
+    ("FJ-C", "Central"),
+    ("FJ-E", "Eastern"),
+    ("FJ-N", "Northern"),
+    ("FJ-R", "Rotuma"),
+    ("FJ-W", "Western"),
+    ("FM-KSA", "Kosrae"),
+    ("FM-PNI", "Pohnpei"),
+    ("FM-TRK", "Chuuk"),
+    ("FM-YAP", "Yap"),
+    ("FR-01", "Ain"),
+    ("FR-02", "Aisne"),
+    ("FR-03", "Allier"),
+    ("FR-04", "Alpes-de-Haute-Provence"),
+    ("FR-05", "Hautes-Alpes"),
+    ("FR-06", "Alpes-Maritimes"),
+    ("FR-07", "Ardèche"),
+    ("FR-08", "Ardennes"),
+    ("FR-09", "Ariège"),
+    ("FR-10", "Aube"),
+    ("FR-11", "Aude"),
+    ("FR-12", "Aveyron"),
+    ("FR-13", "Bouches-du-Rhône"),
+    ("FR-14", "Calvados"),
+    ("FR-15", "Cantal"),
+    ("FR-16", "Charente"),
+    ("FR-17", "Charente-Maritime"),
+    ("FR-18", "Cher"),
+    ("FR-19", "Corrèze"),
+    ("FR-20R", "Corse"),
+    ("FR-21", "Côte-d'Or"),
+    ("FR-22", "Côtes-d'Armor"),
+    ("FR-23", "Creuse"),
+    ("FR-24", "Dordogne"),
+    ("FR-25", "Doubs"),
+    ("FR-26", "Drôme"),
+    ("FR-27", "Eure"),
+    ("FR-28", "Eure-et-Loir"),
+    ("FR-29", "Finistère"),
+    ("FR-2A", "Corse-du-Sud"),
+    ("FR-2B", "Haute-Corse"),
+    ("FR-30", "Gard"),
+    ("FR-31", "Haute-Garonne"),
+    ("FR-32", "Gers"),
+    ("FR-33", "Gironde"),
+    ("FR-34", "Hérault"),
+    ("FR-35", "Ille-et-Vilaine"),
+    ("FR-36", "Indre"),
+    ("FR-37", "Indre-et-Loire"),
+    ("FR-38", "Isère"),
+    ("FR-39", "Jura"),
+    ("FR-40", "Landes"),
+    ("FR-41", "Loir-et-Cher"),
+    ("FR-42", "Loire"),
+    ("FR-43", "Haute-Loire"),
+    ("FR-44", "Loire-Atlantique"),
+    ("FR-45", "Loiret"),
+    ("FR-46", "Lot"),
+    ("FR-47", "Lot-et-Garonne"),
+    ("FR-48", "Lozère"),
+    ("FR-49", "Maine-et-Loire"),
+    ("FR-50", "Manche"),
+    ("FR-51", "Marne"),
+    ("FR-52", "Haute-Marne"),
+    ("FR-53", "Mayenne"),
+    ("FR-54", "Meurthe-et-Moselle"),
+    ("FR-55", "Meuse"),
+    ("FR-56", "Morbihan"),
+    ("FR-57", "Moselle"),
+    ("FR-58", "Nièvre"),
+    ("FR-59", "Nord"),
+    ("FR-60", "Oise"),
+    ("FR-61", "Orne"),
+    ("FR-62", "Pas-de-Calais"),
+    ("FR-63", "Puy-de-Dôme"),
+    ("FR-64", "Pyrénées-Atlantiques"),
+    ("FR-65", "Hautes-Pyrénées"),
+    ("FR-66", "Pyrénées-Orientales"),
+    ("FR-67", "Bas-Rhin"),
+    ("FR-68", "Haut-Rhin"),
+    ("FR-69", "Rhône"),
+    ("FR-70", "Haute-Saône"),
+    ("FR-71", "Saône-et-Loire"),
+    ("FR-72", "Sarthe"),
+    ("FR-73", "Savoie"),
+    ("FR-74", "Haute-Savoie"),
+    ("FR-75", "Paris"),
+    ("FR-76", "Seine-Maritime"),
+    ("FR-77", "Seine-et-Marne"),
+    ("FR-78", "Yvelines"),
+    ("FR-79", "Deux-Sèvres"),
+    ("FR-80", "Somme"),
+    ("FR-81", "Tarn"),
+    ("FR-82", "Tarn-et-Garonne"),
+    ("FR-83", "Var"),
+    ("FR-84", "Vaucluse"),
+    ("FR-85", "Vendée"),
+    ("FR-86", "Vienne"),
+    ("FR-87", "Haute-Vienne"),
+    ("FR-88", "Vosges"),
+    ("FR-89", "Yonne"),
+    ("FR-90", "Territoire de Belfort"),
+    ("FR-91", "Essonne"),
+    ("FR-92", "Hauts-de-Seine"),
+    ("FR-93", "Seine-Saint-Denis"),
+    ("FR-94", "Val-de-Marne"),
+    ("FR-95", "Val-d'Oise"),
+    ("FR-971", "Guadeloupe"),
+    ("FR-972", "Martinique"),
+    ("FR-973", "Guyane (française)"),
+    ("FR-974", "La Réunion"),
+    ("FR-976", "Mayotte"),
+    ("FR-ARA", "Auvergne-Rhône-Alpes"),
+    ("FR-BFC", "Bourgogne-Franche-Comté"),
+    ("FR-BL", "Saint-Barthélemy"),
+    ("FR-BRE", "Bretagne"),
+    ("FR-CP", "Clipperton"),
+    ("FR-CVL", "Centre-Val de Loire"),
+    ("FR-GES", "Grand-Est"),
+    ("FR-GF", "Guyane (française)"),
+    ("FR-GP", "Guadeloupe"),
+    ("FR-HDF", "Hauts-de-France"),
+    ("FR-IDF", "Île-de-France"),
+    ("FR-MF", "Saint-Martin"),
+    ("FR-MQ", "Martinique"),
+    ("FR-NAQ", "Nouvelle-Aquitaine"),
+    ("FR-NC", "Nouvelle-Calédonie"),
+    ("FR-NOR", "Normandie"),
+    ("FR-OCC", "Occitanie"),
+    ("FR-PAC", "Provence-Alpes-Côte-d’Azur"),
+    ("FR-PDL", "Pays-de-la-Loire"),
+    ("FR-PF", "Polynésie française"),
+    ("FR-PM", "Saint-Pierre-et-Miquelon"),
+    ("FR-RE", "La Réunion"),
+    ("FR-TF", "Terres australes françaises"),
+    ("FR-WF", "Wallis-et-Futuna"),
+    ("FR-YT", "Mayotte"),
+    ("GA-1", "Estuaire"),
+    ("GA-2", "Haut-Ogooué"),
+    ("GA-3", "Moyen-Ogooué"),
+    ("GA-4", "Ngounié"),
+    ("GA-5", "Nyanga"),
+    ("GA-6", "Ogooué-Ivindo"),
+    ("GA-7", "Ogooué-Lolo"),
+    ("GA-8", "Ogooué-Maritime"),
+    ("GA-9", "Woleu-Ntem"),
+    ("GB-ABC", "Armagh City, Banbridge and Craigavon"),
+    ("GB-ABD", "Aberdeenshire"),
+    ("GB-ABE", "Aberdeen City"),
+    ("GB-AGB", "Argyll and Bute"),
+    ("GB-AGY", "Isle of Anglesey [Sir Ynys Môn GB-YNM]"),
+    ("GB-AND", "Ards and North Down"),
+    ("GB-ANN", "Antrim and Newtownabbey"),
+    ("GB-ANS", "Angus"),
+    ("GB-BAS", "Bath and North East Somerset"),
+    ("GB-BBD", "Blackburn with Darwen"),
+    ("GB-BCP", "Bournemouth, Christchurch and Poole"),
+    ("GB-BDF", "Bedford"),
+    ("GB-BDG", "Barking and Dagenham"),
+    ("GB-BEN", "Brent"),
+    ("GB-BEX", "Bexley"),
+    ("GB-BFS", "Belfast City"),
+    ("GB-BGE", "Bridgend [Pen-y-bont ar Ogwr GB-POG]"),
+    ("GB-BGW", "Blaenau Gwent"),
+    ("GB-BIR", "Birmingham"),
+    ("GB-BKM", "Buckinghamshire"),
+    ("GB-BNE", "Barnet"),
+    ("GB-BNH", "Brighton and Hove"),
+    ("GB-BNS", "Barnsley"),
+    ("GB-BOL", "Bolton"),
+    ("GB-BPL", "Blackpool"),
+    ("GB-BRC", "Bracknell Forest"),
+    ("GB-BRD", "Bradford"),
+    ("GB-BRY", "Bromley"),
+    ("GB-BST", "Bristol, City of"),
+    ("GB-BUR", "Bury"),
+    ("GB-CAM", "Cambridgeshire"),
+    ("GB-CAY", "Caerphilly [Caerffili GB-CAF]"),
+    ("GB-CBF", "Central Bedfordshire"),
+    ("GB-CCG", "Causeway Coast and Glens"),
+    ("GB-CGN", "Ceredigion [Sir Ceredigion]"),
+    ("GB-CHE", "Cheshire East"),
+    ("GB-CHW", "Cheshire West and Chester"),
+    ("GB-CLD", "Calderdale"),
+    ("GB-CLK", "Clackmannanshire"),
+    ("GB-CMA", "Cumbria"),
+    ("GB-CMD", "Camden"),
+    ("GB-CMN", "Carmarthenshire [Sir Gaerfyrddin GB-GFY]"),
+    ("GB-CON", "Cornwall"),
+    ("GB-COV", "Coventry"),
+    ("GB-CRF", "Cardiff [Caerdydd GB-CRD]"),
+    ("GB-CRY", "Croydon"),
+    ("GB-CWY", "Conwy"),
+    ("GB-DAL", "Darlington"),
+    ("GB-DBY", "Derbyshire"),
+    ("GB-DEN", "Denbighshire [Sir Ddinbych GB-DDB]"),
+    ("GB-DER", "Derby"),
+    ("GB-DEV", "Devon"),
+    ("GB-DGY", "Dumfries and Galloway"),
+    ("GB-DNC", "Doncaster"),
+    ("GB-DND", "Dundee City"),
+    ("GB-DOR", "Dorset"),
+    ("GB-DRS", "Derry and Strabane"),
+    ("GB-DUD", "Dudley"),
+    ("GB-DUR", "Durham, County"),
+    ("GB-EAL", "Ealing"),
+    ("GB-EAY", "East Ayrshire"),
+    ("GB-EDH", "Edinburgh, City of"),
+    ("GB-EDU", "East Dunbartonshire"),
+    ("GB-ELN", "East Lothian"),
+    ("GB-ELS", "Eilean Siar"),
+    ("GB-ENF", "Enfield"),
+    ("GB-ENG", "England"),
+    ("GB-ERW", "East Renfrewshire"),
+    ("GB-ERY", "East Riding of Yorkshire"),
+    ("GB-ESS", "Essex"),
+    ("GB-ESX", "East Sussex"),
+    ("GB-FAL", "Falkirk"),
+    ("GB-FIF", "Fife"),
+    ("GB-FLN", "Flintshire [Sir y Fflint GB-FFL]"),
+    ("GB-FMO", "Fermanagh and Omagh"),
+    ("GB-GAT", "Gateshead"),
+    ("GB-GLG", "Glasgow City"),
+    ("GB-GLS", "Gloucestershire"),
+    ("GB-GRE", "Greenwich"),
+    ("GB-GWN", "Gwynedd"),
+    ("GB-HAL", "Halton"),
+    ("GB-HAM", "Hampshire"),
+    ("GB-HAV", "Havering"),
+    ("GB-HCK", "Hackney"),
+    ("GB-HEF", "Herefordshire"),
+    ("GB-HIL", "Hillingdon"),
+    ("GB-HLD", "Highland"),
+    ("GB-HMF", "Hammersmith and Fulham"),
+    ("GB-HNS", "Hounslow"),
+    ("GB-HPL", "Hartlepool"),
+    ("GB-HRT", "Hertfordshire"),
+    ("GB-HRW", "Harrow"),
+    ("GB-HRY", "Haringey"),
+    ("GB-IOS", "Isles of Scilly"),
+    ("GB-IOW", "Isle of Wight"),
+    ("GB-ISL", "Islington"),
+    ("GB-IVC", "Inverclyde"),
+    ("GB-KEC", "Kensington and Chelsea"),
+    ("GB-KEN", "Kent"),
+    ("GB-KHL", "Kingston upon Hull"),
+    ("GB-KIR", "Kirklees"),
+    ("GB-KTT", "Kingston upon Thames"),
+    ("GB-KWL", "Knowsley"),
+    ("GB-LAN", "Lancashire"),
+    ("GB-LBC", "Lisburn and Castlereagh"),
+    ("GB-LBH", "Lambeth"),
+    ("GB-LCE", "Leicester"),
+    ("GB-LDS", "Leeds"),
+    ("GB-LEC", "Leicestershire"),
+    ("GB-LEW", "Lewisham"),
+    ("GB-LIN", "Lincolnshire"),
+    ("GB-LIV", "Liverpool"),
+    ("GB-LND", "London, City of"),
+    ("GB-LUT", "Luton"),
+    ("GB-MAN", "Manchester"),
+    ("GB-MDB", "Middlesbrough"),
+    ("GB-MDW", "Medway"),
+    ("GB-MEA", "Mid and East Antrim"),
+    ("GB-MIK", "Milton Keynes"),
+    ("GB-MLN", "Midlothian"),
+    ("GB-MON", "Monmouthshire [Sir Fynwy GB-FYN]"),
+    ("GB-MRT", "Merton"),
+    ("GB-MRY", "Moray"),
+    ("GB-MTY", "Merthyr Tydfil [Merthyr Tudful GB-MTU]"),
+    ("GB-MUL", "Mid-Ulster"),
+    ("GB-NAY", "North Ayrshire"),
+    ("GB-NBL", "Northumberland"),
+    ("GB-NEL", "North East Lincolnshire"),
+    ("GB-NET", "Newcastle upon Tyne"),
+    ("GB-NFK", "Norfolk"),
+    ("GB-NGM", "Nottingham"),
+    ("GB-NIR", "Northern Ireland"),
+    ("GB-NLK", "North Lanarkshire"),
+    ("GB-NLN", "North Lincolnshire"),
+    ("GB-NMD", "Newry, Mourne and Down"),
+    ("GB-NSM", "North Somerset"),
+    ("GB-NTH", "Northamptonshire"),
+    ("GB-NTL", "Neath Port Talbot [Castell-nedd Port Talbot GB-CTL]"),
+    ("GB-NTT", "Nottinghamshire"),
+    ("GB-NTY", "North Tyneside"),
+    ("GB-NWM", "Newham"),
+    ("GB-NWP", "Newport [Casnewydd GB-CNW]"),
+    ("GB-NYK", "North Yorkshire"),
+    ("GB-OLD", "Oldham"),
+    ("GB-ORK", "Orkney Islands"),
+    ("GB-OXF", "Oxfordshire"),
+    ("GB-PEM", "Pembrokeshire [Sir Benfro GB-BNF]"),
+    ("GB-PKN", "Perth and Kinross"),
+    ("GB-PLY", "Plymouth"),
+    ("GB-POR", "Portsmouth"),
+    ("GB-POW", "Powys"),
+    ("GB-PTE", "Peterborough"),
+    ("GB-RCC", "Redcar and Cleveland"),
+    ("GB-RCH", "Rochdale"),
+    ("GB-RCT", "Rhondda Cynon Taff [Rhondda CynonTaf]"),
+    ("GB-RDB", "Redbridge"),
+    ("GB-RDG", "Reading"),
+    ("GB-RFW", "Renfrewshire"),
+    ("GB-RIC", "Richmond upon Thames"),
+    ("GB-ROT", "Rotherham"),
+    ("GB-RUT", "Rutland"),
+    ("GB-SAW", "Sandwell"),
+    ("GB-SAY", "South Ayrshire"),
+    ("GB-SCB", "Scottish Borders"),
+    ("GB-SCT", "Scotland"),
+    ("GB-SFK", "Suffolk"),
+    ("GB-SFT", "Sefton"),
+    ("GB-SGC", "South Gloucestershire"),
+    ("GB-SHF", "Sheffield"),
+    ("GB-SHN", "St. Helens"),
+    ("GB-SHR", "Shropshire"),
+    ("GB-SKP", "Stockport"),
+    ("GB-SLF", "Salford"),
+    ("GB-SLG", "Slough"),
+    ("GB-SLK", "South Lanarkshire"),
+    ("GB-SND", "Sunderland"),
+    ("GB-SOL", "Solihull"),
+    ("GB-SOM", "Somerset"),
+    ("GB-SOS", "Southend-on-Sea"),
+    ("GB-SRY", "Surrey"),
+    ("GB-STE", "Stoke-on-Trent"),
+    ("GB-STG", "Stirling"),
+    ("GB-STH", "Southampton"),
+    ("GB-STN", "Sutton"),
+    ("GB-STS", "Staffordshire"),
+    ("GB-STT", "Stockton-on-Tees"),
+    ("GB-STY", "South Tyneside"),
+    ("GB-SWA", "Swansea [Abertawe GB-ATA]"),
+    ("GB-SWD", "Swindon"),
+    ("GB-SWK", "Southwark"),
+    ("GB-TAM", "Tameside"),
+    ("GB-TFW", "Telford and Wrekin"),
+    ("GB-THR", "Thurrock"),
+    ("GB-TOB", "Torbay"),
+    ("GB-TOF", "Torfaen [Tor-faen]"),
+    ("GB-TRF", "Trafford"),
+    ("GB-TWH", "Tower Hamlets"),
+    ("GB-VGL", "Vale of Glamorgan, The [Bro Morgannwg GB-BMG]"),
+    ("GB-WAR", "Warwickshire"),
+    ("GB-WBK", "West Berkshire"),
+    ("GB-WDU", "West Dunbartonshire"),
+    ("GB-WFT", "Waltham Forest"),
+    ("GB-WGN", "Wigan"),
+    ("GB-WIL", "Wiltshire"),
+    ("GB-WKF", "Wakefield"),
+    ("GB-WLL", "Walsall"),
+    ("GB-WLN", "West Lothian"),
+    ("GB-WLS", "Wales [Cymru GB-CYM]"),
+    ("GB-WLV", "Wolverhampton"),
+    ("GB-WND", "Wandsworth"),
+    ("GB-WNM", "Windsor and Maidenhead"),
+    ("GB-WOK", "Wokingham"),
+    ("GB-WOR", "Worcestershire"),
+    ("GB-WRL", "Wirral"),
+    ("GB-WRT", "Warrington"),
+    ("GB-WRX", "Wrexham [Wrecsam GB-WRC]"),
+    ("GB-WSM", "Westminster"),
+    ("GB-WSX", "West Sussex"),
+    ("GB-YOR", "York"),
+    ("GB-ZET", "Shetland Islands"),
+    ("GD-01", "Saint Andrew"),
+    ("GD-02", "Saint David"),
+    ("GD-03", "Saint George"),
+    ("GD-04", "Saint John"),
+    ("GD-05", "Saint Mark"),
+    ("GD-06", "Saint Patrick"),
+    ("GD-10", "Southern Grenadine Islands"),
+    ("GE-AB", "Abkhazia"),
+    ("GE-AJ", "Ajaria"),
+    ("GE-GU", "Guria"),
+    ("GE-IM", "Imereti"),
+    ("GE-KA", "K'akheti"),
+    ("GE-KK", "Kvemo Kartli"),
+    ("GE-MM", "Mtskheta-Mtianeti"),
+    ("GE-RL", "Rach'a-Lechkhumi-Kvemo Svaneti"),
+    ("GE-SJ", "Samtskhe-Javakheti"),
+    ("GE-SK", "Shida Kartli"),
+    ("GE-SZ", "Samegrelo-Zemo Svaneti"),
+    ("GE-TB", "Tbilisi"),
+    ("GH-AA", "Greater Accra"),
+    ("GH-AF", "Ahafo"),
+    ("GH-AH", "Ashanti"),
+    ("GH-BE", "Bono East"),
+    ("GH-BO", "Bono"),
+    ("GH-CP", "Central"),
+    ("GH-EP", "Eastern"),
+    ("GH-NE", "North East"),
+    ("GH-NP", "Northern"),
+    ("GH-OT", "Oti"),
+    ("GH-SV", "Savannah"),
+    ("GH-TV", "Volta"),
+    ("GH-UE", "Upper East"),
+    ("GH-UW", "Upper West"),
+    ("GH-WN", "Western North"),
+    ("GH-WP", "Western"),
+    ("GL-AV", "Avannaata Kommunia"),
+    ("GL-KU", "Kommune Kujalleq"),
+    ("GL-QE", "Qeqqata Kommunia"),
+    ("GL-QT", "Kommune Qeqertalik"),
+    ("GL-SM", "Kommuneqarfik Sermersooq"),
+    ("GM-B", "Banjul"),
+    ("GM-L", "Lower River"),
+    ("GM-M", "Central River"),
+    ("GM-N", "North Bank"),
+    ("GM-U", "Upper River"),
+    ("GM-W", "Western"),
+    ("GN-B", "Boké"),
+    ("GN-BE", "Beyla"),
+    ("GN-BF", "Boffa"),
+    ("GN-BK", "Boké"),
+    ("GN-C", "Conakry"),
+    ("GN-CO", "Coyah"),
+    ("GN-D", "Kindia"),
+    ("GN-DB", "Dabola"),
+    ("GN-DI", "Dinguiraye"),
+    ("GN-DL", "Dalaba"),
+    ("GN-DU", "Dubréka"),
+    ("GN-F", "Faranah"),
+    ("GN-FA", "Faranah"),
+    ("GN-FO", "Forécariah"),
+    ("GN-FR", "Fria"),
+    ("GN-GA", "Gaoual"),
+    ("GN-GU", "Guékédou"),
+    ("GN-K", "Kankan"),
+    ("GN-KA", "Kankan"),
+    ("GN-KB", "Koubia"),
+    ("GN-KD", "Kindia"),
+    ("GN-KE", "Kérouané"),
+    ("GN-KN", "Koundara"),
+    ("GN-KO", "Kouroussa"),
+    ("GN-KS", "Kissidougou"),
+    ("GN-L", "Labé"),
+    ("GN-LA", "Labé"),
+    ("GN-LE", "Lélouma"),
+    ("GN-LO", "Lola"),
+    ("GN-M", "Mamou"),
+    ("GN-MC", "Macenta"),
+    ("GN-MD", "Mandiana"),
+    ("GN-ML", "Mali"),
+    ("GN-MM", "Mamou"),
+    ("GN-N", "Nzérékoré"),
+    ("GN-NZ", "Nzérékoré"),
+    ("GN-PI", "Pita"),
+    ("GN-SI", "Siguiri"),
+    ("GN-TE", "Télimélé"),
+    ("GN-TO", "Tougué"),
+    ("GN-YO", "Yomou"),
+    ("GQ-AN", "Annobon"),
+    ("GQ-BN", "Bioko Nord"),
+    ("GQ-BS", "Bioko Sud"),
+    ("GQ-C", "Região Continental"),
+    ("GQ-CS", "Centro Sud"),
+    ("GQ-DJ", "Djibloho"),
+    ("GQ-I", "Região Insular"),
+    ("GQ-KN", "Kié-Ntem"),
+    ("GQ-LI", "Litoral"),
+    ("GQ-WN", "Wele-Nzas"),
+    ("GR-69", "Ágion Óros"),
+    ("GR-A", "Anatolikí Makedonía kai Thráki"),
+    ("GR-B", "Kentrikí Makedonía"),
+    ("GR-C", "Dytikí Makedonía"),
+    ("GR-D", "Ípeiros"),
+    ("GR-E", "Thessalía"),
+    ("GR-F", "Ionía Nísia"),
+    ("GR-G", "Dytikí Elláda"),
+    ("GR-H", "Stereá Elláda"),
+    ("GR-I", "Attikí"),
+    ("GR-J", "Pelopónnisos"),
+    ("GR-K", "Vóreio Aigaío"),
+    ("GR-L", "Nótio Aigaío"),
+    ("GR-M", "Kríti"),
+    ("GT-AV", "Alta Verapaz"),
+    ("GT-BV", "Baja Verapaz"),
+    ("GT-CM", "Chimaltenango"),
+    ("GT-CQ", "Chiquimula"),
+    ("GT-ES", "Escuintla"),
+    ("GT-GU", "Guatemala"),
+    ("GT-HU", "Huehuetenango"),
+    ("GT-IZ", "Izabal"),
+    ("GT-JA", "Jalapa"),
+    ("GT-JU", "Jutiapa"),
+    ("GT-PE", "Petén"),
+    ("GT-PR", "El Progreso"),
+    ("GT-QC", "Quiché"),
+    ("GT-QZ", "Quetzaltenango"),
+    ("GT-RE", "Retalhuleu"),
+    ("GT-SA", "Sacatepéquez"),
+    ("GT-SM", "San Marcos"),
+    ("GT-SO", "Sololá"),
+    ("GT-SR", "Santa Rosa"),
+    ("GT-SU", "Suchitepéquez"),
+    ("GT-TO", "Totonicapán"),
+    ("GT-ZA", "Zacapa"),
+    ("GW-BA", "Bafatá"),
+    ("GW-BL", "Bolama / Bijagós"),
+    ("GW-BM", "Biombo"),
+    ("GW-BS", "Bissau"),
+    ("GW-CA", "Cacheu"),
+    ("GW-GA", "Gabú"),
+    ("GW-L", "Leste"),
+    ("GW-N", "Norte"),
+    ("GW-OI", "Oio"),
+    ("GW-QU", "Quinara"),
+    ("GW-S", "Sul"),
+    ("GW-TO", "Tombali"),
+    ("GY-BA", "Barima-Waini"),
+    ("GY-CU", "Cuyuni-Mazaruni"),
+    ("GY-DE", "Demerara-Mahaica"),
+    ("GY-EB", "East Berbice-Corentyne"),
+    ("GY-ES", "Essequibo Islands-West Demerara"),
+    ("GY-MA", "Mahaica-Berbice"),
+    ("GY-PM", "Pomeroon-Supenaam"),
+    ("GY-PT", "Potaro-Siparuni"),
+    ("GY-UD", "Upper Demerara-Berbice"),
+    ("GY-UT", "Upper Takutu-Upper Essequibo"),
+    ("HN-AT", "Atlántida"),
+    ("HN-CH", "Choluteca"),
+    ("HN-CL", "Colón"),
+    ("HN-CM", "Comayagua"),
+    ("HN-CP", "Copán"),
+    ("HN-CR", "Cortés"),
+    ("HN-EP", "El Paraíso"),
+    ("HN-FM", "Francisco Morazán"),
+    ("HN-GD", "Gracias a Dios"),
+    ("HN-IB", "Islas de la Bahía"),
+    ("HN-IN", "Intibucá"),
+    ("HN-LE", "Lempira"),
+    ("HN-LP", "La Paz"),
+    ("HN-OC", "Ocotepeque"),
+    ("HN-OL", "Olancho"),
+    ("HN-SB", "Santa Bárbara"),
+    ("HN-VA", "Valle"),
+    ("HN-YO", "Yoro"),
+    ("HR-01", "Zagrebačka županija"),
+    ("HR-02", "Krapinsko-zagorska županija"),
+    ("HR-03", "Sisačko-moslavačka županija"),
+    ("HR-04", "Karlovačka županija"),
+    ("HR-05", "Varaždinska županija"),
+    ("HR-06", "Koprivničko-križevačka županija"),
+    ("HR-07", "Bjelovarsko-bilogorska županija"),
+    ("HR-08", "Primorsko-goranska županija"),
+    ("HR-09", "Ličko-senjska županija"),
+    ("HR-10", "Virovitičko-podravska županija"),
+    ("HR-11", "Požeško-slavonska županija"),
+    ("HR-12", "Brodsko-posavska županija"),
+    ("HR-13", "Zadarska županija"),
+    ("HR-14", "Osječko-baranjska županija"),
+    ("HR-15", "Šibensko-kninska županija"),
+    ("HR-16", "Vukovarsko-srijemska županija"),
+    ("HR-17", "Splitsko-dalmatinska županija"),
+    ("HR-18", "Istarska županija"),
+    ("HR-19", "Dubrovačko-neretvanska županija"),
+    ("HR-20", "Međimurska županija"),
+    ("HR-21", "Grad Zagreb"),
+    ("HT-AR", "Artibonite"),
+    ("HT-CE", "Centre"),
+    ("HT-GA", "Grandans"),
+    ("HT-ND", "Nord"),
+    ("HT-NE", "Nord-Est"),
+    ("HT-NI", "Nip"),
+    ("HT-NO", "Nord-Ouest"),
+    ("HT-OU", "Lwès"),
+    ("HT-SD", "Sid"),
+    ("HT-SE", "Sidès"),
+    ("HU-BA", "Baranya"),
+    ("HU-BC", "Békéscsaba"),
+    ("HU-BE", "Békés"),
+    ("HU-BK", "Bács-Kiskun"),
+    ("HU-BU", "Budapest"),
+    ("HU-BZ", "Borsod-Abaúj-Zemplén"),
+    ("HU-CS", "Csongrád"),
+    ("HU-DE", "Debrecen"),
+    ("HU-DU", "Dunaújváros"),
+    ("HU-EG", "Eger"),
+    ("HU-ER", "Érd"),
+    ("HU-FE", "Fejér"),
+    ("HU-GS", "Győr-Moson-Sopron"),
+    ("HU-GY", "Győr"),
+    ("HU-HB", "Hajdú-Bihar"),
+    ("HU-HE", "Heves"),
+    ("HU-HV", "Hódmezővásárhely"),
+    ("HU-JN", "Jász-Nagykun-Szolnok"),
+    ("HU-KE", "Komárom-Esztergom"),
+    ("HU-KM", "Kecskemét"),
+    ("HU-KV", "Kaposvár"),
+    ("HU-MI", "Miskolc"),
+    ("HU-NK", "Nagykanizsa"),
+    ("HU-NO", "Nógrád"),
+    ("HU-NY", "Nyíregyháza"),
+    ("HU-PE", "Pest"),
+    ("HU-PS", "Pécs"),
+    ("HU-SD", "Szeged"),
+    ("HU-SF", "Székesfehérvár"),
+    ("HU-SH", "Szombathely"),
+    ("HU-SK", "Szolnok"),
+    ("HU-SN", "Sopron"),
+    ("HU-SO", "Somogy"),
+    ("HU-SS", "Szekszárd"),
+    ("HU-ST", "Salgótarján"),
+    ("HU-SZ", "Szabolcs-Szatmár-Bereg"),
+    ("HU-TB", "Tatabánya"),
+    ("HU-TO", "Tolna"),
+    ("HU-VA", "Vas"),
+    ("HU-VE", "Veszprém"),
+    ("HU-VM", "Veszprém"),
+    ("HU-ZA", "Zala"),
+    ("HU-ZE", "Zalaegerszeg"),
+    ("ID-AC", "Aceh"),
+    ("ID-BA", "Bali"),
+    ("ID-BB", "Kepulauan Bangka Belitung"),
+    ("ID-BE", "Bengkulu"),
+    ("ID-BT", "Banten"),
+    ("ID-GO", "Gorontalo"),
+    ("ID-JA", "Jambi"),
+    ("ID-JB", "Jawa Barat"),
+    ("ID-JI", "Jawa Timur"),
+    ("ID-JK", "Jakarta Raya"),
+    ("ID-JT", "Jawa Tengah"),
+    ("ID-JW", "Jawa"),
+    ("ID-KA", "Kalimantan"),
+    ("ID-KB", "Kalimantan Barat"),
+    ("ID-KI", "Kalimantan Timur"),
+    ("ID-KR", "Kepulauan Riau"),
+    ("ID-KS", "Kalimantan Selatan"),
+    ("ID-KT", "Kalimantan Tengah"),
+    ("ID-KU", "Kalimantan Utara"),
+    ("ID-LA", "Lampung"),
+    ("ID-MA", "Maluku"),
+    ("ID-ML", "Maluku"),
+    ("ID-MU", "Maluku Utara"),
+    ("ID-NB", "Nusa Tenggara Barat"),
+    ("ID-NT", "Nusa Tenggara Timur"),
+    ("ID-NU", "Nusa Tenggara"),
+    ("ID-PA", "Papua"),
+    ("ID-PB", "Papua Barat"),
+    ("ID-PP", "Papua"),
+    ("ID-RI", "Riau"),
+    ("ID-SA", "Sulawesi Utara"),
+    ("ID-SB", "Sumatera Barat"),
+    ("ID-SG", "Sulawesi Tenggara"),
+    ("ID-SL", "Sulawesi"),
+    ("ID-SM", "Sumatera"),
+    ("ID-SN", "Sulawesi Selatan"),
+    ("ID-SR", "Sulawesi Barat"),
+    ("ID-SS", "Sumatera Selatan"),
+    ("ID-ST", "Sulawesi Tengah"),
+    ("ID-SU", "Sumatera Utara"),
+    ("ID-YO", "Yogyakarta"),
+    ("IE-C", "Connaught"),
+    ("IE-CE", "Clare"),
+    ("IE-CN", "Cavan"),
+    ("IE-CO", "Cork"),
+    ("IE-CW", "Carlow"),
+    ("IE-D", "Dublin"),
+    ("IE-DL", "Donegal"),
+    ("IE-G", "Galway"),
+    ("IE-KE", "Kildare"),
+    ("IE-KK", "Kilkenny"),
+    ("IE-KY", "Kerry"),
+    ("IE-L", "Leinster"),
+    ("IE-LD", "Longford"),
+    ("IE-LH", "Louth"),
+    ("IE-LK", "Limerick"),
+    ("IE-LM", "Leitrim"),
+    ("IE-LS", "Laois"),
+    ("IE-M", "Munster"),
+    ("IE-MH", "Meath"),
+    ("IE-MN", "Monaghan"),
+    ("IE-MO", "Mayo"),
+    ("IE-OY", "Offaly"),
+    ("IE-RN", "Roscommon"),
+    ("IE-SO", "Sligo"),
+    ("IE-TA", "Tipperary"),
+    ("IE-U", "Ulster"),
+    ("IE-WD", "Waterford"),
+    ("IE-WH", "Westmeath"),
+    ("IE-WW", "Wicklow"),
+    ("IE-WX", "Wexford"),
+    ("IL-D", "Al Janūbī"),
+    ("IL-HA", "H̱efa"),
+    ("IL-JM", "Al Quds"),
+    ("IL-M", "Al Awsaţ"),
+    ("IL-TA", "Tall Abīb"),
+    ("IL-Z", "Ash Shamālī"),
+    ("IN-AN", "Andaman and Nicobar Islands"),
+    ("IN-AP", "Andhra Pradesh"),
+    ("IN-AR", "Arunāchal Pradesh"),
+    ("IN-AS", "Assam"),
+    ("IN-BR", "Bihār"),
+    ("IN-CH", "Chandīgarh"),
+    ("IN-CT", "Chhattīsgarh"),
+    ("IN-DH", "Dādra and Nagar Haveli and Damān and Diu"),
+    ("IN-DL", "Delhi"),
+    ("IN-GA", "Goa"),
+    ("IN-GJ", "Gujarāt"),
+    ("IN-HP", "Himāchal Pradesh"),
+    ("IN-HR", "Haryāna"),
+    ("IN-JH", "Jhārkhand"),
+    ("IN-JK", "Jammu and Kashmīr"),
+    ("IN-KA", "Karnātaka"),
+    ("IN-KL", "Kerala"),
+    ("IN-LA", "Ladākh"),
+    ("IN-LD", "Lakshadweep"),
+    ("IN-MH", "Mahārāshtra"),
+    ("IN-ML", "Meghālaya"),
+    ("IN-MN", "Manipur"),
+    ("IN-MP", "Madhya Pradesh"),
+    ("IN-MZ", "Mizoram"),
+    ("IN-NL", "Nāgāland"),
+    ("IN-OR", "Odisha"),
+    ("IN-PB", "Punjab"),
+    ("IN-PY", "Puducherry"),
+    ("IN-RJ", "Rājasthān"),
+    ("IN-SK", "Sikkim"),
+    ("IN-TG", "Telangāna"),
+    ("IN-TN", "Tamil Nādu"),
+    ("IN-TR", "Tripura"),
+    ("IN-UP", "Uttar Pradesh"),
+    ("IN-UT", "Uttarākhand"),
+    ("IN-WB", "West Bengal"),
+    ("IQ-AN", "Al Anbār"),
+    ("IQ-AR", "Arbīl"),
+    ("IQ-BA", "Al Başrah"),
+    ("IQ-BB", "Bābil"),
+    ("IQ-BG", "Baghdād"),
+    ("IQ-DA", "Dahūk"),
+    ("IQ-DI", "Diyālá"),
+    ("IQ-DQ", "Dhī Qār"),
+    ("IQ-KA", "Karbalā’"),
+    ("IQ-KI", "Kirkūk"),
+    ("IQ-MA", "Maysān"),
+    ("IQ-MU", "Al Muthanná"),
+    ("IQ-NA", "An Najaf"),
+    ("IQ-NI", "Nīnawá"),
+    ("IQ-QA", "Al Qādisīyah"),
+    ("IQ-SD", "Şalāḩ ad Dīn"),
+    ("IQ-SU", "As Sulaymānīyah"),
+    ("IQ-WA", "Wāsiţ"),
+    ("IR-00", "Markazī"),
+    ("IR-01", "Gīlān"),
+    ("IR-02", "Māzandarān"),
+    ("IR-03", "Āz̄ārbāyjān-e Shārqī"),
+    ("IR-04", "Āz̄ārbāyjān-e Ghārbī"),
+    ("IR-05", "Kermānshāh"),
+    ("IR-06", "Khūzestān"),
+    ("IR-07", "Fārs"),
+    ("IR-08", "Kermān"),
+    ("IR-09", "Khorāsān-e Raẕavī"),
+    ("IR-10", "Eşfahān"),
+    ("IR-11", "Sīstān va Balūchestān"),
+    ("IR-12", "Kordestān"),
+    ("IR-13", "Hamadān"),
+    ("IR-14", "Chahār Maḩāl va Bakhtīārī"),
+    ("IR-15", "Lorestān"),
+    ("IR-16", "Īlām"),
+    ("IR-17", "Kohgīlūyeh va Bowyer Aḩmad"),
+    ("IR-18", "Būshehr"),
+    ("IR-19", "Zanjān"),
+    ("IR-20", "Semnān"),
+    ("IR-21", "Yazd"),
+    ("IR-22", "Hormozgān"),
+    ("IR-23", "Tehrān"),
+    ("IR-24", "Ardabīl"),
+    ("IR-25", "Qom"),
+    ("IR-26", "Qazvīn"),
+    ("IR-27", "Golestān"),
+    ("IR-28", "Khorāsān-e Shomālī"),
+    ("IR-29", "Khorāsān-e Jonūbī"),
+    ("IR-30", "Alborz"),
+    ("IS-1", "Höfuðborgarsvæði"),
+    ("IS-2", "Suðurnes"),
+    ("IS-3", "Vesturland"),
+    ("IS-4", "Vestfirðir"),
+    ("IS-5", "Norðurland vestra"),
+    ("IS-6", "Norðurland eystra"),
+    ("IS-7", "Austurland"),
+    ("IS-8", "Suðurland"),
+    ("IS-AKH", "Akrahreppur"),
+    ("IS-AKN", "Akraneskaupstaður"),
+    ("IS-AKU", "Akureyrarbær"),
+    ("IS-ARN", "Árneshreppur"),
+    ("IS-ASA", "Ásahreppur"),
+    ("IS-BFJ", "Borgarfjarðarhreppur"),
+    ("IS-BLA", "Bláskógabyggð"),
+    ("IS-BLO", "Blönduósbær"),
+    ("IS-BOG", "Borgarbyggð"),
+    ("IS-BOL", "Bolungarvíkurkaupstaður"),
+    ("IS-DAB", "Dalabyggð"),
+    ("IS-DAV", "Dalvíkurbyggð"),
+    ("IS-DJU", "Djúpavogshreppur"),
+    ("IS-EOM", "Eyja- og Miklaholtshreppur"),
+    ("IS-EYF", "Eyjafjarðarsveit"),
+    ("IS-FJD", "Fjarðabyggð"),
+    ("IS-FJL", "Fjallabyggð"),
+    ("IS-FLA", "Flóahreppur"),
+    ("IS-FLD", "Fljótsdalshérað"),
+    ("IS-FLR", "Fljótsdalshreppur"),
+    ("IS-GAR", "Garðabær"),
+    ("IS-GOG", "Grímsnes- og Grafningshreppur"),
+    ("IS-GRN", "Grindavíkurbær"),
+    ("IS-GRU", "Grundarfjarðarbær"),
+    ("IS-GRY", "Grýtubakkahreppur"),
+    ("IS-HAF", "Hafnarfjarðarkaupstaður"),
+    ("IS-HEL", "Helgafellssveit"),
+    ("IS-HRG", "Hörgársveit"),
+    ("IS-HRU", "Hrunamannahreppur"),
+    ("IS-HUT", "Húnavatnshreppur"),
+    ("IS-HUV", "Húnaþing vestra"),
+    ("IS-HVA", "Hvalfjarðarsveit"),
+    ("IS-HVE", "Hveragerðisbær"),
+    ("IS-ISA", "Ísafjarðarbær"),
+    ("IS-KAL", "Kaldrananeshreppur"),
+    ("IS-KJO", "Kjósarhreppur"),
+    ("IS-KOP", "Kópavogsbær"),
+    ("IS-LAN", "Langanesbyggð"),
+    ("IS-MOS", "Mosfellsbær"),
+    ("IS-MYR", "Mýrdalshreppur"),
+    ("IS-NOR", "Norðurþing"),
+    ("IS-RGE", "Rangárþing eystra"),
+    ("IS-RGY", "Rangárþing ytra"),
+    ("IS-RHH", "Reykhólahreppur"),
+    ("IS-RKN", "Reykjanesbær"),
+    ("IS-RKV", "Reykjavíkurborg"),
+    ("IS-SBH", "Svalbarðshreppur"),
+    ("IS-SBT", "Svalbarðsstrandarhreppur"),
+    ("IS-SDN", "Suðurnesjabær"),
+    ("IS-SDV", "Súðavíkurhreppur"),
+    ("IS-SEL", "Seltjarnarnesbær"),
+    ("IS-SEY", "Seyðisfjarðarkaupstaður"),
+    ("IS-SFA", "Sveitarfélagið Árborg"),
+    ("IS-SHF", "Sveitarfélagið Hornafjörður"),
+    ("IS-SKF", "Skaftárhreppur"),
+    ("IS-SKG", "Skagabyggð"),
+    ("IS-SKO", "Skorradalshreppur"),
+    ("IS-SKU", "Skútustaðahreppur"),
+    ("IS-SNF", "Snæfellsbær"),
+    ("IS-SOG", "Skeiða- og Gnúpverjahreppur"),
+    ("IS-SOL", "Sveitarfélagið Ölfus"),
+    ("IS-SSF", "Sveitarfélagið Skagafjörður"),
+    ("IS-SSS", "Sveitarfélagið Skagaströnd"),
+    ("IS-STR", "Strandabyggð"),
+    ("IS-STY", "Stykkishólmsbær"),
+    ("IS-SVG", "Sveitarfélagið Vogar"),
+    ("IS-TAL", "Tálknafjarðarhreppur"),
+    ("IS-THG", "Þingeyjarsveit"),
+    ("IS-TJO", "Tjörneshreppur"),
+    ("IS-VEM", "Vestmannaeyjabær"),
+    ("IS-VER", "Vesturbyggð"),
+    ("IS-VOP", "Vopnafjarðarhreppur"),
+    ("IT-21", "Piemonte"),
+    ("IT-23", "Val d'Aoste"),
+    ("IT-25", "Lombardia"),
+    ("IT-32", "Trentino-Alto Adige"),
+    ("IT-34", "Veneto"),
+    ("IT-36", "Friuli Venezia Giulia"),
+    ("IT-42", "Liguria"),
+    ("IT-45", "Emilia-Romagna"),
+    ("IT-52", "Toscana"),
+    ("IT-55", "Umbria"),
+    ("IT-57", "Marche"),
+    ("IT-62", "Lazio"),
+    ("IT-65", "Abruzzo"),
+    ("IT-67", "Molise"),
+    ("IT-72", "Campania"),
+    ("IT-75", "Puglia"),
+    ("IT-77", "Basilicata"),
+    ("IT-78", "Calabria"),
+    ("IT-82", "Sicilia"),
+    ("IT-88", "Sardegna"),
+    ("IT-AG", "Agrigento"),
+    ("IT-AL", "Alessandria"),
+    ("IT-AN", "Ancona"),
+    ("IT-AP", "Ascoli Piceno"),
+    ("IT-AQ", "L'Aquila"),
+    ("IT-AR", "Arezzo"),
+    ("IT-AT", "Asti"),
+    ("IT-AV", "Avellino"),
+    ("IT-BA", "Bari"),
+    ("IT-BG", "Bergamo"),
+    ("IT-BI", "Biella"),
+    ("IT-BL", "Belluno"),
+    ("IT-BN", "Benevento"),
+    ("IT-BO", "Bologna"),
+    ("IT-BR", "Brindisi"),
+    ("IT-BS", "Brescia"),
+    ("IT-BT", "Barletta-Andria-Trani"),
+    ("IT-BZ", "Bolzano"),
+    ("IT-CA", "Cagliari"),
+    ("IT-CB", "Campobasso"),
+    ("IT-CE", "Caserta"),
+    ("IT-CH", "Chieti"),
+    ("IT-CL", "Caltanissetta"),
+    ("IT-CN", "Cuneo"),
+    ("IT-CO", "Como"),
+    ("IT-CR", "Cremona"),
+    ("IT-CS", "Cosenza"),
+    ("IT-CT", "Catania"),
+    ("IT-CZ", "Catanzaro"),
+    ("IT-EN", "Enna"),
+    ("IT-FC", "Forlì-Cesena"),
+    ("IT-FE", "Ferrara"),
+    ("IT-FG", "Foggia"),
+    ("IT-FI", "Firenze"),
+    ("IT-FM", "Fermo"),
+    ("IT-FR", "Frosinone"),
+    ("IT-GE", "Genova"),
+    ("IT-GO", "Gorizia"),
+    ("IT-GR", "Grosseto"),
+    ("IT-IM", "Imperia"),
+    ("IT-IS", "Isernia"),
+    ("IT-KR", "Crotone"),
+    ("IT-LC", "Lecco"),
+    ("IT-LE", "Lecce"),
+    ("IT-LI", "Livorno"),
+    ("IT-LO", "Lodi"),
+    ("IT-LT", "Latina"),
+    ("IT-LU", "Lucca"),
+    ("IT-MB", "Monza e Brianza"),
+    ("IT-MC", "Macerata"),
+    ("IT-ME", "Messina"),
+    ("IT-MI", "Milano"),
+    ("IT-MN", "Mantova"),
+    ("IT-MO", "Modena"),
+    ("IT-MS", "Massa-Carrara"),
+    ("IT-MT", "Matera"),
+    ("IT-NA", "Napoli"),
+    ("IT-NO", "Novara"),
+    ("IT-NU", "Nuoro"),
+    ("IT-OR", "Oristano"),
+    ("IT-PA", "Palermo"),
+    ("IT-PC", "Piacenza"),
+    ("IT-PD", "Padova"),
+    ("IT-PE", "Pescara"),
+    ("IT-PG", "Perugia"),
+    ("IT-PI", "Pisa"),
+    ("IT-PN", "Pordenone"),
+    ("IT-PO", "Prato"),
+    ("IT-PR", "Parma"),
+    ("IT-PT", "Pistoia"),
+    ("IT-PU", "Pesaro e Urbino"),
+    ("IT-PV", "Pavia"),
+    ("IT-PZ", "Potenza"),
+    ("IT-RA", "Ravenna"),
+    ("IT-RC", "Reggio Calabria"),
+    ("IT-RE", "Reggio Emilia"),
+    ("IT-RG", "Ragusa"),
+    ("IT-RI", "Rieti"),
+    ("IT-RM", "Roma"),
+    ("IT-RN", "Rimini"),
+    ("IT-RO", "Rovigo"),
+    ("IT-SA", "Salerno"),
+    ("IT-SI", "Siena"),
+    ("IT-SO", "Sondrio"),
+    ("IT-SP", "La Spezia"),
+    ("IT-SR", "Siracusa"),
+    ("IT-SS", "Sassari"),
+    ("IT-SU", "Sud Sardegna"),
+    ("IT-SV", "Savona"),
+    ("IT-TA", "Taranto"),
+    ("IT-TE", "Teramo"),
+    ("IT-TN", "Trento"),
+    ("IT-TO", "Torino"),
+    ("IT-TP", "Trapani"),
+    ("IT-TR", "Terni"),
+    ("IT-TS", "Trieste"),
+    ("IT-TV", "Treviso"),
+    ("IT-UD", "Udine"),
+    ("IT-VA", "Varese"),
+    ("IT-VB", "Verbano-Cusio-Ossola"),
+    ("IT-VC", "Vercelli"),
+    ("IT-VE", "Venezia"),
+    ("IT-VI", "Vicenza"),
+    ("IT-VR", "Verona"),
+    ("IT-VT", "Viterbo"),
+    ("IT-VV", "Vibo Valentia"),
+    ("JM-01", "Kingston"),
+    ("JM-02", "Saint Andrew"),
+    ("JM-03", "Saint Thomas"),
+    ("JM-04", "Portland"),
+    ("JM-05", "Saint Mary"),
+    ("JM-06", "Saint Ann"),
+    ("JM-07", "Trelawny"),
+    ("JM-08", "Saint James"),
+    ("JM-09", "Hanover"),
+    ("JM-10", "Westmoreland"),
+    ("JM-11", "Saint Elizabeth"),
+    ("JM-12", "Manchester"),
+    ("JM-13", "Clarendon"),
+    ("JM-14", "Saint Catherine"),
+    ("JO-AJ", "‘Ajlūn"),
+    ("JO-AM", "Al ‘A̅şimah"),
+    ("JO-AQ", "Al ‘Aqabah"),
+    ("JO-AT", "Aţ Ţafīlah"),
+    ("JO-AZ", "Az Zarqā’"),
+    ("JO-BA", "Al Balqā’"),
+    ("JO-IR", "Irbid"),
+    ("JO-JA", "Jarash"),
+    ("JO-KA", "Al Karak"),
+    ("JO-MA", "Al Mafraq"),
+    ("JO-MD", "Mādabā"),
+    ("JO-MN", "Ma‘ān"),
+    ("JP-01", "Hokkaido"),
+    ("JP-02", "Aomori"),
+    ("JP-03", "Iwate"),
+    ("JP-04", "Miyagi"),
+    ("JP-05", "Akita"),
+    ("JP-06", "Yamagata"),
+    ("JP-07", "Fukushima"),
+    ("JP-08", "Ibaraki"),
+    ("JP-09", "Tochigi"),
+    ("JP-10", "Gunma"),
+    ("JP-11", "Saitama"),
+    ("JP-12", "Chiba"),
+    ("JP-13", "Tokyo"),
+    ("JP-14", "Kanagawa"),
+    ("JP-15", "Niigata"),
+    ("JP-16", "Toyama"),
+    ("JP-17", "Ishikawa"),
+    ("JP-18", "Fukui"),
+    ("JP-19", "Yamanashi"),
+    ("JP-20", "Nagano"),
+    ("JP-21", "Gifu"),
+    ("JP-22", "Shizuoka"),
+    ("JP-23", "Aichi"),
+    ("JP-24", "Mie"),
+    ("JP-25", "Shiga"),
+    ("JP-26", "Kyoto"),
+    ("JP-27", "Osaka"),
+    ("JP-28", "Hyogo"),
+    ("JP-29", "Nara"),
+    ("JP-30", "Wakayama"),
+    ("JP-31", "Tottori"),
+    ("JP-32", "Shimane"),
+    ("JP-33", "Okayama"),
+    ("JP-34", "Hiroshima"),
+    ("JP-35", "Yamaguchi"),
+    ("JP-36", "Tokushima"),
+    ("JP-37", "Kagawa"),
+    ("JP-38", "Ehime"),
+    ("JP-39", "Kochi"),
+    ("JP-40", "Fukuoka"),
+    ("JP-41", "Saga"),
+    ("JP-42", "Nagasaki"),
+    ("JP-43", "Kumamoto"),
+    ("JP-44", "Oita"),
+    ("JP-45", "Miyazaki"),
+    ("JP-46", "Kagoshima"),
+    ("JP-47", "Okinawa"),
+    ("KE-01", "Baringo"),
+    ("KE-02", "Bomet"),
+    ("KE-03", "Bungoma"),
+    ("KE-04", "Busia"),
+    ("KE-05", "Elgeyo/Marakwet"),
+    ("KE-06", "Embu"),
+    ("KE-07", "Garissa"),
+    ("KE-08", "Homa Bay"),
+    ("KE-09", "Isiolo"),
+    ("KE-10", "Kajiado"),
+    ("KE-11", "Kakamega"),
+    ("KE-12", "Kericho"),
+    ("KE-13", "Kiambu"),
+    ("KE-14", "Kilifi"),
+    ("KE-15", "Kirinyaga"),
+    ("KE-16", "Kisii"),
+    ("KE-17", "Kisumu"),
+    ("KE-18", "Kitui"),
+    ("KE-19", "Kwale"),
+    ("KE-20", "Laikipia"),
+    ("KE-21", "Lamu"),
+    ("KE-22", "Machakos"),
+    ("KE-23", "Makueni"),
+    ("KE-24", "Mandera"),
+    ("KE-25", "Marsabit"),
+    ("KE-26", "Meru"),
+    ("KE-27", "Migori"),
+    ("KE-28", "Mombasa"),
+    ("KE-29", "Murang'a"),
+    ("KE-30", "Nairobi City"),
+    ("KE-31", "Nakuru"),
+    ("KE-32", "Nandi"),
+    ("KE-33", "Narok"),
+    ("KE-34", "Nyamira"),
+    ("KE-35", "Nyandarua"),
+    ("KE-36", "Nyeri"),
+    ("KE-37", "Samburu"),
+    ("KE-38", "Siaya"),
+    ("KE-39", "Taita/Taveta"),
+    ("KE-40", "Tana River"),
+    ("KE-41", "Tharaka-Nithi"),
+    ("KE-42", "Trans Nzoia"),
+    ("KE-43", "Turkana"),
+    ("KE-44", "Uasin Gishu"),
+    ("KE-45", "Vihiga"),
+    ("KE-46", "Wajir"),
+    ("KE-47", "West Pokot"),
+    ("KG-B", "Batken"),
+    ("KG-C", "Chuyskaya oblast'"),
+    ("KG-GB", "Bishkek Shaary"),
+    ("KG-GO", "Gorod Osh"),
+    ("KG-J", "Dzhalal-Abadskaya oblast'"),
+    ("KG-N", "Naryn"),
+    ("KG-O", "Osh"),
+    ("KG-T", "Talas"),
+    ("KG-Y", "Issyk-Kul'skaja oblast'"),
+    ("KH-1", "Banteay Mean Choăy"),
+    ("KH-10", "Kracheh"),
+    ("KH-11", "Mondol Kiri"),
+    ("KH-12", "Phnom Penh"),
+    ("KH-13", "Preah Vihear"),
+    ("KH-14", "Prey Veaeng"),
+    ("KH-15", "Pousaat"),
+    ("KH-16", "Rotanak Kiri"),
+    ("KH-17", "Siem Reab"),
+    ("KH-18", "Preah Sihanouk"),
+    ("KH-19", "Stoĕng Trêng"),
+    ("KH-2", "Baat Dambang"),
+    ("KH-20", "Svaay Rieng"),
+    ("KH-21", "Taakaev"),
+    ("KH-22", "Otdar Mean Chey"),
+    ("KH-23", "Kaeb"),
+    ("KH-24", "Pailin"),
+    ("KH-25", "Tbong Khmum"),
+    ("KH-3", "Kampong Chaam"),
+    ("KH-4", "Kampong Chhnang"),
+    ("KH-5", "Kampong Spueu"),
+    ("KH-6", "Kampong Thum"),
+    ("KH-7", "Kampot"),
+    ("KH-8", "Kandaal"),
+    ("KH-9", "Kaoh Kong"),
+    ("KI-G", "Gilbert Islands"),
+    ("KI-L", "Line Islands"),
+    ("KI-P", "Phoenix Islands"),
+    ("KM-A", "Andjouân"),
+    ("KM-G", "Andjazîdja"),
+    ("KM-M", "Mohéli"),
+    ("KN-01", "Christ Church Nichola Town"),
+    ("KN-02", "Saint Anne Sandy Point"),
+    ("KN-03", "Saint George Basseterre"),
+    ("KN-04", "Saint George Gingerland"),
+    ("KN-05", "Saint James Windward"),
+    ("KN-06", "Saint John Capisterre"),
+    ("KN-07", "Saint John Figtree"),
+    ("KN-08", "Saint Mary Cayon"),
+    ("KN-09", "Saint Paul Capisterre"),
+    ("KN-10", "Saint Paul Charlestown"),
+    ("KN-11", "Saint Peter Basseterre"),
+    ("KN-12", "Saint Thomas Lowland"),
+    ("KN-13", "Saint Thomas Middle Island"),
+    ("KN-15", "Trinity Palmetto Point"),
+    ("KN-K", "Saint Kitts"),
+    ("KN-N", "Nevis"),
+    ("KP-01", "P'yǒngyang"),
+    ("KP-02", "P'yǒngan-namdo"),
+    ("KP-03", "P'yǒngan-bukto"),
+    ("KP-04", "Chagang-do"),
+    ("KP-05", "Hwanghae-namdo"),
+    ("KP-06", "Hwanghae-bukto"),
+    ("KP-07", "Kangweonto"),
+    ("KP-08", "Hamgyǒng-namdo"),
+    ("KP-09", "Hamgyǒng-bukto"),
+    ("KP-10", "Ryanggang-do"),
+    ("KP-13", "Raseon"),
+    ("KP-14", "Nampho"),
+    ("KR-11", "Seoul-teukbyeolsi"),
+    ("KR-26", "Busan-gwangyeoksi"),
+    ("KR-27", "Daegu-gwangyeoksi"),
+    ("KR-28", "Incheon-gwangyeoksi"),
+    ("KR-29", "Gwangju-gwangyeoksi"),
+    ("KR-30", "Daejeon-gwangyeoksi"),
+    ("KR-31", "Ulsan-gwangyeoksi"),
+    ("KR-41", "Gyeonggi-do"),
+    ("KR-42", "Gangwon-do"),
+    ("KR-43", "Chungcheongbuk-do"),
+    ("KR-44", "Chungcheongnam-do"),
+    ("KR-45", "Jeollabuk-do"),
+    ("KR-46", "Jeollanam-do"),
+    ("KR-47", "Gyeongsangbuk-do"),
+    ("KR-48", "Gyeongsangnam-do"),
+    ("KR-49", "Jeju-teukbyeoljachido"),
+    ("KR-50", "Sejong"),
+    ("KW-AH", "Al Aḩmadī"),
+    ("KW-FA", "Al Farwānīyah"),
+    ("KW-HA", "Ḩawallī"),
+    ("KW-JA", "Al Jahrā’"),
+    ("KW-KU", "Al ‘Āşimah"),
+    ("KW-MU", "Mubārak al Kabīr"),
+    ("KZ-AKM", "Akmolinskaja oblast'"),
+    ("KZ-AKT", "Aktjubinskaja oblast'"),
+    ("KZ-ALA", "Almaty"),
+    ("KZ-ALM", "Almatinskaja oblast'"),
+    ("KZ-AST", "Nur-Sultan"),
+    ("KZ-ATY", "Atyrauskaja oblast'"),
+    ("KZ-KAR", "Karagandinskaja oblast'"),
+    ("KZ-KUS", "Kostanajskaja oblast'"),
+    ("KZ-KZY", "Kyzylordinskaja oblast'"),
+    ("KZ-MAN", "Mangghystaū oblysy"),
+    ("KZ-PAV", "Pavlodar oblysy"),
+    ("KZ-SEV", "Severo-Kazahstanskaja oblast'"),
+    ("KZ-SHY", "Shymkent"),
+    ("KZ-VOS", "Shyghys Qazaqstan oblysy"),
+    ("KZ-YUZ", "Turkestankaya oblast'"),
+    ("KZ-ZAP", "Batys Qazaqstan oblysy"),
+    ("KZ-ZHA", "Zhambyl oblysy"),
+    ("LA-AT", "Attapu"),
+    ("LA-BK", "Bokèo"),
+    ("LA-BL", "Bolikhamxai"),
+    ("LA-CH", "Champasak"),
+    ("LA-HO", "Houaphan"),
+    ("LA-KH", "Khammouan"),
+    ("LA-LM", "Louang Namtha"),
+    ("LA-LP", "Louangphabang"),
+    ("LA-OU", "Oudômxai"),
+    ("LA-PH", "Phôngsali"),
+    ("LA-SL", "Salavan"),
+    ("LA-SV", "Savannakhét"),
+    ("LA-VI", "Viangchan"),
+    ("LA-VT", "Viangchan"),
+    ("LA-XA", "Xaignabouli"),
+    ("LA-XE", "Xékong"),
+    ("LA-XI", "Xiangkhouang"),
+    ("LA-XS", "Xaisômboun"),
+    ("LB-AK", "Aakkâr"),
+    ("LB-AS", "Ash Shimāl"),
+    ("LB-BA", "Bayrūt"),
+    ("LB-BH", "Baalbek-Hermel"),
+    ("LB-BI", "Al Biqā‘"),
+    ("LB-JA", "Al Janūb"),
+    ("LB-JL", "Jabal Lubnān"),
+    ("LB-NA", "An Nabaţīyah"),
+    ("LC-01", "Anse la Raye"),
+    ("LC-02", "Castries"),
+    ("LC-03", "Choiseul"),
+    ("LC-05", "Dennery"),
+    ("LC-06", "Gros Islet"),
+    ("LC-07", "Laborie"),
+    ("LC-08", "Micoud"),
+    ("LC-10", "Soufrière"),
+    ("LC-11", "Vieux Fort"),
+    ("LC-12", "Canaries"),
+    ("LI-01", "Balzers"),
+    ("LI-02", "Eschen"),
+    ("LI-03", "Gamprin"),
+    ("LI-04", "Mauren"),
+    ("LI-05", "Planken"),
+    ("LI-06", "Ruggell"),
+    ("LI-07", "Schaan"),
+    ("LI-08", "Schellenberg"),
+    ("LI-09", "Triesen"),
+    ("LI-10", "Triesenberg"),
+    ("LI-11", "Vaduz"),
+    ("LK-1", "Western Province"),
+    ("LK-11", "Colombo"),
+    ("LK-12", "Gampaha"),
+    ("LK-13", "Kalutara"),
+    ("LK-2", "Central Province"),
+    ("LK-21", "Kandy"),
+    ("LK-22", "Matale"),
+    ("LK-23", "Nuwara Eliya"),
+    ("LK-3", "Southern Province"),
+    ("LK-31", "Galle"),
+    ("LK-32", "Matara"),
+    ("LK-33", "Hambantota"),
+    ("LK-4", "Northern Province"),
+    ("LK-41", "Jaffna"),
+    ("LK-42", "Kilinochchi"),
+    ("LK-43", "Mannar"),
+    ("LK-44", "Vavuniya"),
+    ("LK-45", "Mullaittivu"),
+    ("LK-5", "Eastern Province"),
+    ("LK-51", "Batticaloa"),
+    ("LK-52", "Ampara"),
+    ("LK-53", "Trincomalee"),
+    ("LK-6", "North Western Province"),
+    ("LK-61", "Kurunegala"),
+    ("LK-62", "Puttalam"),
+    ("LK-7", "North Central Province"),
+    ("LK-71", "Anuradhapura"),
+    ("LK-72", "Polonnaruwa"),
+    ("LK-8", "Uva Province"),
+    ("LK-81", "Badulla"),
+    ("LK-82", "Monaragala"),
+    ("LK-9", "Sabaragamuwa Province"),
+    ("LK-91", "Ratnapura"),
+    ("LK-92", "Kegalla"),
+    ("LR-BG", "Bong"),
+    ("LR-BM", "Bomi"),
+    ("LR-CM", "Grand Cape Mount"),
+    ("LR-GB", "Grand Bassa"),
+    ("LR-GG", "Grand Gedeh"),
+    ("LR-GK", "Grand Kru"),
+    ("LR-GP", "Gbarpolu"),
+    ("LR-LO", "Lofa"),
+    ("LR-MG", "Margibi"),
+    ("LR-MO", "Montserrado"),
+    ("LR-MY", "Maryland"),
+    ("LR-NI", "Nimba"),
+    ("LR-RG", "River Gee"),
+    ("LR-RI", "River Cess"),
+    ("LR-SI", "Sinoe"),
+    ("LS-A", "Maseru"),
+    ("LS-B", "Botha-Bothe"),
+    ("LS-C", "Leribe"),
+    ("LS-D", "Berea"),
+    ("LS-E", "Mafeteng"),
+    ("LS-F", "Mohale's Hoek"),
+    ("LS-G", "Quthing"),
+    ("LS-H", "Qacha's Nek"),
+    ("LS-J", "Mokhotlong"),
+    ("LS-K", "Thaba-Tseka"),
+    ("LT-01", "Akmenė"),
+    ("LT-02", "Alytaus miestas"),
+    ("LT-03", "Alytus"),
+    ("LT-04", "Anykščiai"),
+    ("LT-05", "Birštono"),
+    ("LT-06", "Biržai"),
+    ("LT-07", "Druskininkai"),
+    ("LT-08", "Elektrėnai"),
+    ("LT-09", "Ignalina"),
+    ("LT-10", "Jonava"),
+    ("LT-11", "Joniškis"),
+    ("LT-12", "Jurbarkas"),
+    ("LT-13", "Kaišiadorys"),
+    ("LT-14", "Kalvarijos"),
+    ("LT-15", "Kauno miestas"),
+    ("LT-16", "Kaunas"),
+    ("LT-17", "Kazlų Rūdos"),
+    ("LT-18", "Kėdainiai"),
+    ("LT-19", "Kelmė"),
+    ("LT-20", "Klaipėdos miestas"),
+    ("LT-21", "Klaipėda"),
+    ("LT-22", "Kretinga"),
+    ("LT-23", "Kupiškis"),
+    ("LT-24", "Lazdijai"),
+    ("LT-25", "Marijampolė"),
+    ("LT-26", "Mažeikiai"),
+    ("LT-27", "Molėtai"),
+    ("LT-28", "Neringa"),
+    ("LT-29", "Pagėgiai"),
+    ("LT-30", "Pakruojis"),
+    ("LT-31", "Palangos miestas"),
+    ("LT-32", "Panevėžio miestas"),
+    ("LT-33", "Panevėžys"),
+    ("LT-34", "Pasvalys"),
+    ("LT-35", "Plungė"),
+    ("LT-36", "Prienai"),
+    ("LT-37", "Radviliškis"),
+    ("LT-38", "Raseiniai"),
+    ("LT-39", "Rietavo"),
+    ("LT-40", "Rokiškis"),
+    ("LT-41", "Šakiai"),
+    ("LT-42", "Šalčininkai"),
+    ("LT-43", "Šiaulių miestas"),
+    ("LT-44", "Šiauliai"),
+    ("LT-45", "Šilalė"),
+    ("LT-46", "Šilutė"),
+    ("LT-47", "Širvintos"),
+    ("LT-48", "Skuodas"),
+    ("LT-49", "Švenčionys"),
+    ("LT-50", "Tauragė"),
+    ("LT-51", "Telšiai"),
+    ("LT-52", "Trakai"),
+    ("LT-53", "Ukmergė"),
+    ("LT-54", "Utena"),
+    ("LT-55", "Varėna"),
+    ("LT-56", "Vilkaviškis"),
+    ("LT-57", "Vilniaus miestas"),
+    ("LT-58", "Vilnius"),
+    ("LT-59", "Visaginas"),
+    ("LT-60", "Zarasai"),
+    ("LT-AL", "Alytaus apskritis"),
+    ("LT-KL", "Klaipėdos apskritis"),
+    ("LT-KU", "Kauno apskritis"),
+    ("LT-MR", "Marijampolės apskritis"),
+    ("LT-PN", "Panevėžio apskritis"),
+    ("LT-SA", "Šiaulių apskritis"),
+    ("LT-TA", "Tauragės apskritis"),
+    ("LT-TE", "Telšių apskritis"),
+    ("LT-UT", "Utenos apskritis"),
+    ("LT-VL", "Vilniaus apskritis"),
+    ("LU-CA", "Capellen"),
+    ("LU-CL", "Clerf"),
+    ("LU-DI", "Diekirch"),
+    ("LU-EC", "Echternach"),
+    ("LU-ES", "Esch an der Alzette"),
+    ("LU-GR", "Grevenmacher"),
+    ("LU-LU", "Luxembourg"),
+    ("LU-ME", "Mersch"),
+    ("LU-RD", "Redange"),
+    ("LU-RM", "Remich"),
+    ("LU-VD", "Veianen"),
+    ("LU-WI", "Wiltz"),
+    ("LV-001", "Aglonas novads"),
+    ("LV-002", "Aizkraukles novads"),
+    ("LV-003", "Aizputes novads"),
+    ("LV-004", "Aknīstes novads"),
+    ("LV-005", "Alojas novads"),
+    ("LV-006", "Alsungas novads"),
+    ("LV-007", "Alūksnes novads"),
+    ("LV-008", "Amatas novads"),
+    ("LV-009", "Apes novads"),
+    ("LV-010", "Auces novads"),
+    ("LV-011", "Ādažu novads"),
+    ("LV-012", "Babītes novads"),
+    ("LV-013", "Baldones novads"),
+    ("LV-014", "Baltinavas novads"),
+    ("LV-015", "Balvu novads"),
+    ("LV-016", "Bauskas novads"),
+    ("LV-017", "Beverīnas novads"),
+    ("LV-018", "Brocēnu novads"),
+    ("LV-019", "Burtnieku novads"),
+    ("LV-020", "Carnikavas novads"),
+    ("LV-021", "Cesvaines novads"),
+    ("LV-022", "Cēsu novads"),
+    ("LV-023", "Ciblas novads"),
+    ("LV-024", "Dagdas novads"),
+    ("LV-025", "Daugavpils novads"),
+    ("LV-026", "Dobeles novads"),
+    ("LV-027", "Dundagas novads"),
+    ("LV-028", "Durbes novads"),
+    ("LV-029", "Engures novads"),
+    ("LV-030", "Ērgļu novads"),
+    ("LV-031", "Garkalnes novads"),
+    ("LV-032", "Grobiņas novads"),
+    ("LV-033", "Gulbenes novads"),
+    ("LV-034", "Iecavas novads"),
+    ("LV-035", "Ikšķiles novads"),
+    ("LV-036", "Ilūkstes novads"),
+    ("LV-037", "Inčukalna novads"),
+    ("LV-038", "Jaunjelgavas novads"),
+    ("LV-039", "Jaunpiebalgas novads"),
+    ("LV-040", "Jaunpils novads"),
+    ("LV-041", "Jelgavas novads"),
+    ("LV-042", "Jēkabpils novads"),
+    ("LV-043", "Kandavas novads"),
+    ("LV-044", "Kārsavas novads"),
+    ("LV-045", "Kocēnu novads"),
+    ("LV-046", "Kokneses novads"),
+    ("LV-047", "Krāslavas novads"),
+    ("LV-048", "Krimuldas novads"),
+    ("LV-049", "Krustpils novads"),
+    ("LV-050", "Kuldīgas novads"),
+    ("LV-051", "Ķeguma novads"),
+    ("LV-052", "Ķekavas novads"),
+    ("LV-053", "Lielvārdes novads"),
+    ("LV-054", "Limbažu novads"),
+    ("LV-055", "Līgatnes novads"),
+    ("LV-056", "Līvānu novads"),
+    ("LV-057", "Lubānas novads"),
+    ("LV-058", "Ludzas novads"),
+    ("LV-059", "Madonas novads"),
+    ("LV-060", "Mazsalacas novads"),
+    ("LV-061", "Mālpils novads"),
+    ("LV-062", "Mārupes novads"),
+    ("LV-063", "Mērsraga novads"),
+    ("LV-064", "Naukšēnu novads"),
+    ("LV-065", "Neretas novads"),
+    ("LV-066", "Nīcas novads"),
+    ("LV-067", "Ogres novads"),
+    ("LV-068", "Olaines novads"),
+    ("LV-069", "Ozolnieku novads"),
+    ("LV-070", "Pārgaujas novads"),
+    ("LV-071", "Pāvilostas novads"),
+    ("LV-072", "Pļaviņu novads"),
+    ("LV-073", "Preiļu novads"),
+    ("LV-074", "Priekules novads"),
+    ("LV-075", "Priekuļu novads"),
+    ("LV-076", "Raunas novads"),
+    ("LV-077", "Rēzeknes novads"),
+    ("LV-078", "Riebiņu novads"),
+    ("LV-079", "Rojas novads"),
+    ("LV-080", "Ropažu novads"),
+    ("LV-081", "Rucavas novads"),
+    ("LV-082", "Rugāju novads"),
+    ("LV-083", "Rundāles novads"),
+    ("LV-084", "Rūjienas novads"),
+    ("LV-085", "Salas novads"),
+    ("LV-086", "Salacgrīvas novads"),
+    ("LV-087", "Salaspils novads"),
+    ("LV-088", "Saldus novads"),
+    ("LV-089", "Saulkrastu novads"),
+    ("LV-090", "Sējas novads"),
+    ("LV-091", "Siguldas novads"),
+    ("LV-092", "Skrīveru novads"),
+    ("LV-093", "Skrundas novads"),
+    ("LV-094", "Smiltenes novads"),
+    ("LV-095", "Stopiņu novads"),
+    ("LV-096", "Strenču novads"),
+    ("LV-097", "Talsu novads"),
+    ("LV-098", "Tērvetes novads"),
+    ("LV-099", "Tukuma novads"),
+    ("LV-100", "Vaiņodes novads"),
+    ("LV-101", "Valkas novads"),
+    ("LV-102", "Varakļānu novads"),
+    ("LV-103", "Vārkavas novads"),
+    ("LV-104", "Vecpiebalgas novads"),
+    ("LV-105", "Vecumnieku novads"),
+    ("LV-106", "Ventspils novads"),
+    ("LV-107", "Viesītes novads"),
+    ("LV-108", "Viļakas novads"),
+    ("LV-109", "Viļānu novads"),
+    ("LV-110", "Zilupes novads"),
+    ("LV-DGV", "Daugavpils"),
+    ("LV-JEL", "Jelgava"),
+    ("LV-JKB", "Jēkabpils"),
+    ("LV-JUR", "Jūrmala"),
+    ("LV-LPX", "Liepāja"),
+    ("LV-REZ", "Rēzekne"),
+    ("LV-RIX", "Rīga"),
+    ("LV-VEN", "Ventspils"),
+    ("LV-VMR", "Valmiera"),
+    ("LY-BA", "Banghāzī"),
+    ("LY-BU", "Al Buţnān"),
+    ("LY-DR", "Darnah"),
+    ("LY-GT", "Ghāt"),
+    ("LY-JA", "Al Jabal al Akhḑar"),
+    ("LY-JG", "Al Jabal al Gharbī"),
+    ("LY-JI", "Al Jafārah"),
+    ("LY-JU", "Al Jufrah"),
+    ("LY-KF", "Al Kufrah"),
+    ("LY-MB", "Al Marqab"),
+    ("LY-MI", "Mişrātah"),
+    ("LY-MJ", "Al Marj"),
+    ("LY-MQ", "Murzuq"),
+    ("LY-NL", "Nālūt"),
+    ("LY-NQ", "An Nuqāţ al Khams"),
+    ("LY-SB", "Sabhā"),
+    ("LY-SR", "Surt"),
+    ("LY-TB", "Ţarābulus"),
+    ("LY-WA", "Al Wāḩāt"),
+    ("LY-WD", "Wādī al Ḩayāt"),
+    ("LY-WS", "Wādī ash Shāţi’"),
+    ("LY-ZA", "Az Zāwiyah"),
+    ("MA-01", "Tanger-Tétouan-Al Hoceïma"),
+    ("MA-02", "L'Oriental"),
+    ("MA-03", "Fès-Meknès"),
+    ("MA-04", "Rabat-Salé-Kénitra"),
+    ("MA-05", "Béni Mellal-Khénifra"),
+    ("MA-06", "Casablanca-Settat"),
+    ("MA-07", "Marrakech-Safi"),
+    ("MA-08", "Drâa-Tafilalet"),
+    ("MA-09", "Souss-Massa"),
+    ("MA-10", "Guelmim-Oued Noun (EH-partial)"),
+    ("MA-11", "Laâyoune-Sakia El Hamra (EH-partial)"),
+    ("MA-12", "Dakhla-Oued Ed-Dahab (EH)"),
+    ("MA-AGD", "Agadir-Ida-Ou-Tanane"),
+    ("MA-AOU", "Aousserd (EH)"),
+    ("MA-ASZ", "Assa-Zag (EH-partial)"),
+    ("MA-AZI", "Azilal"),
+    ("MA-BEM", "Béni Mellal"),
+    ("MA-BER", "Berkane"),
+    ("MA-BES", "Benslimane"),
+    ("MA-BOD", "Boujdour (EH)"),
+    ("MA-BOM", "Boulemane"),
+    ("MA-BRR", "Berrechid"),
+    ("MA-CAS", "Casablanca"),
+    ("MA-CHE", "Chefchaouen"),
+    ("MA-CHI", "Chichaoua"),
+    ("MA-CHT", "Chtouka-Ait Baha"),
+    ("MA-DRI", "Driouch"),
+    ("MA-ERR", "Errachidia"),
+    ("MA-ESI", "Essaouira"),
+    ("MA-ESM", "Es-Semara (EH-partial)"),
+    ("MA-FAH", "Fahs-Anjra"),
+    ("MA-FES", "Fès"),
+    ("MA-FIG", "Figuig"),
+    ("MA-FQH", "Fquih Ben Salah"),
+    ("MA-GUE", "Guelmim"),
+    ("MA-GUF", "Guercif"),
+    ("MA-HAJ", "El Hajeb"),
+    ("MA-HAO", "Al Haouz"),
+    ("MA-HOC", "Al Hoceïma"),
+    ("MA-IFR", "Ifrane"),
+    ("MA-INE", "Inezgane-Ait Melloul"),
+    ("MA-JDI", "El Jadida"),
+    ("MA-JRA", "Jerada"),
+    ("MA-KEN", "Kénitra"),
+    ("MA-KES", "El Kelâa des Sraghna"),
+    ("MA-KHE", "Khémisset"),
+    ("MA-KHN", "Khénifra"),
+    ("MA-KHO", "Khouribga"),
+    ("MA-LAA", "Laâyoune (EH)"),
+    ("MA-LAR", "Larache"),
+    ("MA-MAR", "Marrakech"),
+    ("MA-MDF", "M’diq-Fnideq"),
+    ("MA-MED", "Médiouna"),
+    ("MA-MEK", "Meknès"),
+    ("MA-MID", "Midelt"),
+    ("MA-MOH", "Mohammadia"),
+    ("MA-MOU", "Moulay Yacoub"),
+    ("MA-NAD", "Nador"),
+    ("MA-NOU", "Nouaceur"),
+    ("MA-OUA", "Ouarzazate"),
+    ("MA-OUD", "Oued Ed-Dahab (EH)"),
+    ("MA-OUJ", "Oujda-Angad"),
+    ("MA-OUZ", "Ouezzane"),
+    ("MA-RAB", "Rabat"),
+    ("MA-REH", "Rehamna"),
+    ("MA-SAF", "Safi"),
+    ("MA-SAL", "Salé"),
+    ("MA-SEF", "Sefrou"),
+    ("MA-SET", "Settat"),
+    ("MA-SIB", "Sidi Bennour"),
+    ("MA-SIF", "Sidi Ifni"),
+    ("MA-SIK", "Sidi Kacem"),
+    ("MA-SIL", "Sidi Slimane"),
+    ("MA-SKH", "Skhirate-Témara"),
+    ("MA-TAF", "Tarfaya (EH-partial)"),
+    ("MA-TAI", "Taourirt"),
+    ("MA-TAO", "Taounate"),
+    ("MA-TAR", "Taroudannt"),
+    ("MA-TAT", "Tata"),
+    ("MA-TAZ", "Taza"),
+    ("MA-TET", "Tétouan"),
+    ("MA-TIN", "Tinghir"),
+    ("MA-TIZ", "Tiznit"),
+    ("MA-TNG", "Tanger-Assilah"),
+    ("MA-TNT", "Tan-Tan (EH-partial)"),
+    ("MA-YUS", "Youssoufia"),
+    ("MA-ZAG", "Zagora"),
+    ("MC-CL", "La Colle"),
+    ("MC-CO", "La Condamine"),
+    ("MC-FO", "Fontvieille"),
+    ("MC-GA", "La Gare"),
+    ("MC-JE", "Jardin Exotique"),
+    ("MC-LA", "Larvotto"),
+    ("MC-MA", "Malbousquet"),
+    ("MC-MC", "Monte-Carlo"),
+    ("MC-MG", "Moneghetti"),
+    ("MC-MO", "Monaco-Ville"),
+    ("MC-MU", "Moulins"),
+    ("MC-PH", "Port-Hercule"),
+    ("MC-SD", "Sainte-Dévote"),
+    ("MC-SO", "La Source"),
+    ("MC-SP", "Spélugues"),
+    ("MC-SR", "Saint-Roman"),
+    ("MC-VR", "Vallon de la Rousse"),
+    ("MD-AN", "Anenii Noi"),
+    ("MD-BA", "Bălți"),
+    ("MD-BD", "Bender [Tighina]"),
+    ("MD-BR", "Briceni"),
+    ("MD-BS", "Basarabeasca"),
+    ("MD-CA", "Cahul"),
+    ("MD-CL", "Călărași"),
+    ("MD-CM", "Cimișlia"),
+    ("MD-CR", "Criuleni"),
+    ("MD-CS", "Căușeni"),
+    ("MD-CT", "Cantemir"),
+    ("MD-CU", "Chișinău"),
+    ("MD-DO", "Dondușeni"),
+    ("MD-DR", "Drochia"),
+    ("MD-DU", "Dubăsari"),
+    ("MD-ED", "Edineț"),
+    ("MD-FA", "Fălești"),
+    ("MD-FL", "Florești"),
+    ("MD-GA", "Găgăuzia, Unitatea teritorială autonomă (UTAG)"),
+    ("MD-GL", "Glodeni"),
+    ("MD-HI", "Hîncești"),
+    ("MD-IA", "Ialoveni"),
+    ("MD-LE", "Leova"),
+    ("MD-NI", "Nisporeni"),
+    ("MD-OC", "Ocnița"),
+    ("MD-OR", "Orhei"),
+    ("MD-RE", "Rezina"),
+    ("MD-RI", "Rîșcani"),
+    ("MD-SD", "Șoldănești"),
+    ("MD-SI", "Sîngerei"),
+    ("MD-SN", "Stînga Nistrului, unitatea teritorială din"),
+    ("MD-SO", "Soroca"),
+    ("MD-ST", "Strășeni"),
+    ("MD-SV", "Ștefan Vodă"),
+    ("MD-TA", "Taraclia"),
+    ("MD-TE", "Telenești"),
+    ("MD-UN", "Ungheni"),
+    ("ME-01", "Andrijevica"),
+    ("ME-02", "Bar"),
+    ("ME-03", "Berane"),
+    ("ME-04", "Bijelo Polje"),
+    ("ME-05", "Budva"),
+    ("ME-06", "Cetinje"),
+    ("ME-07", "Danilovgrad"),
+    ("ME-08", "Herceg-Novi"),
+    ("ME-09", "Kolašin"),
+    ("ME-10", "Kotor"),
+    ("ME-11", "Mojkovac"),
+    ("ME-12", "Nikšić"),
+    ("ME-13", "Plav"),
+    ("ME-14", "Pljevlja"),
+    ("ME-15", "Plužine"),
+    ("ME-16", "Podgorica"),
+    ("ME-17", "Rožaje"),
+    ("ME-18", "Šavnik"),
+    ("ME-19", "Tivat"),
+    ("ME-20", "Ulcinj"),
+    ("ME-21", "Žabljak"),
+    ("ME-22", "Gusinje"),
+    ("ME-23", "Petnjica"),
+    ("ME-24", "Tuzi"),
+    ("MG-A", "Toamasina"),
+    ("MG-D", "Antsiranana"),
+    ("MG-F", "Fianarantsoa"),
+    ("MG-M", "Mahajanga"),
+    ("MG-T", "Antananarivo"),
+    ("MG-U", "Toliara"),
+    ("MH-ALK", "Ailuk"),
+    ("MH-ALL", "Ailinglaplap"),
+    ("MH-ARN", "Arno"),
+    ("MH-AUR", "Aur"),
+    ("MH-EBO", "Ebon"),
+    ("MH-ENI", "Enewetak & Ujelang"),
+    ("MH-JAB", "Jabat"),
+    ("MH-JAL", "Jaluit"),
+    ("MH-KIL", "Bikini & Kili"),
+    ("MH-KWA", "Kwajalein"),
+    ("MH-L", "Ralik chain"),
+    ("MH-LAE", "Lae"),
+    ("MH-LIB", "Lib"),
+    ("MH-LIK", "Likiep"),
+    ("MH-MAJ", "Majuro"),
+    ("MH-MAL", "Maloelap"),
+    ("MH-MEJ", "Mejit"),
+    ("MH-MIL", "Mili"),
+    ("MH-NMK", "Namdrik"),
+    ("MH-NMU", "Namu"),
+    ("MH-RON", "Rongelap"),
+    ("MH-T", "Ratak chain"),
+    ("MH-UJA", "Ujae"),
+    ("MH-UTI", "Utrik"),
+    ("MH-WTH", "Wotho"),
+    ("MH-WTJ", "Wotje"),
+    ("MK-101", "Veles"),
+    ("MK-102", "Gradsko"),
+    ("MK-103", "Demir Kapija"),
+    ("MK-104", "Kavadarci"),
+    ("MK-105", "Lozovo"),
+    ("MK-106", "Negotino"),
+    ("MK-107", "Rosoman"),
+    ("MK-108", "Sveti Nikole"),
+    ("MK-109", "Čaška"),
+    ("MK-201", "Berovo"),
+    ("MK-202", "Vinica"),
+    ("MK-203", "Delčevo"),
+    ("MK-204", "Zrnovci"),
+    ("MK-205", "Karbinci"),
+    ("MK-206", "Kočani"),
+    ("MK-207", "Makedonska Kamenica"),
+    ("MK-208", "Pehčevo"),
+    ("MK-209", "Probištip"),
+    ("MK-210", "Češinovo-Obleševo"),
+    ("MK-211", "Štip"),
+    ("MK-301", "Vevčani"),
+    ("MK-303", "Debar"),
+    ("MK-304", "Debrca"),
+    ("MK-307", "Kičevo"),
+    ("MK-308", "Makedonski Brod"),
+    ("MK-310", "Ohrid"),
+    ("MK-311", "Plasnica"),
+    ("MK-312", "Struga"),
+    ("MK-313", "Centar Župa"),
+    ("MK-401", "Bogdanci"),
+    ("MK-402", "Bosilovo"),
+    ("MK-403", "Valandovo"),
+    ("MK-404", "Vasilevo"),
+    ("MK-405", "Gevgelija"),
+    ("MK-406", "Dojran"),
+    ("MK-407", "Konče"),
+    ("MK-408", "Novo Selo"),
+    ("MK-409", "Radoviš"),
+    ("MK-410", "Strumica"),
+    ("MK-501", "Bitola"),
+    ("MK-502", "Demir Hisar"),
+    ("MK-503", "Dolneni"),
+    ("MK-504", "Krivogaštani"),
+    ("MK-505", "Kruševo"),
+    ("MK-506", "Mogila"),
+    ("MK-507", "Novaci"),
+    ("MK-508", "Prilep"),
+    ("MK-509", "Resen"),
+    ("MK-601", "Bogovinje"),
+    ("MK-602", "Brvenica"),
+    ("MK-603", "Vrapčište"),
+    ("MK-604", "Gostivar"),
+    ("MK-605", "Želino"),
+    ("MK-606", "Jegunovce"),
+    ("MK-607", "Mavrovo i Rostuše"),
+    ("MK-608", "Tearce"),
+    ("MK-609", "Tetovo"),
+    ("MK-701", "Kratovo"),
+    ("MK-702", "Kriva Palanka"),
+    ("MK-703", "Kumanovo"),
+    ("MK-704", "Lipkovo"),
+    ("MK-705", "Rankovce"),
+    ("MK-706", "Staro Nagoričane"),
+    ("MK-801", "Aerodrom †"),
+    ("MK-802", "Aračinovo"),
+    ("MK-803", "Butel †"),
+    ("MK-804", "Gazi Baba †"),
+    ("MK-805", "Gjorče Petrov †"),
+    ("MK-806", "Zelenikovo"),
+    ("MK-807", "Ilinden"),
+    ("MK-808", "Karpoš †"),
+    ("MK-809", "Kisela Voda †"),
+    ("MK-810", "Petrovec"),
+    ("MK-811", "Saraj †"),
+    ("MK-812", "Sopište"),
+    ("MK-813", "Studeničani"),
+    ("MK-814", "Centar †"),
+    ("MK-815", "Čair †"),
+    ("MK-816", "Čučer-Sandevo"),
+    ("MK-817", "Šuto Orizari †"),
+    ("ML-1", "Kayes"),
+    ("ML-10", "Taoudénit"),
+    ("ML-2", "Koulikoro"),
+    ("ML-3", "Sikasso"),
+    ("ML-4", "Ségou"),
+    ("ML-5", "Mopti"),
+    ("ML-6", "Tombouctou"),
+    ("ML-7", "Gao"),
+    ("ML-8", "Kidal"),
+    ("ML-9", "Ménaka"),
+    ("ML-BKO", "Bamako"),
+    ("MM-01", "Sagaing"),
+    ("MM-02", "Bago"),
+    ("MM-03", "Magway"),
+    ("MM-04", "Mandalay"),
+    ("MM-05", "Tanintharyi"),
+    ("MM-06", "Yangon"),
+    ("MM-07", "Ayeyarwady"),
+    ("MM-11", "Kachin"),
+    ("MM-12", "Kayah"),
+    ("MM-13", "Kayin"),
+    ("MM-14", "Chin"),
+    ("MM-15", "Mon"),
+    ("MM-16", "Rakhine"),
+    ("MM-17", "Shan"),
+    ("MM-18", "Nay Pyi Taw"),
+    ("MN-035", "Orhon"),
+    ("MN-037", "Darhan uul"),
+    ("MN-039", "Hentiy"),
+    ("MN-041", "Hövsgöl"),
+    ("MN-043", "Hovd"),
+    ("MN-046", "Uvs"),
+    ("MN-047", "Töv"),
+    ("MN-049", "Selenge"),
+    ("MN-051", "Sühbaatar"),
+    ("MN-053", "Ömnögovĭ"),
+    ("MN-055", "Övörhangay"),
+    ("MN-057", "Dzavhan"),
+    ("MN-059", "Dundgovĭ"),
+    ("MN-061", "Dornod"),
+    ("MN-063", "Dornogovĭ"),
+    ("MN-064", "Govĭ-Sümber"),
+    ("MN-065", "Govĭ-Altay"),
+    ("MN-067", "Bulgan"),
+    ("MN-069", "Bayanhongor"),
+    ("MN-071", "Bayan-Ölgiy"),
+    ("MN-073", "Arhangay"),
+    ("MN-1", "Ulaanbaatar"),
+    ("MR-01", "Hodh ech Chargui"),
+    ("MR-02", "Hodh el Gharbi"),
+    ("MR-03", "Assaba"),
+    ("MR-04", "Gorgol"),
+    ("MR-05", "Brakna"),
+    ("MR-06", "Trarza"),
+    ("MR-07", "Adrar"),
+    ("MR-08", "Dakhlet Nouâdhibou"),
+    ("MR-09", "Tagant"),
+    ("MR-10", "Guidimaka"),
+    ("MR-11", "Tiris Zemmour"),
+    ("MR-12", "Inchiri"),
+    ("MR-13", "Nouakchott Ouest"),
+    ("MR-14", "Nouakchott Nord"),
+    ("MR-15", "Nouakchott Sud"),
+    ("MT-01", "Attard"),
+    ("MT-02", "Balzan"),
+    ("MT-03", "Birgu"),
+    ("MT-04", "Birkirkara"),
+    ("MT-05", "Birżebbuġa"),
+    ("MT-06", "Bormla"),
+    ("MT-07", "Dingli"),
+    ("MT-08", "Fgura"),
+    ("MT-09", "Floriana"),
+    ("MT-10", "Fontana"),
+    ("MT-11", "Gudja"),
+    ("MT-12", "Gżira"),
+    ("MT-13", "Għajnsielem"),
+    ("MT-14", "Għarb"),
+    ("MT-15", "Għargħur"),
+    ("MT-16", "Għasri"),
+    ("MT-17", "Għaxaq"),
+    ("MT-18", "Ħamrun"),
+    ("MT-19", "Iklin"),
+    ("MT-20", "Isla"),
+    ("MT-21", "Kalkara"),
+    ("MT-22", "Kerċem"),
+    ("MT-23", "Kirkop"),
+    ("MT-24", "Lija"),
+    ("MT-25", "Luqa"),
+    ("MT-26", "Marsa"),
+    ("MT-27", "Marsaskala"),
+    ("MT-28", "Marsaxlokk"),
+    ("MT-29", "Mdina"),
+    ("MT-30", "Mellieħa"),
+    ("MT-31", "Mġarr"),
+    ("MT-32", "Mosta"),
+    ("MT-33", "Mqabba"),
+    ("MT-34", "Msida"),
+    ("MT-35", "Mtarfa"),
+    ("MT-36", "Munxar"),
+    ("MT-37", "Nadur"),
+    ("MT-38", "Naxxar"),
+    ("MT-39", "Paola"),
+    ("MT-40", "Pembroke"),
+    ("MT-41", "Pietà"),
+    ("MT-42", "Qala"),
+    ("MT-43", "Qormi"),
+    ("MT-44", "Qrendi"),
+    ("MT-45", "Rabat Gozo"),
+    ("MT-46", "Rabat Malta"),
+    ("MT-47", "Safi"),
+    ("MT-48", "Saint Julian's"),
+    ("MT-49", "Saint John"),
+    ("MT-50", "Saint Lawrence"),
+    ("MT-51", "Saint Paul's Bay"),
+    ("MT-52", "Sannat"),
+    ("MT-53", "Saint Lucia's"),
+    ("MT-54", "Santa Venera"),
+    ("MT-55", "Siġġiewi"),
+    ("MT-56", "Sliema"),
+    ("MT-57", "Swieqi"),
+    ("MT-58", "Ta' Xbiex"),
+    ("MT-59", "Tarxien"),
+    ("MT-60", "Valletta"),
+    ("MT-61", "Xagħra"),
+    ("MT-62", "Xewkija"),
+    ("MT-63", "Xgħajra"),
+    ("MT-64", "Żabbar"),
+    ("MT-65", "Żebbuġ Gozo"),
+    ("MT-66", "Żebbuġ Malta"),
+    ("MT-67", "Żejtun"),
+    ("MT-68", "Żurrieq"),
+    ("MU-AG", "Agalega Islands"),
+    ("MU-BL", "Black River"),
+    ("MU-CC", "Cargados Carajos Shoals"),
+    ("MU-FL", "Flacq"),
+    ("MU-GP", "Grand Port"),
+    ("MU-MO", "Moka"),
+    ("MU-PA", "Pamplemousses"),
+    ("MU-PL", "Port Louis"),
+    ("MU-PW", "Plaines Wilhems"),
+    ("MU-RO", "Rodrigues Island"),
+    ("MU-RR", "Rivière du Rempart"),
+    ("MU-SA", "Savanne"),
+    ("MV-00", "South Ari Atoll"),
+    ("MV-01", "Addu City"),
+    ("MV-02", "North Ari Atoll"),
+    ("MV-03", "Faadhippolhu"),
+    ("MV-04", "Felidhu Atoll"),
+    ("MV-05", "Hahdhunmathi"),
+    ("MV-07", "North Thiladhunmathi"),
+    ("MV-08", "Kolhumadulu"),
+    ("MV-12", "Mulaku Atoll"),
+    ("MV-13", "North Maalhosmadulu"),
+    ("MV-14", "North Nilandhe Atoll"),
+    ("MV-17", "South Nilandhe Atoll"),
+    ("MV-20", "South Maalhosmadulu"),
+    ("MV-23", "South Thiladhunmathi"),
+    ("MV-24", "North Miladhunmadulu"),
+    ("MV-25", "South Miladhunmadulu"),
+    ("MV-26", "Male Atoll"),
+    ("MV-27", "North Huvadhu Atoll"),
+    ("MV-28", "South Huvadhu Atoll"),
+    ("MV-29", "Fuvammulah"),
+    ("MV-MLE", "Male"),
+    ("MW-BA", "Balaka"),
+    ("MW-BL", "Blantyre"),
+    ("MW-C", "Central Region"),
+    ("MW-CK", "Chikwawa"),
+    ("MW-CR", "Chiradzulu"),
+    ("MW-CT", "Chitipa"),
+    ("MW-DE", "Dedza"),
+    ("MW-DO", "Dowa"),
+    ("MW-KR", "Karonga"),
+    ("MW-KS", "Kasungu"),
+    ("MW-LI", "Lilongwe"),
+    ("MW-LK", "Likoma"),
+    ("MW-MC", "Mchinji"),
+    ("MW-MG", "Mangochi"),
+    ("MW-MH", "Machinga"),
+    ("MW-MU", "Mulanje"),
+    ("MW-MW", "Mwanza"),
+    ("MW-MZ", "Mzimba"),
+    ("MW-N", "Northern Region"),
+    ("MW-NB", "Nkhata Bay"),
+    ("MW-NE", "Neno"),
+    ("MW-NI", "Ntchisi"),
+    ("MW-NK", "Nkhotakota"),
+    ("MW-NS", "Nsanje"),
+    ("MW-NU", "Ntcheu"),
+    ("MW-PH", "Phalombe"),
+    ("MW-RU", "Rumphi"),
+    ("MW-S", "Southern Region"),
+    ("MW-SA", "Salima"),
+    ("MW-TH", "Thyolo"),
+    ("MW-ZO", "Zomba"),
+    ("MX-AGU", "Aguascalientes"),
+    ("MX-BCN", "Baja California"),
+    ("MX-BCS", "Baja California Sur"),
+    ("MX-CAM", "Campeche"),
+    ("MX-CHH", "Chihuahua"),
+    ("MX-CHP", "Chiapas"),
+    ("MX-CMX", "Ciudad de México"),
+    ("MX-COA", "Coahuila de Zaragoza"),
+    ("MX-COL", "Colima"),
+    ("MX-DUR", "Durango"),
+    ("MX-GRO", "Guerrero"),
+    ("MX-GUA", "Guanajuato"),
+    ("MX-HID", "Hidalgo"),
+    ("MX-JAL", "Jalisco"),
+    ("MX-MEX", "México"),
+    ("MX-MIC", "Michoacán de Ocampo"),
+    ("MX-MOR", "Morelos"),
+    ("MX-NAY", "Nayarit"),
+    ("MX-NLE", "Nuevo León"),
+    ("MX-OAX", "Oaxaca"),
+    ("MX-PUE", "Puebla"),
+    ("MX-QUE", "Querétaro"),
+    ("MX-ROO", "Quintana Roo"),
+    ("MX-SIN", "Sinaloa"),
+    ("MX-SLP", "San Luis Potosí"),
+    ("MX-SON", "Sonora"),
+    ("MX-TAB", "Tabasco"),
+    ("MX-TAM", "Tamaulipas"),
+    ("MX-TLA", "Tlaxcala"),
+    ("MX-VER", "Veracruz de Ignacio de la Llave"),
+    ("MX-YUC", "Yucatán"),
+    ("MX-ZAC", "Zacatecas"),
+    ("MY-01", "Johor"),
+    ("MY-02", "Kedah"),
+    ("MY-03", "Kelantan"),
+    ("MY-04", "Melaka"),
+    ("MY-05", "Negeri Sembilan"),
+    ("MY-06", "Pahang"),
+    ("MY-07", "Pulau Pinang"),
+    ("MY-08", "Perak"),
+    ("MY-09", "Perlis"),
+    ("MY-10", "Selangor"),
+    ("MY-11", "Terengganu"),
+    ("MY-12", "Sabah"),
+    ("MY-13", "Sarawak"),
+    ("MY-14", "Wilayah Persekutuan Kuala Lumpur"),
+    ("MY-15", "Wilayah Persekutuan Labuan"),
+    ("MY-16", "Wilayah Persekutuan Putrajaya"),
+    ("MZ-A", "Niassa"),
+    ("MZ-B", "Manica"),
+    ("MZ-G", "Gaza"),
+    ("MZ-I", "Inhambane"),
+    ("MZ-L", "Maputo"),
+    ("MZ-MPM", "Maputo"),
+    ("MZ-N", "Nampula"),
+    ("MZ-P", "Cabo Delgado"),
+    ("MZ-Q", "Zambézia"),
+    ("MZ-S", "Sofala"),
+    ("MZ-T", "Tete"),
+    ("NA-CA", "Zambezi"),
+    ("NA-ER", "Erongo"),
+    ("NA-HA", "Hardap"),
+    ("NA-KA", "//Karas"),
+    ("NA-KE", "Kavango East"),
+    ("NA-KH", "Khomas"),
+    ("NA-KU", "Kunene"),
+    ("NA-KW", "Kavango West"),
+    ("NA-OD", "Otjozondjupa"),
+    ("NA-OH", "Omaheke"),
+    ("NA-ON", "Oshana"),
+    ("NA-OS", "Omusati"),
+    ("NA-OT", "Oshikoto"),
+    ("NA-OW", "Ohangwena"),
+    ("NE-1", "Agadez"),
+    ("NE-2", "Diffa"),
+    ("NE-3", "Dosso"),
+    ("NE-4", "Maradi"),
+    ("NE-5", "Tahoua"),
+    ("NE-6", "Tillabéri"),
+    ("NE-7", "Zinder"),
+    ("NE-8", "Niamey"),
+    ("NG-AB", "Abia"),
+    ("NG-AD", "Adamawa"),
+    ("NG-AK", "Akwa Ibom"),
+    ("NG-AN", "Anambra"),
+    ("NG-BA", "Bauchi"),
+    ("NG-BE", "Benue"),
+    ("NG-BO", "Borno"),
+    ("NG-BY", "Bayelsa"),
+    ("NG-CR", "Cross River"),
+    ("NG-DE", "Delta"),
+    ("NG-EB", "Ebonyi"),
+    ("NG-ED", "Edo"),
+    ("NG-EK", "Ekiti"),
+    ("NG-EN", "Enugu"),
+    ("NG-FC", "Abuja Federal Capital Territory"),
+    ("NG-GO", "Gombe"),
+    ("NG-IM", "Imo"),
+    ("NG-JI", "Jigawa"),
+    ("NG-KD", "Kaduna"),
+    ("NG-KE", "Kebbi"),
+    ("NG-KN", "Kano"),
+    ("NG-KO", "Kogi"),
+    ("NG-KT", "Katsina"),
+    ("NG-KW", "Kwara"),
+    ("NG-LA", "Lagos"),
+    ("NG-NA", "Nasarawa"),
+    ("NG-NI", "Niger"),
+    ("NG-OG", "Ogun"),
+    ("NG-ON", "Ondo"),
+    ("NG-OS", "Osun"),
+    ("NG-OY", "Oyo"),
+    ("NG-PL", "Plateau"),
+    ("NG-RI", "Rivers"),
+    ("NG-SO", "Sokoto"),
+    ("NG-TA", "Taraba"),
+    ("NG-YO", "Yobe"),
+    ("NG-ZA", "Zamfara"),
+    ("NI-AN", "Costa Caribe Norte"),
+    ("NI-AS", "Costa Caribe Sur"),
+    ("NI-BO", "Boaco"),
+    ("NI-CA", "Carazo"),
+    ("NI-CI", "Chinandega"),
+    ("NI-CO", "Chontales"),
+    ("NI-ES", "Estelí"),
+    ("NI-GR", "Granada"),
+    ("NI-JI", "Jinotega"),
+    ("NI-LE", "León"),
+    ("NI-MD", "Madriz"),
+    ("NI-MN", "Managua"),
+    ("NI-MS", "Masaya"),
+    ("NI-MT", "Matagalpa"),
+    ("NI-NS", "Nueva Segovia"),
+    ("NI-RI", "Rivas"),
+    ("NI-SJ", "Río San Juan"),
+    ("NL-AW", "Aruba"),
+    ("NL-BQ1", "Bonaire"),
+    ("NL-BQ2", "Saba"),
+    ("NL-BQ3", "Sint Eustatius"),
+    ("NL-CW", "Curaçao"),
+    ("NL-DR", "Drenthe"),
+    ("NL-FL", "Flevoland"),
+    ("NL-FR", "Fryslân"),
+    ("NL-GE", "Gelderland"),
+    ("NL-GR", "Groningen"),
+    ("NL-LI", "Limburg"),
+    ("NL-NB", "Noord-Brabant"),
+    ("NL-NH", "Noord-Holland"),
+    ("NL-OV", "Overijssel"),
+    ("NL-SX", "Sint Maarten"),
+    ("NL-UT", "Utrecht"),
+    ("NL-ZE", "Zeeland"),
+    ("NL-ZH", "Zuid-Holland"),
+    ("NO-03", "Oslo"),
+    ("NO-11", "Rogaland"),
+    ("NO-15", "Møre og Romsdal"),
+    ("NO-18", "Nordland"),
+    ("NO-21", "Svalbard (Arctic Region)"),
+    ("NO-22", "Jan Mayen (Arctic Region)"),
+    ("NO-30", "Viken"),
+    ("NO-34", "Innlandet"),
+    ("NO-38", "Vestfold og Telemark"),
+    ("NO-42", "Agder"),
+    ("NO-46", "Vestland"),
+    ("NO-50", "Trööndelage"),
+    ("NO-54", "Romssa ja Finnmárkku"),
+    ("NP-1", "Central"),
+    ("NP-2", "Mid Western"),
+    ("NP-3", "Western"),
+    ("NP-4", "Eastern"),
+    ("NP-5", "Far Western"),
+    ("NP-BA", "Bagmati"),
+    ("NP-BH", "Bheri"),
+    ("NP-DH", "Dhawalagiri"),
+    ("NP-GA", "Gandaki"),
+    ("NP-JA", "Janakpur"),
+    ("NP-KA", "Karnali"),
+    ("NP-KO", "Kosi"),
+    ("NP-LU", "Lumbini"),
+    ("NP-MA", "Mahakali"),
+    ("NP-ME", "Mechi"),
+    ("NP-NA", "Narayani"),
+    ("NP-P1", "Province 1"),
+    ("NP-P2", "Province 2"),
+    ("NP-P3", "Bāgmatī"),
+    ("NP-P4", "Gandaki"),
+    ("NP-P5", "Province 5"),
+    ("NP-P6", "Karnali"),
+    ("NP-P7", "Sudūr Pashchim"),
+    ("NP-RA", "Rapti"),
+    ("NP-SA", "Sagarmatha"),
+    ("NP-SE", "Seti"),
+    ("NR-01", "Aiwo"),
+    ("NR-02", "Anabar"),
+    ("NR-03", "Anetan"),
+    ("NR-04", "Anibare"),
+    ("NR-05", "Baitsi"),
+    ("NR-06", "Boe"),
+    ("NR-07", "Buada"),
+    ("NR-08", "Denigomodu"),
+    ("NR-09", "Ewa"),
+    ("NR-10", "Ijuw"),
+    ("NR-11", "Meneng"),
+    ("NR-12", "Nibok"),
+    ("NR-13", "Uaboe"),
+    ("NR-14", "Yaren"),
+    ("NZ-AUK", "Auckland"),
+    ("NZ-BOP", "Bay of Plenty"),
+    ("NZ-CAN", "Canterbury"),
+    ("NZ-CIT", "Chatham Islands Territory"),
+    ("NZ-GIS", "Gisborne"),
+    ("NZ-HKB", "Hawke's Bay"),
+    ("NZ-MBH", "Marlborough"),
+    ("NZ-MWT", "Manawatu-Wanganui"),
+    ("NZ-NSN", "Nelson"),
+    ("NZ-NTL", "Northland"),
+    ("NZ-OTA", "Otago"),
+    ("NZ-STL", "Southland"),
+    ("NZ-TAS", "Tasman"),
+    ("NZ-TKI", "Taranaki"),
+    ("NZ-WGN", "Wellington"),
+    ("NZ-WKO", "Waikato"),
+    ("NZ-WTC", "West Coast"),
+    ("OM-BJ", "Janūb al Bāţinah"),
+    ("OM-BS", "Shamāl al Bāţinah"),
+    ("OM-BU", "Al Buraymī"),
+    ("OM-DA", "Ad Dākhilīyah"),
+    ("OM-MA", "Masqaţ"),
+    ("OM-MU", "Musandam"),
+    ("OM-SJ", "Janūb ash Sharqīyah"),
+    ("OM-SS", "Shamāl ash Sharqīyah"),
+    ("OM-WU", "Al Wusţá"),
+    ("OM-ZA", "Az̧ Z̧āhirah"),
+    ("OM-ZU", "Z̧ufār"),
+    ("PA-1", "Bocas del Toro"),
+    ("PA-10", "Panamá Oeste"),
+    ("PA-2", "Coclé"),
+    ("PA-3", "Colón"),
+    ("PA-4", "Chiriquí"),
+    ("PA-5", "Darién"),
+    ("PA-6", "Herrera"),
+    ("PA-7", "Los Santos"),
+    ("PA-8", "Panamá"),
+    ("PA-9", "Veraguas"),
+    ("PA-EM", "Emberá"),
+    ("PA-KY", "Guna Yala"),
+    ("PA-NB", "Ngöbe-Buglé"),
+    ("PE-AMA", "Amarumayu"),
+    ("PE-ANC", "Ancash"),
+    ("PE-APU", "Apurimaq"),
+    ("PE-ARE", "Arequipa"),
+    ("PE-AYA", "Ayacucho"),
+    ("PE-CAJ", "Cajamarca"),
+    ("PE-CAL", "El Callao"),
+    ("PE-CUS", "Cusco"),
+    ("PE-HUC", "Huánuco"),
+    ("PE-HUV", "Huancavelica"),
+    ("PE-ICA", "Ica"),
+    ("PE-JUN", "Hunin"),
+    ("PE-LAL", "La Libertad"),
+    ("PE-LAM", "Lambayeque"),
+    ("PE-LIM", "Lima"),
+    ("PE-LMA", "Lima hatun llaqta"),
+    ("PE-LOR", "Loreto"),
+    ("PE-MDD", "Madre de Dios"),
+    ("PE-MOQ", "Moquegua"),
+    ("PE-PAS", "Pasco"),
+    ("PE-PIU", "Piura"),
+    ("PE-PUN", "Puno"),
+    ("PE-SAM", "San Martin"),
+    ("PE-TAC", "Tacna"),
+    ("PE-TUM", "Tumbes"),
+    ("PE-UCA", "Ucayali"),
+    ("PG-CPK", "Chimbu"),
+    ("PG-CPM", "Central"),
+    ("PG-EBR", "East New Britain"),
+    ("PG-EHG", "Eastern Highlands"),
+    ("PG-EPW", "Enga"),
+    ("PG-ESW", "East Sepik"),
+    ("PG-GPK", "Gulf"),
+    ("PG-HLA", "Hela"),
+    ("PG-JWK", "Jiwaka"),
+    ("PG-MBA", "Milne Bay"),
+    ("PG-MPL", "Morobe"),
+    ("PG-MPM", "Madang"),
+    ("PG-MRL", "Manus"),
+    ("PG-NCD", "National Capital District (Port Moresby)"),
+    ("PG-NIK", "New Ireland"),
+    ("PG-NPP", "Northern"),
+    ("PG-NSB", "Bougainville"),
+    ("PG-SAN", "West Sepik"),
+    ("PG-SHM", "Southern Highlands"),
+    ("PG-WBK", "West New Britain"),
+    ("PG-WHM", "Western Highlands"),
+    ("PG-WPD", "Western"),
+    ("PH-00", "National Capital Region"),
+    ("PH-01", "Ilocos (Region I)"),
+    ("PH-02", "Cagayan Valley (Region II)"),
+    ("PH-03", "Central Luzon (Region III)"),
+    ("PH-05", "Bicol (Region V)"),
+    ("PH-06", "Western Visayas (Region VI)"),
+    ("PH-07", "Central Visayas (Region VII)"),
+    ("PH-08", "Eastern Visayas (Region VIII)"),
+    ("PH-09", "Zamboanga Peninsula (Region IX)"),
+    ("PH-10", "Northern Mindanao (Region X)"),
+    ("PH-11", "Davao (Region XI)"),
+    ("PH-12", "Soccsksargen (Region XII)"),
+    ("PH-13", "Caraga (Region XIII)"),
+    ("PH-14", "Autonomous Region in Muslim Mindanao (ARMM)"),
+    ("PH-15", "Cordillera Administrative Region (CAR)"),
+    ("PH-40", "Calabarzon (Region IV-A)"),
+    ("PH-41", "Mimaropa (Region IV-B)"),
+    ("PH-ABR", "Abra"),
+    ("PH-AGN", "Agusan del Norte"),
+    ("PH-AGS", "Agusan del Sur"),
+    ("PH-AKL", "Aklan"),
+    ("PH-ALB", "Albay"),
+    ("PH-ANT", "Antique"),
+    ("PH-APA", "Apayao"),
+    ("PH-AUR", "Aurora"),
+    ("PH-BAN", "Bataan"),
+    ("PH-BAS", "Basilan"),
+    ("PH-BEN", "Benguet"),
+    ("PH-BIL", "Biliran"),
+    ("PH-BOH", "Bohol"),
+    ("PH-BTG", "Batangas"),
+    ("PH-BTN", "Batanes"),
+    ("PH-BUK", "Bukidnon"),
+    ("PH-BUL", "Bulacan"),
+    ("PH-CAG", "Cagayan"),
+    ("PH-CAM", "Camiguin"),
+    ("PH-CAN", "Camarines Norte"),
+    ("PH-CAP", "Capiz"),
+    ("PH-CAS", "Camarines Sur"),
+    ("PH-CAT", "Catanduanes"),
+    ("PH-CAV", "Cavite"),
+    ("PH-CEB", "Cebu"),
+    ("PH-COM", "Davao de Oro"),
+    ("PH-DAO", "Davao Oriental"),
+    ("PH-DAS", "Davao del Sur"),
+    ("PH-DAV", "Davao del Norte"),
+    ("PH-DIN", "Dinagat Islands"),
+    ("PH-DVO", "Davao Occidental"),
+    ("PH-EAS", "Eastern Samar"),
+    ("PH-GUI", "Guimaras"),
+    ("PH-IFU", "Ifugao"),
+    ("PH-ILI", "Iloilo"),
+    ("PH-ILN", "Ilocos Norte"),
+    ("PH-ILS", "Ilocos Sur"),
+    ("PH-ISA", "Isabela"),
+    ("PH-KAL", "Kalinga"),
+    ("PH-LAG", "Laguna"),
+    ("PH-LAN", "Lanao del Norte"),
+    ("PH-LAS", "Lanao del Sur"),
+    ("PH-LEY", "Leyte"),
+    ("PH-LUN", "La Union"),
+    ("PH-MAD", "Marinduque"),
+    ("PH-MAG", "Maguindanao"),
+    ("PH-MAS", "Masbate"),
+    ("PH-MDC", "Mindoro Occidental"),
+    ("PH-MDR", "Mindoro Oriental"),
+    ("PH-MOU", "Mountain Province"),
+    ("PH-MSC", "Misamis Occidental"),
+    ("PH-MSR", "Misamis Oriental"),
+    ("PH-NCO", "Cotabato"),
+    ("PH-NEC", "Negros Occidental"),
+    ("PH-NER", "Negros Oriental"),
+    ("PH-NSA", "Northern Samar"),
+    ("PH-NUE", "Nueva Ecija"),
+    ("PH-NUV", "Nueva Vizcaya"),
+    ("PH-PAM", "Pampanga"),
+    ("PH-PAN", "Pangasinan"),
+    ("PH-PLW", "Palawan"),
+    ("PH-QUE", "Quezon"),
+    ("PH-QUI", "Quirino"),
+    ("PH-RIZ", "Rizal"),
+    ("PH-ROM", "Romblon"),
+    ("PH-SAR", "Sarangani"),
+    ("PH-SCO", "South Cotabato"),
+    ("PH-SIG", "Siquijor"),
+    ("PH-SLE", "Southern Leyte"),
+    ("PH-SLU", "Sulu"),
+    ("PH-SOR", "Sorsogon"),
+    ("PH-SUK", "Sultan Kudarat"),
+    ("PH-SUN", "Surigao del Norte"),
+    ("PH-SUR", "Surigao del Sur"),
+    ("PH-TAR", "Tarlac"),
+    ("PH-TAW", "Tawi-Tawi"),
+    ("PH-WSA", "Samar"),
+    ("PH-ZAN", "Zamboanga del Norte"),
+    ("PH-ZAS", "Zamboanga del Sur"),
+    ("PH-ZMB", "Zambales"),
+    ("PH-ZSI", "Zamboanga Sibugay"),
+    ("PK-BA", "Balochistan"),
+    ("PK-GB", "Gilgit-Baltistan"),
+    ("PK-IS", "Islamabad"),
+    ("PK-JK", "Azad Jammu and Kashmir"),
+    ("PK-KP", "Khyber Pakhtunkhwa"),
+    ("PK-PB", "Punjab"),
+    ("PK-SD", "Sindh"),
+    ("PL-02", "Dolnośląskie"),
+    ("PL-04", "Kujawsko-pomorskie"),
+    ("PL-06", "Lubelskie"),
+    ("PL-08", "Lubuskie"),
+    ("PL-10", "Łódzkie"),
+    ("PL-12", "Małopolskie"),
+    ("PL-14", "Mazowieckie"),
+    ("PL-16", "Opolskie"),
+    ("PL-18", "Podkarpackie"),
+    ("PL-20", "Podlaskie"),
+    ("PL-22", "Pomorskie"),
+    ("PL-24", "Śląskie"),
+    ("PL-26", "Świętokrzyskie"),
+    ("PL-28", "Warmińsko-mazurskie"),
+    ("PL-30", "Wielkopolskie"),
+    ("PL-32", "Zachodniopomorskie"),
+    ("PS-BTH", "Bethlehem"),
+    ("PS-DEB", "Deir El Balah"),
+    ("PS-GZA", "Gaza"),
+    ("PS-HBN", "Hebron"),
+    ("PS-JEM", "Jerusalem"),
+    ("PS-JEN", "Jenin"),
+    ("PS-JRH", "Jericho and Al Aghwar"),
+    ("PS-KYS", "Khan Yunis"),
+    ("PS-NBS", "Nablus"),
+    ("PS-NGZ", "North Gaza"),
+    ("PS-QQA", "Qalqilya"),
+    ("PS-RBH", "Ramallah"),
+    ("PS-RFH", "Rafah"),
+    ("PS-SLT", "Salfit"),
+    ("PS-TBS", "Tubas"),
+    ("PS-TKM", "Tulkarm"),
+    ("PT-01", "Aveiro"),
+    ("PT-02", "Beja"),
+    ("PT-03", "Braga"),
+    ("PT-04", "Bragança"),
+    ("PT-05", "Castelo Branco"),
+    ("PT-06", "Coimbra"),
+    ("PT-07", "Évora"),
+    ("PT-08", "Faro"),
+    ("PT-09", "Guarda"),
+    ("PT-10", "Leiria"),
+    ("PT-11", "Lisboa"),
+    ("PT-12", "Portalegre"),
+    ("PT-13", "Porto"),
+    ("PT-14", "Santarém"),
+    ("PT-15", "Setúbal"),
+    ("PT-16", "Viana do Castelo"),
+    ("PT-17", "Vila Real"),
+    ("PT-18", "Viseu"),
+    ("PT-20", "Região Autónoma dos Açores"),
+    ("PT-30", "Região Autónoma da Madeira"),
+    ("PW-002", "Aimeliik"),
+    ("PW-004", "Airai"),
+    ("PW-010", "Angaur"),
+    ("PW-050", "Hatohobei"),
+    ("PW-100", "Kayangel"),
+    ("PW-150", "Koror"),
+    ("PW-212", "Melekeok"),
+    ("PW-214", "Ngaraard"),
+    ("PW-218", "Ngarchelong"),
+    ("PW-222", "Ngardmau"),
+    ("PW-224", "Ngatpang"),
+    ("PW-226", "Ngchesar"),
+    ("PW-227", "Ngeremlengui"),
+    ("PW-228", "Ngiwal"),
+    ("PW-350", "Peleliu"),
+    ("PW-370", "Sonsorol"),
+    ("PY-1", "Concepción"),
+    ("PY-10", "Alto Paraná"),
+    ("PY-11", "Central"),
+    ("PY-12", "Ñeembucú"),
+    ("PY-13", "Amambay"),
+    ("PY-14", "Canindeyú"),
+    ("PY-15", "Presidente Hayes"),
+    ("PY-16", "Alto Paraguay"),
+    ("PY-19", "Boquerón"),
+    ("PY-2", "San Pedro"),
+    ("PY-3", "Cordillera"),
+    ("PY-4", "Guairá"),
+    ("PY-5", "Caaguazú"),
+    ("PY-6", "Caazapá"),
+    ("PY-7", "Itapúa"),
+    ("PY-8", "Misiones"),
+    ("PY-9", "Paraguarí"),
+    ("PY-ASU", "Asunción"),
+    ("QA-DA", "Ad Dawḩah"),
+    ("QA-KH", "Al Khawr wa adh Dhakhīrah"),
+    ("QA-MS", "Ash Shamāl"),
+    ("QA-RA", "Ar Rayyān"),
+    ("QA-SH", "Ash Shīḩānīyah"),
+    ("QA-US", "Umm Şalāl"),
+    ("QA-WA", "Al Wakrah"),
+    ("QA-ZA", "Az̧ Z̧a‘āyin"),
+    ("RO-AB", "Alba"),
+    ("RO-AG", "Argeș"),
+    ("RO-AR", "Arad"),
+    ("RO-B", "București"),
+    ("RO-BC", "Bacău"),
+    ("RO-BH", "Bihor"),
+    ("RO-BN", "Bistrița-Năsăud"),
+    ("RO-BR", "Brăila"),
+    ("RO-BT", "Botoșani"),
+    ("RO-BV", "Brașov"),
+    ("RO-BZ", "Buzău"),
+    ("RO-CJ", "Cluj"),
+    ("RO-CL", "Călărași"),
+    ("RO-CS", "Caraș-Severin"),
+    ("RO-CT", "Constanța"),
+    ("RO-CV", "Covasna"),
+    ("RO-DB", "Dâmbovița"),
+    ("RO-DJ", "Dolj"),
+    ("RO-GJ", "Gorj"),
+    ("RO-GL", "Galați"),
+    ("RO-GR", "Giurgiu"),
+    ("RO-HD", "Hunedoara"),
+    ("RO-HR", "Harghita"),
+    ("RO-IF", "Ilfov"),
+    ("RO-IL", "Ialomița"),
+    ("RO-IS", "Iași"),
+    ("RO-MH", "Mehedinți"),
+    ("RO-MM", "Maramureș"),
+    ("RO-MS", "Mureș"),
+    ("RO-NT", "Neamț"),
+    ("RO-OT", "Olt"),
+    ("RO-PH", "Prahova"),
+    ("RO-SB", "Sibiu"),
+    ("RO-SJ", "Sălaj"),
+    ("RO-SM", "Satu Mare"),
+    ("RO-SV", "Suceava"),
+    ("RO-TL", "Tulcea"),
+    ("RO-TM", "Timiș"),
+    ("RO-TR", "Teleorman"),
+    ("RO-VL", "Vâlcea"),
+    ("RO-VN", "Vrancea"),
+    ("RO-VS", "Vaslui"),
+    ("RS-00", "Beograd"),
+    ("RS-01", "Severnobački okrug"),
+    ("RS-02", "Srednjebanatski okrug"),
+    ("RS-03", "Severnobanatski okrug"),
+    ("RS-04", "Južnobanatski okrug"),
+    ("RS-05", "Zapadnobački okrug"),
+    ("RS-06", "Južnobački okrug"),
+    ("RS-07", "Sremski okrug"),
+    ("RS-08", "Mačvanski okrug"),
+    ("RS-09", "Kolubarski okrug"),
+    ("RS-10", "Podunavski okrug"),
+    ("RS-11", "Braničevski okrug"),
+    ("RS-12", "Šumadijski okrug"),
+    ("RS-13", "Pomoravski okrug"),
+    ("RS-14", "Borski okrug"),
+    ("RS-15", "Zaječarski okrug"),
+    ("RS-16", "Zlatiborski okrug"),
+    ("RS-17", "Moravički okrug"),
+    ("RS-18", "Raški okrug"),
+    ("RS-19", "Rasinski okrug"),
+    ("RS-20", "Nišavski okrug"),
+    ("RS-21", "Toplički okrug"),
+    ("RS-22", "Pirotski okrug"),
+    ("RS-23", "Jablanički okrug"),
+    ("RS-24", "Pčinjski okrug"),
+    ("RS-25", "Kosovski okrug"),
+    ("RS-26", "Pećki okrug"),
+    ("RS-27", "Prizrenski okrug"),
+    ("RS-28", "Kosovsko-Mitrovački okrug"),
+    ("RS-29", "Kosovsko-Pomoravski okrug"),
+    ("RS-KM", "Kosovo-Metohija"),
+    ("RS-VO", "Vojvodina"),
+    ("RU-AD", "Adygeja, Respublika"),
+    ("RU-AL", "Altaj, Respublika"),
+    ("RU-ALT", "Altajskij kraj"),
+    ("RU-AMU", "Amurskaja oblast'"),
+    ("RU-ARK", "Arhangel'skaja oblast'"),
+    ("RU-AST", "Astrahanskaja oblast'"),
+    ("RU-BA", "Bashkortostan, Respublika"),
+    ("RU-BEL", "Belgorodskaja oblast'"),
+    ("RU-BRY", "Brjanskaja oblast'"),
+    ("RU-BU", "Burjatija, Respublika"),
+    ("RU-CE", "Chechenskaya Respublika"),
+    ("RU-CHE", "Chelyabinskaya oblast'"),
+    ("RU-CHU", "Chukotskiy avtonomnyy okrug"),
+    ("RU-CU", "Chuvashskaya Respublika"),
+    ("RU-DA", "Dagestan, Respublika"),
+    ("RU-IN", "Ingushetiya, Respublika"),
+    ("RU-IRK", "Irkutskaja oblast'"),
+    ("RU-IVA", "Ivanovskaja oblast'"),
+    ("RU-KAM", "Kamchatskiy kray"),
+    ("RU-KB", "Kabardino-Balkarskaja Respublika"),
+    ("RU-KC", "Karachayevo-Cherkesskaya Respublika"),
+    ("RU-KDA", "Krasnodarskij kraj"),
+    ("RU-KEM", "Kemerovskaja oblast'"),
+    ("RU-KGD", "Kaliningradskaja oblast'"),
+    ("RU-KGN", "Kurganskaja oblast'"),
+    ("RU-KHA", "Habarovskij kraj"),
+    ("RU-KHM", "Hanty-Mansijskij avtonomnyj okrug"),
+    ("RU-KIR", "Kirovskaja oblast'"),
+    ("RU-KK", "Hakasija, Respublika"),
+    ("RU-KL", "Kalmykija, Respublika"),
+    ("RU-KLU", "Kaluzhskaya oblast'"),
+    ("RU-KO", "Komi, Respublika"),
+    ("RU-KOS", "Kostromskaja oblast'"),
+    ("RU-KR", "Karelija, Respublika"),
+    ("RU-KRS", "Kurskaja oblast'"),
+    ("RU-KYA", "Krasnojarskij kraj"),
+    ("RU-LEN", "Leningradskaja oblast'"),
+    ("RU-LIP", "Lipeckaja oblast'"),
+    ("RU-MAG", "Magadanskaja oblast'"),
+    ("RU-ME", "Marij Èl, Respublika"),
+    ("RU-MO", "Mordovija, Respublika"),
+    ("RU-MOS", "Moskovskaja oblast'"),
+    ("RU-MOW", "Moskva"),
+    ("RU-MUR", "Murmanskaja oblast'"),
+    ("RU-NEN", "Neneckij avtonomnyj okrug"),
+    ("RU-NGR", "Novgorodskaja oblast'"),
+    ("RU-NIZ", "Nizhegorodskaya oblast'"),
+    ("RU-NVS", "Novosibirskaja oblast'"),
+    ("RU-OMS", "Omskaja oblast'"),
+    ("RU-ORE", "Orenburgskaja oblast'"),
+    ("RU-ORL", "Orlovskaja oblast'"),
+    ("RU-PER", "Permskij kraj"),
+    ("RU-PNZ", "Penzenskaja oblast'"),
+    ("RU-PRI", "Primorskij kraj"),
+    ("RU-PSK", "Pskovskaja oblast'"),
+    ("RU-ROS", "Rostovskaja oblast'"),
+    ("RU-RYA", "Rjazanskaja oblast'"),
+    ("RU-SA", "Saha, Respublika"),
+    ("RU-SAK", "Sahalinskaja oblast'"),
+    ("RU-SAM", "Samarskaja oblast'"),
+    ("RU-SAR", "Saratovskaja oblast'"),
+    ("RU-SE", "Severnaja Osetija, Respublika"),
+    ("RU-SMO", "Smolenskaja oblast'"),
+    ("RU-SPE", "Sankt-Peterburg"),
+    ("RU-STA", "Stavropol'skij kraj"),
+    ("RU-SVE", "Sverdlovskaja oblast'"),
+    ("RU-TA", "Tatarstan, Respublika"),
+    ("RU-TAM", "Tambovskaja oblast'"),
+    ("RU-TOM", "Tomskaja oblast'"),
+    ("RU-TUL", "Tul'skaja oblast'"),
+    ("RU-TVE", "Tverskaja oblast'"),
+    ("RU-TY", "Tyva, Respublika"),
+    ("RU-TYU", "Tjumenskaja oblast'"),
+    ("RU-UD", "Udmurtskaja Respublika"),
+    ("RU-ULY", "Ul'janovskaja oblast'"),
+    ("RU-VGG", "Volgogradskaja oblast'"),
+    ("RU-VLA", "Vladimirskaja oblast'"),
+    ("RU-VLG", "Vologodskaja oblast'"),
+    ("RU-VOR", "Voronezhskaya oblast'"),
+    ("RU-YAN", "Jamalo-Neneckij avtonomnyj okrug"),
+    ("RU-YAR", "Jaroslavskaja oblast'"),
+    ("RU-YEV", "Evrejskaja avtonomnaja oblast'"),
+    ("RU-ZAB", "Zabajkal'skij kraj"),
+    ("RW-01", "City of Kigali"),
+    ("RW-02", "Eastern"),
+    ("RW-03", "Northern"),
+    ("RW-04", "Western"),
+    ("RW-05", "Southern"),
+    ("SA-01", "Ar Riyāḑ"),
+    ("SA-02", "Makkah al Mukarramah"),
+    ("SA-03", "Al Madīnah al Munawwarah"),
+    ("SA-04", "Ash Sharqīyah"),
+    ("SA-05", "Al Qaşīm"),
+    ("SA-06", "Ḩā'il"),
+    ("SA-07", "Tabūk"),
+    ("SA-08", "Al Ḩudūd ash Shamālīyah"),
+    ("SA-09", "Jāzān"),
+    ("SA-10", "Najrān"),
+    ("SA-11", "Al Bāḩah"),
+    ("SA-12", "Al Jawf"),
+    ("SA-14", "'Asīr"),
+    ("SB-CE", "Central"),
+    ("SB-CH", "Choiseul"),
+    ("SB-CT", "Capital Territory (Honiara)"),
+    ("SB-GU", "Guadalcanal"),
+    ("SB-IS", "Isabel"),
+    ("SB-MK", "Makira-Ulawa"),
+    ("SB-ML", "Malaita"),
+    ("SB-RB", "Rennell and Bellona"),
+    ("SB-TE", "Temotu"),
+    ("SB-WE", "Western"),
+    ("SC-01", "Anse aux Pins"),
+    ("SC-02", "Anse Boileau"),
+    ("SC-03", "Anse Etoile"),
+    ("SC-04", "Au Cap"),
+    ("SC-05", "Anse Royale"),
+    ("SC-06", "Baie Lazare"),
+    ("SC-07", "Baie Sainte Anne"),
+    ("SC-08", "Beau Vallon"),
+    ("SC-09", "Bel Air"),
+    ("SC-10", "Bel Ombre"),
+    ("SC-11", "Cascade"),
+    ("SC-12", "Glacis"),
+    ("SC-13", "Grand Anse Mahe"),
+    ("SC-14", "Grand Anse Praslin"),
+    ("SC-15", "La Digue"),
+    ("SC-16", "English River"),
+    ("SC-17", "Mont Buxton"),
+    ("SC-18", "Mont Fleuri"),
+    ("SC-19", "Plaisance"),
+    ("SC-20", "Pointe Larue"),
+    ("SC-21", "Port Glaud"),
+    ("SC-22", "Saint Louis"),
+    ("SC-23", "Takamaka"),
+    ("SC-24", "Les Mamelles"),
+    ("SC-25", "Roche Caiman"),
+    ("SC-26", "Ile Perseverance I"),
+    ("SC-27", "Ile Perseverance II"),
+    ("SD-DC", "Central Darfur"),
+    ("SD-DE", "East Darfur"),
+    ("SD-DN", "North Darfur"),
+    ("SD-DS", "South Darfur"),
+    ("SD-DW", "West Darfur"),
+    ("SD-GD", "Gedaref"),
+    ("SD-GK", "West Kordofan"),
+    ("SD-GZ", "Gezira"),
+    ("SD-KA", "Kassala"),
+    ("SD-KH", "Khartoum"),
+    ("SD-KN", "North Kordofan"),
+    ("SD-KS", "South Kordofan"),
+    ("SD-NB", "Blue Nile"),
+    ("SD-NO", "Northern"),
+    ("SD-NR", "River Nile"),
+    ("SD-NW", "White Nile"),
+    ("SD-RS", "Red Sea"),
+    ("SD-SI", "Sennar"),
+    ("SE-AB", "Stockholms län [SE-01]"),
+    ("SE-AC", "Västerbottens län [SE-24]"),
+    ("SE-BD", "Norrbottens län [SE-25]"),
+    ("SE-C", "Uppsala län [SE-03]"),
+    ("SE-D", "Södermanlands län [SE-04]"),
+    ("SE-E", "Östergötlands län [SE-05]"),
+    ("SE-F", "Jönköpings län [SE-06]"),
+    ("SE-G", "Kronobergs län [SE-07]"),
+    ("SE-H", "Kalmar län [SE-08]"),
+    ("SE-I", "Gotlands län [SE-09]"),
+    ("SE-K", "Blekinge län [SE-10]"),
+    ("SE-M", "Skåne län [SE-12]"),
+    ("SE-N", "Hallands län [SE-13]"),
+    ("SE-O", "Västra Götalands län [SE-14]"),
+    ("SE-S", "Värmlands län [SE-17]"),
+    ("SE-T", "Örebro län [SE-18]"),
+    ("SE-U", "Västmanlands län [SE-19]"),
+    ("SE-W", "Dalarnas län [SE-20]"),
+    ("SE-X", "Gävleborgs län [SE-21]"),
+    ("SE-Y", "Västernorrlands län [SE-22]"),
+    ("SE-Z", "Jämtlands län [SE-23]"),
+    ("SG-01", "Central Singapore"),
+    ("SG-02", "North East"),
+    ("SG-03", "North West"),
+    ("SG-04", "South East"),
+    ("SG-05", "South West"),
+    ("SH-AC", "Ascension"),
+    ("SH-HL", "Saint Helena"),
+    ("SH-TA", "Tristan da Cunha"),
+    ("SI-001", "Ajdovščina"),
+    ("SI-002", "Beltinci"),
+    ("SI-003", "Bled"),
+    ("SI-004", "Bohinj"),
+    ("SI-005", "Borovnica"),
+    ("SI-006", "Bovec"),
+    ("SI-007", "Brda"),
+    ("SI-008", "Brezovica"),
+    ("SI-009", "Brežice"),
+    ("SI-010", "Tišina"),
+    ("SI-011", "Celje"),
+    ("SI-012", "Cerklje na Gorenjskem"),
+    ("SI-013", "Cerknica"),
+    ("SI-014", "Cerkno"),
+    ("SI-015", "Črenšovci"),
+    ("SI-016", "Črna na Koroškem"),
+    ("SI-017", "Črnomelj"),
+    ("SI-018", "Destrnik"),
+    ("SI-019", "Divača"),
+    ("SI-020", "Dobrepolje"),
+    ("SI-021", "Dobrova-Polhov Gradec"),
+    ("SI-022", "Dol pri Ljubljani"),
+    ("SI-023", "Domžale"),
+    ("SI-024", "Dornava"),
+    ("SI-025", "Dravograd"),
+    ("SI-026", "Duplek"),
+    ("SI-027", "Gorenja vas-Poljane"),
+    ("SI-028", "Gorišnica"),
+    ("SI-029", "Gornja Radgona"),
+    ("SI-030", "Gornji Grad"),
+    ("SI-031", "Gornji Petrovci"),
+    ("SI-032", "Grosuplje"),
+    ("SI-033", "Šalovci"),
+    ("SI-034", "Hrastnik"),
+    ("SI-035", "Hrpelje-Kozina"),
+    ("SI-036", "Idrija"),
+    ("SI-037", "Ig"),
+    ("SI-038", "Ilirska Bistrica"),
+    ("SI-039", "Ivančna Gorica"),
+    ("SI-040", "Izola"),
+    ("SI-041", "Jesenice"),
+    ("SI-042", "Juršinci"),
+    ("SI-043", "Kamnik"),
+    ("SI-044", "Kanal"),
+    ("SI-045", "Kidričevo"),
+    ("SI-046", "Kobarid"),
+    ("SI-047", "Kobilje"),
+    ("SI-048", "Kočevje"),
+    ("SI-049", "Komen"),
+    ("SI-050", "Koper"),
+    ("SI-051", "Kozje"),
+    ("SI-052", "Kranj"),
+    ("SI-053", "Kranjska Gora"),
+    ("SI-054", "Krško"),
+    ("SI-055", "Kungota"),
+    ("SI-056", "Kuzma"),
+    ("SI-057", "Laško"),
+    ("SI-058", "Lenart"),
+    ("SI-059", "Lendava"),
+    ("SI-060", "Litija"),
+    ("SI-061", "Ljubljana"),
+    ("SI-062", "Ljubno"),
+    ("SI-063", "Ljutomer"),
+    ("SI-064", "Logatec"),
+    ("SI-065", "Loška dolina"),
+    ("SI-066", "Loški Potok"),
+    ("SI-067", "Luče"),
+    ("SI-068", "Lukovica"),
+    ("SI-069", "Majšperk"),
+    ("SI-070", "Maribor"),
+    ("SI-071", "Medvode"),
+    ("SI-072", "Mengeš"),
+    ("SI-073", "Metlika"),
+    ("SI-074", "Mežica"),
+    ("SI-075", "Miren-Kostanjevica"),
+    ("SI-076", "Mislinja"),
+    ("SI-077", "Moravče"),
+    ("SI-078", "Moravske Toplice"),
+    ("SI-079", "Mozirje"),
+    ("SI-080", "Murska Sobota"),
+    ("SI-081", "Muta"),
+    ("SI-082", "Naklo"),
+    ("SI-083", "Nazarje"),
+    ("SI-084", "Nova Gorica"),
+    ("SI-085", "Novo Mesto"),
+    ("SI-086", "Odranci"),
+    ("SI-087", "Ormož"),
+    ("SI-088", "Osilnica"),
+    ("SI-089", "Pesnica"),
+    ("SI-090", "Piran"),
+    ("SI-091", "Pivka"),
+    ("SI-092", "Podčetrtek"),
+    ("SI-093", "Podvelka"),
+    ("SI-094", "Postojna"),
+    ("SI-095", "Preddvor"),
+    ("SI-096", "Ptuj"),
+    ("SI-097", "Puconci"),
+    ("SI-098", "Rače-Fram"),
+    ("SI-099", "Radeče"),
+    ("SI-100", "Radenci"),
+    ("SI-101", "Radlje ob Dravi"),
+    ("SI-102", "Radovljica"),
+    ("SI-103", "Ravne na Koroškem"),
+    ("SI-104", "Ribnica"),
+    ("SI-105", "Rogašovci"),
+    ("SI-106", "Rogaška Slatina"),
+    ("SI-107", "Rogatec"),
+    ("SI-108", "Ruše"),
+    ("SI-109", "Semič"),
+    ("SI-110", "Sevnica"),
+    ("SI-111", "Sežana"),
+    ("SI-112", "Slovenj Gradec"),
+    ("SI-113", "Slovenska Bistrica"),
+    ("SI-114", "Slovenske Konjice"),
+    ("SI-115", "Starše"),
+    ("SI-116", "Sveti Jurij ob Ščavnici"),
+    ("SI-117", "Šenčur"),
+    ("SI-118", "Šentilj"),
+    ("SI-119", "Šentjernej"),
+    ("SI-120", "Šentjur"),
+    ("SI-121", "Škocjan"),
+    ("SI-122", "Škofja Loka"),
+    ("SI-123", "Škofljica"),
+    ("SI-124", "Šmarje pri Jelšah"),
+    ("SI-125", "Šmartno ob Paki"),
+    ("SI-126", "Šoštanj"),
+    ("SI-127", "Štore"),
+    ("SI-128", "Tolmin"),
+    ("SI-129", "Trbovlje"),
+    ("SI-130", "Trebnje"),
+    ("SI-131", "Tržič"),
+    ("SI-132", "Turnišče"),
+    ("SI-133", "Velenje"),
+    ("SI-134", "Velike Lašče"),
+    ("SI-135", "Videm"),
+    ("SI-136", "Vipava"),
+    ("SI-137", "Vitanje"),
+    ("SI-138", "Vodice"),
+    ("SI-139", "Vojnik"),
+    ("SI-140", "Vrhnika"),
+    ("SI-141", "Vuzenica"),
+    ("SI-142", "Zagorje ob Savi"),
+    ("SI-143", "Zavrč"),
+    ("SI-144", "Zreče"),
+    ("SI-146", "Železniki"),
+    ("SI-147", "Žiri"),
+    ("SI-148", "Benedikt"),
+    ("SI-149", "Bistrica ob Sotli"),
+    ("SI-150", "Bloke"),
+    ("SI-151", "Braslovče"),
+    ("SI-152", "Cankova"),
+    ("SI-153", "Cerkvenjak"),
+    ("SI-154", "Dobje"),
+    ("SI-155", "Dobrna"),
+    ("SI-156", "Dobrovnik"),
+    ("SI-157", "Dolenjske Toplice"),
+    ("SI-158", "Grad"),
+    ("SI-159", "Hajdina"),
+    ("SI-160", "Hoče-Slivnica"),
+    ("SI-161", "Hodoš"),
+    ("SI-162", "Horjul"),
+    ("SI-163", "Jezersko"),
+    ("SI-164", "Komenda"),
+    ("SI-165", "Kostel"),
+    ("SI-166", "Križevci"),
+    ("SI-167", "Lovrenc na Pohorju"),
+    ("SI-168", "Markovci"),
+    ("SI-169", "Miklavž na Dravskem polju"),
+    ("SI-170", "Mirna Peč"),
+    ("SI-171", "Oplotnica"),
+    ("SI-172", "Podlehnik"),
+    ("SI-173", "Polzela"),
+    ("SI-174", "Prebold"),
+    ("SI-175", "Prevalje"),
+    ("SI-176", "Razkrižje"),
+    ("SI-177", "Ribnica na Pohorju"),
+    ("SI-178", "Selnica ob Dravi"),
+    ("SI-179", "Sodražica"),
+    ("SI-180", "Solčava"),
+    ("SI-181", "Sveta Ana"),
+    ("SI-182", "Sveti Andraž v Slovenskih goricah"),
+    ("SI-183", "Šempeter-Vrtojba"),
+    ("SI-184", "Tabor"),
+    ("SI-185", "Trnovska Vas"),
+    ("SI-186", "Trzin"),
+    ("SI-187", "Velika Polana"),
+    ("SI-188", "Veržej"),
+    ("SI-189", "Vransko"),
+    ("SI-190", "Žalec"),
+    ("SI-191", "Žetale"),
+    ("SI-192", "Žirovnica"),
+    ("SI-193", "Žužemberk"),
+    ("SI-194", "Šmartno pri Litiji"),
+    ("SI-195", "Apače"),
+    ("SI-196", "Cirkulane"),
+    ("SI-197", "Kosanjevica na Krki"),
+    ("SI-198", "Makole"),
+    ("SI-199", "Mokronog-Trebelno"),
+    ("SI-200", "Poljčane"),
+    ("SI-201", "Renče-Vogrsko"),
+    ("SI-202", "Središče ob Dravi"),
+    ("SI-203", "Straža"),
+    ("SI-204", "Sveta Trojica v Slovenskih goricah"),
+    ("SI-205", "Sveti Tomaž"),
+    ("SI-206", "Šmarješke Toplice"),
+    ("SI-207", "Gorje"),
+    ("SI-208", "Log-Dragomer"),
+    ("SI-209", "Rečica ob Savinji"),
+    ("SI-210", "Sveti Jurij v Slovenskih goricah"),
+    ("SI-211", "Šentrupert"),
+    ("SI-212", "Mirna"),
+    ("SI-213", "Ankaran"),
+    ("SK-BC", "Banskobystrický kraj"),
+    ("SK-BL", "Bratislavský kraj"),
+    ("SK-KI", "Košický kraj"),
+    ("SK-NI", "Nitriansky kraj"),
+    ("SK-PV", "Prešovský kraj"),
+    ("SK-TA", "Trnavský kraj"),
+    ("SK-TC", "Trenčiansky kraj"),
+    ("SK-ZI", "Žilinský kraj"),
+    ("SL-E", "Eastern"),
+    ("SL-N", "Northern"),
+    ("SL-NW", "North Western"),
+    ("SL-S", "Southern"),
+    ("SL-W", "Western Area (Freetown)"),
+    ("SM-01", "Acquaviva"),
+    ("SM-02", "Chiesanuova"),
+    ("SM-03", "Domagnano"),
+    ("SM-04", "Faetano"),
+    ("SM-05", "Fiorentino"),
+    ("SM-06", "Borgo Maggiore"),
+    ("SM-07", "Città di San Marino"),
+    ("SM-08", "Montegiardino"),
+    ("SM-09", "Serravalle"),
+    ("SN-DB", "Diourbel"),
+    ("SN-DK", "Dakar"),
+    ("SN-FK", "Fatick"),
+    ("SN-KA", "Kaffrine"),
+    ("SN-KD", "Kolda"),
+    ("SN-KE", "Kédougou"),
+    ("SN-KL", "Kaolack"),
+    ("SN-LG", "Louga"),
+    ("SN-MT", "Matam"),
+    ("SN-SE", "Sédhiou"),
+    ("SN-SL", "Saint-Louis"),
+    ("SN-TC", "Tambacounda"),
+    ("SN-TH", "Thiès"),
+    ("SN-ZG", "Ziguinchor"),
+    ("SO-AW", "Awdal"),
+    ("SO-BK", "Bakool"),
+    ("SO-BN", "Banaadir"),
+    ("SO-BR", "Bari"),
+    ("SO-BY", "Bay"),
+    ("SO-GA", "Galguduud"),
+    ("SO-GE", "Gedo"),
+    ("SO-HI", "Hiiraan"),
+    ("SO-JD", "Jubbada Dhexe"),
+    ("SO-JH", "Jubbada Hoose"),
+    ("SO-MU", "Mudug"),
+    ("SO-NU", "Nugaal"),
+    ("SO-SA", "Sanaag"),
+    ("SO-SD", "Shabeellaha Dhexe"),
+    ("SO-SH", "Shabeellaha Hoose"),
+    ("SO-SO", "Sool"),
+    ("SO-TO", "Togdheer"),
+    ("SO-WO", "Woqooyi Galbeed"),
+    ("SR-BR", "Brokopondo"),
+    ("SR-CM", "Commewijne"),
+    ("SR-CR", "Coronie"),
+    ("SR-MA", "Marowijne"),
+    ("SR-NI", "Nickerie"),
+    ("SR-PM", "Paramaribo"),
+    ("SR-PR", "Para"),
+    ("SR-SA", "Saramacca"),
+    ("SR-SI", "Sipaliwini"),
+    ("SR-WA", "Wanica"),
+    ("SS-BN", "Northern Bahr el Ghazal"),
+    ("SS-BW", "Western Bahr el Ghazal"),
+    ("SS-EC", "Central Equatoria"),
+    ("SS-EE", "Eastern Equatoria"),
+    ("SS-EW", "Western Equatoria"),
+    ("SS-JG", "Jonglei"),
+    ("SS-LK", "Lakes"),
+    ("SS-NU", "Upper Nile"),
+    ("SS-UY", "Unity"),
+    ("SS-WR", "Warrap"),
+    ("ST-01", "Água Grande"),
+    ("ST-02", "Cantagalo"),
+    ("ST-03", "Caué"),
+    ("ST-04", "Lembá"),
+    ("ST-05", "Lobata"),
+    ("ST-06", "Mé-Zóchi"),
+    ("ST-P", "Príncipe"),
+    ("SV-AH", "Ahuachapán"),
+    ("SV-CA", "Cabañas"),
+    ("SV-CH", "Chalatenango"),
+    ("SV-CU", "Cuscatlán"),
+    ("SV-LI", "La Libertad"),
+    ("SV-MO", "Morazán"),
+    ("SV-PA", "La Paz"),
+    ("SV-SA", "Santa Ana"),
+    ("SV-SM", "San Miguel"),
+    ("SV-SO", "Sonsonate"),
+    ("SV-SS", "San Salvador"),
+    ("SV-SV", "San Vicente"),
+    ("SV-UN", "La Unión"),
+    ("SV-US", "Usulután"),
+    ("SY-DI", "Dimashq"),
+    ("SY-DR", "Dar'ā"),
+    ("SY-DY", "Dayr az Zawr"),
+    ("SY-HA", "Al Ḩasakah"),
+    ("SY-HI", "Ḩimş"),
+    ("SY-HL", "Ḩalab"),
+    ("SY-HM", "Ḩamāh"),
+    ("SY-ID", "Idlib"),
+    ("SY-LA", "Al Lādhiqīyah"),
+    ("SY-QU", "Al Qunayţirah"),
+    ("SY-RA", "Ar Raqqah"),
+    ("SY-RD", "Rīf Dimashq"),
+    ("SY-SU", "As Suwaydā'"),
+    ("SY-TA", "Ţarţūs"),
+    ("SZ-HH", "Hhohho"),
+    ("SZ-LU", "Lubombo"),
+    ("SZ-MA", "Manzini"),
+    ("SZ-SH", "Shiselweni"),
+    ("TD-BA", "Al Baţḩā’"),
+    ("TD-BG", "Bahr el Ghazal"),
+    ("TD-BO", "Borkou"),
+    ("TD-CB", "Chari-Baguirmi"),
+    ("TD-EE", "Ennedi-Est"),
+    ("TD-EO", "Ennedi-Ouest"),
+    ("TD-GR", "Guéra"),
+    ("TD-HL", "Hadjer Lamis"),
+    ("TD-KA", "Kanem"),
+    ("TD-LC", "Al Buḩayrah"),
+    ("TD-LO", "Logone-Occidental"),
+    ("TD-LR", "Logone-Oriental"),
+    ("TD-MA", "Mandoul"),
+    ("TD-MC", "Moyen-Chari"),
+    ("TD-ME", "Mayo-Kebbi-Est"),
+    ("TD-MO", "Mayo-Kebbi-Ouest"),
+    ("TD-ND", "Madīnat Injamīnā"),
+    ("TD-OD", "Ouaddaï"),
+    ("TD-SA", "Salamat"),
+    ("TD-SI", "Sila"),
+    ("TD-TA", "Tandjilé"),
+    ("TD-TI", "Tibastī"),
+    ("TD-WF", "Wadi Fira"),
+    ("TG-C", "Centrale"),
+    ("TG-K", "Kara"),
+    ("TG-M", "Maritime (Région)"),
+    ("TG-P", "Plateaux"),
+    ("TG-S", "Savanes"),
+    ("TH-10", "Krung Thep Maha Nakhon"),
+    ("TH-11", "Samut Prakan"),
+    ("TH-12", "Nonthaburi"),
+    ("TH-13", "Pathum Thani"),
+    ("TH-14", "Phra Nakhon Si Ayutthaya"),
+    ("TH-15", "Ang Thong"),
+    ("TH-16", "Lop Buri"),
+    ("TH-17", "Sing Buri"),
+    ("TH-18", "Chai Nat"),
+    ("TH-19", "Saraburi"),
+    ("TH-20", "Chon Buri"),
+    ("TH-21", "Rayong"),
+    ("TH-22", "Chanthaburi"),
+    ("TH-23", "Trat"),
+    ("TH-24", "Chachoengsao"),
+    ("TH-25", "Prachin Buri"),
+    ("TH-26", "Nakhon Nayok"),
+    ("TH-27", "Sa Kaeo"),
+    ("TH-30", "Nakhon Ratchasima"),
+    ("TH-31", "Buri Ram"),
+    ("TH-32", "Surin"),
+    ("TH-33", "Si Sa Ket"),
+    ("TH-34", "Ubon Ratchathani"),
+    ("TH-35", "Yasothon"),
+    ("TH-36", "Chaiyaphum"),
+    ("TH-37", "Amnat Charoen"),
+    ("TH-38", "Bueng Kan"),
+    ("TH-39", "Nong Bua Lam Phu"),
+    ("TH-40", "Khon Kaen"),
+    ("TH-41", "Udon Thani"),
+    ("TH-42", "Loei"),
+    ("TH-43", "Nong Khai"),
+    ("TH-44", "Maha Sarakham"),
+    ("TH-45", "Roi Et"),
+    ("TH-46", "Kalasin"),
+    ("TH-47", "Sakon Nakhon"),
+    ("TH-48", "Nakhon Phanom"),
+    ("TH-49", "Mukdahan"),
+    ("TH-50", "Chiang Mai"),
+    ("TH-51", "Lamphun"),
+    ("TH-52", "Lampang"),
+    ("TH-53", "Uttaradit"),
+    ("TH-54", "Phrae"),
+    ("TH-55", "Nan"),
+    ("TH-56", "Phayao"),
+    ("TH-57", "Chiang Rai"),
+    ("TH-58", "Mae Hong Son"),
+    ("TH-60", "Nakhon Sawan"),
+    ("TH-61", "Uthai Thani"),
+    ("TH-62", "Kamphaeng Phet"),
+    ("TH-63", "Tak"),
+    ("TH-64", "Sukhothai"),
+    ("TH-65", "Phitsanulok"),
+    ("TH-66", "Phichit"),
+    ("TH-67", "Phetchabun"),
+    ("TH-70", "Ratchaburi"),
+    ("TH-71", "Kanchanaburi"),
+    ("TH-72", "Suphan Buri"),
+    ("TH-73", "Nakhon Pathom"),
+    ("TH-74", "Samut Sakhon"),
+    ("TH-75", "Samut Songkhram"),
+    ("TH-76", "Phetchaburi"),
+    ("TH-77", "Prachuap Khiri Khan"),
+    ("TH-80", "Nakhon Si Thammarat"),
+    ("TH-81", "Krabi"),
+    ("TH-82", "Phangnga"),
+    ("TH-83", "Phuket"),
+    ("TH-84", "Surat Thani"),
+    ("TH-85", "Ranong"),
+    ("TH-86", "Chumphon"),
+    ("TH-90", "Songkhla"),
+    ("TH-91", "Satun"),
+    ("TH-92", "Trang"),
+    ("TH-93", "Phatthalung"),
+    ("TH-94", "Pattani"),
+    ("TH-95", "Yala"),
+    ("TH-96", "Narathiwat"),
+    ("TH-S", "Phatthaya"),
+    ("TJ-DU", "Dushanbe"),
+    ("TJ-GB", "Kŭhistoni Badakhshon"),
+    ("TJ-KT", "Khatlon"),
+    ("TJ-RA", "nohiyahoi tobei jumhurí"),
+    ("TJ-SU", "Sughd"),
+    ("TL-AL", "Aileu"),
+    ("TL-AN", "Ainaro"),
+    ("TL-BA", "Baucau"),
+    ("TL-BO", "Bobonaro"),
+    ("TL-CO", "Cova Lima"),
+    ("TL-DI", "Díli"),
+    ("TL-ER", "Ermera"),
+    ("TL-LA", "Lautein"),
+    ("TL-LI", "Likisá"),
+    ("TL-MF", "Manufahi"),
+    ("TL-MT", "Manatuto"),
+    ("TL-OE", "Oekusi-Ambenu"),
+    ("TL-VI", "Vikeke"),
+    ("TM-A", "Ahal"),
+    ("TM-B", "Balkan"),
+    ("TM-D", "Daşoguz"),
+    ("TM-L", "Lebap"),
+    ("TM-M", "Mary"),
+    ("TM-S", "Aşgabat"),
+    ("TN-11", "Tunis"),
+    ("TN-12", "L'Ariana"),
+    ("TN-13", "Ben Arous"),
+    ("TN-14", "La Manouba"),
+    ("TN-21", "Nabeul"),
+    ("TN-22", "Zaghouan"),
+    ("TN-23", "Bizerte"),
+    ("TN-31", "Béja"),
+    ("TN-32", "Jendouba"),
+    ("TN-33", "Le Kef"),
+    ("TN-34", "Siliana"),
+    ("TN-41", "Kairouan"),
+    ("TN-42", "Kasserine"),
+    ("TN-43", "Sidi Bouzid"),
+    ("TN-51", "Sousse"),
+    ("TN-52", "Monastir"),
+    ("TN-53", "Mahdia"),
+    ("TN-61", "Sfax"),
+    ("TN-71", "Gafsa"),
+    ("TN-72", "Tozeur"),
+    ("TN-73", "Kébili"),
+    ("TN-81", "Gabès"),
+    ("TN-82", "Médenine"),
+    ("TN-83", "Tataouine"),
+    ("TO-01", "'Eua"),
+    ("TO-02", "Ha'apai"),
+    ("TO-03", "Niuas"),
+    ("TO-04", "Tongatapu"),
+    ("TO-05", "Vava'u"),
+    ("TR-01", "Adana"),
+    ("TR-02", "Adıyaman"),
+    ("TR-03", "Afyonkarahisar"),
+    ("TR-04", "Ağrı"),
+    ("TR-05", "Amasya"),
+    ("TR-06", "Ankara"),
+    ("TR-07", "Antalya"),
+    ("TR-08", "Artvin"),
+    ("TR-09", "Aydın"),
+    ("TR-10", "Balıkesir"),
+    ("TR-11", "Bilecik"),
+    ("TR-12", "Bingöl"),
+    ("TR-13", "Bitlis"),
+    ("TR-14", "Bolu"),
+    ("TR-15", "Burdur"),
+    ("TR-16", "Bursa"),
+    ("TR-17", "Çanakkale"),
+    ("TR-18", "Çankırı"),
+    ("TR-19", "Çorum"),
+    ("TR-20", "Denizli"),
+    ("TR-21", "Diyarbakır"),
+    ("TR-22", "Edirne"),
+    ("TR-23", "Elazığ"),
+    ("TR-24", "Erzincan"),
+    ("TR-25", "Erzurum"),
+    ("TR-26", "Eskişehir"),
+    ("TR-27", "Gaziantep"),
+    ("TR-28", "Giresun"),
+    ("TR-29", "Gümüşhane"),
+    ("TR-30", "Hakkâri"),
+    ("TR-31", "Hatay"),
+    ("TR-32", "Isparta"),
+    ("TR-33", "Mersin"),
+    ("TR-34", "İstanbul"),
+    ("TR-35", "İzmir"),
+    ("TR-36", "Kars"),
+    ("TR-37", "Kastamonu"),
+    ("TR-38", "Kayseri"),
+    ("TR-39", "Kırklareli"),
+    ("TR-40", "Kırşehir"),
+    ("TR-41", "Kocaeli"),
+    ("TR-42", "Konya"),
+    ("TR-43", "Kütahya"),
+    ("TR-44", "Malatya"),
+    ("TR-45", "Manisa"),
+    ("TR-46", "Kahramanmaraş"),
+    ("TR-47", "Mardin"),
+    ("TR-48", "Muğla"),
+    ("TR-49", "Muş"),
+    ("TR-50", "Nevşehir"),
+    ("TR-51", "Niğde"),
+    ("TR-52", "Ordu"),
+    ("TR-53", "Rize"),
+    ("TR-54", "Sakarya"),
+    ("TR-55", "Samsun"),
+    ("TR-56", "Siirt"),
+    ("TR-57", "Sinop"),
+    ("TR-58", "Sivas"),
+    ("TR-59", "Tekirdağ"),
+    ("TR-60", "Tokat"),
+    ("TR-61", "Trabzon"),
+    ("TR-62", "Tunceli"),
+    ("TR-63", "Şanlıurfa"),
+    ("TR-64", "Uşak"),
+    ("TR-65", "Van"),
+    ("TR-66", "Yozgat"),
+    ("TR-67", "Zonguldak"),
+    ("TR-68", "Aksaray"),
+    ("TR-69", "Bayburt"),
+    ("TR-70", "Karaman"),
+    ("TR-71", "Kırıkkale"),
+    ("TR-72", "Batman"),
+    ("TR-73", "Şırnak"),
+    ("TR-74", "Bartın"),
+    ("TR-75", "Ardahan"),
+    ("TR-76", "Iğdır"),
+    ("TR-77", "Yalova"),
+    ("TR-78", "Karabük"),
+    ("TR-79", "Kilis"),
+    ("TR-80", "Osmaniye"),
+    ("TR-81", "Düzce"),
+    ("TT-ARI", "Arima"),
+    ("TT-CHA", "Chaguanas"),
+    ("TT-CTT", "Couva-Tabaquite-Talparo"),
+    ("TT-DMN", "Diego Martin"),
+    ("TT-MRC", "Mayaro-Rio Claro"),
+    ("TT-PED", "Penal-Debe"),
+    ("TT-POS", "Port of Spain"),
+    ("TT-PRT", "Princes Town"),
+    ("TT-PTF", "Point Fortin"),
+    ("TT-SFO", "San Fernando"),
+    ("TT-SGE", "Sangre Grande"),
+    ("TT-SIP", "Siparia"),
+    ("TT-SJL", "San Juan-Laventille"),
+    ("TT-TOB", "Tobago"),
+    ("TT-TUP", "Tunapuna-Piarco"),
+    ("TV-FUN", "Funafuti"),
+    ("TV-NIT", "Niutao"),
+    ("TV-NKF", "Nukufetau"),
+    ("TV-NKL", "Nukulaelae"),
+    ("TV-NMA", "Nanumea"),
+    ("TV-NMG", "Nanumaga"),
+    ("TV-NUI", "Nui"),
+    ("TV-VAI", "Vaitupu"),
+    ("TW-CHA", "Changhua"),
+    ("TW-CYI", "Chiayi"),
+    ("TW-CYQ", "Chiayi"),
+    ("TW-HSQ", "Hsinchu"),
+    ("TW-HSZ", "Hsinchu"),
+    ("TW-HUA", "Hualien"),
+    ("TW-ILA", "Yilan"),
+    ("TW-KEE", "Keelung"),
+    ("TW-KHH", "Kaohsiung"),
+    ("TW-KIN", "Kinmen"),
+    ("TW-LIE", "Lienchiang"),
+    ("TW-MIA", "Miaoli"),
+    ("TW-NAN", "Nantou"),
+    ("TW-NWT", "New Taipei"),
+    ("TW-PEN", "Penghu"),
+    ("TW-PIF", "Pingtung"),
+    ("TW-TAO", "Taoyuan"),
+    ("TW-TNN", "Tainan"),
+    ("TW-TPE", "Taipei"),
+    ("TW-TTT", "Taitung"),
+    ("TW-TXG", "Taichung"),
+    ("TW-YUN", "Yunlin"),
+    ("TZ-01", "Arusha"),
+    ("TZ-02", "Dar es Salaam"),
+    ("TZ-03", "Dodoma"),
+    ("TZ-04", "Iringa"),
+    ("TZ-05", "Kagera"),
+    ("TZ-06", "Pemba North"),
+    ("TZ-07", "Zanzibar North"),
+    ("TZ-08", "Kigoma"),
+    ("TZ-09", "Kilimanjaro"),
+    ("TZ-10", "Pemba South"),
+    ("TZ-11", "Zanzibar South"),
+    ("TZ-12", "Lindi"),
+    ("TZ-13", "Mara"),
+    ("TZ-14", "Mbeya"),
+    ("TZ-15", "Zanzibar West"),
+    ("TZ-16", "Morogoro"),
+    ("TZ-17", "Mtwara"),
+    ("TZ-18", "Mwanza"),
+    ("TZ-19", "Coast"),
+    ("TZ-20", "Rukwa"),
+    ("TZ-21", "Ruvuma"),
+    ("TZ-22", "Shinyanga"),
+    ("TZ-23", "Singida"),
+    ("TZ-24", "Tabora"),
+    ("TZ-25", "Tanga"),
+    ("TZ-26", "Manyara"),
+    ("TZ-27", "Geita"),
+    ("TZ-28", "Katavi"),
+    ("TZ-29", "Njombe"),
+    ("TZ-30", "Simiyu"),
+    ("TZ-31", "Songwe"),
+    ("UA-05", "Vinnytska oblast"),
+    ("UA-07", "Volynska oblast"),
+    ("UA-09", "Luhanska oblast"),
+    ("UA-12", "Dnipropetrovska oblast"),
+    ("UA-14", "Donetska oblast"),
+    ("UA-18", "Zhytomyrska oblast"),
+    ("UA-21", "Zakarpatska oblast"),
+    ("UA-23", "Zaporizka oblast"),
+    ("UA-26", "Ivano-Frankivska oblast"),
+    ("UA-30", "Kyiv"),
+    ("UA-32", "Kyivska oblast"),
+    ("UA-35", "Kirovohradska oblast"),
+    ("UA-40", "Sevastopol"),
+    ("UA-43", "Avtonomna Respublika Krym"),
+    ("UA-46", "Lvivska oblast"),
+    ("UA-48", "Mykolaivska oblast"),
+    ("UA-51", "Odeska oblast"),
+    ("UA-53", "Poltavska oblast"),
+    ("UA-56", "Rivnenska oblast"),
+    ("UA-59", "Sumska oblast"),
+    ("UA-61", "Ternopilska oblast"),
+    ("UA-63", "Kharkivska oblast"),
+    ("UA-65", "Khersonska oblast"),
+    ("UA-68", "Khmelnytska oblast"),
+    ("UA-71", "Cherkaska oblast"),
+    ("UA-74", "Chernihivska oblast"),
+    ("UA-77", "Chernivetska oblast"),
+    ("UG-101", "Kalangala"),
+    ("UG-102", "Kampala"),
+    ("UG-103", "Kiboga"),
+    ("UG-104", "Luwero"),
+    ("UG-105", "Masaka"),
+    ("UG-106", "Mpigi"),
+    ("UG-107", "Mubende"),
+    ("UG-108", "Mukono"),
+    ("UG-109", "Nakasongola"),
+    ("UG-110", "Rakai"),
+    ("UG-111", "Sembabule"),
+    ("UG-112", "Kayunga"),
+    ("UG-113", "Wakiso"),
+    ("UG-114", "Lyantonde"),
+    ("UG-115", "Mityana"),
+    ("UG-116", "Nakaseke"),
+    ("UG-117", "Buikwe"),
+    ("UG-118", "Bukomansibi"),
+    ("UG-119", "Butambala"),
+    ("UG-120", "Buvuma"),
+    ("UG-121", "Gomba"),
+    ("UG-122", "Kalungu"),
+    ("UG-123", "Kyankwanzi"),
+    ("UG-124", "Lwengo"),
+    ("UG-125", "Kyotera"),
+    ("UG-126", "Kasanda"),
+    ("UG-201", "Bugiri"),
+    ("UG-202", "Busia"),
+    ("UG-203", "Iganga"),
+    ("UG-204", "Jinja"),
+    ("UG-205", "Kamuli"),
+    ("UG-206", "Kapchorwa"),
+    ("UG-207", "Katakwi"),
+    ("UG-208", "Kumi"),
+    ("UG-209", "Mbale"),
+    ("UG-210", "Pallisa"),
+    ("UG-211", "Soroti"),
+    ("UG-212", "Tororo"),
+    ("UG-213", "Kaberamaido"),
+    ("UG-214", "Mayuge"),
+    ("UG-215", "Sironko"),
+    ("UG-216", "Amuria"),
+    ("UG-217", "Budaka"),
+    ("UG-218", "Bududa"),
+    ("UG-219", "Bukedea"),
+    ("UG-220", "Bukwo"),
+    ("UG-221", "Butaleja"),
+    ("UG-222", "Kaliro"),
+    ("UG-223", "Manafwa"),
+    ("UG-224", "Namutumba"),
+    ("UG-225", "Bulambuli"),
+    ("UG-226", "Buyende"),
+    ("UG-227", "Kibuku"),
+    ("UG-228", "Kween"),
+    ("UG-229", "Luuka"),
+    ("UG-230", "Namayingo"),
+    ("UG-231", "Ngora"),
+    ("UG-232", "Serere"),
+    ("UG-233", "Butebo"),
+    ("UG-234", "Namisindwa"),
+    ("UG-235", "Bugweri"),
+    ("UG-236", "Kapelebyong"),
+    ("UG-237", "Kalaki"),
+    ("UG-301", "Adjumani"),
+    ("UG-302", "Apac"),
+    ("UG-303", "Arua"),
+    ("UG-304", "Gulu"),
+    ("UG-305", "Kitgum"),
+    ("UG-306", "Kotido"),
+    ("UG-307", "Lira"),
+    ("UG-308", "Moroto"),
+    ("UG-309", "Moyo"),
+    ("UG-310", "Nebbi"),
+    ("UG-311", "Nakapiripirit"),
+    ("UG-312", "Pader"),
+    ("UG-313", "Yumbe"),
+    ("UG-314", "Abim"),
+    ("UG-315", "Amolatar"),
+    ("UG-316", "Amuru"),
+    ("UG-317", "Dokolo"),
+    ("UG-318", "Kaabong"),
+    ("UG-319", "Koboko"),
+    ("UG-320", "Maracha"),
+    ("UG-321", "Oyam"),
+    ("UG-322", "Agago"),
+    ("UG-323", "Alebtong"),
+    ("UG-324", "Amudat"),
+    ("UG-325", "Kole"),
+    ("UG-326", "Lamwo"),
+    ("UG-327", "Napak"),
+    ("UG-328", "Nwoya"),
+    ("UG-329", "Otuke"),
+    ("UG-330", "Zombo"),
+    ("UG-331", "Omoro"),
+    ("UG-332", "Pakwach"),
+    ("UG-333", "Kwania"),
+    ("UG-334", "Nabilatuk"),
+    ("UG-335", "Karenga"),
+    ("UG-336", "Madi-Okollo"),
+    ("UG-337", "Obongi"),
+    ("UG-401", "Bundibugyo"),
+    ("UG-402", "Bushenyi"),
+    ("UG-403", "Hoima"),
+    ("UG-404", "Kabale"),
+    ("UG-405", "Kabarole"),
+    ("UG-406", "Kasese"),
+    ("UG-407", "Kibaale"),
+    ("UG-408", "Kisoro"),
+    ("UG-409", "Masindi"),
+    ("UG-410", "Mbarara"),
+    ("UG-411", "Ntungamo"),
+    ("UG-412", "Rukungiri"),
+    ("UG-413", "Kamwenge"),
+    ("UG-414", "Kanungu"),
+    ("UG-415", "Kyenjojo"),
+    ("UG-416", "Buliisa"),
+    ("UG-417", "Ibanda"),
+    ("UG-418", "Isingiro"),
+    ("UG-419", "Kiruhura"),
+    ("UG-420", "Buhweju"),
+    ("UG-421", "Kiryandongo"),
+    ("UG-422", "Kyegegwa"),
+    ("UG-423", "Mitooma"),
+    ("UG-424", "Ntoroko"),
+    ("UG-425", "Rubirizi"),
+    ("UG-426", "Sheema"),
+    ("UG-427", "Kagadi"),
+    ("UG-428", "Kakumiro"),
+    ("UG-429", "Rubanda"),
+    ("UG-430", "Bunyangabu"),
+    ("UG-431", "Rukiga"),
+    ("UG-432", "Kikuube"),
+    ("UG-433", "Kazo"),
+    ("UG-434", "Kitagwenda"),
+    ("UG-435", "Rwampara"),
+    ("UG-C", "Central"),
+    ("UG-E", "Eastern"),
+    ("UG-N", "Northern"),
+    ("UG-W", "Western"),
+    ("UM-67", "Johnston Atoll"),
+    ("UM-71", "Midway Islands"),
+    ("UM-76", "Navassa Island"),
+    ("UM-79", "Wake Island"),
+    ("UM-81", "Baker Island"),
+    ("UM-84", "Howland Island"),
+    ("UM-86", "Jarvis Island"),
+    ("UM-89", "Kingman Reef"),
+    ("UM-95", "Palmyra Atoll"),
+    ("US-AK", "Alaska"),
+    ("US-AL", "Alabama"),
+    ("US-AR", "Arkansas"),
+    ("US-AS", "American Samoa"),
+    ("US-AZ", "Arizona"),
+    ("US-CA", "California"),
+    ("US-CO", "Colorado"),
+    ("US-CT", "Connecticut"),
+    ("US-DC", "District of Columbia"),
+    ("US-DE", "Delaware"),
+    ("US-FL", "Florida"),
+    ("US-GA", "Georgia"),
+    ("US-GU", "Guam"),
+    ("US-HI", "Hawaii"),
+    ("US-IA", "Iowa"),
+    ("US-ID", "Idaho"),
+    ("US-IL", "Illinois"),
+    ("US-IN", "Indiana"),
+    ("US-KS", "Kansas"),
+    ("US-KY", "Kentucky"),
+    ("US-LA", "Louisiana"),
+    ("US-MA", "Massachusetts"),
+    ("US-MD", "Maryland"),
+    ("US-ME", "Maine"),
+    ("US-MI", "Michigan"),
+    ("US-MN", "Minnesota"),
+    ("US-MO", "Missouri"),
+    ("US-MP", "Northern Mariana Islands"),
+    ("US-MS", "Mississippi"),
+    ("US-MT", "Montana"),
+    ("US-NC", "North Carolina"),
+    ("US-ND", "North Dakota"),
+    ("US-NE", "Nebraska"),
+    ("US-NH", "New Hampshire"),
+    ("US-NJ", "New Jersey"),
+    ("US-NM", "New Mexico"),
+    ("US-NV", "Nevada"),
+    ("US-NY", "New York"),
+    ("US-OH", "Ohio"),
+    ("US-OK", "Oklahoma"),
+    ("US-OR", "Oregon"),
+    ("US-PA", "Pennsylvania"),
+    ("US-PR", "Puerto Rico"),
+    ("US-RI", "Rhode Island"),
+    ("US-SC", "South Carolina"),
+    ("US-SD", "South Dakota"),
+    ("US-TN", "Tennessee"),
+    ("US-TX", "Texas"),
+    ("US-UM", "United States Minor Outlying Islands"),
+    ("US-UT", "Utah"),
+    ("US-VA", "Virginia"),
+    ("US-VI", "Virgin Islands, U.S."),
+    ("US-VT", "Vermont"),
+    ("US-WA", "Washington"),
+    ("US-WI", "Wisconsin"),
+    ("US-WV", "West Virginia"),
+    ("US-WY", "Wyoming"),
+    ("UY-AR", "Artigas"),
+    ("UY-CA", "Canelones"),
+    ("UY-CL", "Cerro Largo"),
+    ("UY-CO", "Colonia"),
+    ("UY-DU", "Durazno"),
+    ("UY-FD", "Florida"),
+    ("UY-FS", "Flores"),
+    ("UY-LA", "Lavalleja"),
+    ("UY-MA", "Maldonado"),
+    ("UY-MO", "Montevideo"),
+    ("UY-PA", "Paysandú"),
+    ("UY-RN", "Río Negro"),
+    ("UY-RO", "Rocha"),
+    ("UY-RV", "Rivera"),
+    ("UY-SA", "Salto"),
+    ("UY-SJ", "San José"),
+    ("UY-SO", "Soriano"),
+    ("UY-TA", "Tacuarembó"),
+    ("UY-TT", "Treinta y Tres"),
+    ("UZ-AN", "Andijon"),
+    ("UZ-BU", "Buxoro"),
+    ("UZ-FA", "Farg‘ona"),
+    ("UZ-JI", "Jizzax"),
+    ("UZ-NG", "Namangan"),
+    ("UZ-NW", "Navoiy"),
+    ("UZ-QA", "Qashqadaryo"),
+    ("UZ-QR", "Qoraqalpog‘iston Respublikasi"),
+    ("UZ-SA", "Samarqand"),
+    ("UZ-SI", "Sirdaryo"),
+    ("UZ-SU", "Surxondaryo"),
+    ("UZ-TK", "Toshkent"),
+    ("UZ-TO", "Toshkent"),
+    ("UZ-XO", "Xorazm"),
+    ("VC-01", "Charlotte"),
+    ("VC-02", "Saint Andrew"),
+    ("VC-03", "Saint David"),
+    ("VC-04", "Saint George"),
+    ("VC-05", "Saint Patrick"),
+    ("VC-06", "Grenadines"),
+    ("VE-A", "Distrito Capital"),
+    ("VE-B", "Anzoátegui"),
+    ("VE-C", "Apure"),
+    ("VE-D", "Aragua"),
+    ("VE-E", "Barinas"),
+    ("VE-F", "Bolívar"),
+    ("VE-G", "Carabobo"),
+    ("VE-H", "Cojedes"),
+    ("VE-I", "Falcón"),
+    ("VE-J", "Guárico"),
+    ("VE-K", "Lara"),
+    ("VE-L", "Mérida"),
+    ("VE-M", "Miranda"),
+    ("VE-N", "Monagas"),
+    ("VE-O", "Nueva Esparta"),
+    ("VE-P", "Portuguesa"),
+    ("VE-R", "Sucre"),
+    ("VE-S", "Táchira"),
+    ("VE-T", "Trujillo"),
+    ("VE-U", "Yaracuy"),
+    ("VE-V", "Zulia"),
+    ("VE-W", "Dependencias Federales"),
+    ("VE-X", "La Guaira"),
+    ("VE-Y", "Delta Amacuro"),
+    ("VE-Z", "Amazonas"),
+    ("VN-01", "Lai Châu"),
+    ("VN-02", "Lào Cai"),
+    ("VN-03", "Hà Giang"),
+    ("VN-04", "Cao Bằng"),
+    ("VN-05", "Sơn La"),
+    ("VN-06", "Yên Bái"),
+    ("VN-07", "Tuyên Quang"),
+    ("VN-09", "Lạng Sơn"),
+    ("VN-13", "Quảng Ninh"),
+    ("VN-14", "Hòa Bình"),
+    ("VN-18", "Ninh Bình"),
+    ("VN-20", "Thái Bình"),
+    ("VN-21", "Thanh Hóa"),
+    ("VN-22", "Nghệ An"),
+    ("VN-23", "Hà Tĩnh"),
+    ("VN-24", "Quảng Bình"),
+    ("VN-25", "Quảng Trị"),
+    ("VN-26", "Thừa Thiên-Huế"),
+    ("VN-27", "Quảng Nam"),
+    ("VN-28", "Kon Tum"),
+    ("VN-29", "Quảng Ngãi"),
+    ("VN-30", "Gia Lai"),
+    ("VN-31", "Bình Định"),
+    ("VN-32", "Phú Yên"),
+    ("VN-33", "Đắk Lắk"),
+    ("VN-34", "Khánh Hòa"),
+    ("VN-35", "Lâm Đồng"),
+    ("VN-36", "Ninh Thuận"),
+    ("VN-37", "Tây Ninh"),
+    ("VN-39", "Đồng Nai"),
+    ("VN-40", "Bình Thuận"),
+    ("VN-41", "Long An"),
+    ("VN-43", "Bà Rịa - Vũng Tàu"),
+    ("VN-44", "An Giang"),
+    ("VN-45", "Đồng Tháp"),
+    ("VN-46", "Tiền Giang"),
+    ("VN-47", "Kiến Giang"),
+    ("VN-49", "Vĩnh Long"),
+    ("VN-50", "Bến Tre"),
+    ("VN-51", "Trà Vinh"),
+    ("VN-52", "Sóc Trăng"),
+    ("VN-53", "Bắc Kạn"),
+    ("VN-54", "Bắc Giang"),
+    ("VN-55", "Bạc Liêu"),
+    ("VN-56", "Bắc Ninh"),
+    ("VN-57", "Bình Dương"),
+    ("VN-58", "Bình Phước"),
+    ("VN-59", "Cà Mau"),
+    ("VN-61", "Hải Dương"),
+    ("VN-63", "Hà Nam"),
+    ("VN-66", "Hưng Yên"),
+    ("VN-67", "Nam Định"),
+    ("VN-68", "Phú Thọ"),
+    ("VN-69", "Thái Nguyên"),
+    ("VN-70", "Vĩnh Phúc"),
+    ("VN-71", "Điện Biên"),
+    ("VN-72", "Đắk Nông"),
+    ("VN-73", "Hậu Giang"),
+    ("VN-CT", "Cần Thơ"),
+    ("VN-DN", "Đà Nẵng"),
+    ("VN-HN", "Hà Nội"),
+    ("VN-HP", "Hải Phòng"),
+    ("VN-SG", "Hồ Chí Minh"),
+    ("VU-MAP", "Malampa"),
+    ("VU-PAM", "Pénama"),
+    ("VU-SAM", "Sanma"),
+    ("VU-SEE", "Shéfa"),
+    ("VU-TAE", "Taféa"),
+    ("VU-TOB", "Torba"),
+    ("WF-AL", "Alo"),
+    ("WF-SG", "Sigave"),
+    ("WF-UV", "Uvea"),
+    ("WS-AA", "A'ana"),
+    ("WS-AL", "Aiga-i-le-Tai"),
+    ("WS-AT", "Atua"),
+    ("WS-FA", "Fa'asaleleaga"),
+    ("WS-GE", "Gaga'emauga"),
+    ("WS-GI", "Gagaifomauga"),
+    ("WS-PA", "Palauli"),
+    ("WS-SA", "Satupa'itea"),
+    ("WS-TU", "Tuamasaga"),
+    ("WS-VF", "Va'a-o-Fonoti"),
+    ("WS-VS", "Vaisigano"),
+    ("YE-AB", "Abyan"),
+    ("YE-AD", "‘Adan"),
+    ("YE-AM", "‘Amrān"),
+    ("YE-BA", "Al Bayḑā’"),
+    ("YE-DA", "Aḑ Ḑāli‘"),
+    ("YE-DH", "Dhamār"),
+    ("YE-HD", "Ḩaḑramawt"),
+    ("YE-HJ", "Ḩajjah"),
+    ("YE-HU", "Al Ḩudaydah"),
+    ("YE-IB", "Ibb"),
+    ("YE-JA", "Al Jawf"),
+    ("YE-LA", "Laḩij"),
+    ("YE-MA", "Ma’rib"),
+    ("YE-MR", "Al Mahrah"),
+    ("YE-MW", "Al Maḩwīt"),
+    ("YE-RA", "Raymah"),
+    ("YE-SA", "Amānat al ‘Āşimah [city]"),
+    ("YE-SD", "Şāʻdah"),
+    ("YE-SH", "Shabwah"),
+    ("YE-SN", "Şanʻā’"),
+    ("YE-SU", "Arkhabīl Suquţrá"),
+    ("YE-TA", "Tāʻizz"),
+    ("ZA-EC", "Eastern Cape"),
+    ("ZA-FS", "Free State"),
+    ("ZA-GP", "Gauteng"),
+    ("ZA-KZN", "Kwazulu-Natal"),
+    ("ZA-LP", "Limpopo"),
+    ("ZA-MP", "Mpumalanga"),
+    ("ZA-NC", "Northern Cape"),
+    ("ZA-NW", "North-West"),
+    ("ZA-WC", "Western Cape"),
+    ("ZM-01", "Western"),
+    ("ZM-02", "Central"),
+    ("ZM-03", "Eastern"),
+    ("ZM-04", "Luapula"),
+    ("ZM-05", "Northern"),
+    ("ZM-06", "North-Western"),
+    ("ZM-07", "Southern"),
+    ("ZM-08", "Copperbelt"),
+    ("ZM-09", "Lusaka"),
+    ("ZM-10", "Muchinga"),
+    ("ZW-BU", "Bulawayo"),
+    ("ZW-HA", "Harare"),
+    ("ZW-MA", "Manicaland"),
+    ("ZW-MC", "Mashonaland Central"),
+    ("ZW-ME", "Mashonaland East"),
+    ("ZW-MI", "Midlands"),
+    ("ZW-MN", "Matabeleland North"),
+    ("ZW-MS", "Matabeleland South"),
+    ("ZW-MV", "Masvingo"),
+    ("ZW-MW", "Mashonaland West"),
+];
+
+// country names that differ from the English ones, sorted by code and locale
+pub(super) const TRANSLATIONS: &[(&str, &str, &str)] = &[
+    ("AD", "el", "Ανδόρρα"),
+    ("AD", "fr", "Andorre"),
+    ("AD", "he", "אנדורה"),
+    ("AD", "ja", "アンドラ"),
+    ("AD", "ko", "안도라"),
+    ("AD", "pl", "Andora"),
+    ("AD", "ro", "Andora"),
+    ("AD", "ru", "Андорра"),
+    ("AD", "th", "อันดอร์รา"),
+    ("AD", "uk", "Андорра"),
+    ("AD", "vi", "Ăn-đoa-râ"),
+    ("AD", "zh-cn", "安道尔"),
+    ("AD", "zh-tw", "安道爾"),
+    ("AE", "cs", "Spojené arabské emiráty"),
+    ("AE", "da", "Forenede Arabiske Emirater"),
+    ("AE", "de", "Vereinigte Arabische Emirate"),
+    ("AE", "el", "Ηνωμένα Αραβικά Εμιράτα"),
+    ("AE", "es", "Emiratos Árabes Unidos"),
+    ("AE", "fi", "Yhdistyneet arabiemiirikunnat"),
+    ("AE", "fr", "Émirats arabes unis"),
+    ("AE", "he", "איחוד האמירויות הערביות"),
+    ("AE", "hu", "Egyesült Arab Emírségek"),
+    ("AE", "id", "Uni Emirat Arab"),
+    ("AE", "it", "Emirati Arabi Uniti"),
+    ("AE", "ja", "アラブ首長国連邦"),
+    ("AE", "ko", "아랍에미리트"),
+    ("AE", "nl", "Verenigde Arabische Emiraten"),
+    ("AE", "pl", "Zjednoczone Emiraty Arabskie"),
+    ("AE", "pt", "Emirados Árabes Unidos"),
+    ("AE", "ro", "Emiratele Arabe Unite"),
+    ("AE", "ru", "Объединённые Арабские Эмираты"),
+    ("AE", "sv", "Förenade Arabemiraten"),
+    ("AE", "th", "สหรัฐอาหรับเอมิเรตส์"),
+    ("AE", "tr", "Birleşik Arap Emirlikleri"),
+    ("AE", "uk", "Об’єднані Арабські Емірати"),
+    ("AE", "vi", "Các Tiểu Vương Quốc A-rập Thống Nhất"),
+    ("AE", "zh-cn", "阿联酋"),
+    ("AE", "zh-tw", "阿拉伯聯合大公國"),
+    ("AF", "cs", "Afghánistán"),
+    ("AF", "el", "Αφγανιστάν"),
+    ("AF", "es", "Afganistán"),
+    ("AF", "he", "אפגניסטן"),
+    ("AF", "hu", "Afganisztán"),
+    ("AF", "id", "Afganistan"),
+    ("AF", "ja", "アフガニスタン"),
+    ("AF", "ko", "아프가니스탄"),
+    ("AF", "pl", "Afganistan"),
+    ("AF", "pt", "Afeganistão"),
+    ("AF", "ro", "Afganistan"),
+    ("AF", "ru", "Афганистан"),
+    ("AF", "th", "อัฟกานิสถาน"),
+    ("AF", "tr", "Afganistan"),
+    ("AF", "uk", "Афганістан"),
+    ("AF", "vi", "A Phú Hãn"),
+    ("AF", "zh-cn", "阿富汗"),
+    ("AF", "zh-tw", "阿富汗"),
+    ("AG", "cs", "Antigua a Barbuda"),
+    ("AG", "da", "Antigua og Barbuda"),
+    ("AG", "de", "Antigua und Barbuda"),
+    ("AG", "el", "Αντίγκουα και Μπαρμπούντα"),
+    ("AG", "es", "Antigua y Barbuda"),
+    ("AG", "fi", "Antigua ja Barbuda"),
+    ("AG", "fr", "Antigua-et-Barbuda"),
+    ("AG", "he", "אנטיגואה וברבודה"),
+    ("AG", "hu", "Antigua és Barbuda"),
+    ("AG", "id", "Antigua dan Barbuda"),
+    ("AG", "it", "Antigua e Barbuda"),
+    ("AG", "ja", "アンティグア・バーブーダ"),
+    ("AG", "ko", "앤티가 바부다"),
+    ("AG", "nl", "Antigua en Barbuda"),
+    ("AG", "pl", "Antigua i Barbuda"),
+    ("AG", "pt", "Antígua e Barbuda"),
+    ("AG", "ro", "Antigua și Barbuda"),
+    ("AG", "ru", "Антигуа и Барбуда"),
+    ("AG", "sv", "Antigua och Barbuda"),
+    ("AG", "th", "แอนติกาและบาร์บูดา"),
+    ("AG", "tr", "Antigua ve Barbuda"),
+    ("AG", "uk", "Антигуа і Барбуда"),
+    ("AG", "vi", "Ănh-thí-gua và Ba-bu-đa"),
+    ("AG", "zh-cn", "安提瓜和巴布达"),
+    ("AG", "zh-tw", "安地卡及巴布達"),
+    ("AI", "el", "Ανγκουίλα"),
+    ("AI", "es", "Anguila"),
+    ("AI", "he", "אנגווילה"),
+    ("AI", "ja", "アングイラ"),
+    ("AI", "ko", "앵귈라"),
+    ("AI", "pt", "Anguila"),
+    ("AI", "ru", "Ангвилла"),
+    ("AI", "th", "แองกวิลลา"),
+    ("AI", "uk", "Ангілья"),
+    ("AI", "vi", "Ăng-ouí-la"),
+    ("AI", "zh-cn", "安圭拉"),
+    ("AI", "zh-tw", "安圭拉"),
+    ("AL", "cs", "Albánie"),
+    ("AL", "da", "Albanien"),
+    ("AL", "de", "Albanien"),
+    ("AL", "el", "Αλβανία"),
+    ("AL", "fr", "Albanie"),
+    ("AL", "he", "אלבניה"),
+    ("AL", "hu", "Albánia"),
+    ("AL", "ja", "アルバニア"),
+    ("AL", "ko", "알바니아"),
+    ("AL", "nl", "Albanië"),
+    ("AL", "pt", "Albânia"),
+    ("AL", "ru", "Албания"),
+    ("AL", "sv", "Albanien"),
+    ("AL", "th", "แอลเบเนีย"),
+    ("AL", "tr", "Arnavutluk"),
+    ("AL", "uk", "Албанія"),
+    ("AL", "vi", "An-ba-ni"),
+    ("AL", "zh-cn", "阿尔巴尼亚"),
+    ("AL", "zh-tw", "阿爾巴尼亞"),
+    ("AM", "cs", "Arménie"),
+    ("AM", "da", "Armenien"),
+    ("AM", "de", "Armenien"),
+    ("AM", "el", "Αρμενία"),
+    ("AM", "fr", "Arménie"),
+    ("AM", "he", "ארמניה"),
+    ("AM", "hu", "Örményország"),
+    ("AM", "ja", "アルメニア"),
+    ("AM", "ko", "아르메니아"),
+    ("AM", "nl", "Armenië"),
+    ("AM", "pt", "Armênia"),
+    ("AM", "ru", "Армения"),
+    ("AM", "sv", "Armenien"),
+    ("AM", "th", "อาร์เมเนีย"),
+    ("AM", "tr", "Ermenistan"),
+    ("AM", "uk", "Вірменія"),
+    ("AM", "vi", "Ac-mê-ni"),
+    ("AM", "zh-cn", "亚美尼亚"),
+    ("AM", "zh-tw", "亞美尼亞"),
+    ("AO", "el", "Ανγκόλα"),
+    ("AO", "he", "אנגולה"),
+    ("AO", "ja", "アンゴラ"),
+    ("AO", "ko", "앙골라"),
+    ("AO", "ru", "Ангола"),
+    ("AO", "th", "แองโกลา"),
+    ("AO", "uk", "Ангола"),
+    ("AO", "vi", "Ăng-gô-la"),
+    ("AO", "zh-cn", "安哥拉"),
+    ("AO", "zh-tw", "安哥拉"),
+    ("AQ", "cs", "Antarktida"),
+    ("AQ", "da", "Antarktis"),
+    ("AQ", "de", "Antarktis"),
+    ("AQ", "el", "Ανταρκτική"),
+    ("AQ", "es", "Antártida"),
+    ("AQ", "fi", "Antarktis"),
+    ("AQ", "fr", "Antarctique"),
+    ("AQ", "he", "אנטרקטיקה"),
+    ("AQ", "hu", "Antarktisz"),
+    ("AQ", "id", "Antartika"),
+    ("AQ", "it", "Antartide"),
+    ("AQ", "ja", "南極大陸"),
+    ("AQ", "ko", "남극"),
+    ("AQ", "pl", "Antarktyka"),
+    ("AQ", "pt", "Antártida"),
+    ("AQ", "ru", "Антарктика"),
+    ("AQ", "sv", "Antarktis"),
+    ("AQ", "th", "แอนตาร์กติกา"),
+    ("AQ", "tr", "Antarktika"),
+    ("AQ", "uk", "Антарктида"),
+    ("AQ", "vi", "Nam Cực"),
+    ("AQ", "zh-cn", "南极洲"),
+    ("AQ", "zh-tw", "南極洲"),
+    ("AR", "de", "Argentinien"),
+    ("AR", "el", "Αργεντινή"),
+    ("AR", "fi", "Argentiina"),
+    ("AR", "fr", "Argentine"),
+    ("AR", "he", "ארגנטינה"),
+    ("AR", "hu", "Argentína"),
+    ("AR", "ja", "アルゼンチン"),
+    ("AR", "ko", "아르헨티나"),
+    ("AR", "nl", "Argentinië"),
+    ("AR", "pl", "Argentyna"),
+    ("AR", "ru", "Аргентина"),
+    ("AR", "th", "อาร์เจนตินา"),
+    ("AR", "tr", "Arjantin"),
+    ("AR", "uk", "Аргентина"),
+    ("AR", "vi", "Á-căn-đình"),
+    ("AR", "zh-cn", "阿根廷"),
+    ("AR", "zh-tw", "阿根廷"),
+    ("AS", "cs", "Americká Samoa"),
+    ("AS", "da", "Amerikansk Samoa"),
+    ("AS", "de", "Amerikanisch-Samoa"),
+    ("AS", "el", "Αμερικανική Σαμόα"),
+    ("AS", "es", "Samoa Estadounidense"),
+    ("AS", "fi", "Amerikan Samoa"),
+    ("AS", "fr", "Samoa américaines"),
+    ("AS", "he", "סמואה האמריקנית"),
+    ("AS", "hu", "Amerikai Szamoa"),
+    ("AS", "id", "Samoa Amerika"),
+    ("AS", "it", "Samoa americane"),
+    ("AS", "ja", "米領サモア"),
+    ("AS", "ko", "아메리칸사모아"),
+    ("AS", "nl", "Amerikaans-Samoa"),
+    ("AS", "pl", "Samoa Amerykańskie"),
+    ("AS", "pt", "Samoa Americana"),
+    ("AS", "ro", "Samoa americană"),
+    ("AS", "ru", "Американские Самоа"),
+    ("AS", "sv", "Amerikanska Samoa"),
+    ("AS", "th", "อเมริกันซามัว"),
+    ("AS", "tr", "Amerikan Samoası"),
+    ("AS", "uk", "Американське Самоа"),
+    ("AS", "vi", "Xa-mô-a Mỹ"),
+    ("AS", "zh-cn", "美属萨摩亚"),
+    ("AS", "zh-tw", "美屬薩摩亞"),
+    ("AT", "cs", "Rakousko"),
+    ("AT", "da", "Østrig"),
+    ("AT", "de", "Österreich"),
+    ("AT", "el", "Αυστρία"),
+    ("AT", "fi", "Itävalta"),
+    ("AT", "fr", "Autriche"),
+    ("AT", "he", "אוסטריה"),
+    ("AT", "hu", "Ausztria"),
+    ("AT", "ja", "オーストリア"),
+    ("AT", "ko", "오스트리아"),
+    ("AT", "nl", "Oostenrijk"),
+    ("AT", "pt", "Áustria"),
+    ("AT", "ru", "Австрия"),
+    ("AT", "sv", "Österrike"),
+    ("AT", "th", "ออสเตรีย"),
+    ("AT", "tr", "Avusturya"),
+    ("AT", "uk", "Австрія"),
+    ("AT", "vi", "Ao"),
+    ("AT", "zh-cn", "奥地利"),
+    ("AT", "zh-tw", "奧地利"),
+    ("AU", "cs", "Austrálie"),
+    ("AU", "da", "Australien"),
+    ("AU", "de", "Australien"),
+    ("AU", "el", "Αυστραλία"),
+    ("AU", "fr", "Australie"),
+    ("AU", "he", "אוסטרליה"),
+    ("AU", "hu", "Ausztrália"),
+    ("AU", "ja", "オーストラリア連邦"),
+    ("AU", "ko", "오스트레일리아"),
+    ("AU", "nl", "Australië"),
+    ("AU", "pt", "Austrália"),
+    ("AU", "ru", "Австралия"),
+    ("AU", "sv", "Australien"),
+    ("AU", "th", "ออสเตรเลีย"),
+    ("AU", "tr", "Avustralya"),
+    ("AU", "uk", "Австралія"),
+    ("AU", "vi", "Úc"),
+    ("AU", "zh-cn", "澳大利亚"),
+    ("AU", "zh-tw", "澳大利亞"),
+    ("AW", "el", "Αρούμπα"),
+    ("AW", "he", "ארובה"),
+    ("AW", "ja", "アルーバ"),
+    ("AW", "ko", "아루바"),
+    ("AW", "ru", "Аруба"),
+    ("AW", "th", "อารูบา"),
+    ("AW", "uk", "Аруба"),
+    ("AW", "vi", "Ă-ru-ba"),
+    ("AW", "zh-cn", "阿鲁巴"),
+    ("AW", "zh-tw", "阿路巴"),
+    ("AX", "cs", "Ålandské ostrovy"),
+    ("AX", "da", "Åland"),
+    ("AX", "de", "Åland-Inseln"),
+    ("AX", "el", "Νήσοι Ώλαντ"),
+    ("AX", "es", "Islas Äland"),
+    ("AX", "fi", "Ahvenanmaa"),
+    ("AX", "fr", "Åland, Îles"),
+    ("AX", "he", "אולנד"),
+    ("AX", "hu", "Åland-szigetek"),
+    ("AX", "id", "Kepulauan Åland"),
+    ("AX", "it", "Isole Åland"),
+    ("AX", "ja", "オーランド諸島"),
+    ("AX", "ko", "올란드 제도"),
+    ("AX", "nl", "Ålandseilanden"),
+    ("AX", "pl", "Wyspy Alandzkie"),
+    ("AX", "pt", "Ilhas Åland"),
+    ("AX", "ro", "Insulele Åland"),
+    ("AX", "ru", "Аландские острова"),
+    ("AX", "sv", "Åland"),
+    ("AX", "th", "หมู่เกาะโอลันด์"),
+    ("AX", "tr", "Åland Adaları"),
+    ("AX", "uk", "Аландські острови"),
+    ("AX", "vi", "Quần đảo A-lanh"),
+    ("AX", "zh-cn", "奥兰群岛"),
+    ("AX", "zh-tw", "奧蘭群島"),
+    ("AZ", "cs", "Ázerbájdžán"),
+    ("AZ", "da", "Aserbajdsjan"),
+    ("AZ", "de", "Aserbaidschan"),
+    ("AZ", "el", "Αζερμπαϊτζάν"),
+    ("AZ", "es", "Azerbaiyán"),
+    ("AZ", "fi", "Azerbaidžan"),
+    ("AZ", "fr", "Azerbaïdjan"),
+    ("AZ", "he", "אזרבייג׳ן"),
+    ("AZ", "hu", "Azerbajdzsán"),
+    ("AZ", "it", "Azerbaigian"),
+    ("AZ", "ja", "アゼルバイジャン"),
+    ("AZ", "ko", "아제르바이잔"),
+    ("AZ", "nl", "Azerbeidzjan"),
+    ("AZ", "pl", "Azerbejdżan"),
+    ("AZ", "pt", "Azerbaidjão"),
+    ("AZ", "ru", "Азербайджан"),
+    ("AZ", "sv", "Azerbajdzjan"),
+    ("AZ", "th", "อาเซอร์ไบจาน"),
+    ("AZ", "tr", "Azerbaycan"),
+    ("AZ", "uk", "Азербайджан"),
+    ("AZ", "vi", "Ai-xợ-bai-gianh"),
+    ("AZ", "zh-cn", "阿塞拜疆"),
+    ("AZ", "zh-tw", "亞塞拜然"),
+    ("BA", "cs", "Bosna a Hercegovina"),
+    ("BA", "da", "Bosnien-Hercegovina"),
+    ("BA", "de", "Bosnien und Herzegowina"),
+    ("BA", "el", "Βοσνία και Ερζεγοβίνη"),
+    ("BA", "es", "Bosnia y Herzegovina"),
+    ("BA", "fi", "Bosnia-Hertsegovina"),
+    ("BA", "fr", "Bosnie-Herzégovine"),
+    ("BA", "he", "בוסניה והרצגובינה"),
+    ("BA", "hu", "Bosznia-Hercegovina"),
+    ("BA", "id", "Bosnia dan Herzegovina"),
+    ("BA", "it", "Bosnia-Erzegovina"),
+    ("BA", "ja", "ボスニア・ヘルツェゴビナ"),
+    ("BA", "ko", "보스니아 헤르체고비나"),
+    ("BA", "nl", "Bosnië en Herzegovina"),
+    ("BA", "pl", "Bośnia i Hercegowina"),
+    ("BA", "pt", "Bósnia-Herzegóvina"),
+    ("BA", "ro", "Bosnia și Herțegovina"),
+    ("BA", "ru", "Босния и Герцеговина"),
+    ("BA", "sv", "Bosnien-Hercegovina"),
+    ("BA", "th", "บอสเนียและเฮอร์เซโกวีนา"),
+    ("BA", "tr", "Bosna-Hersek"),
+    ("BA", "uk", "Боснія і Герцеговина"),
+    ("BA", "vi", "Bô-xni-a và Hẻ-xê-gô-vi-na"),
+    ("BA", "zh-cn", "波斯尼亚和黑塞哥维那"),
+    ("BA", "zh-tw", "波士尼亞及赫塞哥維納"),
+    ("BB", "el", "Μπαρμπάντος"),
+    ("BB", "fr", "Barbade"),
+    ("BB", "he", "ברבדוס"),
+    ("BB", "ja", "バルバドス"),
+    ("BB", "ko", "바베이도스"),
+    ("BB", "ru", "Барбадос"),
+    ("BB", "th", "บาร์เบโดส"),
+    ("BB", "uk", "Барбадос"),
+    ("BB", "vi", "Bă-ba-đôxợ"),
+    ("BB", "zh-cn", "巴巴多斯"),
+    ("BB", "zh-tw", "巴貝多"),
+    ("BD", "cs", "Bangladéš"),
+    ("BD", "de", "Bangladesch"),
+    ("BD", "el", "Μπανγκλαντές"),
+    ("BD", "es", "Bangladés"),
+    ("BD", "he", "בנגלדש"),
+    ("BD", "hu", "Banglades"),
+    ("BD", "ja", "バングラデシュ"),
+    ("BD", "ko", "방글라데시"),
+    ("BD", "pl", "Bangladesz"),
+    ("BD", "ro", "Bangladeș"),
+    ("BD", "ru", "Бангладеш"),
+    ("BD", "th", "บังกลาเทศ"),
+    ("BD", "tr", "Bangladeş"),
+    ("BD", "uk", "Бангладеш"),
+    ("BD", "vi", "Bang-la-đesợ"),
+    ("BD", "zh-cn", "孟加拉"),
+    ("BD", "zh-tw", "孟加拉"),
+    ("BE", "cs", "Belgie"),
+    ("BE", "da", "Belgien"),
+    ("BE", "de", "Belgien"),
+    ("BE", "el", "Βέλγιο"),
+    ("BE", "es", "Bélgica"),
+    ("BE", "fi", "Belgia"),
+    ("BE", "fr", "Belgique"),
+    ("BE", "he", "בלגיה"),
+    ("BE", "id", "Belgia"),
+    ("BE", "it", "Belgio"),
+    ("BE", "ja", "ベルギー"),
+    ("BE", "ko", "벨기에"),
+    ("BE", "nl", "België"),
+    ("BE", "pl", "Belgia"),
+    ("BE", "pt", "Bélgica"),
+    ("BE", "ro", "Belgia"),
+    ("BE", "ru", "Бельгия"),
+    ("BE", "sv", "Belgien"),
+    ("BE", "th", "เบลเยียม"),
+    ("BE", "tr", "Belçika"),
+    ("BE", "uk", "Бельгія"),
+    ("BE", "vi", "Bỉ"),
+    ("BE", "zh-cn", "比利时"),
+    ("BE", "zh-tw", "比利時"),
+    ("BF", "el", "Μπουρκίνα Φάσο"),
+    ("BF", "es", "Burquina Faso"),
+    ("BF", "he", "בורקינה פאסו"),
+    ("BF", "ja", "ブルキナファソ"),
+    ("BF", "ko", "부르키나파소"),
+    ("BF", "pt", "Burquina"),
+    ("BF", "ru", "Буркина-Фасо"),
+    ("BF", "th", "บูร์กินาฟาโซ"),
+    ("BF", "uk", "Буркіна-Фасо"),
+    ("BF", "vi", "Buốc-khi-na Pha-xô"),
+    ("BF", "zh-cn", "布基纳法索"),
+    ("BF", "zh-tw", "布吉納法索"),
+    ("BG", "cs", "Bulharsko"),
+    ("BG", "da", "Bulgarien"),
+    ("BG", "de", "Bulgarien"),
+    ("BG", "el", "Βουλγαρία"),
+    ("BG", "fr", "Bulgarie"),
+    ("BG", "he", "בולגריה"),
+    ("BG", "hu", "Bulgária"),
+    ("BG", "ja", "ブルガリア"),
+    ("BG", "ko", "불가리아"),
+    ("BG", "nl", "Bulgarije"),
+    ("BG", "pl", "Bułgaria"),
+    ("BG", "pt", "Bulgária"),
+    ("BG", "ru", "Болгария"),
+    ("BG", "sv", "Bulgarien"),
+    ("BG", "th", "บัลแกเรีย"),
+    ("BG", "tr", "Bulgaristan"),
+    ("BG", "uk", "Болгарія"),
+    ("BG", "vi", "Bua-ga-ri"),
+    ("BG", "zh-cn", "保加利亚"),
+    ("BG", "zh-tw", "保加利亞"),
+    ("BH", "cs", "Bahrajn"),
+    ("BH", "el", "Μπαχρέιν"),
+    ("BH", "es", "Baréin"),
+    ("BH", "fr", "Bahreïn"),
+    ("BH", "he", "בחריין"),
+    ("BH", "hu", "Bahrein"),
+    ("BH", "it", "Bahrein"),
+    ("BH", "ja", "バーレーン"),
+    ("BH", "ko", "바레인"),
+    ("BH", "nl", "Bahrein"),
+    ("BH", "pl", "Bahrajn"),
+    ("BH", "pt", "Barein"),
+    ("BH", "ro", "Bahrein"),
+    ("BH", "ru", "Бахрейн"),
+    ("BH", "th", "บาห์เรน"),
+    ("BH", "tr", "Bahreyn"),
+    ("BH", "uk", "Бахрейн"),
+    ("BH", "vi", "Ba-rainh"),
+    ("BH", "zh-cn", "巴林"),
+    ("BH", "zh-tw", "巴林"),
+    ("BI", "el", "Μπουρούντι"),
+    ("BI", "he", "בורונדי"),
+    ("BI", "ja", "ブルンジ"),
+    ("BI", "ko", "부룬디"),
+    ("BI", "ru", "Бурунди"),
+    ("BI", "th", "บุรุนดี"),
+    ("BI", "uk", "Бурунді"),
+    ("BI", "vi", "Bu-run-đi"),
+    ("BI", "zh-cn", "布隆迪"),
+    ("BI", "zh-tw", "蒲隆地"),
+    ("BJ", "el", "Μπενίν"),
+    ("BJ", "es", "Benín"),
+    ("BJ", "fr", "Bénin"),
+    ("BJ", "he", "בנין"),
+    ("BJ", "ja", "ベナン"),
+    ("BJ", "ko", "베냉"),
+    ("BJ", "ru", "Бенин"),
+    ("BJ", "th", "เบนิน"),
+    ("BJ", "uk", "Бенін"),
+    ("BJ", "vi", "Bê-ninh"),
+    ("BJ", "zh-cn", "贝宁"),
+    ("BJ", "zh-tw", "貝南"),
+    ("BL", "cs", "Svatý Bartoloměj"),
+    ("BL", "da", "Sankt Bartolomæus"),
+    ("BL", "de", "Saint-Barthélemy"),
+    ("BL", "el", "Άγιος Βαρθολομαίος"),
+    ("BL", "es", "San Bartolomé"),
+    ("BL", "fr", "Saint-Barthélemy"),
+    ("BL", "he", "סנט ברתלמי"),
+    ("BL", "it", "Saint-Barthélemy"),
+    ("BL", "ja", "サンバルテルミ"),
+    ("BL", "ko", "생바르텔레미"),
+    ("BL", "nl", "Saint-Barthélemy"),
+    ("BL", "pl", "Saint-Barthélemy"),
+    ("BL", "pt", "São Bartolomeu"),
+    ("BL", "ro", "Sfântul Bartolomeu"),
+    ("BL", "ru", "Сен-Бартельми"),
+    ("BL", "sv", "Saint-Barthélemy"),
+    ("BL", "th", "แซงบาร์เตเลอมี"),
+    ("BL", "uk", "Сен-Бартельмі"),
+    ("BL", "zh-cn", "圣巴泰勒米岛"),
+    ("BL", "zh-tw", "聖巴瑟米"),
+    ("BM", "cs", "Bermudy"),
+    ("BM", "el", "Βερμούδες"),
+    ("BM", "es", "Islas Bermudas"),
+    ("BM", "fr", "Bermudes"),
+    ("BM", "he", "ברמודה"),
+    ("BM", "ja", "バーミューダ"),
+    ("BM", "ko", "버뮤다"),
+    ("BM", "pl", "Bermudy"),
+    ("BM", "ro", "Bermude"),
+    ("BM", "ru", "Бермуды"),
+    ("BM", "th", "เบอร์มิวดา"),
+    ("BM", "uk", "Бермудські острови"),
+    ("BM", "vi", "Be-mu-đa"),
+    ("BM", "zh-cn", "百慕大"),
+    ("BM", "zh-tw", "百慕達"),
+    ("BN", "cs", "Brunej"),
+    ("BN", "da", "Brunei"),
+    ("BN", "el", "Μπρουνέι Νταρουσαλάμ"),
+    ("BN", "fi", "Brunei Darussalamin valtio"),
+    ("BN", "fr", "Brunéi Darussalam"),
+    ("BN", "he", "ברונאי דרוסלאלם"),
+    ("BN", "hu", "Brunei Darussalam Állam"),
+    ("BN", "it", "Brunei"),
+    ("BN", "ja", "ブルネイ・ダルサラーム国"),
+    ("BN", "ko", "브루나이 다루살람"),
+    ("BN", "nl", "Brunei"),
+    ("BN", "pl", "Państwo Brunei"),
+    ("BN", "pt", "Brunei"),
+    ("BN", "ro", "Brunei"),
+    ("BN", "ru", "Бруней Даруссалам"),
+    ("BN", "sv", "Brunei"),
+    ("BN", "th", "บรูไนดารุสซาลาม"),
+    ("BN", "tr", "Brunei Krallığı"),
+    ("BN", "uk", "Бруней"),
+    ("BN", "vi", "Bợru-này Đa-ru-xa-làm"),
+    ("BN", "zh-cn", "文莱"),
+    ("BN", "zh-tw", "汶萊"),
+    ("BO", "cs", "Bolívie"),
+    ("BO", "de", "Bolivien"),
+    ("BO", "el", "Βολιβία"),
+    ("BO", "fr", "Bolivie"),
+    ("BO", "he", "בוליביה"),
+    ("BO", "hu", "Bolívia"),
+    ("BO", "ja", "ボリビア"),
+    ("BO", "ko", "볼리비아"),
+    ("BO", "pl", "Boliwia"),
+    ("BO", "pt", "Bolívia"),
+    ("BO", "ru", "Боливия"),
+    ("BO", "th", "โบลิเวีย"),
+    ("BO", "tr", "Bolivya"),
+    ("BO", "uk", "Болівія"),
+    ("BO", "vi", "Bô-li-vi-a"),
+    ("BO", "zh-cn", "波利维亚"),
+    ("BO", "zh-tw", "玻利維亞"),
+    ("BQ", "cs", "Bonaire, Svatý Eustach a Saba"),
+    ("BQ", "da", "Bonaire, Sint Eustatius og Saba"),
+    ("BQ", "de", "Bonaire, Sint Eustatius und Saba"),
+    ("BQ", "el", "Μποναίρ, Άγιος Ευστράτιος και Σάμπα"),
+    ("BQ", "es", "Islas BES (Caribe Neerlandés)"),
+    ("BQ", "fi", "Bonaire, Sint Eustatius ja Saba"),
+    ("BQ", "fr", "Bonaire, Saint-Eustache et Saba"),
+    ("BQ", "he", "בונייר, סנט אוסטתיוס וסאבא"),
+    ("BQ", "hu", "Bonaire, Saint Eustatius és Saba"),
+    ("BQ", "it", "Paesi Bassi caraibici"),
+    ("BQ", "ja", "ボネール、シントユースタティウス及びサバ"),
+    ("BQ", "ko", "보네르, 신트외스타티위스, 사바 섬"),
+    ("BQ", "nl", "Bonaire, Sint Eustatius en Saba"),
+    ("BQ", "pl", "Bonaire, Sint Eustatius i Saba"),
+    ("BQ", "pt", "Bonaire, Saba e Santo Eustáquio"),
+    ("BQ", "ro", "Bonaire, Sint Eustatius și Saba"),
+    ("BQ", "ru", "Бонайре, Синт-Эстатиус и Саба"),
+    ("BQ", "sv", "Bonaire, Sint Eustatius och Saba"),
+    ("BQ", "th", "บอแนร์, เซนต์ยูสเตเชียส และ เซบา"),
+    ("BQ", "tr", "Bonaire, Sint Eustatius ve Saba"),
+    ("BQ", "uk", "Бонайре, Сінт-Естатіус і Саба"),
+    ("BQ", "vi", "Bông-Ne, Xin E-u-xờ-ta-ti-tút và Xa-ba"),
+    ("BQ", "zh-cn", "博奈尔、圣尤斯特歇斯岛和萨巴"),
+    ("BQ", "zh-tw", "波內赫、聖尤斯特歇斯及薩巴"),
+    ("BR", "cs", "Brazílie"),
+    ("BR", "da", "Brasilien"),
+    ("BR", "de", "Brasilien"),
+    ("BR", "el", "Βραζιλία"),
+    ("BR", "es", "Brasil"),
+    ("BR", "fi", "Brasilia"),
+    ("BR", "fr", "Brésil"),
+    ("BR", "he", "ברזיל"),
+    ("BR", "hu", "Brazília"),
+    ("BR", "id", "Brasil"),
+    ("BR", "it", "Brasile"),
+    ("BR", "ja", "ブラジル"),
+    ("BR", "ko", "브라질"),
+    ("BR", "nl", "Brazilië"),
+    ("BR", "pl", "Brazylia"),
+    ("BR", "pt", "Brasil"),
+    ("BR", "ro", "Brazilia"),
+    ("BR", "ru", "Бразилия"),
+    ("BR", "sv", "Brasilien"),
+    ("BR", "th", "บราซิล"),
+    ("BR", "tr", "Brezilya"),
+    ("BR", "uk", "Бразилія"),
+    ("BR", "vi", "Bra-xin"),
+    ("BR", "zh-cn", "巴西"),
+    ("BR", "zh-tw", "巴西"),
+    ("BS", "cs", "Bahamy"),
+    ("BS", "el", "Μπαχάμες"),
+    ("BS", "fi", "Bahama"),
+    ("BS", "he", "בהמאס"),
+    ("BS", "hu", "Bahama-szigetek"),
+    ("BS", "id", "Bahama"),
+    ("BS", "ja", "バハマ"),
+    ("BS", "ko", "바하마"),
+    ("BS", "nl", "Bahama's"),
+    ("BS", "pl", "Bahamy"),
+    ("BS", "ru", "Багамы"),
+    ("BS", "th", "บาฮามาส"),
+    ("BS", "tr", "Bahamalar"),
+    ("BS", "uk", "Багамські острови"),
+    ("BS", "vi", "Ba-ha-ma"),
+    ("BS", "zh-cn", "巴哈马"),
+    ("BS", "zh-tw", "巴哈馬"),
+    ("BT", "cs", "Bhútán"),
+    ("BT", "el", "Μπουτάν"),
+    ("BT", "es", "Bután"),
+    ("BT", "fr", "Bhoutan"),
+    ("BT", "he", "בהוטן"),
+    ("BT", "hu", "Bhután"),
+    ("BT", "ja", "ブータン"),
+    ("BT", "ko", "부탄"),
+    ("BT", "pt", "Butão"),
+    ("BT", "ru", "Бутан"),
+    ("BT", "th", "ภูฏาน"),
+    ("BT", "uk", "Бутан"),
+    ("BT", "vi", "Bu-thănh"),
+    ("BT", "zh-cn", "不丹"),
+    ("BT", "zh-tw", "不丹"),
+    ("BV", "cs", "Bouvetův ostrov"),
+    ("BV", "da", "Bouvet-øen"),
+    ("BV", "de", "Bouvet-Insel"),
+    ("BV", "el", "Νήσος Μπουβέ"),
+    ("BV", "es", "Isla Bouvet"),
+    ("BV", "fi", "Bouvet'nsaari"),
+    ("BV", "fr", "île Bouvet"),
+    ("BV", "he", "בובה"),
+    ("BV", "hu", "Bouvet-sziget"),
+    ("BV", "id", "Pulau Bouvet"),
+    ("BV", "it", "Isola Bouvet"),
+    ("BV", "ja", "ブーベ島"),
+    ("BV", "ko", "부베 섬"),
+    ("BV", "nl", "Bouveteiland"),
+    ("BV", "pl", "Wyspa Bouveta"),
+    ("BV", "pt", "Ilha Bouvet"),
+    ("BV", "ro", "Insula Bouvet"),
+    ("BV", "ru", "Остров Буве"),
+    ("BV", "sv", "Bouvetön"),
+    ("BV", "th", "เกาะบูเวต์"),
+    ("BV", "tr", "Bouvet Adası"),
+    ("BV", "uk", "Острів Буве"),
+    ("BV", "vi", "Quần đảo Bu-vê"),
+    ("BV", "zh-cn", "布维群岛"),
+    ("BV", "zh-tw", "布威島"),
+    ("BW", "de", "Botsuana"),
+    ("BW", "el", "Μποτσουάνα"),
+    ("BW", "es", "Botsuana"),
+    ("BW", "he", "בוטסואנה"),
+    ("BW", "ja", "ボツワナ"),
+    ("BW", "ko", "보츠와나"),
+    ("BW", "pt", "Botsuana"),
+    ("BW", "ru", "Ботсвана"),
+    ("BW", "th", "บอตสวานา"),
+    ("BW", "tr", "Botsvana"),
+    ("BW", "uk", "Ботсвана"),
+    ("BW", "vi", "Bốt-xoă-na"),
+    ("BW", "zh-cn", "博兹瓦那"),
+    ("BW", "zh-tw", "波札那"),
+    ("BY", "cs", "Bělorusko"),
+    ("BY", "da", "Hviderusland"),
+    ("BY", "el", "Λευκορωσία"),
+    ("BY", "es", "Bielorrusia"),
+    ("BY", "fi", "Valko-Venäjä"),
+    ("BY", "fr", "Bélarus"),
+    ("BY", "he", "בלארוס"),
+    ("BY", "hu", "Fehéroroszország"),
+    ("BY", "it", "Bielorussia"),
+    ("BY", "ja", "ベラルーシ"),
+    ("BY", "ko", "벨라루스"),
+    ("BY", "nl", "Wit-Rusland"),
+    ("BY", "pl", "Białoruś"),
+    ("BY", "pt", "Bielo-Rússia"),
+    ("BY", "ro", "Bielorusia"),
+    ("BY", "ru", "Беларусь"),
+    ("BY", "sv", "Vitryssland"),
+    ("BY", "th", "เบลารุส"),
+    ("BY", "uk", "Білорусь"),
+    ("BY", "vi", "Be-la-ruxợ"),
+    ("BY", "zh-cn", "白俄罗斯"),
+    ("BY", "zh-tw", "白俄羅斯"),
+    ("BZ", "el", "Μπελίζ"),
+    ("BZ", "es", "Belice"),
+    ("BZ", "he", "בליז"),
+    ("BZ", "ja", "ベリーズ"),
+    ("BZ", "ko", "벨리즈"),
+    ("BZ", "ru", "Белиз"),
+    ("BZ", "th", "เบลีซ"),
+    ("BZ", "uk", "Беліз"),
+    ("BZ", "vi", "Bê-li-xê"),
+    ("BZ", "zh-cn", "伯利兹"),
+    ("BZ", "zh-tw", "貝里斯"),
+    ("CA", "cs", "Kanada"),
+    ("CA", "de", "Kanada"),
+    ("CA", "el", "Καναδάς"),
+    ("CA", "es", "Canadá"),
+    ("CA", "fi", "Kanada"),
+    ("CA", "he", "קנדה"),
+    ("CA", "hu", "Kanada"),
+    ("CA", "id", "Kanada"),
+    ("CA", "ja", "カナダ"),
+    ("CA", "ko", "캐나다"),
+    ("CA", "pl", "Kanada"),
+    ("CA", "pt", "Canadá"),
+    ("CA", "ru", "Канада"),
+    ("CA", "sv", "Kanada"),
+    ("CA", "th", "แคนาดา"),
+    ("CA", "tr", "Kanada"),
+    ("CA", "uk", "Канада"),
+    ("CA", "vi", "Ca-na-đa"),
+    ("CA", "zh-cn", "加拿大"),
+    ("CA", "zh-tw", "加拿大"),
+    ("CC", "cs", "Kokosové ostrovy"),
+    ("CC", "da", "Cocosøerne (Keelingøerne)"),
+    ("CC", "de", "Kokos-(Keeling-)Inseln"),
+    ("CC", "el", "Νήσοι Κόκος (Κήλινγκ)"),
+    ("CC", "es", "Islas Cocos (Keeling)"),
+    ("CC", "fi", "Kookossaaret"),
+    ("CC", "fr", "Cocos (Keeling), Îles"),
+    ("CC", "he", "איי קוקוס"),
+    ("CC", "hu", "Kókusz (Keeling)-szigetek"),
+    ("CC", "id", "Kepulauan Cocos (Keeling)"),
+    ("CC", "it", "Isole Cocos (Keeling)"),
+    ("CC", "ja", "ココス (キーリング) 諸島"),
+    ("CC", "ko", "코코스 제도"),
+    ("CC", "nl", "Cocoseilanden (Keelingeilanden)"),
+    ("CC", "pl", "Wyspy Kokosowe (Wyspy Keelinga)"),
+    ("CC", "pt", "Ilhas Cocos"),
+    ("CC", "ro", "Insulele Cocos (Keeling)"),
+    ("CC", "ru", "Кокосовые острова"),
+    ("CC", "sv", "Kokosöarna"),
+    ("CC", "th", "หมู่เกาะโคโคส (คีลิง)"),
+    ("CC", "tr", "Cocos (Keeling) Adaları"),
+    ("CC", "uk", "Кокосові (Кілінг) острови"),
+    ("CC", "vi", "Quần đảo Co-co-xợ (Khi-lịng)"),
+    ("CC", "zh-cn", "科科斯群岛"),
+    ("CC", "zh-tw", "科科斯 (基林) 群島"),
+    ("CD", "cs", "Konžská demokratická republika"),
+    ("CD", "da", "Den Demokratiske Republik Congo"),
+    ("CD", "de", "Demokratische Republik Kongo"),
+    ("CD", "el", "Κονγκό, Λαϊκή Δημοκρατία του"),
+    ("CD", "es", "Congo, República Democrática del"),
+    ("CD", "fi", "Kongon demokraattinen tasavalta"),
+    ("CD", "fr", "République démocratique du Congo"),
+    ("CD", "he", "קונגו, הרפובליקה הדמוקרטית של"),
+    ("CD", "hu", "Kongói Demokratikus Köztársaság"),
+    ("CD", "id", "Republik Demokrat Congo"),
+    ("CD", "it", "Repubblica democratica del Congo"),
+    ("CD", "ja", "コンゴ民主共和国"),
+    ("CD", "ko", "콩고 민주 공화국"),
+    ("CD", "nl", "Congo, Democratische Republiek"),
+    ("CD", "pl", "Kongo, Demokratyczna Republika Konga"),
+    ("CD", "pt", "Congo, República Democrática do"),
+    ("CD", "ro", "Congo, Republica democrată"),
+    ("CD", "ru", "Демократическая Республика Конго"),
+    ("CD", "sv", "Kongo, demokratiska republiken"),
+    ("CD", "th", "คองโก, สาธารณรัฐประชาธิปไตย"),
+    ("CD", "tr", "Kongo Demokratik Cumhuriyeti"),
+    ("CD", "uk", "Конго, демократична республіка"),
+    ("CD", "vi", "Cộng hoà Dân chủ Công-gô"),
+    ("CD", "zh-cn", "刚果民主共和国"),
+    ("CD", "zh-tw", "剛果民主共和國"),
+    ("CF", "cs", "Středoafrická republika"),
+    ("CF", "da", "Centralafrikanske Republik"),
+    ("CF", "de", "Zentralafrikanische Republik"),
+    ("CF", "el", "Δημοκρατία Κεντρικής Αφρικής"),
+    ("CF", "es", "República Centroafricana"),
+    ("CF", "fi", "Keski-Afrikan tasavalta"),
+    ("CF", "fr", "République centrafricaine"),
+    ("CF", "he", "הרפובליקה המרכז־אפריקאית"),
+    ("CF", "hu", "Közép-afrikai Köztársaság"),
+    ("CF", "id", "Republik Afrika Tengah"),
+    ("CF", "it", "Repubblica Centrafricana"),
+    ("CF", "ja", "中央アフリカ共和国"),
+    ("CF", "ko", "중앙아프리카 공화국"),
+    ("CF", "nl", "Centraal-Afrikaanse Republiek"),
+    ("CF", "pl", "Republika Środkowoafrykańska"),
+    ("CF", "pt", "República Centro-Africana"),
+    ("CF", "ro", "Republica Central Africană"),
+    ("CF", "ru", "Центрально-африканская республика"),
+    ("CF", "sv", "Centralafrikanska republiken"),
+    ("CF", "th", "สาธารณรัฐแอฟริกากลาง"),
+    ("CF", "tr", "Orta Afrika Cumhuriyeti"),
+    ("CF", "uk", "Центральноафриканська Республіка"),
+    ("CF", "vi", "Nước Cộng Hoà Trung Phi"),
+    ("CF", "zh-cn", "中非"),
+    ("CF", "zh-tw", "中非共和國"),
+    ("CG", "cs", "Kongo"),
+    ("CG", "de", "Kongo"),
+    ("CG", "el", "Κονγκό"),
+    ("CG", "fi", "Kongo"),
+    ("CG", "fr", "République du Congo"),
+    ("CG", "he", "קונגו"),
+    ("CG", "hu", "Kongó"),
+    ("CG", "ja", "コンゴ"),
+    ("CG", "ko", "콩고"),
+    ("CG", "pl", "Kongo"),
+    ("CG", "ru", "Конго"),
+    ("CG", "sv", "Kongo"),
+    ("CG", "th", "คองโก"),
+    ("CG", "tr", "Kongo"),
+    ("CG", "uk", "Конго"),
+    ("CG", "vi", "Công-gô"),
+    ("CG", "zh-cn", "刚果"),
+    ("CG", "zh-tw", "剛果"),
+    ("CH", "cs", "Švýcarsko"),
+    ("CH", "da", "Schweiz"),
+    ("CH", "de", "Schweiz"),
+    ("CH", "el", "Ελβετία"),
+    ("CH", "es", "Suiza"),
+    ("CH", "fi", "Sveitsi"),
+    ("CH", "fr", "Suisse"),
+    ("CH", "he", "שווייץ"),
+    ("CH", "hu", "Svájc"),
+    ("CH", "id", "Swiss"),
+    ("CH", "it", "Svizzera"),
+    ("CH", "ja", "スイス"),
+    ("CH", "ko", "스위스"),
+    ("CH", "nl", "Zwitserland"),
+    ("CH", "pl", "Szwajcaria"),
+    ("CH", "pt", "Suíça"),
+    ("CH", "ro", "Elveția"),
+    ("CH", "ru", "Швейцария"),
+    ("CH", "sv", "Schweiz"),
+    ("CH", "th", "สวิตเซอร์แลนด์"),
+    ("CH", "tr", "İsviçre"),
+    ("CH", "uk", "Швейцарія"),
+    ("CH", "vi", "Thụy Sĩ"),
+    ("CH", "zh-cn", "瑞士"),
+    ("CH", "zh-tw", "瑞士"),
+    ("CI", "cs", "Pobřeží slonoviny"),
+    ("CI", "da", "Elfenbenskysten"),
+    ("CI", "el", "Ακτή Ελεφαντοστού"),
+    ("CI", "es", "Costa de Marfíl"),
+    ("CI", "fi", "Norsunluurannikko"),
+    ("CI", "he", "חוף השנהב"),
+    ("CI", "hu", "Elefántcsontpart"),
+    ("CI", "id", "Pantai Gading"),
+    ("CI", "it", "Costa d'Avorio"),
+    ("CI", "ja", "コートジボワール"),
+    ("CI", "ko", "코트디부아르"),
+    ("CI", "nl", "Ivoorkust"),
+    ("CI", "pl", "Wybrzeże Kości Słoniowej"),
+    ("CI", "pt", "Costa do Marfim"),
+    ("CI", "ro", "Coasta de Fildeș"),
+    ("CI", "ru", "Кот-д'Ивуар"),
+    ("CI", "sv", "Elfenbenskusten"),
+    ("CI", "th", "โกตดิวัวร์"),
+    ("CI", "tr", "Fildişi Sahili"),
+    ("CI", "uk", "Кот-д'Івуар"),
+    ("CI", "vi", "Cốt đi-vouă"),
+    ("CI", "zh-cn", "科特迪瓦"),
+    ("CI", "zh-tw", "象牙海岸"),
+    ("CK", "cs", "Cookovy ostrovy"),
+    ("CK", "da", "Cookøerne"),
+    ("CK", "de", "Cookinseln"),
+    ("CK", "el", "Νήσοι Κουκ"),
+    ("CK", "es", "Islas Cook"),
+    ("CK", "fi", "Cookinsaaret"),
+    ("CK", "fr", "îles Cook"),
+    ("CK", "he", "איי קוק"),
+    ("CK", "hu", "Cook-szigetek"),
+    ("CK", "id", "Kepulauan Cook"),
+    ("CK", "it", "Isole Cook"),
+    ("CK", "ja", "クック諸島"),
+    ("CK", "ko", "쿡 제도"),
+    ("CK", "nl", "Cookeilanden"),
+    ("CK", "pl", "Wyspy Cooka"),
+    ("CK", "pt", "Ilhas Cook"),
+    ("CK", "ro", "Insulele Cook"),
+    ("CK", "ru", "Острова Кука"),
+    ("CK", "sv", "Cooköarna"),
+    ("CK", "th", "หมู่เกาะคุก"),
+    ("CK", "tr", "Cook Adaları"),
+    ("CK", "uk", "Острови Кука"),
+    ("CK", "vi", "Quần đảo Khu-khợ"),
+    ("CK", "zh-cn", "库克群岛"),
+    ("CK", "zh-tw", "庫克群島"),
+    ("CL", "el", "Χιλή"),
+    ("CL", "fr", "Chili"),
+    ("CL", "he", "צ'ילה"),
+    ("CL", "id", "Chili"),
+    ("CL", "it", "Cile"),
+    ("CL", "ja", "チリ"),
+    ("CL", "ko", "칠레"),
+    ("CL", "nl", "Chili"),
+    ("CL", "ru", "Чили"),
+    ("CL", "th", "ชิลี"),
+    ("CL", "tr", "Şili"),
+    ("CL", "uk", "Чилі"),
+    ("CL", "vi", "Chi-lê"),
+    ("CL", "zh-cn", "智利"),
+    ("CL", "zh-tw", "智利"),
+    ("CM", "cs", "Kamerun"),
+    ("CM", "da", "Cameroun"),
+    ("CM", "de", "Kamerun"),
+    ("CM", "el", "Καμερούν"),
+    ("CM", "es", "Camerún"),
+    ("CM", "fi", "Kamerun"),
+    ("CM", "fr", "Cameroun"),
+    ("CM", "he", "קמרון"),
+    ("CM", "hu", "Kamerun"),
+    ("CM", "id", "Kamerun"),
+    ("CM", "it", "Camerun"),
+    ("CM", "ja", "カメルーン"),
+    ("CM", "ko", "카메룬"),
+    ("CM", "nl", "Kameroen"),
+    ("CM", "pl", "Kamerun"),
+    ("CM", "pt", "Camarões"),
+    ("CM", "ro", "Camerun"),
+    ("CM", "ru", "Камерун"),
+    ("CM", "sv", "Kamerun"),
+    ("CM", "th", "แคเมอรูน"),
+    ("CM", "tr", "Kamerun"),
+    ("CM", "uk", "Камерун"),
+    ("CM", "vi", "Ca-mơ-runh"),
+    ("CM", "zh-cn", "喀麦隆"),
+    ("CM", "zh-tw", "喀麥隆"),
+    ("CN", "cs", "Čína"),
+    ("CN", "da", "Kina"),
+    ("CN", "el", "Κίνα"),
+    ("CN", "fi", "Kiina"),
+    ("CN", "fr", "Chine"),
+    ("CN", "he", "סין"),
+    ("CN", "hu", "Kína"),
+    ("CN", "id", "Cina"),
+    ("CN", "it", "Cina"),
+    ("CN", "ja", "中国"),
+    ("CN", "ko", "중국"),
+    ("CN", "pl", "Chiny"),
+    ("CN", "ru", "Китай"),
+    ("CN", "sv", "Kina"),
+    ("CN", "th", "จีน"),
+    ("CN", "tr", "Çin"),
+    ("CN", "uk", "Китай"),
+    ("CN", "vi", "Trung Quốc"),
+    ("CN", "zh-cn", "中国"),
+    ("CN", "zh-tw", "中國"),
+    ("CO", "cs", "Kolumbie"),
+    ("CO", "de", "Kolumbien"),
+    ("CO", "el", "Κολομβία"),
+    ("CO", "fi", "Kolumbia"),
+    ("CO", "fr", "Colombie"),
+    ("CO", "he", "קולומביה"),
+    ("CO", "hu", "Kolumbia"),
+    ("CO", "id", "Kolombia"),
+    ("CO", "ja", "コロンビア"),
+    ("CO", "ko", "콜롬비아"),
+    ("CO", "pl", "Kolumbia"),
+    ("CO", "pt", "Colômbia"),
+    ("CO", "ro", "Columbia"),
+    ("CO", "ru", "Колумбия"),
+    ("CO", "th", "โคลอมเบีย"),
+    ("CO", "tr", "Kolombiya"),
+    ("CO", "uk", "Колумбія"),
+    ("CO", "vi", "Cô-lôm-bi-a"),
+    ("CO", "zh-cn", "哥伦比亚"),
+    ("CO", "zh-tw", "哥倫比亞"),
+    ("CR", "cs", "Kostarika"),
+    ("CR", "el", "Κόστα Ρίκα"),
+    ("CR", "he", "קוסטה ריקה"),
+    ("CR", "id", "Kosta Rika"),
+    ("CR", "ja", "コスタリカ"),
+    ("CR", "ko", "코스타리카"),
+    ("CR", "pl", "Kostaryka"),
+    ("CR", "ru", "Коста-Рика"),
+    ("CR", "th", "คอสตาริกา"),
+    ("CR", "tr", "Kosta Rika"),
+    ("CR", "uk", "Коста-Рика"),
+    ("CR", "vi", "Cốt-x-tha Ri-ca"),
+    ("CR", "zh-cn", "哥斯达黎加"),
+    ("CR", "zh-tw", "哥斯大黎加"),
+    ("CU", "cs", "Kuba"),
+    ("CU", "de", "Kuba"),
+    ("CU", "el", "Κούβα"),
+    ("CU", "fi", "Kuuba"),
+    ("CU", "he", "קובה"),
+    ("CU", "hu", "Kuba"),
+    ("CU", "id", "Kuba"),
+    ("CU", "ja", "キューバ"),
+    ("CU", "ko", "쿠바"),
+    ("CU", "pl", "Kuba"),
+    ("CU", "ru", "Куба"),
+    ("CU", "sv", "Kuba"),
+    ("CU", "th", "คิวบา"),
+    ("CU", "tr", "Küba"),
+    ("CU", "uk", "Куба"),
+    ("CU", "vi", "Cu-ba"),
+    ("CU", "zh-cn", "古巴"),
+    ("CU", "zh-tw", "古巴"),
+    ("CV", "cs", "Kapverdské ostrovy"),
+    ("CV", "da", "Kap Verde"),
+    ("CV", "de", "Kap Verde"),
+    ("CV", "el", "Πράσινο Ακρωτήριο"),
+    ("CV", "fr", "Cap-Vert"),
+    ("CV", "he", "קאבו ורדה"),
+    ("CV", "hu", "Zöld-foki-szigetek"),
+    ("CV", "it", "Capo Verde"),
+    ("CV", "ja", "カーボヴェルデ"),
+    ("CV", "ko", "카보베르데"),
+    ("CV", "nl", "Kaapverdië"),
+    ("CV", "pl", "Republika Zielonego Przylądka"),
+    ("CV", "ro", "Capul Verde"),
+    ("CV", "ru", "Кабо-Верде"),
+    ("CV", "sv", "Kap Verde"),
+    ("CV", "th", "กาบูเวร์ดี"),
+    ("CV", "tr", "Yeşil Burun Adaları"),
+    ("CV", "uk", "Кабо-Верде"),
+    ("CV", "zh-cn", "佛得角"),
+    ("CV", "zh-tw", "維德角"),
+    ("CW", "el", "Κουρασάο"),
+    ("CW", "es", "Curazao"),
+    ("CW", "he", "קוראסאו"),
+    ("CW", "ja", "キュラソー"),
+    ("CW", "ko", "퀴라소"),
+    ("CW", "ru", "Кюрасао"),
+    ("CW", "th", "คิวราเซา"),
+    ("CW", "uk", "Кюрасао"),
+    ("CW", "vi", "Cu-ra-cao"),
+    ("CW", "zh-cn", "库拉索"),
+    ("CW", "zh-tw", "古拉索"),
+    ("CX", "cs", "Vánoční ostrov"),
+    ("CX", "da", "Juleøen"),
+    ("CX", "de", "Weihnachtsinseln"),
+    ("CX", "el", "Νήσοι Χριστουγέννων"),
+    ("CX", "es", "Isla de Navidad"),
+    ("CX", "fi", "Joulusaari"),
+    ("CX", "fr", "Christmas, Île"),
+    ("CX", "he", "איי חג המולד"),
+    ("CX", "hu", "Karácsony-sziget"),
+    ("CX", "id", "Kepulauan Christmas"),
+    ("CX", "it", "Isola di Natale"),
+    ("CX", "ja", "クリスマス島"),
+    ("CX", "ko", "크리스마스 섬"),
+    ("CX", "nl", "Christmaseiland"),
+    ("CX", "pl", "Wyspa Bożego Narodzenia"),
+    ("CX", "pt", "Ilha Christmas"),
+    ("CX", "ro", "Insula Crăciunului"),
+    ("CX", "ru", "Остров Рождества"),
+    ("CX", "sv", "Julön"),
+    ("CX", "th", "เกาะคริสต์มาส"),
+    ("CX", "tr", "Christmas Adası"),
+    ("CX", "uk", "Острів Різдва"),
+    ("CX", "vi", "Đảo Kh-ri-xợ-mà-xợ"),
+    ("CX", "zh-cn", "圣诞岛"),
+    ("CX", "zh-tw", "聖誕島"),
+    ("CY", "cs", "Kypr"),
+    ("CY", "da", "Cypern"),
+    ("CY", "de", "Zypern"),
+    ("CY", "el", "Κύπρος"),
+    ("CY", "es", "Chipre"),
+    ("CY", "fi", "Kypros"),
+    ("CY", "fr", "Chypre"),
+    ("CY", "he", "קפריסין"),
+    ("CY", "hu", "Ciprus"),
+    ("CY", "id", "Siprus"),
+    ("CY", "it", "Cipro"),
+    ("CY", "ja", "キプロス"),
+    ("CY", "ko", "키프로스"),
+    ("CY", "pl", "Cypr"),
+    ("CY", "pt", "Chipre"),
+    ("CY", "ro", "Cipru"),
+    ("CY", "ru", "Кипр"),
+    ("CY", "sv", "Cypern"),
+    ("CY", "th", "ไซปรัส"),
+    ("CY", "tr", "Kıbrıs"),
+    ("CY", "uk", "Кіпр"),
+    ("CY", "vi", "Síp"),
+    ("CY", "zh-cn", "塞浦路斯"),
+    ("CY", "zh-tw", "賽普勒斯"),
+    ("CZ", "cs", "Česko"),
+    ("CZ", "da", "Tjekkiet"),
+    ("CZ", "de", "Tschechien"),
+    ("CZ", "el", "Τσεχία"),
+    ("CZ", "es", "Chequia"),
+    ("CZ", "fr", "Tchéquie"),
+    ("CZ", "he", "צ'כיה"),
+    ("CZ", "hu", "Csehország"),
+    ("CZ", "it", "Cechia"),
+    ("CZ", "ko", "체코"),
+    ("CZ", "nl", "Tsjechië"),
+    ("CZ", "pl", "Czechy"),
+    ("CZ", "pt", "Chéquia"),
+    ("CZ", "ro", "Cehia"),
+    ("CZ", "ru", "Чехия"),
+    ("CZ", "sv", "Tjeckien"),
+    ("CZ", "th", "ประเทศเช็กเกีย"),
+    ("CZ", "tr", "Çekya"),
+    ("CZ", "uk", "Чехія"),
+    ("CZ", "zh-cn", "捷克"),
+    ("CZ", "zh-tw", "捷克"),
+    ("DE", "cs", "Německo"),
+    ("DE", "da", "Tyskland"),
+    ("DE", "de", "Deutschland"),
+    ("DE", "el", "Γερμανία"),
+    ("DE", "es", "Alemania"),
+    ("DE", "fi", "Saksa"),
+    ("DE", "fr", "Allemagne"),
+    ("DE", "he", "גרמניה"),
+    ("DE", "hu", "Németország"),
+    ("DE", "id", "Jerman"),
+    ("DE", "it", "Germania"),
+    ("DE", "ja", "ドイツ"),
+    ("DE", "ko", "독일"),
+    ("DE", "nl", "Duitsland"),
+    ("DE", "pl", "Niemcy"),
+    ("DE", "pt", "Alemanha"),
+    ("DE", "ro", "Germania"),
+    ("DE", "ru", "Германия"),
+    ("DE", "sv", "Tyskland"),
+    ("DE", "th", "เยอรมนี"),
+    ("DE", "tr", "Almanya"),
+    ("DE", "uk", "Німеччина"),
+    ("DE", "vi", "Đức"),
+    ("DE", "zh-cn", "德国"),
+    ("DE", "zh-tw", "德國"),
+    ("DJ", "cs", "Džibutsko"),
+    ("DJ", "de", "Dschibuti"),
+    ("DJ", "el", "Τζιμπουτί"),
+    ("DJ", "es", "Yibuti"),
+    ("DJ", "he", "ג׳יבוטי"),
+    ("DJ", "hu", "Dzsibuti"),
+    ("DJ", "it", "Gibuti"),
+    ("DJ", "ja", "ジブチ"),
+    ("DJ", "ko", "지부티"),
+    ("DJ", "pl", "Dżibuti"),
+    ("DJ", "pt", "Djibuti"),
+    ("DJ", "ru", "Джибути"),
+    ("DJ", "th", "จิบูตี"),
+    ("DJ", "tr", "Cibuti"),
+    ("DJ", "uk", "Джибуті"),
+    ("DJ", "vi", "Gi-bu-ti"),
+    ("DJ", "zh-cn", "吉布提"),
+    ("DJ", "zh-tw", "吉布地"),
+    ("DK", "cs", "Dánsko"),
+    ("DK", "da", "Danmark"),
+    ("DK", "de", "Dänemark"),
+    ("DK", "el", "Δανία"),
+    ("DK", "es", "Dinamarca"),
+    ("DK", "fi", "Tanska"),
+    ("DK", "fr", "Danemark"),
+    ("DK", "he", "דנמרק"),
+    ("DK", "hu", "Dánia"),
+    ("DK", "it", "Danimarca"),
+    ("DK", "ja", "デンマーク"),
+    ("DK", "ko", "덴마크"),
+    ("DK", "nl", "Denemarken"),
+    ("DK", "pl", "Dania"),
+    ("DK", "pt", "Dinamarca"),
+    ("DK", "ro", "Danemarca"),
+    ("DK", "ru", "Дания"),
+    ("DK", "sv", "Danmark"),
+    ("DK", "th", "เดนมาร์ก"),
+    ("DK", "tr", "Danimarka"),
+    ("DK", "uk", "Данія"),
+    ("DK", "vi", "Đan Mạch"),
+    ("DK", "zh-cn", "丹麦"),
+    ("DK", "zh-tw", "丹麥"),
+    ("DM", "cs", "Dominika"),
+    ("DM", "el", "Ντομίνικα"),
+    ("DM", "fr", "Dominique"),
+    ("DM", "he", "דומיניקה"),
+    ("DM", "hu", "Dominika"),
+    ("DM", "id", "Dominika"),
+    ("DM", "ja", "ドミニカ"),
+    ("DM", "ko", "도미니카 연방"),
+    ("DM", "pl", "Dominika"),
+    ("DM", "pt", "Domínica"),
+    ("DM", "ru", "Доминика"),
+    ("DM", "th", "โดมินิกา"),
+    ("DM", "tr", "Dominika"),
+    ("DM", "uk", "Домініка"),
+    ("DM", "vi", "Đô-mi-ni-cạ"),
+    ("DM", "zh-cn", "多米尼克"),
+    ("DM", "zh-tw", "多米尼克"),
+    ("DO", "cs", "Dominikánská republika"),
+    ("DO", "da", "Dominikanske Republik"),
+    ("DO", "de", "Dominikanische Republik"),
+    ("DO", "el", "Δομινικανή Δημοκρατία"),
+    ("DO", "es", "República Dominicana"),
+    ("DO", "fi", "Dominikaaninen tasavalta"),
+    ("DO", "fr", "République dominicaine"),
+    ("DO", "he", "הרפובליקה הדומיניקנית"),
+    ("DO", "hu", "Dominikai Köztársaság"),
+    ("DO", "id", "Republik Dominika"),
+    ("DO", "it", "Repubblica Dominicana"),
+    ("DO", "ja", "ドミニカ共和国"),
+    ("DO", "ko", "도미니카 공화국"),
+    ("DO", "nl", "Dominicaanse Republiek"),
+    ("DO", "pl", "Republika Dominikańska"),
+    ("DO", "pt", "República Dominicana"),
+    ("DO", "ro", "Republica Dominicană"),
+    ("DO", "ru", "Доминиканская республика"),
+    ("DO", "sv", "Dominikanska republiken"),
+    ("DO", "th", "สาธารณรัฐโดมินิกัน"),
+    ("DO", "tr", "Dominik Cumhuriyeti"),
+    ("DO", "uk", "Домініканська республіка"),
+    ("DO", "vi", "Cộng hoà Đô-mi-ni-cạ"),
+    ("DO", "zh-cn", "多米尼加共和国"),
+    ("DO", "zh-tw", "多明尼加共和國"),
+    ("DZ", "cs", "Alžírsko"),
+    ("DZ", "da", "Algeriet"),
+    ("DZ", "de", "Algerien"),
+    ("DZ", "el", "Αλγερία"),
+    ("DZ", "fr", "Algérie"),
+    ("DZ", "he", "אלג'יריה"),
+    ("DZ", "hu", "Algéria"),
+    ("DZ", "id", "Aljazair"),
+    ("DZ", "ja", "アルジェリア"),
+    ("DZ", "ko", "알제리"),
+    ("DZ", "nl", "Algerije"),
+    ("DZ", "pl", "Algieria"),
+    ("DZ", "pt", "Argélia"),
+    ("DZ", "ru", "Алжир"),
+    ("DZ", "sv", "Algeriet"),
+    ("DZ", "th", "แอลจีเรีย"),
+    ("DZ", "tr", "Cezayir"),
+    ("DZ", "uk", "Алжир"),
+    ("DZ", "vi", "An-giê-ri"),
+    ("DZ", "zh-cn", "阿尔及利亚"),
+    ("DZ", "zh-tw", "阿爾及利亞"),
+    ("EC", "cs", "Ekvádor"),
+    ("EC", "el", "Ισημερινός"),
+    ("EC", "fr", "Équateur"),
+    ("EC", "he", "אקוודור"),
+    ("EC", "id", "Ekuador"),
+    ("EC", "ja", "エクアドル"),
+    ("EC", "ko", "에콰도르"),
+    ("EC", "pl", "Ekwador"),
+    ("EC", "pt", "Equador"),
+    ("EC", "ru", "Эквадор"),
+    ("EC", "th", "เอกวาดอร์"),
+    ("EC", "tr", "Ekvador"),
+    ("EC", "uk", "Еквадор"),
+    ("EC", "vi", "Ê-cu-a-đoa"),
+    ("EC", "zh-cn", "厄瓜多尔"),
+    ("EC", "zh-tw", "厄瓜多"),
+    ("EE", "cs", "Estonsko"),
+    ("EE", "da", "Estland"),
+    ("EE", "de", "Estland"),
+    ("EE", "el", "Εσθονία"),
+    ("EE", "fi", "Viro"),
+    ("EE", "fr", "Estonie"),
+    ("EE", "he", "אסטוניה"),
+    ("EE", "hu", "Észtország"),
+    ("EE", "ja", "エストニア"),
+    ("EE", "ko", "에스토니아"),
+    ("EE", "nl", "Estland"),
+    ("EE", "pt", "Estônia"),
+    ("EE", "ru", "Эстония"),
+    ("EE", "sv", "Estland"),
+    ("EE", "th", "เอสโตเนีย"),
+    ("EE", "tr", "Estonya"),
+    ("EE", "uk", "Естонія"),
+    ("EE", "vi", "E-xợ-tô-ni-a"),
+    ("EE", "zh-cn", "爱沙尼亚"),
+    ("EE", "zh-tw", "愛沙尼亞"),
+    ("EG", "da", "Egypten"),
+    ("EG", "de", "Ägypten"),
+    ("EG", "el", "Αίγυπτος"),
+    ("EG", "es", "Egipto"),
+    ("EG", "fi", "Egypti"),
+    ("EG", "fr", "Égypte"),
+    ("EG", "he", "מצרים"),
+    ("EG", "hu", "Egyiptom"),
+    ("EG", "id", "Mesir"),
+    ("EG", "it", "Egitto"),
+    ("EG", "ja", "エジプト"),
+    ("EG", "ko", "이집트"),
+    ("EG", "nl", "Egypte"),
+    ("EG", "pl", "Egipt"),
+    ("EG", "pt", "Egito"),
+    ("EG", "ro", "Egipt"),
+    ("EG", "ru", "Египет"),
+    ("EG", "sv", "Egypten"),
+    ("EG", "th", "อียิปต์"),
+    ("EG", "tr", "Mısır"),
+    ("EG", "uk", "Єгипет"),
+    ("EG", "vi", "Ai Cập"),
+    ("EG", "zh-cn", "埃及"),
+    ("EG", "zh-tw", "埃及"),
+    ("EH", "cs", "Západní Sahara"),
+    ("EH", "da", "Vestsahara"),
+    ("EH", "de", "Westsahara"),
+    ("EH", "el", "Δυτική Σαχάρα"),
+    ("EH", "es", "Sahara Occidental"),
+    ("EH", "fi", "Länsi-Sahara"),
+    ("EH", "fr", "Sahara occidental"),
+    ("EH", "he", "סהרה המערבית"),
+    ("EH", "hu", "Nyugat-Szahara"),
+    ("EH", "id", "Sahara Barat"),
+    ("EH", "it", "Sahara occidentale"),
+    ("EH", "ja", "西サハラ"),
+    ("EH", "ko", "서사하라"),
+    ("EH", "nl", "Westelijke Sahara"),
+    ("EH", "pl", "Sahara Zachodnia"),
+    ("EH", "pt", "Saara Ocidental"),
+    ("EH", "ro", "Sahara de Vest"),
+    ("EH", "ru", "Западная Сахара"),
+    ("EH", "sv", "Västsahara"),
+    ("EH", "th", "เวสเทิร์นสะฮารา"),
+    ("EH", "tr", "Batı Sahra"),
+    ("EH", "uk", "Західна Сахара"),
+    ("EH", "vi", "Tây Sa-ha-ra"),
+    ("EH", "zh-cn", "西撒哈拉"),
+    ("EH", "zh-tw", "西撒哈拉"),
+    ("ER", "el", "Ερυθραία"),
+    ("ER", "fr", "Érythrée"),
+    ("ER", "he", "אריתריאה"),
+    ("ER", "ja", "エリトリア国"),
+    ("ER", "ko", "에리트레아"),
+    ("ER", "pl", "Erytrea"),
+    ("ER", "pt", "Eritréia"),
+    ("ER", "ro", "Eritreea"),
+    ("ER", "ru", "Эритрея"),
+    ("ER", "th", "เอริเทรีย"),
+    ("ER", "tr", "Eritre"),
+    ("ER", "uk", "Еритрея"),
+    ("ER", "vi", "Ê-ri-tơ-rê-a"),
+    ("ER", "zh-cn", "厄立特里亚"),
+    ("ER", "zh-tw", "厄利垂亞"),
+    ("ES", "cs", "Španělsko"),
+    ("ES", "da", "Spanien"),
+    ("ES", "de", "Spanien"),
+    ("ES", "el", "Ισπανία"),
+    ("ES", "es", "España"),
+    ("ES", "fi", "Espanja"),
+    ("ES", "fr", "Espagne"),
+    ("ES", "he", "ספרד"),
+    ("ES", "hu", "Spanyolország"),
+    ("ES", "id", "Spanyol"),
+    ("ES", "it", "Spagna"),
+    ("ES", "ja", "スペイン"),
+    ("ES", "ko", "스페인"),
+    ("ES", "nl", "Spanje"),
+    ("ES", "pl", "Hiszpania"),
+    ("ES", "pt", "Espanha"),
+    ("ES", "ro", "Spania"),
+    ("ES", "ru", "Испания"),
+    ("ES", "sv", "Spanien"),
+    ("ES", "th", "สเปน"),
+    ("ES", "tr", "İspanya"),
+    ("ES", "uk", "Іспанія"),
+    ("ES", "vi", "Tây Ban Nha"),
+    ("ES", "zh-cn", "西班牙"),
+    ("ES", "zh-tw", "西班牙"),
+    ("ET", "cs", "Etiopie"),
+    ("ET", "da", "Etiopien"),
+    ("ET", "de", "Äthiopien"),
+    ("ET", "el", "Αιθιοπία"),
+    ("ET", "es", "Etiopía"),
+    ("ET", "fi", "Etiopia"),
+    ("ET", "fr", "Éthiopie"),
+    ("ET", "he", "אתיופיה"),
+    ("ET", "hu", "Etiópia"),
+    ("ET", "it", "Etiopia"),
+    ("ET", "ja", "エチオピア"),
+    ("ET", "ko", "에티오피아"),
+    ("ET", "nl", "Ethiopië"),
+    ("ET", "pl", "Etiopia"),
+    ("ET", "pt", "Etiópia"),
+    ("ET", "ro", "Etiopia"),
+    ("ET", "ru", "Эфиопия"),
+    ("ET", "sv", "Etiopien"),
+    ("ET", "th", "เอธิโอเปีย"),
+    ("ET", "tr", "Etiyopya"),
+    ("ET", "uk", "Ефіопія"),
+    ("ET", "vi", "Ê-ti-ô-pi-a"),
+    ("ET", "zh-cn", "埃塞俄比亚"),
+    ("ET", "zh-tw", "衣索比亞"),
+    ("FI", "cs", "Finsko"),
+    ("FI", "de", "Finnland"),
+    ("FI", "el", "Φινλανδία"),
+    ("FI", "es", "Finlandia"),
+    ("FI", "fi", "Suomi"),
+    ("FI", "fr", "Finlande"),
+    ("FI", "he", "פינלנד"),
+    ("FI", "hu", "Finnország"),
+    ("FI", "id", "Finlandia"),
+    ("FI", "it", "Finlandia"),
+    ("FI", "ja", "フィンランド"),
+    ("FI", "ko", "핀란드"),
+    ("FI", "pl", "Finlandia"),
+    ("FI", "pt", "Finlândia"),
+    ("FI", "ro", "Finlanda"),
+    ("FI", "ru", "Финляндия"),
+    ("FI", "th", "ฟินแลนด์"),
+    ("FI", "tr", "Finlandiya"),
+    ("FI", "uk", "Фінляндія"),
+    ("FI", "vi", "Phần Lan"),
+    ("FI", "zh-cn", "芬兰"),
+    ("FI", "zh-tw", "芬蘭"),
+    ("FJ", "cs", "Fidži"),
+    ("FJ", "de", "Fidschi"),
+    ("FJ", "el", "Φίτζι"),
+    ("FJ", "es", "Fiyi"),
+    ("FJ", "fi", "Fidži"),
+    ("FJ", "fr", "Fidji"),
+    ("FJ", "he", "פיג'י"),
+    ("FJ", "hu", "Fidzsi-szigetek"),
+    ("FJ", "it", "Figi"),
+    ("FJ", "ja", "フィジー"),
+    ("FJ", "ko", "피지"),
+    ("FJ", "pl", "Fidżi"),
+    ("FJ", "ru", "Фиджи"),
+    ("FJ", "th", "ฟิจิ"),
+    ("FJ", "uk", "Фіджі"),
+    ("FJ", "vi", "Phi-gi"),
+    ("FJ", "zh-cn", "斐济"),
+    ("FJ", "zh-tw", "斐濟"),
+    ("FK", "cs", "Falkandské ostrovy (Malvíny)"),
+    ("FK", "da", "Falklandsøerne (Malvinas)"),
+    ("FK", "de", "Falklandinseln (Malwinen)"),
+    ("FK", "el", "Νήσοι Φώκλαντ (Μαλβίνες)"),
+    ("FK", "es", "Islas Falkland (Malvinas)"),
+    ("FK", "fi", "Falklandinsaaret"),
+    ("FK", "fr", "Malouines, Îles (Falkland)"),
+    ("FK", "he", "איי פוקלנד"),
+    ("FK", "hu", "Falkland-szigetek (Malvinas)"),
+    ("FK", "id", "Kepulauan Falkland (Malvinas)"),
+    ("FK", "it", "Isole Falkland (Malvine)"),
+    ("FK", "ja", "フォークランド諸島 (マルビナス)"),
+    ("FK", "ko", "포클랜드 제도 (말비나스)"),
+    ("FK", "nl", "Falklandeilanden (Malvinas)"),
+    ("FK", "pl", "Falklandy (Malwiny)"),
+    ("FK", "pt", "Ilhas Malvinas (Falkland)"),
+    ("FK", "ro", "Insulele Falkland (Insulele Malvine)"),
+    ("FK", "ru", "Фолклендские (Мальвинские) острова"),
+    ("FK", "sv", "Falklandsöarna (Malvinas)"),
+    ("FK", "th", "หมู่เกาะฟอล์กแลนด์ (มาลบีนาส)"),
+    ("FK", "tr", "Falkland Adaları (Malvinas)"),
+    ("FK", "uk", "Фолклендські острови (Британія)"),
+    ("FK", "vi", "Quần Đảo Phoa-kh-lận-đợ (Man-vi-na)"),
+    ("FK", "zh-cn", "福克兰群岛(马尔维纳斯)"),
+    ("FK", "zh-tw", "福克蘭群島 (馬維娜斯)"),
+    ("FM", "cs", "Mikronésie, federativní státy"),
+    ("FM", "da", "Mikronesiens Forenede Stater"),
+    ("FM", "de", "Mikronesien, Föderierte Staaten von"),
+    ("FM", "el", "Μικρονησία, Ομόσπονδες Πολιτείες της"),
+    ("FM", "es", "Micronesia, Estados Federados de"),
+    ("FM", "fi", "Mikronesian liittovaltio"),
+    ("FM", "fr", "Micronésie, États fédérés de"),
+    ("FM", "he", "מיקרונזיה"),
+    ("FM", "hu", "Mikronézia, Államszövetség"),
+    ("FM", "id", "Federasi Negara-negara Micronesia"),
+    ("FM", "it", "Micronesia"),
+    ("FM", "ja", "ミクロネシア連邦"),
+    ("FM", "ko", "미크로네시아 연방"),
+    ("FM", "nl", "Micronesia"),
+    ("FM", "pl", "Mikronezja"),
+    ("FM", "pt", "Micronésia, Estados Federados da"),
+    ("FM", "ro", "Micronesia, Statele federale ale"),
+    ("FM", "ru", "Федеративные Штаты Микронезии"),
+    ("FM", "sv", "Mikronesien, federala staterna"),
+    ("FM", "th", "ไมโครนีเซีย"),
+    ("FM", "tr", "Mikronezya Federe Devletleri"),
+    ("FM", "uk", "Мікронезія, федеративні штати"),
+    ("FM", "vi", "Mi-khợ-rô-nê-xi-a, Liên Bang"),
+    ("FM", "zh-cn", "密克罗尼西亚"),
+    ("FM", "zh-tw", "密克羅尼西亞聯邦"),
+    ("FO", "cs", "Faerské ostrovy"),
+    ("FO", "da", "Færøerne"),
+    ("FO", "de", "Färöer-Inseln"),
+    ("FO", "el", "Νησιά Φερόε"),
+    ("FO", "es", "Islas Feroe"),
+    ("FO", "fi", "Färsaaret"),
+    ("FO", "fr", "îles Féroé"),
+    ("FO", "he", "איי פארו"),
+    ("FO", "hu", "Feröer"),
+    ("FO", "id", "Kepulauan Faroe"),
+    ("FO", "it", "Isole Fær Øer"),
+    ("FO", "ja", "フェロー諸島"),
+    ("FO", "ko", "페로 제도"),
+    ("FO", "nl", "Faeröer"),
+    ("FO", "pl", "Wyspy Owcze"),
+    ("FO", "pt", "Ilhas Faroe"),
+    ("FO", "ro", "Insulele Feroe"),
+    ("FO", "ru", "Фарерские острова"),
+    ("FO", "sv", "Färöarna"),
+    ("FO", "th", "หมู่เกาะแฟโร"),
+    ("FO", "tr", "Faroe Adaları"),
+    ("FO", "uk", "Фарерські острови"),
+    ("FO", "vi", "Quần đảo Pha-rô"),
+    ("FO", "zh-cn", "法罗群岛"),
+    ("FO", "zh-tw", "法羅群島"),
+    ("FR", "cs", "Francie"),
+    ("FR", "da", "Frankrig"),
+    ("FR", "de", "Frankreich"),
+    ("FR", "el", "Γαλλία"),
+    ("FR", "es", "Francia"),
+    ("FR", "fi", "Ranska"),
+    ("FR", "he", "צרפת"),
+    ("FR", "hu", "Franciaország"),
+    ("FR", "id", "Perancis"),
+    ("FR", "it", "Francia"),
+    ("FR", "ja", "フランス"),
+    ("FR", "ko", "프랑스"),
+    ("FR", "nl", "Frankrijk"),
+    ("FR", "pl", "Francja"),
+    ("FR", "pt", "França"),
+    ("FR", "ro", "Franța"),
+    ("FR", "ru", "Франция"),
+    ("FR", "sv", "Frankrike"),
+    ("FR", "th", "ฝรั่งเศส"),
+    ("FR", "tr", "Fransa"),
+    ("FR", "uk", "Франція"),
+    ("FR", "vi", "Pháp"),
+    ("FR", "zh-cn", "法国"),
+    ("FR", "zh-tw", "法國"),
+    ("GA", "de", "Gabun"),
+    ("GA", "el", "Γκαμπόν"),
+    ("GA", "es", "Gabón"),
+    ("GA", "he", "גבון"),
+    ("GA", "ja", "ガボン"),
+    ("GA", "ko", "가봉"),
+    ("GA", "pt", "Gabão"),
+    ("GA", "ru", "Габон"),
+    ("GA", "th", "กาบอง"),
+    ("GA", "uk", "Габон"),
+    ("GA", "vi", "Ga-bon"),
+    ("GA", "zh-cn", "加蓬"),
+    ("GA", "zh-tw", "加彭"),
+    ("GB", "cs", "Spojené království"),
+    ("GB", "da", "Storbritannien"),
+    ("GB", "de", "Vereinigtes Königreich"),
+    ("GB", "el", "Ηνωμένο Βασίλειο"),
+    ("GB", "es", "Reino Unido"),
+    ("GB", "fi", "Yhdistynyt kuningaskunta"),
+    ("GB", "fr", "Royaume-Uni"),
+    ("GB", "he", "הממלכה המאוחדת"),
+    ("GB", "hu", "Egyesült Királyság"),
+    ("GB", "id", "Britania Raya"),
+    ("GB", "it", "Regno Unito"),
+    ("GB", "ja", "英国"),
+    ("GB", "ko", "영국"),
+    ("GB", "nl", "Verenigd Koninkrijk"),
+    ("GB", "pl", "Wielka Brytania"),
+    ("GB", "pt", "Reino Unido"),
+    ("GB", "ro", "Regatul Unit"),
+    ("GB", "ru", "Соединённое Королевство"),
+    ("GB", "sv", "Förenade kungariket"),
+    ("GB", "th", "สหราชอาณาจักร"),
+    ("GB", "tr", "Birleşik Krallık"),
+    ("GB", "uk", "Велика Британія"),
+    ("GB", "vi", "Vương Quốc Anh Thống Nhất"),
+    ("GB", "zh-cn", "英国"),
+    ("GB", "zh-tw", "英國"),
+    ("GD", "el", "Γρενάδα"),
+    ("GD", "es", "Granada"),
+    ("GD", "fr", "Grenade"),
+    ("GD", "he", "גרנדה"),
+    ("GD", "ja", "グレナダ"),
+    ("GD", "ko", "그레나다"),
+    ("GD", "pt", "Granada"),
+    ("GD", "ru", "Гренада"),
+    ("GD", "th", "เกรนาดา"),
+    ("GD", "uk", "Гренада"),
+    ("GD", "vi", "Gợ-rê-na-đa"),
+    ("GD", "zh-cn", "格林纳达"),
+    ("GD", "zh-tw", "格瑞那達"),
+    ("GE", "cs", "Gruzie"),
+    ("GE", "da", "Georgien"),
+    ("GE", "de", "Georgien"),
+    ("GE", "el", "Γεωργία"),
+    ("GE", "fr", "Géorgie"),
+    ("GE", "he", "גאורגיה"),
+    ("GE", "hu", "Grúzia"),
+    ("GE", "ja", "グルジア"),
+    ("GE", "ko", "조지아"),
+    ("GE", "pl", "Gruzja"),
+    ("GE", "pt", "Geórgia"),
+    ("GE", "ru", "Грузия"),
+    ("GE", "sv", "Georgien"),
+    ("GE", "th", "จอร์เจีย"),
+    ("GE", "tr", "Gürcistan"),
+    ("GE", "uk", "Грузія"),
+    ("GE", "vi", "Gi-oa-gi-a"),
+    ("GE", "zh-cn", "格鲁吉亚"),
+    ("GE", "zh-tw", "喬治亞"),
+    ("GF", "cs", "Francouzská Guayana"),
+    ("GF", "da", "Fransk Guyana"),
+    ("GF", "de", "Französisch-Guyana"),
+    ("GF", "el", "Γαλλική Γουιάνα"),
+    ("GF", "es", "Guayana Francesa"),
+    ("GF", "fi", "Ranskan Guayana"),
+    ("GF", "fr", "Guyane française"),
+    ("GF", "he", "גיאנה הצרפתית"),
+    ("GF", "hu", "Francia Guyana"),
+    ("GF", "id", "Guyana Perancis"),
+    ("GF", "it", "Guyana francese"),
+    ("GF", "ja", "仏領ギアナ"),
+    ("GF", "ko", "프랑스령 기아나"),
+    ("GF", "nl", "Frans-Guyana"),
+    ("GF", "pl", "Gujana Francuska"),
+    ("GF", "pt", "Guiana Francesa"),
+    ("GF", "ro", "Guiana Franceză"),
+    ("GF", "ru", "Французская Гвиана"),
+    ("GF", "sv", "Franska Guyana"),
+    ("GF", "th", "เฟรนช์เกียนา"),
+    ("GF", "tr", "Fransız Guyanası"),
+    ("GF", "uk", "Французька Гвіана"),
+    ("GF", "vi", "Ghi-a-na Pháp"),
+    ("GF", "zh-cn", "法属圭亚那"),
+    ("GF", "zh-tw", "法屬蓋亞那"),
+    ("GG", "el", "Γκέρνσεϊ"),
+    ("GG", "fr", "Guernesey"),
+    ("GG", "he", "גרנזי"),
+    ("GG", "ja", "ガーンジー"),
+    ("GG", "ko", "건지 섬"),
+    ("GG", "ru", "Гернси"),
+    ("GG", "th", "เกิร์นซีย์"),
+    ("GG", "uk", "Острів Гернсі"),
+    ("GG", "vi", "Gơnh-xị"),
+    ("GG", "zh-cn", "根西岛"),
+    ("GG", "zh-tw", "根息島"),
+    ("GH", "el", "Γκάνα"),
+    ("GH", "he", "גאנה"),
+    ("GH", "hu", "Ghána"),
+    ("GH", "ja", "ガーナ"),
+    ("GH", "ko", "가나"),
+    ("GH", "pt", "Gana"),
+    ("GH", "ru", "Гана"),
+    ("GH", "th", "กานา"),
+    ("GH", "tr", "Gana"),
+    ("GH", "uk", "Гана"),
+    ("GH", "vi", "Ga-na"),
+    ("GH", "zh-cn", "加纳"),
+    ("GH", "zh-tw", "迦納"),
+    ("GI", "el", "Γιβραλτάρ"),
+    ("GI", "he", "גיברלטר"),
+    ("GI", "hu", "Gibraltár"),
+    ("GI", "it", "Gibilterra"),
+    ("GI", "ja", "ジブラルタル"),
+    ("GI", "ko", "지브롤터"),
+    ("GI", "ru", "Гибралтар"),
+    ("GI", "th", "ยิบรอลตาร์"),
+    ("GI", "tr", "Cebelitarık"),
+    ("GI", "uk", "Гібралтар"),
+    ("GI", "vi", "Gi-boa-tha"),
+    ("GI", "zh-cn", "直布罗陀"),
+    ("GI", "zh-tw", "直布羅陀"),
+    ("GL", "cs", "Grónsko"),
+    ("GL", "da", "Grønland"),
+    ("GL", "de", "Grönland"),
+    ("GL", "el", "Γροιλανδία"),
+    ("GL", "es", "Groenlandia"),
+    ("GL", "fi", "Grönlanti"),
+    ("GL", "fr", "Groënland"),
+    ("GL", "he", "גרינלנד"),
+    ("GL", "hu", "Grönland"),
+    ("GL", "it", "Groenlandia"),
+    ("GL", "ja", "グリーンランド"),
+    ("GL", "ko", "그린란드"),
+    ("GL", "nl", "Groenland"),
+    ("GL", "pl", "Grenlandia"),
+    ("GL", "pt", "Groenlândia"),
+    ("GL", "ro", "Groenlanda"),
+    ("GL", "ru", "Гренландия"),
+    ("GL", "sv", "Grönland"),
+    ("GL", "th", "กรีนแลนด์"),
+    ("GL", "tr", "Grönland"),
+    ("GL", "uk", "Ґренландія"),
+    ("GL", "vi", "Đảo Băng"),
+    ("GL", "zh-cn", "格陵兰"),
+    ("GL", "zh-tw", "格陵蘭"),
+    ("GM", "cs", "Gambie"),
+    ("GM", "el", "Γκάμπια"),
+    ("GM", "fr", "Gambie"),
+    ("GM", "he", "גמביה"),
+    ("GM", "ja", "ガンビア"),
+    ("GM", "ko", "감비아"),
+    ("GM", "pt", "Gâmbia"),
+    ("GM", "ru", "Гамбия"),
+    ("GM", "th", "แกมเบีย"),
+    ("GM", "tr", "Gambiya"),
+    ("GM", "uk", "Гамбія"),
+    ("GM", "vi", "Găm-bi-a"),
+    ("GM", "zh-cn", "冈比亚"),
+    ("GM", "zh-tw", "甘比亞"),
+    ("GN", "el", "Γουινέα"),
+    ("GN", "fr", "Guinée"),
+    ("GN", "he", "גינאה"),
+    ("GN", "ja", "ギニア"),
+    ("GN", "ko", "기니"),
+    ("GN", "nl", "Guinee"),
+    ("GN", "pl", "Gwinea"),
+    ("GN", "pt", "Guiné"),
+    ("GN", "ru", "Гвинея"),
+    ("GN", "th", "กินี"),
+    ("GN", "tr", "Gine"),
+    ("GN", "uk", "Гвінея"),
+    ("GN", "vi", "Ghi-nê"),
+    ("GN", "zh-cn", "几内亚"),
+    ("GN", "zh-tw", "幾內亞"),
+    ("GP", "el", "Γουαδελούπη"),
+    ("GP", "es", "Guadalupe"),
+    ("GP", "he", "גוואדלופ"),
+    ("GP", "it", "Guadalupa"),
+    ("GP", "ja", "グアドループ"),
+    ("GP", "ko", "과들루프"),
+    ("GP", "pl", "Gwadelupa"),
+    ("GP", "pt", "Guadalupe"),
+    ("GP", "ro", "Guadelupa"),
+    ("GP", "ru", "Гваделупа"),
+    ("GP", "th", "กวาเดอลูป"),
+    ("GP", "uk", "Гваделупа"),
+    ("GP", "vi", "Gu-a-đe-lup"),
+    ("GP", "zh-cn", "瓜德罗普"),
+    ("GP", "zh-tw", "瓜地洛普"),
+    ("GQ", "cs", "Rovníková Guinea"),
+    ("GQ", "da", "Ækvatorialguinea"),
+    ("GQ", "de", "Äquatorialguinea"),
+    ("GQ", "el", "Ισημερινή Γουινέα"),
+    ("GQ", "es", "Guinea Ecuatorial"),
+    ("GQ", "fi", "Päiväntasaajan Guinea"),
+    ("GQ", "fr", "Guinée Équatoriale"),
+    ("GQ", "he", "גינאה המשוונית"),
+    ("GQ", "hu", "Egyenlítői-Guinea"),
+    ("GQ", "id", "Guinea Khatulistiwa"),
+    ("GQ", "it", "Guinea equatoriale"),
+    ("GQ", "ja", "赤道ギニア"),
+    ("GQ", "ko", "적도 기니"),
+    ("GQ", "nl", "Equatoriaal-Guinea"),
+    ("GQ", "pl", "Gwinea Równikowa"),
+    ("GQ", "pt", "Guiné Equatorial"),
+    ("GQ", "ro", "Guinea Ecuatorială"),
+    ("GQ", "ru", "Экваториальная Гвинея"),
+    ("GQ", "sv", "Ekvatorialguinea"),
+    ("GQ", "th", "อิเควทอเรียลกินี"),
+    ("GQ", "tr", "Ekvator Ginesi"),
+    ("GQ", "uk", "Екваторіальна Гвінея"),
+    ("GQ", "vi", "Ghi-nê Xích Đạo"),
+    ("GQ", "zh-cn", "赤道几内亚"),
+    ("GQ", "zh-tw", "赤道幾內亞"),
+    ("GR", "cs", "Řecko"),
+    ("GR", "da", "Grækenland"),
+    ("GR", "de", "Griechenland"),
+    ("GR", "el", "Ελλάδα"),
+    ("GR", "es", "Grecia"),
+    ("GR", "fi", "Kreikka"),
+    ("GR", "fr", "Grèce"),
+    ("GR", "he", "יוון"),
+    ("GR", "hu", "Görögország"),
+    ("GR", "id", "Yunani"),
+    ("GR", "it", "Grecia"),
+    ("GR", "ja", "ギリシャ"),
+    ("GR", "ko", "그리스"),
+    ("GR", "nl", "Griekenland"),
+    ("GR", "pl", "Grecja"),
+    ("GR", "pt", "Grécia"),
+    ("GR", "ro", "Grecia"),
+    ("GR", "ru", "Греция"),
+    ("GR", "sv", "Grekland"),
+    ("GR", "th", "กรีซ"),
+    ("GR", "tr", "Yunanistan"),
+    ("GR", "uk", "Греція"),
+    ("GR", "vi", "Hy Lạp"),
+    ("GR", "zh-cn", "希腊"),
+    ("GR", "zh-tw", "希臘"),
+    ("GS", "cs", "Jižní Georgie a Jižní Sandwichovy ostrovy"),
+    ("GS", "da", "South Georgia og De Sydlige Sandwichøer"),
+    ("GS", "de", "South Georgia und die Südlichen Sandwichinseln"),
+    ("GS", "el", "Νήσοι Νότια Γεωργία και Νότιες Σάντουιτς"),
+    ("GS", "es", "Islas Georgias del Sur y Sándwich del Sur"),
+    ("GS", "fi", "Etelä-Georgia ja Eteläiset Sandwichinsaaret"),
+    ("GS", "fr", "Géorgie du Sud et les îles Sandwich du Sud"),
+    ("GS", "he", "איי ג׳ורג׳יה הדרומית ואיי סנדוויץ׳ הדרומיים"),
+    ("GS", "hu", "Déli-Georgia és Déli-Sandwich-szigetek"),
+    ("GS", "id", "Georgia Selatan dan Kepulauan Sandwich Selatan"),
+    ("GS", "it", "Georgia del Sud e Isole Sandwich Australi"),
+    ("GS", "ja", "サウスジョージア及びサウスサンドウィッチ諸島"),
+    ("GS", "ko", "사우스조지아 사우스샌드위치 제도"),
+    ("GS", "nl", "Zuid-Georgia en de Zuidelijke Sandwicheilanden"),
+    ("GS", "pl", "Georgia Południowa i Sandwich Południowy"),
+    ("GS", "pt", "Geórgia do Sul e Ilhas Sandwich do Sul"),
+    ("GS", "ro", "Georgia de Sud și Insulele Sandwich de sud"),
+    ("GS", "ru", "Южная Джорджия и Южные Сандвичевы острова"),
+    ("GS", "sv", "Sydgeorgien och södra Sandwichöarna"),
+    ("GS", "th", "เกาะเซาท์จอร์เจียและหมู่เกาะเซาท์แซนด์วิช"),
+    ("GS", "tr", "Güney Georgia ve Güney Sandwich Adaları"),
+    ("GS", "uk", "Південна Джорджія та Південні Сандвічеві острови"),
+    ("GS", "vi", "Nam Gi-oa-gi-a va Nam Quần Đảo Xan-oui-chợ"),
+    ("GS", "zh-cn", "南乔治亚岛和南桑德韦奇岛"),
+    ("GS", "zh-tw", "南喬治亞及南三明治群島"),
+    ("GT", "el", "Γουατεμάλα"),
+    ("GT", "he", "גואטמלה"),
+    ("GT", "ja", "グアテマラ"),
+    ("GT", "ko", "과테말라"),
+    ("GT", "pl", "Gwatemala"),
+    ("GT", "ru", "Гватемала"),
+    ("GT", "th", "กัวเตมาลา"),
+    ("GT", "uk", "Гватемала"),
+    ("GT", "vi", "Gua-tê-ma-la"),
+    ("GT", "zh-cn", "瓜地马拉"),
+    ("GT", "zh-tw", "瓜地馬拉"),
+    ("GU", "el", "Γκουάμ"),
+    ("GU", "he", "גואם"),
+    ("GU", "ja", "グアム"),
+    ("GU", "ko", "괌"),
+    ("GU", "ru", "Гуам"),
+    ("GU", "th", "กวม"),
+    ("GU", "uk", "Гуам"),
+    ("GU", "vi", "Gu-ăm"),
+    ("GU", "zh-cn", "关岛"),
+    ("GU", "zh-tw", "關島"),
+    ("GW", "el", "Γουινέα-Μπισσάου"),
+    ("GW", "es", "Guinea-Bisáu"),
+    ("GW", "fr", "Guinée-Bissau"),
+    ("GW", "he", "גינאה ביסאו"),
+    ("GW", "hu", "Bissau-Guinea"),
+    ("GW", "ja", "ギニアビサウ"),
+    ("GW", "ko", "기니비사우"),
+    ("GW", "nl", "Guinee-Bissau"),
+    ("GW", "pl", "Gwinea Bissau"),
+    ("GW", "pt", "Guiné-Bissau"),
+    ("GW", "ru", "Гвинея-Бисау"),
+    ("GW", "th", "กินีบิสเซา"),
+    ("GW", "tr", "Gine-Bissau"),
+    ("GW", "uk", "Гвінея-Бісау"),
+    ("GW", "vi", "Ghi-nê Bi-xau"),
+    ("GW", "zh-cn", "几内亚比绍"),
+    ("GW", "zh-tw", "幾內亞比索"),
+    ("GY", "el", "Γουιάνα"),
+    ("GY", "he", "גיאנה"),
+    ("GY", "ja", "ガイアナ"),
+    ("GY", "ko", "가이아나"),
+    ("GY", "pl", "Gujana"),
+    ("GY", "pt", "Guiana"),
+    ("GY", "ru", "Гайана"),
+    ("GY", "th", "กายอานา"),
+    ("GY", "uk", "Гаяна"),
+    ("GY", "vi", "Guy-a-na"),
+    ("GY", "zh-cn", "圭亚那"),
+    ("GY", "zh-tw", "蓋亞那"),
+    ("HK", "cs", "Hongkong"),
+    ("HK", "da", "Hongkong"),
+    ("HK", "de", "Hongkong"),
+    ("HK", "el", "Χονγκ Κονγκ"),
+    ("HK", "he", "הונג קונג"),
+    ("HK", "hu", "Hongkong"),
+    ("HK", "ja", "香港"),
+    ("HK", "ko", "홍콩"),
+    ("HK", "nl", "Hongkong"),
+    ("HK", "pl", "Hongkong"),
+    ("HK", "ru", "Гонконг"),
+    ("HK", "sv", "Hongkong"),
+    ("HK", "th", "ฮ่องกง"),
+    ("HK", "uk", "Гонконг"),
+    ("HK", "vi", "Hông Kông"),
+    ("HK", "zh-cn", "香港"),
+    ("HK", "zh-tw", "香港"),
+    ("HM", "cs", "Heardův a McDonaldovy ostrovy"),
+    ("HM", "da", "Heard-øen og McDonald-øerne"),
+    ("HM", "de", "Heard und McDonaldinseln"),
+    ("HM", "el", "Νήσος Χερντ και Νήσοι ΜακΝτόναλντ"),
+    ("HM", "es", "Islas Heard y McDonald"),
+    ("HM", "fi", "Heard ja McDonaldinsaaret"),
+    ("HM", "fr", "îles Heard-et-MacDonald"),
+    ("HM", "he", "האי הרד ואיי מקדונלד"),
+    ("HM", "hu", "Heard-sziget és McDonald-szigetek"),
+    ("HM", "id", "Pulau Heard dan Kepulauan McDonald"),
+    ("HM", "it", "Isole Heard e McDonald"),
+    ("HM", "ja", "ハード島及びマクドナルド諸島"),
+    ("HM", "ko", "허드 맥도널드 제도"),
+    ("HM", "nl", "Heardeiland en McDonaldeilanden"),
+    ("HM", "pl", "Wyspy Heard i McDonalda"),
+    ("HM", "pt", "Ilha Heard e Ilhas McDonald"),
+    ("HM", "ro", "Insula Heard și Insulele McDonald"),
+    ("HM", "ru", "Остров Херд и острова МакДональд"),
+    ("HM", "sv", "Heardön och McDonaldöarna"),
+    ("HM", "th", "เกาะเฮิร์ดและหมู่เกาะแมกดอนัลด์"),
+    ("HM", "tr", "Heard Adası ve McDonald Adaları"),
+    ("HM", "uk", "Острів Герд і острови Макдональд"),
+    ("HM", "vi", "Đảo He-ợ-đợ và Quần Đảo Mợc-đo-nậ-đợ"),
+    ("HM", "zh-cn", "赫德岛与麦克唐纳群岛"),
+    ("HM", "zh-tw", "赫德島及麥當勞群島"),
+    ("HN", "el", "Ονδούρα"),
+    ("HN", "he", "הונדורס"),
+    ("HN", "ja", "ホンジュラス"),
+    ("HN", "ko", "온두라스"),
+    ("HN", "ru", "Гондурас"),
+    ("HN", "th", "ฮอนดูรัส"),
+    ("HN", "uk", "Гондурас"),
+    ("HN", "vi", "Hôn-đu-ra-xợ"),
+    ("HN", "zh-cn", "洪都拉斯"),
+    ("HN", "zh-tw", "宏都拉斯"),
+    ("HR", "cs", "Chorvatsko"),
+    ("HR", "da", "Kroatien"),
+    ("HR", "de", "Kroatien"),
+    ("HR", "el", "Κροατία"),
+    ("HR", "es", "Croacia"),
+    ("HR", "fi", "Kroatia"),
+    ("HR", "fr", "Croatie"),
+    ("HR", "he", "קרואטיה"),
+    ("HR", "hu", "Horvátország"),
+    ("HR", "id", "Kroasia"),
+    ("HR", "it", "Croazia"),
+    ("HR", "ja", "クロアチア"),
+    ("HR", "ko", "크로아티아"),
+    ("HR", "nl", "Kroatië"),
+    ("HR", "pl", "Chorwacja"),
+    ("HR", "pt", "Croácia"),
+    ("HR", "ro", "Croația"),
+    ("HR", "ru", "Хорватия"),
+    ("HR", "sv", "Kroatien"),
+    ("HR", "th", "โครเอเชีย"),
+    ("HR", "tr", "Hırvatistan"),
+    ("HR", "uk", "Хорватія"),
+    ("HR", "vi", "Cợ-rô-a-ti-a"),
+    ("HR", "zh-cn", "克罗地亚"),
+    ("HR", "zh-tw", "克羅埃西亞"),
+    ("HT", "el", "Αϊτή"),
+    ("HT", "es", "Haití"),
+    ("HT", "fr", "Haïti"),
+    ("HT", "he", "האיטי"),
+    ("HT", "ja", "ハイチ"),
+    ("HT", "ko", "아이티"),
+    ("HT", "nl", "Haïti"),
+    ("HT", "ru", "Гаити"),
+    ("HT", "th", "เฮติ"),
+    ("HT", "uk", "Гаїті"),
+    ("HT", "vi", "Ha-i-ti"),
+    ("HT", "zh-cn", "海地"),
+    ("HT", "zh-tw", "海地"),
+    ("HU", "cs", "Maďarsko"),
+    ("HU", "da", "Ungarn"),
+    ("HU", "de", "Ungarn"),
+    ("HU", "el", "Ουγγαρία"),
+    ("HU", "es", "Hungría"),
+    ("HU", "fi", "Unkari"),
+    ("HU", "fr", "Hongrie"),
+    ("HU", "he", "הונגריה"),
+    ("HU", "hu", "Magyarország"),
+    ("HU", "id", "Hongaria"),
+    ("HU", "it", "Ungheria"),
+    ("HU", "ja", "ハンガリー"),
+    ("HU", "ko", "헝가리"),
+    ("HU", "nl", "Hongarije"),
+    ("HU", "pl", "Węgry"),
+    ("HU", "pt", "Hungria"),
+    ("HU", "ro", "Ungaria"),
+    ("HU", "ru", "Венгрия"),
+    ("HU", "sv", "Ungern"),
+    ("HU", "th", "ฮังการี"),
+    ("HU", "tr", "Macaristan"),
+    ("HU", "uk", "Угорщина"),
+    ("HU", "vi", "Hun-ga-ri"),
+    ("HU", "zh-cn", "匈牙利"),
+    ("HU", "zh-tw", "匈牙利"),
+    ("ID", "cs", "Indonésie"),
+    ("ID", "da", "Indonesien"),
+    ("ID", "de", "Indonesien"),
+    ("ID", "el", "Ινδονησία"),
+    ("ID", "fr", "Indonésie"),
+    ("ID", "he", "אינדונזיה"),
+    ("ID", "hu", "Indonézia"),
+    ("ID", "ja", "インドネシア"),
+    ("ID", "ko", "인도네시아"),
+    ("ID", "nl", "Indonesië"),
+    ("ID", "pl", "Indonezja"),
+    ("ID", "pt", "Indonésia"),
+    ("ID", "ro", "Indonezia"),
+    ("ID", "ru", "Индонезия"),
+    ("ID", "sv", "Indonesien"),
+    ("ID", "th", "อินโดนีเซีย"),
+    ("ID", "tr", "Endonezya"),
+    ("ID", "uk", "Індонезія"),
+    ("ID", "vi", "Nam Dương"),
+    ("ID", "zh-cn", "印度尼西亚"),
+    ("ID", "zh-tw", "印度尼西亞"),
+    ("IE", "cs", "Irsko"),
+    ("IE", "da", "Irland"),
+    ("IE", "de", "Irland"),
+    ("IE", "el", "Ιρλανδία"),
+    ("IE", "es", "Irlanda"),
+    ("IE", "fi", "Irlanti"),
+    ("IE", "fr", "Irlande"),
+    ("IE", "he", "אירלנד"),
+    ("IE", "hu", "Írország"),
+    ("IE", "id", "Irlandia"),
+    ("IE", "it", "Irlanda"),
+    ("IE", "ja", "アイルランド"),
+    ("IE", "ko", "아일랜드"),
+    ("IE", "nl", "Ierland"),
+    ("IE", "pl", "Irlandia"),
+    ("IE", "pt", "Irlanda"),
+    ("IE", "ro", "Irlanda"),
+    ("IE", "ru", "Ирландия"),
+    ("IE", "sv", "Irland"),
+    ("IE", "th", "ไอร์แลนด์"),
+    ("IE", "tr", "İrlanda"),
+    ("IE", "uk", "Ірландія"),
+    ("IE", "vi", "Ái Nhĩ Lan"),
+    ("IE", "zh-cn", "爱尔兰"),
+    ("IE", "zh-tw", "愛爾蘭"),
+    ("IL", "cs", "Izrael"),
+    ("IL", "el", "Ισραήλ"),
+    ("IL", "fr", "Israël"),
+    ("IL", "he", "ישראל"),
+    ("IL", "hu", "Izrael"),
+    ("IL", "it", "Israele"),
+    ("IL", "ja", "イスラエル"),
+    ("IL", "ko", "이스라엘"),
+    ("IL", "nl", "Israël"),
+    ("IL", "pl", "Izrael"),
+    ("IL", "ru", "Израиль"),
+    ("IL", "th", "อิสราเอล"),
+    ("IL", "tr", "İsrail"),
+    ("IL", "uk", "Ізраїль"),
+    ("IL", "vi", "Do Thái"),
+    ("IL", "zh-cn", "以色列"),
+    ("IL", "zh-tw", "以色列"),
+    ("IM", "cs", "Ostrov Man"),
+    ("IM", "de", "Insel Man"),
+    ("IM", "el", "Νήσος του Μαν"),
+    ("IM", "es", "Isla de Man"),
+    ("IM", "fi", "Mansaari"),
+    ("IM", "fr", "Île de Man"),
+    ("IM", "he", "האי מאן"),
+    ("IM", "hu", "Man"),
+    ("IM", "id", "Pulau Man"),
+    ("IM", "it", "Isola di Man"),
+    ("IM", "ja", "マン島"),
+    ("IM", "ko", "맨 섬"),
+    ("IM", "nl", "Eiland Man"),
+    ("IM", "pl", "Wyspa Man"),
+    ("IM", "pt", "Ilha de Man"),
+    ("IM", "ro", "Insula Man"),
+    ("IM", "ru", "Остров Мэн"),
+    ("IM", "th", "เกาะแมน"),
+    ("IM", "tr", "Man Adası"),
+    ("IM", "uk", "Острів Мен"),
+    ("IM", "vi", "Đảo Man"),
+    ("IM", "zh-cn", "曼岛"),
+    ("IM", "zh-tw", "曼島"),
+    ("IN", "cs", "Indie"),
+    ("IN", "da", "Indien"),
+    ("IN", "de", "Indien"),
+    ("IN", "el", "Ινδία"),
+    ("IN", "fi", "Intia"),
+    ("IN", "fr", "Inde"),
+    ("IN", "he", "הודו"),
+    ("IN", "ja", "インド"),
+    ("IN", "ko", "인도"),
+    ("IN", "pl", "Indie"),
+    ("IN", "pt", "Índia"),
+    ("IN", "ru", "Индия"),
+    ("IN", "sv", "Indien"),
+    ("IN", "th", "อินเดีย"),
+    ("IN", "tr", "Hindistan"),
+    ("IN", "uk", "Індія"),
+    ("IN", "vi", "Ấn-độ"),
+    ("IN", "zh-cn", "印度"),
+    ("IN", "zh-tw", "印度"),
+    ("IO", "cs", "Britské indickooceánské území"),
+    ("IO", "da", "Det britiske territorium i Det Indiske Ocean"),
+    ("IO", "de", "Britisches Territorium im Indischen Ozean"),
+    ("IO", "el", "Βρετανικό Έδαφος Ινδικού Ωκεανού"),
+    ("IO", "es", "Territorio Británico del Océano Índico"),
+    ("IO", "fi", "Brittiläinen Intian valtameren alue"),
+    ("IO", "fr", "Territoire britannique de l'océan Indien"),
+    ("IO", "he", "הטריטוריה הבריטית באוקיינוס ההודי"),
+    ("IO", "hu", "Brit Indiai-óceáni Terület"),
+    ("IO", "id", "Wilayah Samudra Hindia Britania"),
+    ("IO", "it", "Territorio britannico dell'Oceano Indiano"),
+    ("IO", "ja", "英国インド洋領土"),
+    ("IO", "ko", "영국령 인도양 지역"),
+    ("IO", "nl", "Brits Indische Oceaanterritorium"),
+    ("IO", "pl", "Brytyjskie Terytorium Oceanu Indyjskiego"),
+    ("IO", "pt", "Território Britânico do Oceano Índico"),
+    ("IO", "ro", "Teritoriul britanic din Oceanul Indian"),
+    ("IO", "ru", "Британская территория Индийского океана"),
+    ("IO", "sv", "Brittiskt territorium i Indiska Oceanen"),
+    ("IO", "th", "บริติชอินเดียนโอเชียนเทร์ริทอรี"),
+    ("IO", "tr", "Britanya Hint Okyanusu Toprakları"),
+    ("IO", "uk", "Британська територія в Індійському океані"),
+    ("IO", "vi", "Miền Đại Dương Ấn-độ Anh"),
+    ("IO", "zh-cn", "英属印度洋领地"),
+    ("IO", "zh-tw", "英屬印度洋領地"),
+    ("IQ", "cs", "Irák"),
+    ("IQ", "da", "Irak"),
+    ("IQ", "de", "Irak"),
+    ("IQ", "el", "Ιράκ"),
+    ("IQ", "es", "Irak"),
+    ("IQ", "fi", "Irak"),
+    ("IQ", "fr", "Irak"),
+    ("IQ", "he", "עיראק"),
+    ("IQ", "hu", "Irak"),
+    ("IQ", "id", "Irak"),
+    ("IQ", "ja", "イラク"),
+    ("IQ", "ko", "이라크"),
+    ("IQ", "nl", "Irak"),
+    ("IQ", "pl", "Irak"),
+    ("IQ", "pt", "Iraque"),
+    ("IQ", "ro", "Irak"),
+    ("IQ", "ru", "Ирак"),
+    ("IQ", "sv", "Irak"),
+    ("IQ", "th", "อิรัก"),
+    ("IQ", "tr", "Irak"),
+    ("IQ", "uk", "Ірак"),
+    ("IQ", "vi", "I-rắc"),
+    ("IQ", "zh-cn", "伊拉克"),
+    ("IQ", "zh-tw", "伊拉克"),
+    ("IR", "cs", "Írán"),
+    ("IR", "he", "איראן"),
+    ("IR", "tr", "İran"),
+    ("IR", "uk", "Іран"),
+    ("IR", "zh-cn", "伊朗"),
+    ("IR", "zh-tw", "伊朗"),
+    ("IS", "cs", "Island"),
+    ("IS", "da", "Island"),
+    ("IS", "de", "Island"),
+    ("IS", "el", "Ισλανδία"),
+    ("IS", "es", "Islandia"),
+    ("IS", "fi", "Islanti"),
+    ("IS", "fr", "Islande"),
+    ("IS", "he", "איסלנד"),
+    ("IS", "hu", "Izland"),
+    ("IS", "id", "Islandia"),
+    ("IS", "it", "Islanda"),
+    ("IS", "ja", "アイスランド"),
+    ("IS", "ko", "아이슬란드"),
+    ("IS", "nl", "IJsland"),
+    ("IS", "pl", "Islandia"),
+    ("IS", "pt", "Islândia"),
+    ("IS", "ro", "Islanda"),
+    ("IS", "ru", "Исландия"),
+    ("IS", "sv", "Island"),
+    ("IS", "th", "ไอซ์แลนด์"),
+    ("IS", "tr", "İzlanda"),
+    ("IS", "uk", "Ісландія"),
+    ("IS", "vi", "Băng Đảo"),
+    ("IS", "zh-cn", "冰岛"),
+    ("IS", "zh-tw", "冰島"),
+    ("IT", "cs", "Itálie"),
+    ("IT", "da", "Italien"),
+    ("IT", "de", "Italien"),
+    ("IT", "el", "Ιταλία"),
+    ("IT", "es", "Italia"),
+    ("IT", "fi", "Italia"),
+    ("IT", "fr", "Italie"),
+    ("IT", "he", "איטליה"),
+    ("IT", "hu", "Olaszország"),
+    ("IT", "id", "Italia"),
+    ("IT", "it", "Italia"),
+    ("IT", "ja", "イタリア"),
+    ("IT", "ko", "이탈리아"),
+    ("IT", "nl", "Italië"),
+    ("IT", "pl", "Włochy"),
+    ("IT", "pt", "Itália"),
+    ("IT", "ro", "Italia"),
+    ("IT", "ru", "Италия"),
+    ("IT", "sv", "Italien"),
+    ("IT", "th", "อิตาลี"),
+    ("IT", "tr", "İtalya"),
+    ("IT", "uk", "Італія"),
+    ("IT", "vi", "Ý"),
+    ("IT", "zh-cn", "意大利"),
+    ("IT", "zh-tw", "義大利"),
+    ("JE", "el", "Τζέρσεϊ"),
+    ("JE", "he", "ג'רזי"),
+    ("JE", "ja", "ジャージー"),
+    ("JE", "ko", "저지 섬"),
+    ("JE", "ru", "Джерси"),
+    ("JE", "th", "เจอร์ซีย์"),
+    ("JE", "uk", "Джерсі"),
+    ("JE", "vi", "Giơ-xị"),
+    ("JE", "zh-cn", "泽西岛"),
+    ("JE", "zh-tw", "澤西島"),
+    ("JM", "cs", "Jamajka"),
+    ("JM", "de", "Jamaika"),
+    ("JM", "el", "Τζαμάικα"),
+    ("JM", "fi", "Jamaika"),
+    ("JM", "fr", "Jamaïque"),
+    ("JM", "he", "ג'מייקה"),
+    ("JM", "id", "Jamaika"),
+    ("JM", "it", "Giamaica"),
+    ("JM", "ja", "ジャマイカ"),
+    ("JM", "ko", "자메이카"),
+    ("JM", "pl", "Jamajka"),
+    ("JM", "ru", "Ямайка"),
+    ("JM", "th", "จาเมกา"),
+    ("JM", "tr", "Jamaika"),
+    ("JM", "uk", "Ямайка"),
+    ("JM", "vi", "Gia-mê-ca"),
+    ("JM", "zh-cn", "牙买加"),
+    ("JM", "zh-tw", "牙買加"),
+    ("JO", "cs", "Jordánsko"),
+    ("JO", "de", "Jordanien"),
+    ("JO", "el", "Ιορδανία"),
+    ("JO", "es", "Jordania"),
+    ("JO", "fi", "Jordania"),
+    ("JO", "fr", "Jordanie"),
+    ("JO", "he", "ירדן"),
+    ("JO", "hu", "Jordánia"),
+    ("JO", "id", "Yordania"),
+    ("JO", "it", "Giordania"),
+    ("JO", "ja", "ヨルダン"),
+    ("JO", "ko", "요르단"),
+    ("JO", "nl", "Jordanië"),
+    ("JO", "pl", "Jordania"),
+    ("JO", "pt", "Jordânia"),
+    ("JO", "ro", "Iordania"),
+    ("JO", "ru", "Иордания"),
+    ("JO", "sv", "Jordanien"),
+    ("JO", "th", "จอร์แดน"),
+    ("JO", "tr", "Ürdün"),
+    ("JO", "uk", "Йорданія"),
+    ("JO", "vi", "Gi-oa-đanh"),
+    ("JO", "zh-cn", "约旦"),
+    ("JO", "zh-tw", "約旦"),
+    ("JP", "cs", "Japonsko"),
+    ("JP", "el", "Ιαπωνία"),
+    ("JP", "es", "Japón"),
+    ("JP", "fi", "Japani"),
+    ("JP", "fr", "Japon"),
+    ("JP", "he", "יפן"),
+    ("JP", "hu", "Japán"),
+    ("JP", "id", "Jepang"),
+    ("JP", "it", "Giappone"),
+    ("JP", "ja", "日本"),
+    ("JP", "ko", "일본"),
+    ("JP", "pl", "Japonia"),
+    ("JP", "pt", "Japão"),
+    ("JP", "ro", "Japonia"),
+    ("JP", "ru", "Япония"),
+    ("JP", "th", "ญี่ปุ่น"),
+    ("JP", "tr", "Japonya"),
+    ("JP", "uk", "Японія"),
+    ("JP", "vi", "Nhật"),
+    ("JP", "zh-cn", "日本"),
+    ("JP", "zh-tw", "日本"),
+    ("KE", "cs", "Keňa"),
+    ("KE", "de", "Kenia"),
+    ("KE", "el", "Κένυα"),
+    ("KE", "es", "Kenia"),
+    ("KE", "fi", "Kenia"),
+    ("KE", "he", "קניה"),
+    ("KE", "ja", "ケニア"),
+    ("KE", "ko", "케냐"),
+    ("KE", "nl", "Kenia"),
+    ("KE", "pl", "Kenia"),
+    ("KE", "pt", "Quênia"),
+    ("KE", "ro", "Kenia"),
+    ("KE", "ru", "Кения"),
+    ("KE", "th", "เคนยา"),
+    ("KE", "uk", "Кенія"),
+    ("KE", "vi", "Khi-ni-a"),
+    ("KE", "zh-cn", "肯尼亚"),
+    ("KE", "zh-tw", "肯亞"),
+    ("KG", "cs", "Kyrgyzstán"),
+    ("KG", "da", "Kirgisistan"),
+    ("KG", "de", "Kirgisistan"),
+    ("KG", "el", "Κιργιζία"),
+    ("KG", "es", "Kirguistán"),
+    ("KG", "fi", "Kirgisia"),
+    ("KG", "fr", "Kirghizistan"),
+    ("KG", "he", "קירגיזסטן"),
+    ("KG", "hu", "Kirgizisztán"),
+    ("KG", "id", "Kirgizstan"),
+    ("KG", "it", "Kirghizistan"),
+    ("KG", "ja", "キルギスタン"),
+    ("KG", "ko", "키르기스스탄"),
+    ("KG", "nl", "Kirgizië"),
+    ("KG", "pl", "Kirgistan"),
+    ("KG", "pt", "Quirguistão"),
+    ("KG", "ro", "Kârgâzstan"),
+    ("KG", "ru", "Киргизия"),
+    ("KG", "sv", "Kirgizistan"),
+    ("KG", "th", "คีร์กีซสถาน"),
+    ("KG", "tr", "Kırgızistan"),
+    ("KG", "uk", "Киргизстан"),
+    ("KG", "vi", "Khư-rơ-gư-xtanh"),
+    ("KG", "zh-cn", "吉尔吉斯坦"),
+    ("KG", "zh-tw", "吉爾吉斯"),
+    ("KH", "cs", "Kambodža"),
+    ("KH", "da", "Cambodja"),
+    ("KH", "de", "Kambodscha"),
+    ("KH", "el", "Καμπότζη"),
+    ("KH", "es", "Camboya"),
+    ("KH", "fi", "Kambodža"),
+    ("KH", "fr", "Cambodge"),
+    ("KH", "he", "קמבודיה"),
+    ("KH", "hu", "Kambodzsa"),
+    ("KH", "id", "Kamboja"),
+    ("KH", "it", "Cambogia"),
+    ("KH", "ja", "カンボジア"),
+    ("KH", "ko", "캄보디아"),
+    ("KH", "nl", "Cambodja"),
+    ("KH", "pl", "Kambodża"),
+    ("KH", "pt", "Camboja"),
+    ("KH", "ro", "Cambogia"),
+    ("KH", "ru", "Камбоджа"),
+    ("KH", "sv", "Kambodja"),
+    ("KH", "th", "กัมพูชา"),
+    ("KH", "tr", "Kamboçya"),
+    ("KH", "uk", "Камбоджа"),
+    ("KH", "vi", "Căm Bốt"),
+    ("KH", "zh-cn", "柬埔塞"),
+    ("KH", "zh-tw", "柬埔寨"),
+    ("KI", "el", "Κιριμπάτι"),
+    ("KI", "he", "קיריבטי"),
+    ("KI", "ja", "キリバス"),
+    ("KI", "ko", "키리바시"),
+    ("KI", "ru", "Кирибати"),
+    ("KI", "th", "คิริบาตี"),
+    ("KI", "uk", "Кірибаті"),
+    ("KI", "vi", "Ki-ri-ba-ti"),
+    ("KI", "zh-cn", "基里巴斯"),
+    ("KI", "zh-tw", "吉里巴斯"),
+    ("KM", "cs", "Komory"),
+    ("KM", "da", "Comorerne"),
+    ("KM", "de", "Komoren"),
+    ("KM", "el", "Κομόρες"),
+    ("KM", "es", "Comores, Islas"),
+    ("KM", "fi", "Komorit"),
+    ("KM", "fr", "Comores"),
+    ("KM", "he", "קומורו"),
+    ("KM", "hu", "Comore-szigetek"),
+    ("KM", "id", "Komoro"),
+    ("KM", "it", "Comore"),
+    ("KM", "ja", "コモロ"),
+    ("KM", "ko", "코모로"),
+    ("KM", "nl", "Comoren"),
+    ("KM", "pl", "Komory"),
+    ("KM", "pt", "Comores"),
+    ("KM", "ru", "Коморы"),
+    ("KM", "sv", "Comorerna"),
+    ("KM", "th", "คอโมโรส"),
+    ("KM", "tr", "Komorlar"),
+    ("KM", "uk", "Коморські острови"),
+    ("KM", "vi", "Cô-mô-rô-xợ"),
+    ("KM", "zh-cn", "科摩罗"),
+    ("KM", "zh-tw", "葛摩"),
+    ("KN", "cs", "Svatý Kryštof a Nevis"),
+    ("KN", "da", "Sankt Kitts og Nevis"),
+    ("KN", "de", "St. Kitts und Nevis"),
+    ("KN", "el", "Άγιος Χριστόφορος και Νέβις"),
+    ("KN", "es", "San Cristóbal y Nieves"),
+    ("KN", "fi", "Saint Kitts ja Nevis"),
+    ("KN", "fr", "Saint-Christophe-et-Niévès"),
+    ("KN", "he", "סנט קיטס ונוויס"),
+    ("KN", "hu", "Saint Kitts és Nevis"),
+    ("KN", "id", "Saint Kitts dan Nevis"),
+    ("KN", "it", "Saint Kitts e Nevis"),
+    ("KN", "ja", "セントクリストファー・ネーヴィス"),
+    ("KN", "ko", "세인트키츠 네비스"),
+    ("KN", "nl", "Saint Kitts en Nevis"),
+    ("KN", "pl", "Saint Kitts i Nevis"),
+    ("KN", "pt", "São Cristóvão e Névis"),
+    ("KN", "ro", "Saint Kitts și Nevis"),
+    ("KN", "ru", "Сент-Китс и Невис"),
+    ("KN", "sv", "Sankt Kitts och Nevis"),
+    ("KN", "th", "เซนต์คิตส์และเนวิส"),
+    ("KN", "tr", "Saint Kitts ve Nevis"),
+    ("KN", "uk", "Сент-Кіттс і Невіс"),
+    ("KN", "vi", "Xan-kít và Nê-vi"),
+    ("KN", "zh-cn", "圣基茨和尼维斯"),
+    ("KN", "zh-tw", "聖克里斯多福及尼維斯"),
+    ("KP", "cs", "Severní Korea"),
+    ("KP", "de", "Nordkorea"),
+    ("KP", "fr", "Corée du Nord"),
+    ("KP", "he", "קוריאה הצפונית"),
+    ("KP", "hu", "Észak-Korea"),
+    ("KP", "id", "Korea Utara"),
+    ("KP", "it", "Corea del Nord"),
+    ("KP", "ko", "조선민주주의인민공화국"),
+    ("KP", "nl", "Noord-Korea"),
+    ("KP", "pl", "Korea Północna"),
+    ("KP", "pt", "Coreia do Norte"),
+    ("KP", "ru", "Северная Корея"),
+    ("KP", "sv", "Nordkorea"),
+    ("KP", "tr", "Kuzey Kore"),
+    ("KP", "uk", "Північна Корея"),
+    ("KP", "zh-cn", "朝鲜"),
+    ("KP", "zh-tw", "北韓"),
+    ("KR", "cs", "Jižní Korea"),
+    ("KR", "de", "Südkorea"),
+    ("KR", "fr", "Corée du Sud"),
+    ("KR", "he", "קוריאה הדרומית"),
+    ("KR", "hu", "Dél-Korea"),
+    ("KR", "id", "Korea Selatan"),
+    ("KR", "it", "Corea del Sud"),
+    ("KR", "ko", "대한민국"),
+    ("KR", "nl", "Zuid-Korea"),
+    ("KR", "pl", "Korea Południowa"),
+    ("KR", "pt", "Coreia do Sul"),
+    ("KR", "ru", "Южная Корея"),
+    ("KR", "sv", "Sydkorea"),
+    ("KR", "tr", "Güney Kore"),
+    ("KR", "uk", "Південна Корея"),
+    ("KR", "zh-cn", "韩国"),
+    ("KR", "zh-tw", "南韓"),
+    ("KW", "cs", "Kuvajt"),
+    ("KW", "el", "Κουβέιτ"),
+    ("KW", "fr", "Koweït"),
+    ("KW", "he", "כווית"),
+    ("KW", "hu", "Kuvait"),
+    ("KW", "ja", "クウェート"),
+    ("KW", "ko", "쿠웨이트"),
+    ("KW", "nl", "Koeweit"),
+    ("KW", "pl", "Kuwejt"),
+    ("KW", "ro", "Kuweit"),
+    ("KW", "ru", "Кувейт"),
+    ("KW", "th", "คูเวต"),
+    ("KW", "tr", "Kuveyt"),
+    ("KW", "uk", "Кувейт"),
+    ("KW", "vi", "Cu-ouai-thợ"),
+    ("KW", "zh-cn", "科威特"),
+    ("KW", "zh-tw", "科威特"),
+    ("KY", "cs", "Kajmanské ostrovy"),
+    ("KY", "da", "Caymanøerne"),
+    ("KY", "de", "Cayman-Inseln"),
+    ("KY", "el", "Νησιά Κέιμαν"),
+    ("KY", "es", "Islas Caimán"),
+    ("KY", "fi", "Caymansaaret"),
+    ("KY", "fr", "îles Caïmans"),
+    ("KY", "he", "איי קיימן"),
+    ("KY", "hu", "Kajmán-szigetek"),
+    ("KY", "id", "Kepulauan Cayman"),
+    ("KY", "it", "Isole Cayman"),
+    ("KY", "ja", "ケイマン諸島"),
+    ("KY", "ko", "케이맨 제도"),
+    ("KY", "nl", "Kaaimaneilanden"),
+    ("KY", "pl", "Kajmany"),
+    ("KY", "pt", "Ilhas Cayman"),
+    ("KY", "ro", "Insulele Caiman"),
+    ("KY", "ru", "Каймановы острова"),
+    ("KY", "sv", "Caymanöarna"),
+    ("KY", "th", "หมู่เกาะเคย์แมน"),
+    ("KY", "tr", "Cayman Adaları"),
+    ("KY", "uk", "Кайманові острови"),
+    ("KY", "vi", "Quần đảo Cay-man"),
+    ("KY", "zh-cn", "开曼群岛"),
+    ("KY", "zh-tw", "開曼群島"),
+    ("KZ", "cs", "Kazachstán"),
+    ("KZ", "da", "Kasakhstan"),
+    ("KZ", "de", "Kasachstan"),
+    ("KZ", "el", "Καζακστάν"),
+    ("KZ", "es", "Kazajistán"),
+    ("KZ", "fi", "Kazakstan"),
+    ("KZ", "he", "קזחסטן"),
+    ("KZ", "hu", "Kazahsztán"),
+    ("KZ", "it", "Kazakistan"),
+    ("KZ", "ja", "カザフスタン"),
+    ("KZ", "ko", "카자흐스탄"),
+    ("KZ", "nl", "Kazachstan"),
+    ("KZ", "pl", "Kazachstan"),
+    ("KZ", "pt", "Cazaquistão"),
+    ("KZ", "ru", "Казахстан"),
+    ("KZ", "sv", "Kazakstan"),
+    ("KZ", "th", "คาซัคสถาน"),
+    ("KZ", "tr", "Kazakistan"),
+    ("KZ", "uk", "Казахстан"),
+    ("KZ", "vi", "Kha-xa-kh-x-thanh"),
+    ("KZ", "zh-cn", "哈萨克斯坦"),
+    ("KZ", "zh-tw", "哈薩克"),
+    ("LA", "he", "לאוס"),
+    ("LA", "uk", "Лаос"),
+    ("LA", "zh-cn", "老挝"),
+    ("LA", "zh-tw", "寮國"),
+    ("LB", "cs", "Libanon"),
+    ("LB", "da", "Libanon"),
+    ("LB", "de", "Libanon"),
+    ("LB", "el", "Λίβανος"),
+    ("LB", "es", "Líbano"),
+    ("LB", "fi", "Libanon"),
+    ("LB", "fr", "Liban"),
+    ("LB", "he", "לבנון"),
+    ("LB", "hu", "Libanon"),
+    ("LB", "it", "Libano"),
+    ("LB", "ja", "レバノン"),
+    ("LB", "ko", "레바논"),
+    ("LB", "nl", "Libanon"),
+    ("LB", "pl", "Liban"),
+    ("LB", "pt", "Líbano"),
+    ("LB", "ro", "Liban"),
+    ("LB", "ru", "Ливан"),
+    ("LB", "sv", "Libanon"),
+    ("LB", "th", "เลบานอน"),
+    ("LB", "tr", "Lübnan"),
+    ("LB", "uk", "Ліван"),
+    ("LB", "vi", "Le-ba-non"),
+    ("LB", "zh-cn", "黎巴嫩"),
+    ("LB", "zh-tw", "黎巴嫩"),
+    ("LC", "cs", "Svatá Lucie"),
+    ("LC", "da", "Sankt Lucia"),
+    ("LC", "de", "St. Lucia"),
+    ("LC", "el", "Αγία Λουκία"),
+    ("LC", "es", "Santa Lucía"),
+    ("LC", "fr", "Sainte-Lucie"),
+    ("LC", "he", "סנט לוסיה"),
+    ("LC", "ja", "セントルシア"),
+    ("LC", "ko", "세인트루시아"),
+    ("LC", "pt", "Santa Lúcia"),
+    ("LC", "ro", "Sfânta Lucia"),
+    ("LC", "ru", "Сент-Люсия"),
+    ("LC", "sv", "Sankt Lucia"),
+    ("LC", "th", "เซนต์ลูเซีย"),
+    ("LC", "uk", "Сент-Люсія"),
+    ("LC", "vi", "Xan Lu-xi"),
+    ("LC", "zh-cn", "圣路西亚"),
+    ("LC", "zh-tw", "聖露西亞"),
+    ("LI", "cs", "Lichtenštejnsko"),
+    ("LI", "el", "Λίχτενσταϊν"),
+    ("LI", "he", "ליכטנשטיין"),
+    ("LI", "ja", "リヒテンシュタイン"),
+    ("LI", "ko", "리히텐슈타인"),
+    ("LI", "ru", "Лихтенштейн"),
+    ("LI", "th", "ลิกเตนสไตน์"),
+    ("LI", "tr", "Lihtenştayn"),
+    ("LI", "uk", "Ліхтенштейн"),
+    ("LI", "vi", "Likh-ten-xtainh"),
+    ("LI", "zh-cn", "列支敦士登"),
+    ("LI", "zh-tw", "列支敦斯登"),
+    ("LK", "cs", "Šrí Lanka"),
+    ("LK", "el", "Σρι Λάνκα"),
+    ("LK", "he", "סרי לנקה"),
+    ("LK", "hu", "Srí Lanka"),
+    ("LK", "ja", "スリランカ"),
+    ("LK", "ko", "스리랑카"),
+    ("LK", "ru", "Шри-Ланка"),
+    ("LK", "th", "ศรีลังกา"),
+    ("LK", "uk", "Шрі-Ланка"),
+    ("LK", "vi", "Tích Lan"),
+    ("LK", "zh-cn", "斯里兰卡"),
+    ("LK", "zh-tw", "斯里蘭卡"),
+    ("LR", "cs", "Libérie"),
+    ("LR", "el", "Λιβερία"),
+    ("LR", "fr", "Libéria"),
+    ("LR", "he", "ליבריה"),
+    ("LR", "hu", "Libéria"),
+    ("LR", "ja", "リベリア"),
+    ("LR", "ko", "라이베리아"),
+    ("LR", "pt", "Libéria"),
+    ("LR", "ru", "Либерия"),
+    ("LR", "th", "ไลบีเรีย"),
+    ("LR", "tr", "Liberya"),
+    ("LR", "uk", "Ліберія"),
+    ("LR", "vi", "Li-bê-ri-a"),
+    ("LR", "zh-cn", "利比里亚"),
+    ("LR", "zh-tw", "賴比瑞亞"),
+    ("LS", "el", "Λεσότο"),
+    ("LS", "es", "Lesoto"),
+    ("LS", "he", "לסוטו"),
+    ("LS", "ja", "レソト"),
+    ("LS", "ko", "레소토"),
+    ("LS", "pt", "Lesoto"),
+    ("LS", "ru", "Лесото"),
+    ("LS", "th", "เลโซโท"),
+    ("LS", "tr", "Lesoto"),
+    ("LS", "uk", "Лесото"),
+    ("LS", "vi", "Lê-xô-thô"),
+    ("LS", "zh-cn", "莱索托"),
+    ("LS", "zh-tw", "賴索托"),
+    ("LT", "cs", "Litva"),
+    ("LT", "da", "Litauen"),
+    ("LT", "de", "Litauen"),
+    ("LT", "el", "Λιθουανία"),
+    ("LT", "es", "Lituania"),
+    ("LT", "fi", "Liettua"),
+    ("LT", "fr", "Lituanie"),
+    ("LT", "he", "ליטא"),
+    ("LT", "hu", "Litvánia"),
+    ("LT", "id", "Lituania"),
+    ("LT", "it", "Lituania"),
+    ("LT", "ja", "リトアニア"),
+    ("LT", "ko", "리투아니아"),
+    ("LT", "nl", "Litouwen"),
+    ("LT", "pl", "Litwa"),
+    ("LT", "pt", "Lituânia"),
+    ("LT", "ro", "Lituania"),
+    ("LT", "ru", "Литва"),
+    ("LT", "sv", "Litauen"),
+    ("LT", "th", "ลิทัวเนีย"),
+    ("LT", "tr", "Litvanya"),
+    ("LT", "uk", "Литва"),
+    ("LT", "vi", "Li-tu-a-ni-a"),
+    ("LT", "zh-cn", "立陶宛"),
+    ("LT", "zh-tw", "立陶宛"),
+    ("LU", "cs", "Lucembursko"),
+    ("LU", "de", "Luxemburg"),
+    ("LU", "el", "Λουξεμβούργο"),
+    ("LU", "es", "Luxemburgo"),
+    ("LU", "fi", "Luxemburg"),
+    ("LU", "he", "לוקסמבורג"),
+    ("LU", "hu", "Luxemburg"),
+    ("LU", "id", "Luksemburg"),
+    ("LU", "it", "Lussemburgo"),
+    ("LU", "ja", "ルクセンブルク"),
+    ("LU", "ko", "룩셈부르크"),
+    ("LU", "nl", "Luxemburg"),
+    ("LU", "pl", "Luksemburg"),
+    ("LU", "pt", "Luxemburgo"),
+    ("LU", "ro", "Luxemburg"),
+    ("LU", "ru", "Люксембург"),
+    ("LU", "sv", "Luxemburg"),
+    ("LU", "th", "ลักเซมเบิร์ก"),
+    ("LU", "tr", "Lüksemburg"),
+    ("LU", "uk", "Люксембург"),
+    ("LU", "vi", "Lục Xâm Bảo"),
+    ("LU", "zh-cn", "卢森堡"),
+    ("LU", "zh-tw", "盧森堡"),
+    ("LV", "cs", "Lotyšsko"),
+    ("LV", "da", "Letland"),
+    ("LV", "de", "Lettland"),
+    ("LV", "el", "Λετονία"),
+    ("LV", "es", "Letonia"),
+    ("LV", "fr", "Lettonie"),
+    ("LV", "he", "לטביה"),
+    ("LV", "hu", "Lettország"),
+    ("LV", "it", "Lettonia"),
+    ("LV", "ja", "ラトビア"),
+    ("LV", "ko", "라트비아"),
+    ("LV", "nl", "Letland"),
+    ("LV", "pl", "Łotwa"),
+    ("LV", "pt", "Letônia"),
+    ("LV", "ro", "Letonia"),
+    ("LV", "ru", "Латвия"),
+    ("LV", "sv", "Lettland"),
+    ("LV", "th", "ลัตเวีย"),
+    ("LV", "tr", "Letonya"),
+    ("LV", "uk", "Латвія"),
+    ("LV", "vi", "Lát-vi-a"),
+    ("LV", "zh-cn", "拉脱维亚"),
+    ("LV", "zh-tw", "拉脫維亞"),
+    ("LY", "cs", "Libye"),
+    ("LY", "da", "Libyen"),
+    ("LY", "de", "Libyen"),
+    ("LY", "el", "Λιβύη"),
+    ("LY", "es", "Libia"),
+    ("LY", "fr", "Libye"),
+    ("LY", "he", "לוב"),
+    ("LY", "hu", "Líbia"),
+    ("LY", "it", "Libia"),
+    ("LY", "ja", "リビア"),
+    ("LY", "ko", "리비아"),
+    ("LY", "nl", "Libië"),
+    ("LY", "pl", "Libia"),
+    ("LY", "pt", "Líbia"),
+    ("LY", "ro", "Libia"),
+    ("LY", "ru", "Ливия"),
+    ("LY", "sv", "Libyen"),
+    ("LY", "th", "ลิเบีย"),
+    ("LY", "uk", "Лівія"),
+    ("LY", "vi", "Li-bi"),
+    ("LY", "zh-cn", "利比亚"),
+    ("LY", "zh-tw", "利比亞"),
+    ("MA", "cs", "Maroko"),
+    ("MA", "da", "Marokko"),
+    ("MA", "de", "Marokko"),
+    ("MA", "el", "Μαρόκο"),
+    ("MA", "es", "Marruecos"),
+    ("MA", "fi", "Marokko"),
+    ("MA", "fr", "Maroc"),
+    ("MA", "he", "מרוקו"),
+    ("MA", "hu", "Marokkó"),
+    ("MA", "id", "Maroko"),
+    ("MA", "it", "Marocco"),
+    ("MA", "ja", "モロッコ"),
+    ("MA", "ko", "모로코"),
+    ("MA", "nl", "Marokko"),
+    ("MA", "pl", "Maroko"),
+    ("MA", "pt", "Marrocos"),
+    ("MA", "ro", "Maroc"),
+    ("MA", "ru", "Марокко"),
+    ("MA", "sv", "Marocko"),
+    ("MA", "th", "โมร็อกโก"),
+    ("MA", "tr", "Fas"),
+    ("MA", "uk", "Марокко"),
+    ("MA", "vi", "Mo-ro-cô"),
+    ("MA", "zh-cn", "摩洛哥"),
+    ("MA", "zh-tw", "摩洛哥"),
+    ("MC", "cs", "Monako"),
+    ("MC", "el", "Μονακό"),
+    ("MC", "es", "Mónaco"),
+    ("MC", "he", "מונקו"),
+    ("MC", "id", "Monako"),
+    ("MC", "ja", "モナコ"),
+    ("MC", "ko", "모나코"),
+    ("MC", "pl", "Monako"),
+    ("MC", "pt", "Mônaco"),
+    ("MC", "ru", "Монако"),
+    ("MC", "th", "โมนาโก"),
+    ("MC", "tr", "Monako"),
+    ("MC", "uk", "Монако"),
+    ("MC", "vi", "Mo-na-cô"),
+    ("MC", "zh-cn", "摩纳哥"),
+    ("MC", "zh-tw", "摩納哥"),
+    ("MD", "cs", "Moldavsko"),
+    ("MD", "de", "Moldau"),
+    ("MD", "el", "Μολδαβία"),
+    ("MD", "es", "Moldavia"),
+    ("MD", "fr", "Moldavie"),
+    ("MD", "he", "מולדובה"),
+    ("MD", "it", "Moldavia"),
+    ("MD", "ja", "モルドバ"),
+    ("MD", "ko", "몰도바"),
+    ("MD", "nl", "Moldavië"),
+    ("MD", "pl", "Mołdawia"),
+    ("MD", "pt", "Moldávia"),
+    ("MD", "ru", "Молдавия"),
+    ("MD", "sv", "Moldavien"),
+    ("MD", "th", "มอลโดวา"),
+    ("MD", "uk", "Молдова"),
+    ("MD", "vi", "Mon-đô-va"),
+    ("MD", "zh-cn", "摩尔多瓦"),
+    ("MD", "zh-tw", "摩爾多瓦"),
+    ("ME", "cs", "Černá Hora"),
+    ("ME", "el", "Μαυροβούνιο"),
+    ("ME", "fr", "Monténégro"),
+    ("ME", "he", "מונטנגרו"),
+    ("ME", "hu", "Montenegró"),
+    ("ME", "ja", "モンテネグロ"),
+    ("ME", "ko", "몬테네그로"),
+    ("ME", "pl", "Czarnogóra"),
+    ("ME", "ro", "Muntenegru"),
+    ("ME", "ru", "Черногория"),
+    ("ME", "th", "มอนเตเนโกร"),
+    ("ME", "tr", "Karadağ"),
+    ("ME", "uk", "Чорногорія"),
+    ("ME", "vi", "Mon-te-nê-gợ-rô"),
+    ("ME", "zh-cn", "黑山"),
+    ("ME", "zh-tw", "蒙特內哥羅"),
+    ("MF", "cs", "Svatý Martin (francouzská část)"),
+    ("MF", "da", "Sankt Martin (Fransk del)"),
+    ("MF", "de", "Saint Martin (Französischer Teil)"),
+    ("MF", "el", "Άγιος Μαρτίνος (Γαλλικό τμήμα)"),
+    ("MF", "es", "San Martín (zona francesa)"),
+    ("MF", "fi", "Saint-Martin (ranskalainen osa)"),
+    ("MF", "fr", "Saint-Martin (partie française)"),
+    ("MF", "he", "סן מרטן"),
+    ("MF", "hu", "Saint Martin (francia oldal)"),
+    ("MF", "id", "Saint Martin (wilayah Prancis)"),
+    ("MF", "it", "Saint-Martin (Francia)"),
+    ("MF", "ja", "サンマルタン (仏領)"),
+    ("MF", "ko", "생마르탱 (프랑스령)"),
+    ("MF", "nl", "Sint-Maarten (Frans deel)"),
+    ("MF", "pl", "Saint-Martin (część francuska)"),
+    ("MF", "pt", "São Martim (parte francesa)"),
+    ("MF", "ro", "Sfântul Martin (partea franceză)"),
+    ("MF", "ru", "Сен-Мартен (Франция)"),
+    ("MF", "sv", "Saint Martin (franska delen)"),
+    ("MF", "th", "แซงมาร์แตง (ส่วนของฝรั่งเศส)"),
+    ("MF", "tr", "Saint Martin (Fransız kısmı)"),
+    ("MF", "uk", "Сен-Мартен (французька частина)"),
+    ("MF", "vi", "Saint Martin (vùng Pháp)"),
+    ("MF", "zh-cn", "法属圣马丁"),
+    ("MF", "zh-tw", "聖馬丁 (法屬)"),
+    ("MG", "cs", "Madagaskar"),
+    ("MG", "da", "Madagaskar"),
+    ("MG", "de", "Madagaskar"),
+    ("MG", "el", "Μαδαγασκάρη"),
+    ("MG", "fi", "Madagaskar"),
+    ("MG", "he", "מדגסקר"),
+    ("MG", "hu", "Madagaszkár"),
+    ("MG", "id", "Madagaskar"),
+    ("MG", "ja", "マダガスカル"),
+    ("MG", "ko", "마다가스카르"),
+    ("MG", "nl", "Madagaskar"),
+    ("MG", "pl", "Madagaskar"),
+    ("MG", "ru", "Мадагаскар"),
+    ("MG", "sv", "Madagaskar"),
+    ("MG", "th", "มาดากัสการ์"),
+    ("MG", "tr", "Madagaskar"),
+    ("MG", "uk", "Мадагаскар"),
+    ("MG", "vi", "Ma-đa-ga-xợ-ca"),
+    ("MG", "zh-cn", "马达加斯加"),
+    ("MG", "zh-tw", "馬達加斯加"),
+    ("MH", "cs", "Marshallovy ostrovy"),
+    ("MH", "da", "Marshalløerne"),
+    ("MH", "de", "Marshallinseln"),
+    ("MH", "el", "Νήσοι Μάρσαλ"),
+    ("MH", "es", "Islas Marshall"),
+    ("MH", "fi", "Marshallinsaaret"),
+    ("MH", "fr", "Îles Marshall"),
+    ("MH", "he", "איי מרשל"),
+    ("MH", "hu", "Marshall-szigetek"),
+    ("MH", "id", "Kepulauan Marshall"),
+    ("MH", "it", "Isole Marshall"),
+    ("MH", "ja", "マーシャル諸島"),
+    ("MH", "ko", "마셜 제도"),
+    ("MH", "nl", "Marshalleilanden"),
+    ("MH", "pl", "Wyspy Marshalla"),
+    ("MH", "pt", "Ilhas Marshall"),
+    ("MH", "ro", "Insulele Marshall"),
+    ("MH", "ru", "Маршалловы острова"),
+    ("MH", "sv", "Marshallöarna"),
+    ("MH", "th", "หมู่เกาะมาร์แชลล์"),
+    ("MH", "tr", "Marşal Adaları"),
+    ("MH", "uk", "Маршаллові острови"),
+    ("MH", "vi", "Quần Đảo Ma-san"),
+    ("MH", "zh-cn", "马绍尔群岛"),
+    ("MH", "zh-tw", "馬紹爾群島"),
+    ("MK", "cs", "Severní Makedonie"),
+    ("MK", "da", "Nordmakedonien"),
+    ("MK", "de", "Nordmazedonien"),
+    ("MK", "el", "Βόρεια Μακεδονία"),
+    ("MK", "es", "Macedonia del Norte"),
+    ("MK", "fr", "Macédoine du Nord"),
+    ("MK", "he", "צפון קלדוניה"),
+    ("MK", "hu", "Észak-Macedónia"),
+    ("MK", "id", "Makedonia Utara"),
+    ("MK", "it", "Macedonia del Nord"),
+    ("MK", "ko", "북마케도니아"),
+    ("MK", "nl", "Noord-Macedonië"),
+    ("MK", "pl", "Macedonia Północna"),
+    ("MK", "pt", "Macedônia do Norte"),
+    ("MK", "ru", "Северная Македония"),
+    ("MK", "sv", "Nordmakedonien"),
+    ("MK", "tr", "Kuzey Makedonya"),
+    ("MK", "uk", "Північна Македонія"),
+    ("MK", "zh-cn", "北马其顿"),
+    ("MK", "zh-tw", "北馬其頓"),
+    ("ML", "el", "Μάλι"),
+    ("ML", "es", "Malí"),
+    ("ML", "he", "מאלי"),
+    ("ML", "ja", "マリ"),
+    ("ML", "ko", "말리"),
+    ("ML", "ru", "Мали"),
+    ("ML", "th", "มาลี"),
+    ("ML", "uk", "Малі"),
+    ("ML", "vi", "Ma-li"),
+    ("ML", "zh-cn", "马里"),
+    ("ML", "zh-tw", "馬利"),
+    ("MM", "da", "Burma"),
+    ("MM", "el", "Μιανμάρ"),
+    ("MM", "es", "Birmania"),
+    ("MM", "fr", "Birmanie"),
+    ("MM", "he", "מיאנמר"),
+    ("MM", "hu", "Mianmar"),
+    ("MM", "it", "Birmania"),
+    ("MM", "ja", "ミャンマー"),
+    ("MM", "ko", "미얀마"),
+    ("MM", "pl", "Mjanma"),
+    ("MM", "ru", "Мьянма"),
+    ("MM", "th", "พม่า"),
+    ("MM", "uk", "М’янма"),
+    ("MM", "vi", "Miến Điện"),
+    ("MM", "zh-cn", "缅甸"),
+    ("MM", "zh-tw", "緬甸"),
+    ("MN", "cs", "Mongolsko"),
+    ("MN", "da", "Mongoliet"),
+    ("MN", "de", "Mongolei"),
+    ("MN", "el", "Μογγολία"),
+    ("MN", "fr", "Mongolie"),
+    ("MN", "he", "מונגוליה"),
+    ("MN", "hu", "Mongólia"),
+    ("MN", "ja", "モンゴル国"),
+    ("MN", "ko", "몽골"),
+    ("MN", "nl", "Mongolië"),
+    ("MN", "pt", "Mongólia"),
+    ("MN", "ru", "Монголия"),
+    ("MN", "sv", "Mongoliet"),
+    ("MN", "th", "มองโกเลีย"),
+    ("MN", "tr", "Moğolistan"),
+    ("MN", "uk", "Монголія"),
+    ("MN", "vi", "Mông Cổ"),
+    ("MN", "zh-cn", "蒙古"),
+    ("MN", "zh-tw", "蒙古"),
+    ("MO", "el", "Μακάο"),
+    ("MO", "fr", "Macau"),
+    ("MO", "he", "מאקאו"),
+    ("MO", "hu", "Makaó"),
+    ("MO", "ja", "マカオ"),
+    ("MO", "ko", "마카오"),
+    ("MO", "nl", "Macau"),
+    ("MO", "pl", "Makau"),
+    ("MO", "pt", "Macau"),
+    ("MO", "ru", "Макао"),
+    ("MO", "th", "มาเก๊า"),
+    ("MO", "tr", "Makao"),
+    ("MO", "uk", "Макао"),
+    ("MO", "vi", "Ma-cao"),
+    ("MO", "zh-cn", "澳门"),
+    ("MO", "zh-tw", "澳門"),
+    ("MP", "cs", "Severní Mariany"),
+    ("MP", "da", "Nordmarianerne"),
+    ("MP", "de", "Nördliche Marianen"),
+    ("MP", "el", "Βόρειες Μαριάνες Νήσοι"),
+    ("MP", "es", "Islas Marianas del Norte"),
+    ("MP", "fi", "Pohjois-Mariaanit"),
+    ("MP", "fr", "Îles Mariannes du Nord"),
+    ("MP", "he", "איי מריאנה הצפוניים"),
+    ("MP", "hu", "Északi-Mariana-szigetek"),
+    ("MP", "id", "Kepulauan Mariana Utara"),
+    ("MP", "it", "Isole Marianne Settentrionali"),
+    ("MP", "ja", "北マリアナ諸島"),
+    ("MP", "ko", "북마리아나 제도"),
+    ("MP", "nl", "Noordelijke Marianen"),
+    ("MP", "pl", "Mariany Północne"),
+    ("MP", "pt", "Ilhas Marianas do Norte"),
+    ("MP", "ro", "Insulele Mariane de Nord"),
+    ("MP", "ru", "Острова северной Марианы"),
+    ("MP", "sv", "Nordmarianerna"),
+    ("MP", "th", "หมู่เกาะนอร์เทิร์นมาเรียนา"),
+    ("MP", "tr", "Kuzey Mariana Adaları"),
+    ("MP", "uk", "Північні Маріанські Острови"),
+    ("MP", "vi", "Bắc Quần Đảo Ma-ri-a-na"),
+    ("MP", "zh-cn", "北马里亚纳群岛"),
+    ("MP", "zh-tw", "北馬里亞納群島"),
+    ("MQ", "cs", "Martinik"),
+    ("MQ", "el", "Μαρτινίκα"),
+    ("MQ", "es", "Martinica"),
+    ("MQ", "he", "מרטיניק"),
+    ("MQ", "id", "Martinik"),
+    ("MQ", "it", "Martinica"),
+    ("MQ", "ja", "マルティニーク"),
+    ("MQ", "ko", "마르티니크"),
+    ("MQ", "pl", "Martynika"),
+    ("MQ", "pt", "Martinica"),
+    ("MQ", "ro", "Martinica"),
+    ("MQ", "ru", "Мартиника"),
+    ("MQ", "th", "มาร์ตินีก"),
+    ("MQ", "uk", "Мартиніка"),
+    ("MQ", "vi", "Ma-thi-ni-khợ"),
+    ("MQ", "zh-cn", "马提尼克"),
+    ("MQ", "zh-tw", "馬丁尼克"),
+    ("MR", "cs", "Mauritánie"),
+    ("MR", "da", "Mauretanien"),
+    ("MR", "de", "Mauretanien"),
+    ("MR", "el", "Μαυριτανία"),
+    ("MR", "fr", "Mauritanie"),
+    ("MR", "he", "מאוריטניה"),
+    ("MR", "hu", "Mauritánia"),
+    ("MR", "ja", "モーリタニア"),
+    ("MR", "ko", "모리타니"),
+    ("MR", "nl", "Mauritanië"),
+    ("MR", "pl", "Mauretania"),
+    ("MR", "pt", "Mauritânia"),
+    ("MR", "ru", "Мавритания"),
+    ("MR", "sv", "Mauretanien"),
+    ("MR", "th", "มอริเตเนีย"),
+    ("MR", "tr", "Moritanya"),
+    ("MR", "uk", "Мавританія"),
+    ("MR", "vi", "Mô-ri-ta-ni-a"),
+    ("MR", "zh-cn", "毛里塔尼亚"),
+    ("MR", "zh-tw", "茅利塔尼亞"),
+    ("MS", "el", "Μοντσεράτ"),
+    ("MS", "he", "מונטסראט"),
+    ("MS", "ja", "モントセラト"),
+    ("MS", "ko", "몬트세랫"),
+    ("MS", "ru", "Монтсеррат"),
+    ("MS", "th", "มอนต์เซอร์รัต"),
+    ("MS", "uk", "Монтсеррат"),
+    ("MS", "vi", "Mon-xe-rạc"),
+    ("MS", "zh-cn", "蒙塞拉特岛"),
+    ("MS", "zh-tw", "蒙塞拉特島"),
+    ("MT", "el", "Μάλτα"),
+    ("MT", "fr", "Malte"),
+    ("MT", "he", "מלטה"),
+    ("MT", "hu", "Málta"),
+    ("MT", "ja", "マルタ"),
+    ("MT", "ko", "몰타"),
+    ("MT", "ru", "Мальта"),
+    ("MT", "th", "มอลตา"),
+    ("MT", "uk", "Мальта"),
+    ("MT", "vi", "Moa-ta"),
+    ("MT", "zh-cn", "马尔他"),
+    ("MT", "zh-tw", "馬爾他"),
+    ("MU", "cs", "Mauricius"),
+    ("MU", "el", "Μαυρίκιος"),
+    ("MU", "es", "Mauricio"),
+    ("MU", "fr", "Maurice"),
+    ("MU", "he", "מאוריציוס"),
+    ("MU", "it", "Maurizio"),
+    ("MU", "ja", "モーリシャス"),
+    ("MU", "ko", "모리셔스"),
+    ("MU", "pt", "Maurício"),
+    ("MU", "ro", "Maurițius"),
+    ("MU", "ru", "Маврикий"),
+    ("MU", "th", "มอริเชียส"),
+    ("MU", "uk", "Маврикій"),
+    ("MU", "vi", "Mô-ri-sơ-xợ"),
+    ("MU", "zh-cn", "毛里求斯"),
+    ("MU", "zh-tw", "模里西斯"),
+    ("MV", "cs", "Maledivy"),
+    ("MV", "da", "Maldiverne"),
+    ("MV", "de", "Malediven"),
+    ("MV", "el", "Μαλδίβες"),
+    ("MV", "es", "Islas Maldivas"),
+    ("MV", "fi", "Malediivit"),
+    ("MV", "he", "האיים המלדיביים"),
+    ("MV", "hu", "Maldív-szigetek"),
+    ("MV", "id", "Maladewa"),
+    ("MV", "it", "Maldive"),
+    ("MV", "ja", "モルディブ"),
+    ("MV", "ko", "몰디브"),
+    ("MV", "nl", "Maldiven"),
+    ("MV", "pl", "Malediwy"),
+    ("MV", "pt", "Maldivas"),
+    ("MV", "ro", "Maldive"),
+    ("MV", "ru", "Мальдивы"),
+    ("MV", "sv", "Maldiverna"),
+    ("MV", "th", "มัลดีฟส์"),
+    ("MV", "tr", "Maldivler"),
+    ("MV", "uk", "Мальдіви"),
+    ("MV", "vi", "Mal-đi-vợx"),
+    ("MV", "zh-cn", "马尔代夫"),
+    ("MV", "zh-tw", "馬爾地夫"),
+    ("MW", "el", "Μαλάουι"),
+    ("MW", "es", "Malaui"),
+    ("MW", "he", "מלאווי"),
+    ("MW", "ja", "マラウイ"),
+    ("MW", "ko", "말라위"),
+    ("MW", "pt", "Malaui"),
+    ("MW", "ru", "Малави"),
+    ("MW", "th", "มาลาวี"),
+    ("MW", "tr", "Malavi"),
+    ("MW", "uk", "Малаві"),
+    ("MW", "vi", "Ma-la-uy"),
+    ("MW", "zh-cn", "马拉维"),
+    ("MW", "zh-tw", "馬拉威"),
+    ("MX", "cs", "Mexiko"),
+    ("MX", "de", "Mexiko"),
+    ("MX", "el", "Μεξικό"),
+    ("MX", "es", "México"),
+    ("MX", "fi", "Meksiko"),
+    ("MX", "fr", "Mexique"),
+    ("MX", "he", "מקסיקו"),
+    ("MX", "hu", "Mexikó"),
+    ("MX", "id", "Meksiko"),
+    ("MX", "it", "Messico"),
+    ("MX", "ja", "メキシコ"),
+    ("MX", "ko", "멕시코"),
+    ("MX", "pl", "Meksyk"),
+    ("MX", "pt", "México"),
+    ("MX", "ro", "Mexic"),
+    ("MX", "ru", "Мексика"),
+    ("MX", "sv", "Mexiko"),
+    ("MX", "th", "เม็กซิโก"),
+    ("MX", "tr", "Meksika"),
+    ("MX", "uk", "Мексика"),
+    ("MX", "vi", "Mê-hi-cô"),
+    ("MX", "zh-cn", "墨西哥"),
+    ("MX", "zh-tw", "墨西哥"),
+    ("MY", "cs", "Malajsie"),
+    ("MY", "el", "Μαλαισία"),
+    ("MY", "es", "Malasia"),
+    ("MY", "fi", "Malesia"),
+    ("MY", "fr", "Malaisie"),
+    ("MY", "he", "מלזיה"),
+    ("MY", "hu", "Malajzia"),
+    ("MY", "ja", "マレーシア"),
+    ("MY", "ko", "말레이시아"),
+    ("MY", "nl", "Maleisië"),
+    ("MY", "pl", "Malezja"),
+    ("MY", "pt", "Malásia"),
+    ("MY", "ro", "Malaezia"),
+    ("MY", "ru", "Малайзия"),
+    ("MY", "th", "มาเลเซีย"),
+    ("MY", "tr", "Malezya"),
+    ("MY", "uk", "Малайзія"),
+    ("MY", "vi", "Ma-lai-xi-a"),
+    ("MY", "zh-cn", "马来西亚"),
+    ("MY", "zh-tw", "馬來西亞"),
+    ("MZ", "cs", "Mosambik"),
+    ("MZ", "da", "Mocambique"),
+    ("MZ", "de", "Mosambik"),
+    ("MZ", "el", "Μοζαμβίκη"),
+    ("MZ", "fi", "Mosambik"),
+    ("MZ", "he", "מוזמביק"),
+    ("MZ", "hu", "Mozambik"),
+    ("MZ", "id", "Mozambik"),
+    ("MZ", "it", "Mozambico"),
+    ("MZ", "ja", "モザンビーク"),
+    ("MZ", "ko", "모잠비크"),
+    ("MZ", "pl", "Mozambik"),
+    ("MZ", "pt", "Moçambique"),
+    ("MZ", "ro", "Mozambic"),
+    ("MZ", "ru", "Мозамбик"),
+    ("MZ", "sv", "Moçambique"),
+    ("MZ", "th", "โมซัมบิก"),
+    ("MZ", "tr", "Mozambik"),
+    ("MZ", "uk", "Мозамбік"),
+    ("MZ", "vi", "Mô-xam-bí-khợ"),
+    ("MZ", "zh-cn", "莫桑比克"),
+    ("MZ", "zh-tw", "莫三比克"),
+    ("NA", "cs", "Namibie"),
+    ("NA", "el", "Ναμίμπια"),
+    ("NA", "fr", "Namibie"),
+    ("NA", "he", "נמיביה"),
+    ("NA", "hu", "Namíbia"),
+    ("NA", "ja", "ナミビア"),
+    ("NA", "ko", "나미비아"),
+    ("NA", "nl", "Namibië"),
+    ("NA", "pt", "Namíbia"),
+    ("NA", "ru", "Намибия"),
+    ("NA", "th", "นามิเบีย"),
+    ("NA", "tr", "Namibya"),
+    ("NA", "uk", "Намібія"),
+    ("NA", "vi", "Na-mi-bi-a"),
+    ("NA", "zh-cn", "纳米比亚"),
+    ("NA", "zh-tw", "納米比亞"),
+    ("NC", "cs", "Nová Kaledonie"),
+    ("NC", "da", "Ny Kaledonien"),
+    ("NC", "de", "Neukaledonien"),
+    ("NC", "el", "Νέα Καληδονία"),
+    ("NC", "es", "Nueva Caledonia"),
+    ("NC", "fi", "Uusi-Kaledonia"),
+    ("NC", "fr", "Nouvelle-Calédonie"),
+    ("NC", "he", "קלדוניה החדשה"),
+    ("NC", "hu", "Új-Kaledónia"),
+    ("NC", "id", "Kaledonia Baru"),
+    ("NC", "it", "Nuova Caledonia"),
+    ("NC", "ja", "ニューカレドニア"),
+    ("NC", "ko", "누벨칼레도니"),
+    ("NC", "nl", "Nieuw-Caledonië"),
+    ("NC", "pl", "Nowa Kaledonia"),
+    ("NC", "pt", "Nova Caledônia"),
+    ("NC", "ro", "Noua Caledonie"),
+    ("NC", "ru", "Новая Каледония"),
+    ("NC", "sv", "Nya Kaledonien"),
+    ("NC", "th", "นิวแคลิโดเนีย"),
+    ("NC", "tr", "Yeni Kaledonya"),
+    ("NC", "uk", "Нова Каледонія"),
+    ("NC", "vi", "Niu Ca-lê-đô-ni-a"),
+    ("NC", "zh-cn", "新喀里多尼亚"),
+    ("NC", "zh-tw", "新喀里多尼亞"),
+    ("NE", "el", "Νίγηρας"),
+    ("NE", "he", "ניז׳ר"),
+    ("NE", "ja", "ニジェール"),
+    ("NE", "ko", "니제르"),
+    ("NE", "pt", "Níger"),
+    ("NE", "ru", "Нигер"),
+    ("NE", "th", "ไนเจอร์"),
+    ("NE", "tr", "Nijer"),
+    ("NE", "uk", "Нігер"),
+    ("NE", "vi", "Ni-gie"),
+    ("NE", "zh-cn", "尼日尔"),
+    ("NE", "zh-tw", "尼日"),
+    ("NF", "cs", "Norfolkský ostrov"),
+    ("NF", "da", "Norfolk Øen"),
+    ("NF", "de", "Norfolkinsel"),
+    ("NF", "el", "Νήσος Νόρφολκ"),
+    ("NF", "es", "Isla Norfolk"),
+    ("NF", "fi", "Norfolkinsaari"),
+    ("NF", "fr", "île Norfolk"),
+    ("NF", "he", "נורפוק"),
+    ("NF", "hu", "Norfolk-sziget"),
+    ("NF", "id", "Pulau Norfolk"),
+    ("NF", "it", "Isola Norfolk"),
+    ("NF", "ja", "ノーフォーク島"),
+    ("NF", "ko", "노퍽 섬"),
+    ("NF", "nl", "Norfolk"),
+    ("NF", "pl", "Wyspy Norfolk"),
+    ("NF", "pt", "Ilha Norfolk"),
+    ("NF", "ro", "Insula Norfolk"),
+    ("NF", "ru", "Остров Норфолк"),
+    ("NF", "sv", "Norfolköarna"),
+    ("NF", "th", "เกาะนอร์ฟอล์ก"),
+    ("NF", "tr", "Norfolk Adası"),
+    ("NF", "uk", "Острів Норфолк"),
+    ("NF", "vi", "Đảo Noa-phọ-khợ"),
+    ("NF", "zh-cn", "诺福克岛"),
+    ("NF", "zh-tw", "諾福克島"),
+    ("NG", "cs", "Nigérie"),
+    ("NG", "el", "Νιγηρία"),
+    ("NG", "he", "ניגריה"),
+    ("NG", "hu", "Nigéria"),
+    ("NG", "ja", "ナイジェリア"),
+    ("NG", "ko", "나이지리아"),
+    ("NG", "pt", "Nigéria"),
+    ("NG", "ru", "Нигерия"),
+    ("NG", "th", "ไนจีเรีย"),
+    ("NG", "tr", "Nijerya"),
+    ("NG", "uk", "Нігерія"),
+    ("NG", "vi", "Ni-giê-ri-a"),
+    ("NG", "zh-cn", "尼日利亚"),
+    ("NG", "zh-tw", "奈及利亞"),
+    ("NI", "cs", "Nikaragua"),
+    ("NI", "el", "Νικαράγουα"),
+    ("NI", "he", "ניקרגואה"),
+    ("NI", "id", "Nikaragua"),
+    ("NI", "ja", "ニカラグア"),
+    ("NI", "ko", "니카라과"),
+    ("NI", "pl", "Nikaragua"),
+    ("NI", "pt", "Nicarágua"),
+    ("NI", "ru", "Никарагуа"),
+    ("NI", "th", "นิการากัว"),
+    ("NI", "tr", "Nikaragua"),
+    ("NI", "uk", "Нікарагуа"),
+    ("NI", "vi", "Ni-ca-ra-gua"),
+    ("NI", "zh-cn", "尼加拉瓜"),
+    ("NI", "zh-tw", "尼加拉瓜"),
+    ("NL", "cs", "Nizozemsko"),
+    ("NL", "da", "Holland"),
+    ("NL", "de", "Niederlande"),
+    ("NL", "el", "Ολλανδία"),
+    ("NL", "es", "Países Bajos"),
+    ("NL", "fi", "Alankomaat"),
+    ("NL", "fr", "Pays-Bas"),
+    ("NL", "he", "הולנד"),
+    ("NL", "hu", "Hollandia"),
+    ("NL", "id", "Belanda"),
+    ("NL", "it", "Paesi Bassi"),
+    ("NL", "ja", "オランダ"),
+    ("NL", "ko", "네덜란드"),
+    ("NL", "nl", "Nederland"),
+    ("NL", "pl", "Holandia"),
+    ("NL", "pt", "Países Baixos"),
+    ("NL", "ro", "Olanda"),
+    ("NL", "ru", "Нидерланды"),
+    ("NL", "sv", "Nederländerna"),
+    ("NL", "th", "เนเธอร์แลนด์"),
+    ("NL", "tr", "Hollanda"),
+    ("NL", "uk", "Нідерланди"),
+    ("NL", "vi", "Hoà Lan"),
+    ("NL", "zh-cn", "荷兰"),
+    ("NL", "zh-tw", "荷蘭"),
+    ("NO", "cs", "Norsko"),
+    ("NO", "da", "Norge"),
+    ("NO", "de", "Norwegen"),
+    ("NO", "el", "Νορβηγία"),
+    ("NO", "es", "Noruega"),
+    ("NO", "fi", "Norja"),
+    ("NO", "fr", "Norvège"),
+    ("NO", "he", "נורווגיה"),
+    ("NO", "hu", "Norvégia"),
+    ("NO", "id", "Norwegia"),
+    ("NO", "it", "Norvegia"),
+    ("NO", "ja", "ノルウェー"),
+    ("NO", "ko", "노르웨이"),
+    ("NO", "nl", "Noorwegen"),
+    ("NO", "pl", "Norwegia"),
+    ("NO", "pt", "Noruega"),
+    ("NO", "ro", "Norvegia"),
+    ("NO", "ru", "Норвегия"),
+    ("NO", "sv", "Norge"),
+    ("NO", "th", "นอร์เวย์"),
+    ("NO", "tr", "Norveç"),
+    ("NO", "uk", "Норвегія"),
+    ("NO", "vi", "Na Uy"),
+    ("NO", "zh-cn", "挪威"),
+    ("NO", "zh-tw", "挪威"),
+    ("NP", "cs", "Nepál"),
+    ("NP", "el", "Νεπάλ"),
+    ("NP", "fr", "Népal"),
+    ("NP", "he", "נפאל"),
+    ("NP", "hu", "Nepál"),
+    ("NP", "ja", "ネパール"),
+    ("NP", "ko", "네팔"),
+    ("NP", "ru", "Непал"),
+    ("NP", "th", "เนปาล"),
+    ("NP", "uk", "Непал"),
+    ("NP", "vi", "Nê-pan"),
+    ("NP", "zh-cn", "尼泊尔"),
+    ("NP", "zh-tw", "尼泊爾"),
+    ("NR", "el", "Ναουρού"),
+    ("NR", "he", "נאורו"),
+    ("NR", "ja", "ナウル"),
+    ("NR", "ko", "나우루"),
+    ("NR", "ru", "Науру"),
+    ("NR", "th", "นาอูรู"),
+    ("NR", "uk", "науру"),
+    ("NR", "vi", "Nau-ru"),
+    ("NR", "zh-cn", "瑙鲁"),
+    ("NR", "zh-tw", "諾魯"),
+    ("NU", "el", "Νιούεϊ"),
+    ("NU", "fr", "Nioue"),
+    ("NU", "he", "ניואה"),
+    ("NU", "ja", "ニウエ"),
+    ("NU", "ko", "니우에"),
+    ("NU", "ru", "Ниуэ"),
+    ("NU", "th", "นีอูเอ"),
+    ("NU", "uk", "Ніуе"),
+    ("NU", "vi", "Ni-u-e"),
+    ("NU", "zh-cn", "纽埃"),
+    ("NU", "zh-tw", "紐埃"),
+    ("NZ", "cs", "Nový Zéland"),
+    ("NZ", "de", "Neuseeland"),
+    ("NZ", "el", "Νέα Ζηλανδία"),
+    ("NZ", "es", "Nueva Zelanda"),
+    ("NZ", "fi", "Uusi-Seelanti"),
+    ("NZ", "fr", "Nouvelle-Zélande"),
+    ("NZ", "he", "ניו זילנד"),
+    ("NZ", "hu", "Új-Zéland"),
+    ("NZ", "id", "Selandia Baru"),
+    ("NZ", "it", "Nuova Zelanda"),
+    ("NZ", "ja", "ニュージーランド"),
+    ("NZ", "ko", "뉴질랜드"),
+    ("NZ", "nl", "Nieuw-Zeeland"),
+    ("NZ", "pl", "Nowa Zelandia"),
+    ("NZ", "pt", "Nova Zelândia"),
+    ("NZ", "ro", "Noua Zeelandă"),
+    ("NZ", "ru", "Новая Зеландия"),
+    ("NZ", "sv", "Nya Zeeland"),
+    ("NZ", "th", "นิวซีแลนด์"),
+    ("NZ", "tr", "Yeni Zelanda"),
+    ("NZ", "uk", "Нова Зеландія"),
+    ("NZ", "vi", "Niu Xi-lân"),
+    ("NZ", "zh-cn", "新西兰"),
+    ("NZ", "zh-tw", "紐西蘭"),
+    ("OM", "cs", "Omán"),
+    ("OM", "el", "Ομάν"),
+    ("OM", "es", "Omán"),
+    ("OM", "he", "עומאן"),
+    ("OM", "hu", "Omán"),
+    ("OM", "ja", "オマーン"),
+    ("OM", "ko", "오만"),
+    ("OM", "pt", "Omã"),
+    ("OM", "ru", "Оман"),
+    ("OM", "th", "โอมาน"),
+    ("OM", "tr", "Umman"),
+    ("OM", "uk", "Оман"),
+    ("OM", "vi", "Ô-man"),
+    ("OM", "zh-cn", "阿曼"),
+    ("OM", "zh-tw", "阿曼"),
+    ("PA", "el", "Παναμάς"),
+    ("PA", "es", "Panamá"),
+    ("PA", "he", "פנמה"),
+    ("PA", "ja", "パナマ"),
+    ("PA", "ko", "파나마"),
+    ("PA", "pt", "Panamá"),
+    ("PA", "ru", "Панама"),
+    ("PA", "th", "ปานามา"),
+    ("PA", "uk", "Панама"),
+    ("PA", "vi", "Pa-na-ma"),
+    ("PA", "zh-cn", "巴拿马"),
+    ("PA", "zh-tw", "巴拿馬"),
+    ("PE", "el", "Περού"),
+    ("PE", "es", "Perú"),
+    ("PE", "fr", "Pérou"),
+    ("PE", "he", "פרו"),
+    ("PE", "it", "Perù"),
+    ("PE", "ja", "ペルー"),
+    ("PE", "ko", "페루"),
+    ("PE", "ru", "Перу"),
+    ("PE", "th", "เปรู"),
+    ("PE", "uk", "Перу"),
+    ("PE", "vi", "Pê-ru"),
+    ("PE", "zh-cn", "秘鲁"),
+    ("PE", "zh-tw", "祕魯"),
+    ("PF", "cs", "Francouzská Polynésie"),
+    ("PF", "da", "Fransk Polynesien"),
+    ("PF", "de", "Französisch-Polynesien"),
+    ("PF", "el", "Γαλλική Πολυνησία"),
+    ("PF", "es", "Polinesia Francesa"),
+    ("PF", "fi", "Ranskan Polynesia"),
+    ("PF", "fr", "Polynésie française"),
+    ("PF", "he", "פולינזיה הצרפתית"),
+    ("PF", "hu", "Francia Polinézia"),
+    ("PF", "id", "Polinesia Perancis"),
+    ("PF", "it", "Polinesia francese"),
+    ("PF", "ja", "仏領ポリネシア"),
+    ("PF", "ko", "프랑스령 폴리네시아"),
+    ("PF", "nl", "Frans-Polynesië"),
+    ("PF", "pl", "Polinezja Francuska"),
+    ("PF", "pt", "Polinésia Francesa"),
+    ("PF", "ro", "Polinezia Franceză"),
+    ("PF", "ru", "Французская Полинезия"),
+    ("PF", "sv", "Franska Polynesien"),
+    ("PF", "th", "เฟรนช์โปลินีเซีย"),
+    ("PF", "tr", "Fransız Polinezyası"),
+    ("PF", "uk", "Французька Полінезія"),
+    ("PF", "vi", "Pô-li-nê-xi Pháp"),
+    ("PF", "zh-cn", "法属玻利尼西亚"),
+    ("PF", "zh-tw", "法屬玻里尼西亞"),
+    ("PG", "cs", "Papua Nová Guinea"),
+    ("PG", "da", "Papua Ny Guinea"),
+    ("PG", "de", "Papua-Neuguinea"),
+    ("PG", "el", "Παπούα Νέα Γουινέα"),
+    ("PG", "es", "Papúa Nueva Guinea"),
+    ("PG", "fi", "Papua-Uusi-Guinea"),
+    ("PG", "fr", "Papouasie-Nouvelle-Guinée"),
+    ("PG", "he", "פפואה גינאה החדשה"),
+    ("PG", "hu", "Pápua Új-Guinea"),
+    ("PG", "id", "Papua Nugini"),
+    ("PG", "it", "Papua Nuova Guinea"),
+    ("PG", "ja", "パプアニューギニア"),
+    ("PG", "ko", "파푸아뉴기니"),
+    ("PG", "nl", "Papoea-Nieuw-Guinea"),
+    ("PG", "pl", "Papua-Nowa Gwinea"),
+    ("PG", "pt", "Papua-Nova Guiné"),
+    ("PG", "ro", "Papua Noua Guinee"),
+    ("PG", "ru", "Папуа — Новая Гвинея"),
+    ("PG", "sv", "Papua Nya Guinea"),
+    ("PG", "th", "ปาปัวนิวกินี"),
+    ("PG", "tr", "Papua Yeni Gine"),
+    ("PG", "uk", "Папуа Нова Гвінея"),
+    ("PG", "vi", "Pa-pu-a Niu Ghi-nê"),
+    ("PG", "zh-cn", "巴布亚新几内亚"),
+    ("PG", "zh-tw", "巴布亞紐幾內亞"),
+    ("PH", "cs", "Filipíny"),
+    ("PH", "da", "Filippinerne"),
+    ("PH", "de", "Philippinen"),
+    ("PH", "el", "Φιλιππίνες"),
+    ("PH", "es", "Filipinas"),
+    ("PH", "fi", "Filippiinit"),
+    ("PH", "he", "הפיליפינים"),
+    ("PH", "hu", "Fülöp-szigetek"),
+    ("PH", "id", "Filipina"),
+    ("PH", "it", "Filippine"),
+    ("PH", "ja", "フィリピン"),
+    ("PH", "ko", "필리핀"),
+    ("PH", "nl", "Filipijnen"),
+    ("PH", "pl", "Filipiny"),
+    ("PH", "pt", "Filipinas"),
+    ("PH", "ro", "Filipine"),
+    ("PH", "ru", "Филиппины"),
+    ("PH", "sv", "Filippinerna"),
+    ("PH", "th", "ฟิลิปปินส์"),
+    ("PH", "tr", "Filipinler"),
+    ("PH", "uk", "Філіппіни"),
+    ("PH", "vi", "Phi-li-pi-nợ"),
+    ("PH", "zh-cn", "菲律宾"),
+    ("PH", "zh-tw", "菲律賓"),
+    ("PK", "cs", "Pákistán"),
+    ("PK", "el", "Πακιστάν"),
+    ("PK", "es", "Pakistán"),
+    ("PK", "he", "פקיסטן"),
+    ("PK", "hu", "Pakisztán"),
+    ("PK", "ja", "パキスタン"),
+    ("PK", "ko", "파키스탄"),
+    ("PK", "pt", "Paquistão"),
+    ("PK", "ru", "Пакистан"),
+    ("PK", "th", "ปากีสถาน"),
+    ("PK", "uk", "Пакистан"),
+    ("PK", "vi", "Pa-ki-xợ-thănh"),
+    ("PK", "zh-cn", "巴基斯坦"),
+    ("PK", "zh-tw", "巴基斯坦"),
+    ("PL", "cs", "Polsko"),
+    ("PL", "da", "Polen"),
+    ("PL", "de", "Polen"),
+    ("PL", "el", "Πολωνία"),
+    ("PL", "es", "Polonia"),
+    ("PL", "fi", "Puola"),
+    ("PL", "fr", "Pologne"),
+    ("PL", "he", "פולין"),
+    ("PL", "hu", "Lengyelország"),
+    ("PL", "id", "Polandia"),
+    ("PL", "it", "Polonia"),
+    ("PL", "ja", "ポーランド"),
+    ("PL", "ko", "폴란드"),
+    ("PL", "nl", "Polen"),
+    ("PL", "pl", "Polska"),
+    ("PL", "pt", "Polônia"),
+    ("PL", "ro", "Polonia"),
+    ("PL", "ru", "Польша"),
+    ("PL", "sv", "Polen"),
+    ("PL", "th", "โปแลนด์"),
+    ("PL", "tr", "Polonya"),
+    ("PL", "uk", "Польща"),
+    ("PL", "vi", "Ba Lan"),
+    ("PL", "zh-cn", "波兰"),
+    ("PL", "zh-tw", "波蘭"),
+    ("PM", "cs", "Svatý Pierre a Miquelon"),
+    ("PM", "da", "Sankt Pierre og Miquelon"),
+    ("PM", "de", "St. Pierre und Miquelon"),
+    ("PM", "el", "Σαιν Πιερ και Μικελόν"),
+    ("PM", "es", "San Pedro y Miquelon"),
+    ("PM", "fi", "Saint-Pierre ja Miquelon"),
+    ("PM", "fr", "Saint-Pierre-et-Miquelon"),
+    ("PM", "he", "סן פייר ומיקלון"),
+    ("PM", "hu", "Saint-Pierre és Miquelon"),
+    ("PM", "id", "Saint Pierre dan Miquelon"),
+    ("PM", "it", "Saint-Pierre e Miquelon"),
+    ("PM", "ja", "サンピエール及びミクロン"),
+    ("PM", "ko", "생피에르 미클롱"),
+    ("PM", "nl", "Saint-Pierre en Miquelon"),
+    ("PM", "pl", "Saint-Pierre i Miquelon"),
+    ("PM", "pt", "São Pedro e Miquelon"),
+    ("PM", "ro", "Saint Pierre și Miquelon"),
+    ("PM", "ru", "Сен-Пьер и Микелон"),
+    ("PM", "sv", "Sankt Pierre och Miquelon"),
+    ("PM", "th", "แซงปีแยร์และมีเกอลง"),
+    ("PM", "tr", "Saint Pierre ve Miquelon"),
+    ("PM", "uk", "Сен-П'єр і Мікелон"),
+    ("PM", "vi", "Xan Pi-e và Mi-quê-lon"),
+    ("PM", "zh-cn", "圣皮埃尔和密克隆"),
+    ("PM", "zh-tw", "聖皮耶及密克隆群島"),
+    ("PN", "cs", "Pitcairnovy ostrovy"),
+    ("PN", "el", "Πίτκαϊρν"),
+    ("PN", "fr", "Îles Pitcairn"),
+    ("PN", "he", "פיטקרן"),
+    ("PN", "hu", "Pitcairn-szigetek"),
+    ("PN", "ja", "ピトケアン"),
+    ("PN", "ko", "핏케언 제도"),
+    ("PN", "nl", "Pitcairneilanden"),
+    ("PN", "ru", "Питкэрн"),
+    ("PN", "th", "พิตแคร์น"),
+    ("PN", "uk", "Піткерн"),
+    ("PN", "vi", "Pi-thợ-khenh"),
+    ("PN", "zh-cn", "皮特克恩"),
+    ("PN", "zh-tw", "皮特肯島"),
+    ("PR", "cs", "Portoriko"),
+    ("PR", "el", "Πουέρτο Ρίκο"),
+    ("PR", "fr", "Porto Rico"),
+    ("PR", "he", "פוארטו ריקו"),
+    ("PR", "id", "Puerto Riko"),
+    ("PR", "it", "Portorico"),
+    ("PR", "ja", "プエルトリコ"),
+    ("PR", "ko", "푸에르토리코"),
+    ("PR", "pl", "Portoryko"),
+    ("PR", "pt", "Porto Rico"),
+    ("PR", "ru", "Пуэрто-Рико"),
+    ("PR", "th", "เปอร์โตริโก"),
+    ("PR", "tr", "Porto Riko"),
+    ("PR", "uk", "Пуерто-Рико"),
+    ("PR", "vi", "Pu-éc-thô Ri-cô"),
+    ("PR", "zh-cn", "波多黎各"),
+    ("PR", "zh-tw", "波多黎各"),
+    ("PS", "cs", "Palestinský stát"),
+    ("PS", "da", "Palæstina, staten"),
+    ("PS", "de", "Palästina, Staat"),
+    ("PS", "el", "Παλαιστίνη"),
+    ("PS", "es", "Palestina, Estado de"),
+    ("PS", "fr", "Palestine, État de"),
+    ("PS", "he", "פסלטין, מדינת"),
+    ("PS", "hu", "Palesztina"),
+    ("PS", "id", "Negara Palestina"),
+    ("PS", "it", "Palestina, Stato di"),
+    ("PS", "ja", "パレスチナ"),
+    ("PS", "ko", "팔레스타인"),
+    ("PS", "nl", "Palestina, Staat"),
+    ("PS", "pl", "Palestyna (państwo)"),
+    ("PS", "pt", "Palestina, Estado da"),
+    ("PS", "ro", "Palestina, Statul"),
+    ("PS", "ru", "Палестина"),
+    ("PS", "sv", "Staten Palestina"),
+    ("PS", "th", "ปาเลสไตน์, รัฐ"),
+    ("PS", "tr", "Filistin Devleti"),
+    ("PS", "uk", "Палестина, Держава"),
+    ("PS", "vi", "Palestine, quốc gia"),
+    ("PS", "zh-cn", "巴勒斯坦"),
+    ("PS", "zh-tw", "巴勒斯坦"),
+    ("PT", "cs", "Portugalsko"),
+    ("PT", "el", "Πορτογαλία"),
+    ("PT", "fi", "Portugali"),
+    ("PT", "he", "פורטוגל"),
+    ("PT", "hu", "Portugália"),
+    ("PT", "it", "Portogallo"),
+    ("PT", "ja", "ポルトガル"),
+    ("PT", "ko", "포르투갈"),
+    ("PT", "pl", "Portugalia"),
+    ("PT", "ro", "Portugalia"),
+    ("PT", "ru", "Португалия"),
+    ("PT", "th", "โปรตุเกส"),
+    ("PT", "tr", "Portekiz"),
+    ("PT", "uk", "Португалія"),
+    ("PT", "vi", "Bồ Đào Nha"),
+    ("PT", "zh-cn", "葡萄牙"),
+    ("PT", "zh-tw", "葡萄牙"),
+    ("PW", "el", "Παλάου"),
+    ("PW", "es", "Palaos"),
+    ("PW", "fr", "Palaos"),
+    ("PW", "he", "פלאו"),
+    ("PW", "ja", "パラオ"),
+    ("PW", "ko", "팔라우"),
+    ("PW", "ru", "Палау"),
+    ("PW", "th", "ปาเลา"),
+    ("PW", "uk", "Палау"),
+    ("PW", "vi", "Pa-lau"),
+    ("PW", "zh-cn", "帕劳"),
+    ("PW", "zh-tw", "帛琉"),
+    ("PY", "el", "Παραγουάη"),
+    ("PY", "he", "פרגוואי"),
+    ("PY", "ja", "パラグアイ"),
+    ("PY", "ko", "파라과이"),
+    ("PY", "pl", "Paragwaj"),
+    ("PY", "pt", "Paraguai"),
+    ("PY", "ru", "Парагвай"),
+    ("PY", "th", "ปารากวัย"),
+    ("PY", "uk", "Парагвай"),
+    ("PY", "vi", "Pa-ra-guay"),
+    ("PY", "zh-cn", "巴拉圭"),
+    ("PY", "zh-tw", "巴拉圭"),
+    ("QA", "cs", "Katar"),
+    ("QA", "de", "Katar"),
+    ("QA", "el", "Κατάρ"),
+    ("QA", "es", "Catar"),
+    ("QA", "he", "קטר"),
+    ("QA", "hu", "Katar"),
+    ("QA", "ja", "カタール"),
+    ("QA", "ko", "카타르"),
+    ("QA", "pl", "Katar"),
+    ("QA", "pt", "Catar"),
+    ("QA", "ru", "Катар"),
+    ("QA", "th", "กาตาร์"),
+    ("QA", "tr", "Katar"),
+    ("QA", "uk", "Катар"),
+    ("QA", "vi", "Ca-tă"),
+    ("QA", "zh-cn", "卡塔尔"),
+    ("QA", "zh-tw", "卡達"),
+    ("RE", "el", "Ρεϋνιόν"),
+    ("RE", "es", "Reunión"),
+    ("RE", "fr", "Réunion, Île de la"),
+    ("RE", "he", "ראוניון"),
+    ("RE", "it", "Riunione"),
+    ("RE", "ja", "レユニオン"),
+    ("RE", "ko", "레위니옹"),
+    ("RE", "pl", "Reunion"),
+    ("RE", "pt", "Reunião"),
+    ("RE", "ru", "Реюньон"),
+    ("RE", "th", "เรอูนียง"),
+    ("RE", "uk", "Реюньйон"),
+    ("RE", "vi", "Rê-u-ni-ợnh"),
+    ("RE", "zh-cn", "留尼汪"),
+    ("RE", "zh-tw", "留尼旺島"),
+    ("RO", "cs", "Rumunsko"),
+    ("RO", "da", "Rumænien"),
+    ("RO", "de", "Rumänien"),
+    ("RO", "el", "Ρουμανία"),
+    ("RO", "es", "Rumanía"),
+    ("RO", "fr", "Roumanie"),
+    ("RO", "he", "רומניה"),
+    ("RO", "hu", "Románia"),
+    ("RO", "id", "Rumania"),
+    ("RO", "ja", "ルーマニア"),
+    ("RO", "ko", "루마니아"),
+    ("RO", "nl", "Roemenië"),
+    ("RO", "pl", "Rumunia"),
+    ("RO", "pt", "Romênia"),
+    ("RO", "ro", "România"),
+    ("RO", "ru", "Румыния"),
+    ("RO", "sv", "Rumänien"),
+    ("RO", "th", "โรมาเนีย"),
+    ("RO", "tr", "Romanya"),
+    ("RO", "uk", "Румунія"),
+    ("RO", "vi", "Rô-ma-ni"),
+    ("RO", "zh-cn", "罗马尼亚"),
+    ("RO", "zh-tw", "羅馬尼亞"),
+    ("RS", "cs", "Srbsko"),
+    ("RS", "da", "Serbien"),
+    ("RS", "de", "Serbien"),
+    ("RS", "el", "Σερβία"),
+    ("RS", "fr", "Serbie"),
+    ("RS", "he", "סרביה"),
+    ("RS", "hu", "Szerbia"),
+    ("RS", "ja", "セルビア"),
+    ("RS", "ko", "세르비아"),
+    ("RS", "nl", "Servië"),
+    ("RS", "pt", "Sérvia"),
+    ("RS", "ru", "Сербия"),
+    ("RS", "sv", "Serbien"),
+    ("RS", "th", "เซอร์เบีย"),
+    ("RS", "tr", "Sırbistan"),
+    ("RS", "uk", "Сербія"),
+    ("RS", "vi", "Xéc-bi"),
+    ("RS", "zh-cn", "塞尔维亚"),
+    ("RS", "zh-tw", "塞爾維亞"),
+    ("RU", "cs", "Ruská federace"),
+    ("RU", "da", "Russiske føderation"),
+    ("RU", "de", "Russische Föderation"),
+    ("RU", "el", "Ρωσική Ομοσπονδία"),
+    ("RU", "es", "Federación Rusa"),
+    ("RU", "fi", "Venäjän federaatio"),
+    ("RU", "fr", "Russie, Fédération de"),
+    ("RU", "he", "הפדרציה הרוסית"),
+    ("RU", "hu", "Orosz Föderáció"),
+    ("RU", "id", "Federasi Rusia"),
+    ("RU", "it", "Russia"),
+    ("RU", "ja", "ロシア連邦"),
+    ("RU", "ko", "러시아 연방"),
+    ("RU", "nl", "Rusland"),
+    ("RU", "pl", "Federacja Rosyjska"),
+    ("RU", "pt", "Federação Russa"),
+    ("RU", "ro", "Federația Rusă"),
+    ("RU", "ru", "Российская Федерация"),
+    ("RU", "sv", "Ryska federationen"),
+    ("RU", "th", "สหพันธรัฐรัสเซีย"),
+    ("RU", "tr", "Rusya Federasyonu"),
+    ("RU", "uk", "Російська Федерація"),
+    ("RU", "vi", "Liên Bang Nga"),
+    ("RU", "zh-cn", "俄罗斯"),
+    ("RU", "zh-tw", "俄羅斯聯邦"),
+    ("RW", "de", "Ruanda"),
+    ("RW", "el", "Ρουάντα"),
+    ("RW", "es", "Ruanda"),
+    ("RW", "fi", "Ruanda"),
+    ("RW", "he", "רואנדה"),
+    ("RW", "hu", "Ruanda"),
+    ("RW", "it", "Ruanda"),
+    ("RW", "ja", "ルワンダ"),
+    ("RW", "ko", "르완다"),
+    ("RW", "pl", "Ruanda"),
+    ("RW", "pt", "Ruanda"),
+    ("RW", "ru", "Руанда"),
+    ("RW", "th", "รวันดา"),
+    ("RW", "tr", "Ruanda"),
+    ("RW", "uk", "Руанда"),
+    ("RW", "vi", "Ru-oanh-đa"),
+    ("RW", "zh-cn", "卢旺达"),
+    ("RW", "zh-tw", "盧安達"),
+    ("SA", "cs", "Saúdská Arábie"),
+    ("SA", "da", "Saudi-Arabien"),
+    ("SA", "de", "Saudi-Arabien"),
+    ("SA", "el", "Σαουδική Αραβία"),
+    ("SA", "es", "Arabia Saudí"),
+    ("SA", "fi", "Saudi-Arabia"),
+    ("SA", "fr", "Arabie saoudite"),
+    ("SA", "he", "ערב הסעודית"),
+    ("SA", "hu", "Szaúd-Arábia"),
+    ("SA", "id", "Arab Saudi"),
+    ("SA", "it", "Arabia Saudita"),
+    ("SA", "ja", "サウジアラビア"),
+    ("SA", "ko", "사우디아라비아"),
+    ("SA", "nl", "Saoedi-Arabië"),
+    ("SA", "pl", "Arabia Saudyjska"),
+    ("SA", "pt", "Arábia Saudita"),
+    ("SA", "ro", "Arabia Saudită"),
+    ("SA", "ru", "Саудовская Аравия"),
+    ("SA", "sv", "Saudiarabien"),
+    ("SA", "th", "ซาอุดีอาระเบีย"),
+    ("SA", "tr", "Suudi Arabistan"),
+    ("SA", "uk", "Саудівська Аравія"),
+    ("SA", "vi", "A-rập Xau-đi"),
+    ("SA", "zh-cn", "沙特阿拉伯"),
+    ("SA", "zh-tw", "沙烏地阿拉伯"),
+    ("SB", "cs", "Šalamounovy ostrovy"),
+    ("SB", "da", "Salomonøerne"),
+    ("SB", "de", "Salomoninseln"),
+    ("SB", "el", "Νήσοι Σολομώντα"),
+    ("SB", "es", "Islas Salomón"),
+    ("SB", "fi", "Salomonsaaret"),
+    ("SB", "fr", "Salomon, Îles"),
+    ("SB", "he", "איי שלמה"),
+    ("SB", "hu", "Salamon-szigetek"),
+    ("SB", "id", "Kepulauan Solomon"),
+    ("SB", "it", "Isole Salomone"),
+    ("SB", "ja", "ソロモン諸島"),
+    ("SB", "ko", "솔로몬 제도"),
+    ("SB", "nl", "Salomonseilanden"),
+    ("SB", "pl", "Wyspy Salomona"),
+    ("SB", "pt", "Ilhas Salomão"),
+    ("SB", "ro", "Insulele Solomon"),
+    ("SB", "ru", "Соломоновы Острова"),
+    ("SB", "sv", "Salomonöarna"),
+    ("SB", "th", "หมู่เกาะโซโลมอน"),
+    ("SB", "tr", "Solomon Adaları"),
+    ("SB", "uk", "Соломонові Острови"),
+    ("SB", "vi", "Quần đảo Xô-lô-mông"),
+    ("SB", "zh-cn", "所罗门群岛"),
+    ("SB", "zh-tw", "索羅門群島"),
+    ("SC", "cs", "Seychely"),
+    ("SC", "da", "Seychellerne"),
+    ("SC", "de", "Seychellen"),
+    ("SC", "el", "Σεϋχέλλες"),
+    ("SC", "fi", "Seychellit"),
+    ("SC", "he", "סיישל"),
+    ("SC", "hu", "Seychelle-szigetek"),
+    ("SC", "ja", "セーシェル"),
+    ("SC", "ko", "세이셸"),
+    ("SC", "nl", "Seychellen"),
+    ("SC", "pl", "Seszele"),
+    ("SC", "ru", "Сейшелы"),
+    ("SC", "sv", "Seychellerna"),
+    ("SC", "th", "เซเชลส์"),
+    ("SC", "tr", "Seyşeller"),
+    ("SC", "uk", "Сейшели"),
+    ("SC", "vi", "Xây-sen"),
+    ("SC", "zh-cn", "塞舌尔"),
+    ("SC", "zh-tw", "塞席爾"),
+    ("SD", "cs", "Súdán"),
+    ("SD", "el", "Σουδάν"),
+    ("SD", "es", "Sudán"),
+    ("SD", "fr", "Soudan"),
+    ("SD", "he", "סודאן"),
+    ("SD", "hu", "Szudán"),
+    ("SD", "ja", "スーダン"),
+    ("SD", "ko", "수단"),
+    ("SD", "nl", "Soedan"),
+    ("SD", "pt", "Sudão"),
+    ("SD", "ru", "Судан"),
+    ("SD", "th", "ซูดาน"),
+    ("SD", "uk", "Судан"),
+    ("SD", "vi", "Xu-đanh"),
+    ("SD", "zh-cn", "苏丹"),
+    ("SD", "zh-tw", "蘇丹"),
+    ("SE", "cs", "Švédsko"),
+    ("SE", "da", "Sverige"),
+    ("SE", "de", "Schweden"),
+    ("SE", "el", "Σουηδία"),
+    ("SE", "es", "Suecia"),
+    ("SE", "fi", "Ruotsi"),
+    ("SE", "fr", "Suède"),
+    ("SE", "he", "שוודיה"),
+    ("SE", "hu", "Svédország"),
+    ("SE", "id", "Swedia"),
+    ("SE", "it", "Svezia"),
+    ("SE", "ja", "スウェーデン"),
+    ("SE", "ko", "스웨덴"),
+    ("SE", "nl", "Zweden"),
+    ("SE", "pl", "Szwecja"),
+    ("SE", "pt", "Suécia"),
+    ("SE", "ro", "Suedia"),
+    ("SE", "ru", "Швеция"),
+    ("SE", "sv", "Sverige"),
+    ("SE", "th", "สวีเดน"),
+    ("SE", "tr", "İsveç"),
+    ("SE", "uk", "Швеція"),
+    ("SE", "vi", "Thuỵ Điển"),
+    ("SE", "zh-cn", "瑞典"),
+    ("SE", "zh-tw", "瑞典"),
+    ("SG", "cs", "Singapur"),
+    ("SG", "de", "Singapur"),
+    ("SG", "el", "Σιγκαπούρη"),
+    ("SG", "es", "Singapur"),
+    ("SG", "fr", "Singapour"),
+    ("SG", "he", "סינגפור"),
+    ("SG", "hu", "Szingapúr"),
+    ("SG", "id", "Singapura"),
+    ("SG", "ja", "シンガポール"),
+    ("SG", "ko", "싱가포르"),
+    ("SG", "pl", "Singapur"),
+    ("SG", "pt", "Cingapura"),
+    ("SG", "ru", "Сингапур"),
+    ("SG", "th", "สิงคโปร์"),
+    ("SG", "tr", "Singapur"),
+    ("SG", "uk", "Сінгапур"),
+    ("SG", "vi", "Xin-ga-po"),
+    ("SG", "zh-cn", "新加坡"),
+    ("SG", "zh-tw", "新加坡"),
+    ("SH", "cs", "Svatá Helena, Ascension a Tristan da Cunha"),
+    ("SH", "da", "Sankt Helena, Ascension og Tristan da Cunha"),
+    ("SH", "de", "St. Helena, Ascension und Tristan da Cunha"),
+    ("SH", "el", "Σεντ Ελένα, Ασενσιόν και Τριστάν ντα Κούνχα"),
+    ("SH", "es", "Santa Elena, Ascensión y Tristán de Acuña"),
+    ("SH", "fi", "Saint Helena, Ascension ja Tristan da Cunha"),
+    ("SH", "fr", "Sainte-Hélène, Ascension et Tristan da Cunha"),
+    ("SH", "he", "סנט הלנה, אסנשן וטריסטן דה קונה"),
+    ("SH", "hu", "Szent Ilona, Ascension és Tristan da Cunha"),
+    ("SH", "id", "Saint Helena, Ascension, dan Tristan da Cunha"),
+    ("SH", "it", "Sant'Elena, Ascensione e Tristan da Cunha"),
+    ("SH", "ja", "セントヘレナ、アセンション及びトリスタン・ダ・クーニャ"),
+    ("SH", "ko", "세인트헬레나 어센션 트리스탄다쿠냐"),
+    ("SH", "nl", "Sint-Helena, Ascension en Tristan da Cunha"),
+    (
+        "SH",
+        "pl",
+        "Wyspa Świętej Heleny, Wyspa Wniebowstąpienia i Tristan da Cunha",
+    ),
+    ("SH", "pt", "Santa Helena, Ascensão e Tristão da Cunha"),
+    ("SH", "ro", "Sfânta Elena, Ascension și Tristan da Cunha"),
+    ("SH", "ru", "Остров Святой Елены, Остров Вознесения и Тристан-да-Кунья"),
+    ("SH", "sv", "Saint Helena, Ascension och Tristan da Cunha"),
+    ("SH", "th", "เซนต์เฮเลนา, แอสเซนชัน และ ทริสแตน ดา คูนญา"),
+    ("SH", "tr", "Saint Helena, Ascension ve Tristan da Cunha"),
+    ("SH", "uk", "Острови Святої Єлени, Вознесіння і Тристан-да-Кунья"),
+    ("SH", "vi", "Xan He-lê-na, A-xen-siónh và Tợ-rí-x-tan đa Cun-ha"),
+    ("SH", "zh-cn", "圣赫勒拿-阿森松-特里斯坦达库尼亚"),
+    ("SH", "zh-tw", "聖赫倫那島、阿森松島及崔斯坦達庫尼亞群島"),
+    ("SI", "cs", "Slovinsko"),
+    ("SI", "da", "Slovenien"),
+    ("SI", "de", "Slowenien"),
+    ("SI", "el", "Σλοβενία"),
+    ("SI", "es", "Eslovenia"),
+    ("SI", "fr", "Slovénie"),
+    ("SI", "he", "סלובניה"),
+    ("SI", "hu", "Szlovénia"),
+    ("SI", "ja", "スロベニア"),
+    ("SI", "ko", "슬로베니아"),
+    ("SI", "nl", "Slovenië"),
+    ("SI", "pl", "Słowenia"),
+    ("SI", "pt", "Eslovênia"),
+    ("SI", "ru", "Словения"),
+    ("SI", "sv", "Slovenien"),
+    ("SI", "th", "สโลวีเนีย"),
+    ("SI", "tr", "Slovenya"),
+    ("SI", "uk", "Словенія"),
+    ("SI", "vi", "Xlô-ven"),
+    ("SI", "zh-cn", "斯洛文尼亚"),
+    ("SI", "zh-tw", "斯洛維尼亞"),
+    ("SJ", "cs", "Svalbard a Jan Mayen"),
+    ("SJ", "da", "Svalbard og Jan Mayen"),
+    ("SJ", "de", "Svalbard und Jan Mayen"),
+    ("SJ", "el", "Σβάλμπαρντ και Γιαν Μαγέν"),
+    ("SJ", "es", "Svalbard y Jan Mayen"),
+    ("SJ", "fi", "Svalbard ja Jan Mayen"),
+    ("SJ", "fr", "Svalbard et île Jan Mayen"),
+    ("SJ", "he", "סוולברד ויאן מאין"),
+    ("SJ", "hu", "Svalbard-szigetek és Jan Mayen-sziget"),
+    ("SJ", "id", "Svalbard dan Jan Mayen"),
+    ("SJ", "it", "Svalbard e Jan Mayen"),
+    ("SJ", "ja", "スヴァールバル及びヤンマイエン"),
+    ("SJ", "ko", "스발바르 얀마옌 제도"),
+    ("SJ", "nl", "Spitsbergen en Jan Mayen"),
+    ("SJ", "pl", "Svalbard i Jan Mayen"),
+    ("SJ", "pt", "Svalbard e a Ilha de Jan Mayen"),
+    ("SJ", "ro", "Svalbard și Jan Mayen"),
+    ("SJ", "ru", "Шпицберген и Ян-Майен"),
+    ("SJ", "sv", "Svalbard och Jan Mayen"),
+    ("SJ", "th", "สฟาลบาร์ และ ยานไมเอน"),
+    ("SJ", "tr", "Svalbard ve Jan Mayen"),
+    ("SJ", "uk", "Острови Свальбард і Ян Маєн"),
+    ("SJ", "vi", "Xợ-van-bat và Ian-may-en"),
+    ("SJ", "zh-cn", "斯瓦尔巴特和扬马延岛"),
+    ("SJ", "zh-tw", "冷岸群島及央棉"),
+    ("SK", "cs", "Slovensko"),
+    ("SK", "da", "Slovakiet"),
+    ("SK", "de", "Slowakei"),
+    ("SK", "el", "Σλοβακία"),
+    ("SK", "es", "Eslovaquia"),
+    ("SK", "fr", "Slovaquie"),
+    ("SK", "he", "סלובקיה"),
+    ("SK", "hu", "Szlovákia"),
+    ("SK", "id", "Slowakia"),
+    ("SK", "it", "Slovacchia"),
+    ("SK", "ja", "スロバキア"),
+    ("SK", "ko", "슬로바키아"),
+    ("SK", "nl", "Slowakije"),
+    ("SK", "pl", "Słowacja"),
+    ("SK", "pt", "Eslováquia"),
+    ("SK", "ro", "Slovacia"),
+    ("SK", "ru", "Словакия"),
+    ("SK", "sv", "Slovakien"),
+    ("SK", "th", "สโลวะเกีย"),
+    ("SK", "tr", "Slovakya"),
+    ("SK", "uk", "Словаччина"),
+    ("SK", "vi", "Xlô-vác"),
+    ("SK", "zh-cn", "斯洛伐克"),
+    ("SK", "zh-tw", "斯洛伐克"),
+    ("SL", "el", "Σιέρα Λεόνε"),
+    ("SL", "es", "Sierra Leona"),
+    ("SL", "he", "סיירה לאון"),
+    ("SL", "ja", "シエラレオネ"),
+    ("SL", "ko", "시에라리온"),
+    ("SL", "pt", "Serra Leoa"),
+    ("SL", "ru", "Сьерра-Леоне"),
+    ("SL", "th", "เซียร์ราลีโอน"),
+    ("SL", "uk", "Сьєрра-Леоне"),
+    ("SL", "vi", "Xi-ê-ra Lê-ô-nê"),
+    ("SL", "zh-cn", "塞拉利昂"),
+    ("SL", "zh-tw", "獅子山"),
+    ("SM", "el", "Άγιος Μαρίνος"),
+    ("SM", "fr", "Saint-Marin"),
+    ("SM", "he", "סן מרינו"),
+    ("SM", "ja", "サンマリノ"),
+    ("SM", "ko", "산마리노"),
+    ("SM", "pt", "São Marino"),
+    ("SM", "ru", "Сан-Марино"),
+    ("SM", "th", "ซานมารีโน"),
+    ("SM", "uk", "Сан-Марино"),
+    ("SM", "vi", "Xan Ma-ri-nô"),
+    ("SM", "zh-cn", "圣马力诺市"),
+    ("SM", "zh-tw", "聖馬利諾"),
+    ("SN", "el", "Σενεγάλη"),
+    ("SN", "fr", "Sénégal"),
+    ("SN", "he", "סנגל"),
+    ("SN", "hu", "Szenegál"),
+    ("SN", "ja", "セネガル"),
+    ("SN", "ko", "세네갈"),
+    ("SN", "ru", "Сенегал"),
+    ("SN", "th", "เซเนกัล"),
+    ("SN", "uk", "Сенегал"),
+    ("SN", "vi", "Xê-nê-gan"),
+    ("SN", "zh-cn", "塞内加尔"),
+    ("SN", "zh-tw", "塞內加爾"),
+    ("SO", "cs", "Somálsko"),
+    ("SO", "el", "Σομαλία"),
+    ("SO", "fr", "Somalie"),
+    ("SO", "he", "סומליה"),
+    ("SO", "hu", "Szomália"),
+    ("SO", "ja", "ソマリア"),
+    ("SO", "ko", "소말리아"),
+    ("SO", "nl", "Somalië"),
+    ("SO", "pt", "Somália"),
+    ("SO", "ru", "Сомали"),
+    ("SO", "th", "โซมาเลีย"),
+    ("SO", "tr", "Somali"),
+    ("SO", "uk", "Сомалі"),
+    ("SO", "vi", "Xo-ma-li"),
+    ("SO", "zh-cn", "索马里"),
+    ("SO", "zh-tw", "索馬利亞"),
+    ("SR", "cs", "Surinam"),
+    ("SR", "da", "Surinam"),
+    ("SR", "el", "Σουρινάμ"),
+    ("SR", "es", "Surinám"),
+    ("SR", "fr", "Surinam"),
+    ("SR", "he", "סורינאם"),
+    ("SR", "ja", "スリナム"),
+    ("SR", "ko", "수리남"),
+    ("SR", "pl", "Surinam"),
+    ("SR", "ro", "Surinam"),
+    ("SR", "ru", "Суринам"),
+    ("SR", "sv", "Surinam"),
+    ("SR", "th", "ซูรินาเม"),
+    ("SR", "tr", "Surinam"),
+    ("SR", "uk", "Суринам"),
+    ("SR", "vi", "Xu-ri-na-me"),
+    ("SR", "zh-cn", "苏里南"),
+    ("SR", "zh-tw", "蘇利南"),
+    ("SS", "cs", "Jižní Súdán"),
+    ("SS", "da", "Sydsudan"),
+    ("SS", "de", "Südsudan"),
+    ("SS", "el", "Νότιο Σουδάν"),
+    ("SS", "es", "Sudán del Sur"),
+    ("SS", "fi", "Etelä-Sudan"),
+    ("SS", "fr", "Soudan du Sud"),
+    ("SS", "he", "דרום סודאן"),
+    ("SS", "hu", "Dél-Szudán"),
+    ("SS", "id", "Sudan Selatan"),
+    ("SS", "it", "Sudan del sud"),
+    ("SS", "ja", "南スーダン"),
+    ("SS", "ko", "남수단"),
+    ("SS", "nl", "Zuid-Soedan"),
+    ("SS", "pl", "Sudan Południowy"),
+    ("SS", "pt", "Sudão do Sul"),
+    ("SS", "ro", "Sudanul de Sud"),
+    ("SS", "ru", "Южный Судан"),
+    ("SS", "sv", "Sydsudan"),
+    ("SS", "th", "ซูดานใต้"),
+    ("SS", "tr", "Güney Sudan"),
+    ("SS", "uk", "Південний Судан"),
+    ("SS", "vi", "Nam Xu-đăng"),
+    ("SS", "zh-cn", "南苏丹"),
+    ("SS", "zh-tw", "南蘇丹"),
+    ("ST", "cs", "Svatý Tomáš a Princův ostrov"),
+    ("ST", "da", "São Tomé og Príncipe"),
+    ("ST", "de", "São Tomé und Príncipe"),
+    ("ST", "el", "Σάο Τομέ και Πρίνσιπε"),
+    ("ST", "es", "Santo Tomé y Príncipe"),
+    ("ST", "fi", "São Tomé ja Príncipe"),
+    ("ST", "fr", "Sao Tomé-et-Principe"),
+    ("ST", "he", "סאו טומה ופרינסיפה"),
+    ("ST", "hu", "São Tomé és Príncipe"),
+    ("ST", "id", "Sao Tome dan Principe"),
+    ("ST", "it", "São Tomé e Príncipe"),
+    ("ST", "ja", "サントメ・プリンシペ"),
+    ("ST", "ko", "상투메 프린시페"),
+    ("ST", "nl", "Sao Tomé en Principe"),
+    ("ST", "pl", "Wyspy Świętego Tomasza i Książęca"),
+    ("ST", "pt", "São Tomé e Príncipe"),
+    ("ST", "ro", "Sao Tome și Principe"),
+    ("ST", "ru", "Сан-Томе и Принсипи"),
+    ("ST", "sv", "São Tomé och Príncipe"),
+    ("ST", "th", "เซาตูเมและปรินซิปี"),
+    ("ST", "tr", "Sao Tome ve Principe"),
+    ("ST", "uk", "Сан-Томе і Принсіпі"),
+    ("ST", "vi", "Xao Tô-mê và Pợ-rin-xi-pê"),
+    ("ST", "zh-cn", "圣多美和普林西比"),
+    ("ST", "zh-tw", "聖多美及普林西比"),
+    ("SV", "cs", "Salvador"),
+    ("SV", "el", "Ελ Σαλβαδόρ"),
+    ("SV", "fr", "Salvador"),
+    ("SV", "he", "אל סלוודור"),
+    ("SV", "ja", "エルサルバドル"),
+    ("SV", "ko", "엘살바도르"),
+    ("SV", "pl", "Salwador"),
+    ("SV", "ru", "Сальвадор"),
+    ("SV", "th", "เอลซัลวาดอร์"),
+    ("SV", "uk", "Сальвадор"),
+    ("SV", "vi", "En-xan-va-đoa"),
+    ("SV", "zh-cn", "萨尔瓦多"),
+    ("SV", "zh-tw", "薩爾瓦多"),
+    ("SX", "cs", "Svatý Martin (nizozemská část)"),
+    ("SX", "da", "Sint Maarten (hollandsk del)"),
+    ("SX", "de", "Saint-Martin (Niederländischer Teil)"),
+    ("SX", "el", "Άγιος Μαρτίνος (Ολλανδικό τμήμα)"),
+    ("SX", "es", "Isla de San Martín (zona holandsea)"),
+    ("SX", "fi", "Sint Maarten (hollantilainen osa)"),
+    ("SX", "fr", "Saint-Martin (partie néerlandaise)"),
+    ("SX", "he", "סנט מארטן (החלק ההולנדי)"),
+    ("SX", "hu", "Szent Martin (holland oldal)"),
+    ("SX", "id", "Sint Maarten (wilayah Belanda)"),
+    ("SX", "it", "Sint Maarten (Olanda)"),
+    ("SX", "ja", "サンマルタン (オランダ領)"),
+    ("SX", "ko", "신트마르턴 (네덜란드령)"),
+    ("SX", "nl", "Sint Maarten (Nederlands deel)"),
+    ("SX", "pl", "Sint Maarten (część holenderska)"),
+    ("SX", "pt", "São Martim (parte holandesa)"),
+    ("SX", "ro", "Sfântul Martin (partea olandeză)"),
+    ("SX", "ru", "Синт-Мартен (голландская часть)"),
+    ("SX", "sv", "Sint Maarten (nederländska delen)"),
+    ("SX", "th", "เซนต์มาร์ติน (ส่วนของดัตช์)"),
+    ("SX", "tr", "Sint Maarten (Hollanda kısmı)"),
+    ("SX", "uk", "Сінт-Мартен (голландська частина)"),
+    ("SX", "vi", "Xin Mác-Ten (vùng Hà Lan)"),
+    ("SX", "zh-cn", "荷属圣马丁"),
+    ("SX", "zh-tw", "聖馬丁 (荷屬)"),
+    ("SY", "cs", "Sýrie"),
+    ("SY", "de", "Syrien"),
+    ("SY", "he", "סוריה"),
+    ("SY", "id", "Suriah"),
+    ("SY", "nl", "Syrië"),
+    ("SY", "sv", "Syrien"),
+    ("SY", "tr", "Suriye"),
+    ("SY", "uk", "Сирія"),
+    ("SY", "zh-cn", "叙利亚"),
+    ("SY", "zh-tw", "敘利亞"),
+    ("SZ", "cs", "Svazijsko"),
+    ("SZ", "el", "Εσουατίνι"),
+    ("SZ", "es", "Esuatini"),
+    ("SZ", "he", "אסווטני"),
+    ("SZ", "hu", "Szváziföld"),
+    ("SZ", "ko", "에스와티니"),
+    ("SZ", "pt", "Suazilândia"),
+    ("SZ", "ru", "Эсватини"),
+    ("SZ", "sv", "Swaziland"),
+    ("SZ", "uk", "Есватіні"),
+    ("SZ", "zh-cn", "斯威士兰"),
+    ("SZ", "zh-tw", "史瓦帝尼"),
+    ("TC", "cs", "Turks a Caicos"),
+    ("TC", "da", "Turks- og Caicosøerne"),
+    ("TC", "de", "Turks- und Caicosinseln"),
+    ("TC", "el", "Τερκς και Κάικος Νήσοι"),
+    ("TC", "es", "Islas Turcas y Caicos"),
+    ("TC", "fi", "Turks- ja Caicossaaret"),
+    ("TC", "fr", "îles Turques-et-Caïques"),
+    ("TC", "he", "איי טרקס וקייקוס"),
+    ("TC", "hu", "Turks- és Caicos-szigetek"),
+    ("TC", "id", "Kepulauan Turks dan Caicos"),
+    ("TC", "it", "Isole Turks e Caicos"),
+    ("TC", "ja", "タークス及びカイコス諸島"),
+    ("TC", "ko", "터크스 케이커스 제도"),
+    ("TC", "nl", "Turks- en Caicoseilanden"),
+    ("TC", "pl", "Turks i Caicos"),
+    ("TC", "pt", "Ilhas Turks e Caicos"),
+    ("TC", "ro", "Insulele Turks și Caicos"),
+    ("TC", "ru", "Острова Туркс и Каикос"),
+    ("TC", "sv", "Turks- och Caicosöarna"),
+    ("TC", "th", "หมู่เกาะเติกส์และหมู่เกาะเคคอส"),
+    ("TC", "tr", "Turks ve Caicos Adaları"),
+    ("TC", "uk", "Острови Теркс і Кайкос"),
+    ("TC", "vi", "Quần Đảo Tuốc và Cai-cox"),
+    ("TC", "zh-cn", "特克斯和凯科斯群岛"),
+    ("TC", "zh-tw", "土克凱可群島"),
+    ("TD", "cs", "Čad"),
+    ("TD", "da", "Tchad"),
+    ("TD", "de", "Tschad"),
+    ("TD", "el", "Τσαντ"),
+    ("TD", "fi", "Tšad"),
+    ("TD", "fr", "Tchad"),
+    ("TD", "he", "צ׳אד"),
+    ("TD", "hu", "Csád"),
+    ("TD", "it", "Ciad"),
+    ("TD", "ja", "チャド"),
+    ("TD", "ko", "차드"),
+    ("TD", "nl", "Tsjaad"),
+    ("TD", "pl", "Czad"),
+    ("TD", "pt", "Chade"),
+    ("TD", "ro", "Ciad"),
+    ("TD", "ru", "Чад"),
+    ("TD", "sv", "Tchad"),
+    ("TD", "th", "ชาด"),
+    ("TD", "tr", "Çad"),
+    ("TD", "uk", "Чад"),
+    ("TD", "vi", "Chê-đ"),
+    ("TD", "zh-cn", "乍得"),
+    ("TD", "zh-tw", "查德"),
+    ("TF", "cs", "Francouzská jižní území"),
+    ("TF", "da", "Sydlige Franske Territorier"),
+    ("TF", "de", "Französische Süd- und Antarktisgebiete"),
+    ("TF", "el", "Γαλλικά Νότια Εδάφη"),
+    ("TF", "es", "Territorios Franceses del Sur"),
+    ("TF", "fi", "Ranskan eteläiset alueet"),
+    ("TF", "fr", "Terres australes françaises"),
+    ("TF", "he", "הטריטוריות הדרומיות של צרפת"),
+    ("TF", "hu", "Francia déli területek"),
+    ("TF", "id", "Perancis, Wilayah Bagian Selatan"),
+    ("TF", "it", "Territori francesi meridionali"),
+    ("TF", "ja", "フランス南方領土"),
+    ("TF", "ko", "프랑스령 남 자치구역"),
+    ("TF", "nl", "Franse Zuidelijke Gebieden"),
+    ("TF", "pl", "Francuskie Terytoria Południowe"),
+    ("TF", "pt", "Territórios Franceses do Sul"),
+    ("TF", "ro", "Teritoriile franceze de sud"),
+    ("TF", "ru", "Французские южные территории"),
+    ("TF", "sv", "Franska sydterritorierna"),
+    ("TF", "th", "เฟรนช์เซาเทิร์นเทร์ริทอรีส์"),
+    ("TF", "tr", "Fransız Güney Bölgeleri"),
+    ("TF", "uk", "Французькі Південні Території"),
+    ("TF", "vi", "Miền Nam Pháp"),
+    ("TF", "zh-cn", "法属南半球领地"),
+    ("TF", "zh-tw", "法屬南部領地"),
+    ("TG", "el", "Τόγκο"),
+    ("TG", "he", "טוגו"),
+    ("TG", "ja", "トーゴ"),
+    ("TG", "ko", "토고"),
+    ("TG", "ru", "Того"),
+    ("TG", "th", "โตโก"),
+    ("TG", "uk", "Того"),
+    ("TG", "vi", "Tô-gô"),
+    ("TG", "zh-cn", "多哥"),
+    ("TG", "zh-tw", "多哥"),
+    ("TH", "cs", "Thajsko"),
+    ("TH", "el", "Ταϊλάνδη"),
+    ("TH", "es", "Tailandia"),
+    ("TH", "fi", "Thaimaa"),
+    ("TH", "fr", "Thaïlande"),
+    ("TH", "he", "תאילנד"),
+    ("TH", "hu", "Thaiföld"),
+    ("TH", "it", "Thailandia"),
+    ("TH", "ja", "タイ"),
+    ("TH", "ko", "태국"),
+    ("TH", "pl", "Tajlandia"),
+    ("TH", "pt", "Tailândia"),
+    ("TH", "ro", "Tailanda"),
+    ("TH", "ru", "Таиланд"),
+    ("TH", "th", "ไทย"),
+    ("TH", "tr", "Tayland"),
+    ("TH", "uk", "Таїланд"),
+    ("TH", "vi", "Thái Lan"),
+    ("TH", "zh-cn", "泰国"),
+    ("TH", "zh-tw", "泰國"),
+    ("TJ", "cs", "Tádžikistán"),
+    ("TJ", "da", "Tadsjikistan"),
+    ("TJ", "de", "Tadschikistan"),
+    ("TJ", "el", "Τατζικιστάν"),
+    ("TJ", "es", "Tayikistán"),
+    ("TJ", "fi", "Tadžikistan"),
+    ("TJ", "fr", "Tadjikistan"),
+    ("TJ", "he", "טג׳יקיסטן"),
+    ("TJ", "hu", "Tádzsikisztán"),
+    ("TJ", "it", "Tagikistan"),
+    ("TJ", "ja", "タジキスタン"),
+    ("TJ", "ko", "타지키스탄"),
+    ("TJ", "nl", "Tadzjikistan"),
+    ("TJ", "pl", "Tadżykistan"),
+    ("TJ", "pt", "Tadjiquistão"),
+    ("TJ", "ru", "Таджикистан"),
+    ("TJ", "sv", "Tadzjikistan"),
+    ("TJ", "th", "ทาจิกิสถาน"),
+    ("TJ", "tr", "Tacikistan"),
+    ("TJ", "uk", "Таджикистан"),
+    ("TJ", "vi", "Tha-gi-ki-xthanh"),
+    ("TJ", "zh-cn", "塔吉克斯坦"),
+    ("TJ", "zh-tw", "塔吉克"),
+    ("TK", "el", "Τοκελάου"),
+    ("TK", "he", "טוקלאו"),
+    ("TK", "hu", "Tokelau-szigetek"),
+    ("TK", "ja", "トケラウ"),
+    ("TK", "ko", "토켈라우"),
+    ("TK", "pt", "Toquelau"),
+    ("TK", "ru", "Токелау"),
+    ("TK", "th", "โตเกเลา"),
+    ("TK", "uk", "токелау"),
+    ("TK", "vi", "To-ke-lau"),
+    ("TK", "zh-cn", "托克劳"),
+    ("TK", "zh-tw", "托克勞"),
+    ("TL", "cs", "Východní Timor"),
+    ("TL", "el", "Τιμόρ-Λέστε"),
+    ("TL", "es", "Timor Oriental"),
+    ("TL", "fi", "Itä-Timor"),
+    ("TL", "fr", "Timor oriental"),
+    ("TL", "he", "טימור מזרח"),
+    ("TL", "hu", "Kelet-Timor"),
+    ("TL", "id", "Timor Timur"),
+    ("TL", "it", "Timor Est"),
+    ("TL", "ja", "東ティモール"),
+    ("TL", "ko", "동티모르"),
+    ("TL", "nl", "Oost-Timor"),
+    ("TL", "pl", "Timor Wschodni"),
+    ("TL", "pt", "Timor Leste"),
+    ("TL", "ro", "Timorul de Est"),
+    ("TL", "ru", "Восточный Тимор"),
+    ("TL", "sv", "Östtimor"),
+    ("TL", "th", "ติมอร์-เลสเต"),
+    ("TL", "uk", "Східний Тимор"),
+    ("TL", "vi", "Thi-moa Le-xợ-te"),
+    ("TL", "zh-cn", "东帝汶"),
+    ("TL", "zh-tw", "東帝汶"),
+    ("TM", "cs", "Turkmenistán"),
+    ("TM", "el", "Τουρκμενιστάν"),
+    ("TM", "es", "Turkmenistán"),
+    ("TM", "fr", "Turkménistan"),
+    ("TM", "he", "טורקמניסטן"),
+    ("TM", "hu", "Türkmenisztán"),
+    ("TM", "ja", "トルクメニスタン"),
+    ("TM", "ko", "투르크메니스탄"),
+    ("TM", "pt", "Turcomenistão"),
+    ("TM", "ru", "Туркменистан"),
+    ("TM", "th", "เติร์กเมนิสถาน"),
+    ("TM", "tr", "Türkmenistan"),
+    ("TM", "uk", "Туркменістан"),
+    ("TM", "vi", "Tuốc-mê-ni-xtanh"),
+    ("TM", "zh-cn", "土库曼斯坦"),
+    ("TM", "zh-tw", "土庫曼"),
+    ("TN", "cs", "Tunisko"),
+    ("TN", "da", "Tunesien"),
+    ("TN", "de", "Tunesien"),
+    ("TN", "el", "Τυνησία"),
+    ("TN", "es", "Tunez"),
+    ("TN", "fr", "Tunisie"),
+    ("TN", "he", "תוניסיה"),
+    ("TN", "hu", "Tunézia"),
+    ("TN", "ja", "チュニジア"),
+    ("TN", "ko", "튀니지"),
+    ("TN", "nl", "Tunesië"),
+    ("TN", "pl", "Tunezja"),
+    ("TN", "pt", "Tunísia"),
+    ("TN", "ru", "Тунис"),
+    ("TN", "sv", "Tunisien"),
+    ("TN", "th", "ตูนิเซีย"),
+    ("TN", "tr", "Tunus"),
+    ("TN", "uk", "Туніс"),
+    ("TN", "vi", "Tu-ni-xi-a"),
+    ("TN", "zh-cn", "突尼斯"),
+    ("TN", "zh-tw", "突尼西亞"),
+    ("TO", "el", "Τόνγκα"),
+    ("TO", "he", "טונגה"),
+    ("TO", "ja", "トンガ"),
+    ("TO", "ko", "통가"),
+    ("TO", "ru", "Тонга"),
+    ("TO", "th", "ตองกา"),
+    ("TO", "uk", "Тонга"),
+    ("TO", "vi", "Tông-ga"),
+    ("TO", "zh-cn", "汤加"),
+    ("TO", "zh-tw", "東加"),
+    ("TR", "cs", "Turecko"),
+    ("TR", "de", "Türkei"),
+    ("TR", "he", "טורקיה"),
+    ("TR", "hu", "Törökország"),
+    ("TR", "id", "Turki"),
+    ("TR", "ko", "튀르키예"),
+    ("TR", "nl", "Turkije"),
+    ("TR", "pl", "Turcja"),
+    ("TR", "pt", "Turquia"),
+    ("TR", "sv", "Turkiet"),
+    ("TR", "uk", "Туреччина"),
+    ("TR", "zh-cn", "土耳其"),
+    ("TR", "zh-tw", "土耳其"),
+    ("TT", "cs", "Trinidad a Tobago"),
+    ("TT", "da", "Trinidad og Tobago"),
+    ("TT", "de", "Trinidad und Tobago"),
+    ("TT", "el", "Τρινιντάντ και Τομπάγκο"),
+    ("TT", "es", "Trinidad y Tobago"),
+    ("TT", "fi", "Trinidad ja Tobago"),
+    ("TT", "fr", "Trinité-et-Tobago"),
+    ("TT", "he", "טרינידד וטובגו"),
+    ("TT", "hu", "Trinidad és Tobago"),
+    ("TT", "id", "Trinidad dan Tobago"),
+    ("TT", "it", "Trinidad e Tobago"),
+    ("TT", "ja", "トリニダード・トバゴ"),
+    ("TT", "ko", "트리니다드 토바고"),
+    ("TT", "nl", "Trinidad en Tobago"),
+    ("TT", "pl", "Trynidad i Tobago"),
+    ("TT", "pt", "Trinidade e Tobago"),
+    ("TT", "ro", "Trinidad și Tobago"),
+    ("TT", "ru", "Тринидад и Тобаго"),
+    ("TT", "sv", "Trinidad och Tobago"),
+    ("TT", "th", "ตรินิแดดและโตเบโก"),
+    ("TT", "tr", "Trinidad ve Tobago"),
+    ("TT", "uk", "Тринідад і Тобаго"),
+    ("TT", "vi", "Trinh-i-đat và To-ba-gô"),
+    ("TT", "zh-cn", "特里尼达和多巴哥"),
+    ("TT", "zh-tw", "千里達及托巴哥"),
+    ("TV", "el", "Τουβαλού"),
+    ("TV", "he", "טובאלו"),
+    ("TV", "ja", "ツバル"),
+    ("TV", "ko", "투발루"),
+    ("TV", "ru", "Тувалу"),
+    ("TV", "th", "ตูวาลู"),
+    ("TV", "uk", "тувалу"),
+    ("TV", "vi", "Tu-va-lu"),
+    ("TV", "zh-cn", "图瓦卢"),
+    ("TV", "zh-tw", "吐瓦魯"),
+    ("TW", "cs", "Tchaj-wan"),
+    ("TW", "el", "Ταϊβάν"),
+    ("TW", "es", "Taiwán"),
+    ("TW", "fr", "Taïwan"),
+    ("TW", "he", "טאיוואן"),
+    ("TW", "hu", "Tajvan"),
+    ("TW", "ja", "台湾"),
+    ("TW", "ko", "타이완"),
+    ("TW", "pl", "Tajwan"),
+    ("TW", "ru", "Тайвань"),
+    ("TW", "th", "ไต้หวัน"),
+    ("TW", "tr", "Tayvan"),
+    ("TW", "uk", "Тайвань"),
+    ("TW", "vi", "Đài Loan"),
+    ("TW", "zh-cn", "台湾"),
+    ("TW", "zh-tw", "臺灣"),
+    ("TZ", "cs", "Tanzánie"),
+    ("TZ", "de", "Tansania"),
+    ("TZ", "el", "Τανζανία"),
+    ("TZ", "fr", "Tanzanie"),
+    ("TZ", "he", "טנזניה"),
+    ("TZ", "hu", "Tanzánia"),
+    ("TZ", "ja", "タンザニア"),
+    ("TZ", "ko", "탄자니아"),
+    ("TZ", "pt", "Tanzânia"),
+    ("TZ", "ru", "Танзания"),
+    ("TZ", "th", "แทนซาเนีย"),
+    ("TZ", "tr", "Tanzanya"),
+    ("TZ", "uk", "Танзанія"),
+    ("TZ", "zh-cn", "坦桑尼亚"),
+    ("TZ", "zh-tw", "坦尚尼亞"),
+    ("UA", "cs", "Ukrajina"),
+    ("UA", "el", "Ουκρανία"),
+    ("UA", "es", "Ucrania"),
+    ("UA", "fi", "Ukraina"),
+    ("UA", "he", "אוקראינה"),
+    ("UA", "hu", "Ukrajna"),
+    ("UA", "id", "Ukraina"),
+    ("UA", "it", "Ucraina"),
+    ("UA", "ja", "ウクライナ"),
+    ("UA", "ko", "우크라이나"),
+    ("UA", "nl", "Oekraïne"),
+    ("UA", "pl", "Ukraina"),
+    ("UA", "pt", "Ucrânia"),
+    ("UA", "ro", "Ucraina"),
+    ("UA", "ru", "Украина"),
+    ("UA", "sv", "Ukraina"),
+    ("UA", "th", "ยูเครน"),
+    ("UA", "tr", "Ukrayna"),
+    ("UA", "uk", "Україна"),
+    ("UA", "vi", "U-cờ-rai-na"),
+    ("UA", "zh-cn", "乌克兰"),
+    ("UA", "zh-tw", "烏克蘭"),
+    ("UG", "el", "Ουγκάντα"),
+    ("UG", "fr", "Ouganda"),
+    ("UG", "he", "אוגנדה"),
+    ("UG", "ja", "ウガンダ"),
+    ("UG", "ko", "우간다"),
+    ("UG", "nl", "Oeganda"),
+    ("UG", "ru", "Уганда"),
+    ("UG", "th", "ยูกันดา"),
+    ("UG", "uk", "Уганда"),
+    ("UG", "vi", "U-gan-đa"),
+    ("UG", "zh-cn", "乌干达"),
+    ("UG", "zh-tw", "烏干達"),
+    ("UM", "cs", "Menší odlehlé ostrovy Spojených států"),
+    ("UM", "da", "USA's ydre småøer"),
+    ("UM", "el", "Απομακρυσμένες Νησίδες των Ηνωμένων Πολιτειών"),
+    ("UM", "es", "Islas Ultramarinas Menores de Estados Unidos"),
+    ("UM", "fi", "Yhdysvaltain pienet erillissaaret"),
+    ("UM", "fr", "Îles mineures éloignées des États-Unis"),
+    ("UM", "he", "האיים המרוחקים הקטנים של ארצות הברית"),
+    ("UM", "hu", "Az Amerikai Egyesült Államok lakatlan külbirtokai"),
+    ("UM", "id", "Kepulauan Terluar Kecil Amerika Serikat"),
+    ("UM", "it", "Isole minori esterne degli Stati Uniti d'America"),
+    ("UM", "ja", "アメリカ合衆国外諸島"),
+    ("UM", "ko", "미국령 군소 제도"),
+    ("UM", "nl", "Kleine afgelegen eilanden van de Verenigde Staten"),
+    ("UM", "pl", "Dalekie Wyspy Mniejsze Stanów Zjednoczonych"),
+    ("UM", "pt", "Ilhas Menores Distantes dos Estados Unidos"),
+    ("UM", "ro", "Insulele de Coasta ale Statelor Unite"),
+    ("UM", "ru", "Соединенные штаты Малых Удаленных островов"),
+    ("UM", "sv", "Förenta staternas mindre öar i Oceanien och Västindien"),
+    ("UM", "th", "เกาะเล็กรอบนอกของสหรัฐอเมริกา"),
+    ("UM", "tr", "Amerika Birleşik Devletleri Küçük Dış Adaları"),
+    ("UM", "uk", "Зовнішні малі острови США"),
+    ("UM", "vi", "Quần Đảo ở xa nhỏ Mỹ"),
+    ("UM", "zh-cn", "美国本土外小岛屿"),
+    ("UM", "zh-tw", "美屬邊疆群島"),
+    ("US", "cs", "Spojené státy"),
+    ("US", "da", "USA"),
+    ("US", "de", "Vereinigte Staaten"),
+    ("US", "el", "Ηνωμένες Πολιτείες"),
+    ("US", "es", "Estados Unidos"),
+    ("US", "fi", "Yhdysvallat"),
+    ("US", "fr", "États-Unis"),
+    ("US", "he", "ארצות הברית"),
+    ("US", "hu", "Egyesült Államok"),
+    ("US", "id", "Amerika Serikat"),
+    ("US", "it", "Stati Uniti"),
+    ("US", "ja", "米国"),
+    ("US", "ko", "미국"),
+    ("US", "nl", "Verenigde Staten"),
+    ("US", "pl", "Stany Zjednoczone"),
+    ("US", "pt", "Estados Unidos"),
+    ("US", "ro", "Statele Unite"),
+    ("US", "ru", "Соединённые штаты"),
+    ("US", "sv", "USA"),
+    ("US", "th", "สหรัฐ"),
+    ("US", "tr", "Amerika Birleşik Devletleri"),
+    ("US", "uk", "США"),
+    ("US", "vi", "Mỹ"),
+    ("US", "zh-cn", "美国"),
+    ("US", "zh-tw", "美國"),
+    ("UY", "el", "Ουρουγουάη"),
+    ("UY", "he", "אורוגוואי"),
+    ("UY", "ja", "ウルグアイ"),
+    ("UY", "ko", "우루과이"),
+    ("UY", "pl", "Urugwaj"),
+    ("UY", "pt", "Uruguai"),
+    ("UY", "ru", "Уругвай"),
+    ("UY", "th", "อุรุกวัย"),
+    ("UY", "uk", "Уругвай"),
+    ("UY", "vi", "U-ru-guay"),
+    ("UY", "zh-cn", "乌拉圭"),
+    ("UY", "zh-tw", "烏拉圭"),
+    ("UZ", "cs", "Uzbekistán"),
+    ("UZ", "da", "Usbekistan"),
+    ("UZ", "de", "Usbekistan"),
+    ("UZ", "el", "Ουζμπεκιστάν"),
+    ("UZ", "es", "Uzbekistán"),
+    ("UZ", "fr", "Ouzbékistan"),
+    ("UZ", "he", "אוזבקיסטן"),
+    ("UZ", "hu", "Üzbegisztán"),
+    ("UZ", "ja", "ウズベキスタン"),
+    ("UZ", "ko", "우즈베키스탄"),
+    ("UZ", "nl", "Oezbekistan"),
+    ("UZ", "pt", "Uzbequistão"),
+    ("UZ", "ru", "Узбекистан"),
+    ("UZ", "th", "อุซเบกิสถาน"),
+    ("UZ", "tr", "Özbekistan"),
+    ("UZ", "uk", "Узбекистан"),
+    ("UZ", "vi", "U-xợ-bê-khi-xtanh"),
+    ("UZ", "zh-cn", "乌兹别克斯坦"),
+    ("UZ", "zh-tw", "烏茲別克"),
+    ("VA", "cs", "Svatý stolec (Vatikánský městský stát)"),
+    ("VA", "da", "Vatikanstaten"),
+    ("VA", "de", "Heiliger Stuhl (Staat Vatikanstadt)"),
+    ("VA", "el", "Αγία Έδρα (το Κράτος της Πόλεως του Βατικανού)"),
+    ("VA", "es", "Santa Sede (Ciudad Estado del Vaticano)"),
+    ("VA", "fi", "Pyhä istuin (Vatikaanivaltio)"),
+    ("VA", "fr", "Saint-Siège (état de la cité du Vatican)"),
+    ("VA", "he", "וותיקן"),
+    ("VA", "hu", "Szentszék (Vatikánvárosi Állam)"),
+    ("VA", "id", "Takhta Suci Vatican (Negara Kota)"),
+    ("VA", "it", "Santa Sede (Stato della Città del Vaticano)"),
+    ("VA", "ja", "聖庁 (バチカン市国)"),
+    ("VA", "ko", "바티칸 시티 (Holy See)"),
+    ("VA", "nl", "Vaticaanstad, Staat"),
+    ("VA", "pl", "Państwo Watykańskie (Stolica Apostolska)"),
+    ("VA", "pt", "Santa Sé (Cidade-Estado do Vaticano)"),
+    ("VA", "ro", "Vatican"),
+    ("VA", "ru", "Государство-город Ватикан"),
+    ("VA", "sv", "Vatikanstaten"),
+    ("VA", "th", "นครรัฐวาติกัน"),
+    ("VA", "tr", "Holy See (Vatikan Şehir Devleti)"),
+    ("VA", "uk", "Святий Престол (Ватикан, Місто-Держава)"),
+    ("VA", "vi", "Toà Thánh (Bang Thành Phố Va-ti-canh)"),
+    ("VA", "zh-cn", "梵地冈"),
+    ("VA", "zh-tw", "教廷 (梵蒂岡城市國)"),
+    ("VC", "cs", "Svatý Vincenc a Grenadiny"),
+    ("VC", "da", "Sankt Vincent og Grenadinerne"),
+    ("VC", "de", "St. Vincent und die Grenadinen"),
+    ("VC", "el", "Άγιος Βικέντιος και Γρεναδίνες"),
+    ("VC", "es", "San Vicente y las Granadinas"),
+    ("VC", "fi", "Saint Vincent ja Grenadiinit"),
+    ("VC", "fr", "Saint-Vincent-et-les-Grenadines"),
+    ("VC", "he", "סנט וינסנט והגרנדינים"),
+    ("VC", "hu", "Saint Vincent és a Grenadine-szigetek"),
+    ("VC", "id", "Saint Vincent dan Grenadines"),
+    ("VC", "it", "Saint Vincent e Grenadine"),
+    ("VC", "ja", "セントビンセント及びグレナディーン諸島"),
+    ("VC", "ko", "세인트빈센트 그레나딘"),
+    ("VC", "nl", "Saint Vincent en de Grenadines"),
+    ("VC", "pl", "Saint Vincent i Grenadyny"),
+    ("VC", "pt", "São Vicente e Granadinas"),
+    ("VC", "ro", "Saint Vincent și Grenadinele"),
+    ("VC", "ru", "Сент-Винсент и Гренадины"),
+    ("VC", "sv", "Sankt Vincent och Grenadinerna"),
+    ("VC", "th", "เซนต์วินเซนต์และเกรนาดีนส์"),
+    ("VC", "tr", "Saint Vincent ve Grenadinler"),
+    ("VC", "uk", "Сент-Вінсент і Гренадини"),
+    ("VC", "vi", "Xan Vinh-xen và Gou-en-a-đinh"),
+    ("VC", "zh-cn", "圣文森特和格林纳丁斯"),
+    ("VC", "zh-tw", "聖文森及格瑞納丁"),
+    ("VE", "el", "Βενεζουέλα"),
+    ("VE", "fr", "Vénézuela"),
+    ("VE", "he", "ונצואלה"),
+    ("VE", "ja", "ベネズエラ"),
+    ("VE", "ko", "베네수엘라"),
+    ("VE", "pl", "Wenezuela"),
+    ("VE", "ru", "Венесуэла"),
+    ("VE", "th", "เวเนซุเอลา"),
+    ("VE", "uk", "Венесуела"),
+    ("VE", "vi", "Ve-ne-xu-ê-la"),
+    ("VE", "zh-cn", "委内瑞拉"),
+    ("VE", "zh-tw", "委內瑞拉"),
+    ("VG", "cs", "Panenské ostrovy, britské"),
+    ("VG", "da", "Britiske Jomfruøer, De"),
+    ("VG", "de", "Britische Jungferninseln"),
+    ("VG", "el", "Παρθένοι Νήσοι, Βρετανικές"),
+    ("VG", "es", "Islas Vírgenes, Británicas"),
+    ("VG", "fi", "Neitsytsaaret, Brittiläiset"),
+    ("VG", "fr", "Îles Vierges britanniques"),
+    ("VG", "he", "איי הבתולה (בריטיים)"),
+    ("VG", "hu", "Brit Virgin-szigetek"),
+    ("VG", "id", "Kepulauan Virgin Inggris"),
+    ("VG", "it", "Isole Vergini, Regno Unito"),
+    ("VG", "ja", "英領ヴァージン諸島"),
+    ("VG", "ko", "버진 제도, 영국령"),
+    ("VG", "nl", "Maagdeneilanden, Britse"),
+    ("VG", "pl", "Brytyjskie Wyspy Dziewicze"),
+    ("VG", "pt", "Ilhas Virgens Britânicas"),
+    ("VG", "ro", "Insulele virgine (britanice)"),
+    ("VG", "ru", "Виргинские острова (Британия)"),
+    ("VG", "sv", "Jungfruöarna, brittiska"),
+    ("VG", "th", "หมู่เกาะบริติชเวอร์จิน"),
+    ("VG", "tr", "İngiliz Virgin Adaları"),
+    ("VG", "uk", "Віргінські острови (Британія)"),
+    ("VG", "vi", "Quần Đảo Vơ-chin Anh"),
+    ("VG", "zh-cn", "英属维尔京群岛"),
+    ("VG", "zh-tw", "英屬維京群島"),
+    ("VI", "cs", "Panenské ostrovy, americké"),
+    ("VI", "da", "Amerikanske Jomfruøer, De"),
+    ("VI", "de", "Amerikanische Jungferninseln"),
+    ("VI", "el", "Παρθένοι Νήσοι, Η.Π.Α."),
+    ("VI", "es", "Islas Vírgenes, de EEUU"),
+    ("VI", "fi", "Neitsytsaaret, Yhdysvaltain"),
+    ("VI", "fr", "Îles Vierges, États-Unis"),
+    ("VI", "he", "איי הבתולה (ארה״ב)"),
+    ("VI", "hu", "Amerikai Virgin-szigetek"),
+    ("VI", "id", "Kepulauan Virgin, A.S."),
+    ("VI", "it", "Isole Vergini, U.S.A."),
+    ("VI", "ja", "米領ヴァージン諸島"),
+    ("VI", "ko", "버진 제도, 미국령"),
+    ("VI", "nl", "Maagdeneilanden, Amerikaanse"),
+    ("VI", "pl", "Wyspy Dziewicze Stanów Zjednoczonych"),
+    ("VI", "pt", "Ilhas Virgens dos Estados Unidos"),
+    ("VI", "ro", "Insulele virgine (SUA)"),
+    ("VI", "ru", "Виргинские острова (США)"),
+    ("VI", "sv", "Jungfruöarna, amerikanska"),
+    ("VI", "th", "หมู่เกาะเวอร์จินของสหรัฐอเมริกา"),
+    ("VI", "tr", "Virgin Adaları, A.B.D."),
+    ("VI", "uk", "Віргінські острови (США)"),
+    ("VI", "vi", "Quần Đảo Vơ-chin Mỹ"),
+    ("VI", "zh-cn", "美属维尔京群岛"),
+    ("VI", "zh-tw", "美屬維京群島"),
+    ("VN", "el", "Βιετνάμ"),
+    ("VN", "fr", "Viêt Nam"),
+    ("VN", "he", "וייטנאם"),
+    ("VN", "hu", "Vietnám"),
+    ("VN", "ja", "ベトナム"),
+    ("VN", "ko", "베트남"),
+    ("VN", "pl", "Wietnam"),
+    ("VN", "pt", "Vietnã"),
+    ("VN", "ru", "Вьетнам"),
+    ("VN", "uk", "В'єтнам"),
+    ("VN", "zh-cn", "越南"),
+    ("VN", "zh-tw", "越南"),
+    ("VU", "el", "Βανουάτου"),
+    ("VU", "he", "ונואטו"),
+    ("VU", "ja", "バヌアツ"),
+    ("VU", "ko", "바누아투"),
+    ("VU", "ru", "Вануату"),
+    ("VU", "th", "วานูอาตู"),
+    ("VU", "uk", "Вануату"),
+    ("VU", "vi", "Va-nu-a-tu"),
+    ("VU", "zh-cn", "瓦努阿图"),
+    ("VU", "zh-tw", "萬那杜"),
+    ("WF", "cs", "Wallis a Futuna"),
+    ("WF", "da", "Wallis og Futunaøerne"),
+    ("WF", "de", "Wallis und Futuna"),
+    ("WF", "el", "Ουαλίς και Φουτούνα"),
+    ("WF", "es", "Wallis y Futuna"),
+    ("WF", "fi", "Wallis ja Futuna"),
+    ("WF", "fr", "Wallis et Futuna"),
+    ("WF", "he", "ואליס ופוטונה"),
+    ("WF", "hu", "Wallis és Futuna"),
+    ("WF", "id", "Wallis dan Futuna"),
+    ("WF", "it", "Wallis e Futuna"),
+    ("WF", "ja", "ワリー及びフテュナ"),
+    ("WF", "ko", "왈리스 퓌튀나"),
+    ("WF", "nl", "Wallis en Futuna"),
+    ("WF", "pl", "Wallis i Futuna"),
+    ("WF", "pt", "Wallis e Futuna"),
+    ("WF", "ro", "Wallis și Futuna"),
+    ("WF", "ru", "Уоллес и Футана"),
+    ("WF", "sv", "Wallis och Futuna"),
+    ("WF", "th", "หมู่เกาะวาลลิสและหมู่เกาะฟุตูนา"),
+    ("WF", "tr", "Wallis ve Futuna Adaları"),
+    ("WF", "uk", "Волліс і Футуна"),
+    ("WF", "vi", "Oua-li-xợ va Phu-tu-na"),
+    ("WF", "zh-cn", "瓦利斯和富图纳"),
+    ("WF", "zh-tw", "沃里斯及伏塔那群島"),
+    ("WS", "el", "Σαμόα"),
+    ("WS", "he", "סמואה"),
+    ("WS", "hu", "Szamoa"),
+    ("WS", "ja", "サモア"),
+    ("WS", "ko", "사모아"),
+    ("WS", "ru", "Самоа"),
+    ("WS", "th", "ซามัว"),
+    ("WS", "uk", "Самоа"),
+    ("WS", "vi", "Xa-mô-a"),
+    ("WS", "zh-cn", "萨摩亚"),
+    ("WS", "zh-tw", "薩摩亞"),
+    ("YE", "cs", "Jemen"),
+    ("YE", "de", "Jemen"),
+    ("YE", "el", "Υεμένη"),
+    ("YE", "fi", "Jemen"),
+    ("YE", "fr", "Yémen"),
+    ("YE", "he", "תימן"),
+    ("YE", "hu", "Jemen"),
+    ("YE", "id", "Yaman"),
+    ("YE", "ja", "イエメン"),
+    ("YE", "ko", "예멘"),
+    ("YE", "nl", "Jemen"),
+    ("YE", "pl", "Jemen"),
+    ("YE", "pt", "Iêmen"),
+    ("YE", "ru", "Йемен"),
+    ("YE", "th", "เยเมน"),
+    ("YE", "uk", "Ємен"),
+    ("YE", "vi", "Y-ê-men"),
+    ("YE", "zh-cn", "也门"),
+    ("YE", "zh-tw", "葉門"),
+    ("YT", "el", "Μαγιότ"),
+    ("YT", "he", "מיוט"),
+    ("YT", "ja", "マヨット"),
+    ("YT", "ko", "마요트"),
+    ("YT", "pl", "Majotta"),
+    ("YT", "pt", "Maiote"),
+    ("YT", "ru", "Майот"),
+    ("YT", "th", "มายอต"),
+    ("YT", "uk", "Майотта"),
+    ("YT", "vi", "May-o-thợ"),
+    ("YT", "zh-cn", "马约特"),
+    ("YT", "zh-tw", "馬約特"),
+    ("ZA", "cs", "Jihoafrická republika"),
+    ("ZA", "da", "Sydafrika"),
+    ("ZA", "de", "Südafrika"),
+    ("ZA", "el", "Νότια Αφρική"),
+    ("ZA", "es", "Sudáfrica"),
+    ("ZA", "fi", "Etelä-Afrikka"),
+    ("ZA", "fr", "Afrique du Sud"),
+    ("ZA", "he", "דרום אפריקה"),
+    ("ZA", "hu", "Dél-Afrika"),
+    ("ZA", "id", "Afrika Selatan"),
+    ("ZA", "it", "Sudafrica"),
+    ("ZA", "ja", "南アフリカ"),
+    ("ZA", "ko", "남아프리카 공화국"),
+    ("ZA", "nl", "Zuid-Afrika"),
+    ("ZA", "pl", "Południowa Afryka"),
+    ("ZA", "pt", "África do Sul"),
+    ("ZA", "ro", "Africa de sud"),
+    ("ZA", "ru", "Южная Африка"),
+    ("ZA", "sv", "Sydafrika"),
+    ("ZA", "th", "แอฟริกาใต้"),
+    ("ZA", "tr", "Güney Afrika"),
+    ("ZA", "uk", "Південна Африка"),
+    ("ZA", "vi", "Nam Phi"),
+    ("ZA", "zh-cn", "南非"),
+    ("ZA", "zh-tw", "南非"),
+    ("ZM", "cs", "Zambie"),
+    ("ZM", "de", "Sambia"),
+    ("ZM", "el", "Ζάμπια"),
+    ("ZM", "fi", "Sambia"),
+    ("ZM", "fr", "Zambie"),
+    ("ZM", "he", "זמביה"),
+    ("ZM", "ja", "ザンビア"),
+    ("ZM", "ko", "잠비아"),
+    ("ZM", "pt", "Zâmbia"),
+    ("ZM", "ru", "Замбия"),
+    ("ZM", "th", "แซมเบีย"),
+    ("ZM", "tr", "Zambiya"),
+    ("ZM", "uk", "Замбія"),
+    ("ZM", "vi", "Xam-bi-a"),
+    ("ZM", "zh-cn", "赞比亚"),
+    ("ZM", "zh-tw", "尚比亞"),
+    ("ZW", "de", "Simbabwe"),
+    ("ZW", "el", "Ζιμπάμπουε"),
+    ("ZW", "es", "Zimbabue"),
+    ("ZW", "he", "זימבבואה"),
+    ("ZW", "ja", "ジンバブエ"),
+    ("ZW", "ko", "짐바브웨"),
+    ("ZW", "pt", "Zimbábue"),
+    ("ZW", "ru", "Зимбабве"),
+    ("ZW", "th", "ซิมบับเว"),
+    ("ZW", "tr", "Zimbabve"),
+    ("ZW", "uk", "Зімбабве"),
+    ("ZW", "vi", "Xim-ba-bu-ê"),
+    ("ZW", "zh-cn", "津巴布韦"),
+    ("ZW", "zh-tw", "辛巴威"),
+];
@@ -0,0 +1,250 @@
+use crate::{
+    MultirangeData, RankedQuery, RankedTopic, RegionData, RelatedQueries, RelatedTopics, RisingQuery, RisingValue,
+    TimeSeriesData, TrendValue, TrendingDay, TrendsReport,
+};
+
+/// A cheap fingerprint of the data in a result, to tell whether a fresh pull differs from a stored one.
+///
+/// The hash is FNV-1a over the data, links and formatted values are left out (trending traffic only
+/// comes formatted, so it is kept). It doesn't depend on the Rust version or platform, so it can be
+/// persisted. Collections without a meaningful order (regions, related queries and topics, trending
+/// searches) hash the same in any order.
+pub trait ContentHash {
+    fn content_hash(&self) -> u64;
+}
+
+const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const PRIME: u64 = 0x0100_0000_01b3;
+
+pub(crate) struct Fnv(u64);
+
+impl Fnv {
+    pub(crate) fn new() -> Fnv {
+        Fnv(OFFSET_BASIS)
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(PRIME);
+        }
+        self
+    }
+
+    fn u64(&mut self, value: u64) -> &mut Self {
+        self.bytes(&value.to_le_bytes())
+    }
+
+    // length-prefixed, so that ("ab", "c") and ("a", "bc") don't collide
+    pub(crate) fn str(&mut self, value: &str) -> &mut Self {
+        self.u64(value.len() as u64).bytes(value.as_bytes())
+    }
+
+    fn value(&mut self, value: TrendValue) -> &mut Self {
+        match value {
+            TrendValue::Missing => self.bytes(&[0]),
+            TrendValue::LessThanOne => self.bytes(&[1]),
+            // -0.0 and 0.0 are the same value, as are all NaNs
+            TrendValue::Value(value) if value == 0.0 => self.bytes(&[2]).bytes(&0f32.to_bits().to_le_bytes()),
+            TrendValue::Value(value) if value.is_nan() => self.bytes(&[2]).bytes(&f32::NAN.to_bits().to_le_bytes()),
+            TrendValue::Value(value) => self.bytes(&[2]).bytes(&value.to_bits().to_le_bytes()),
+        }
+    }
+
+    /// Folds in hashes whose order doesn't matter.
+    fn unordered<I: IntoIterator<Item = u64>>(&mut self, hashes: I) -> &mut Self {
+        let (count, sum) = hashes
+            .into_iter()
+            .fold((0u64, 0u64), |(count, sum), hash| (count + 1, sum.wrapping_add(hash)));
+        self.u64(count).u64(sum)
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl ContentHash for TimeSeriesData {
+    fn content_hash(&self) -> u64 {
+        let mut hasher = Fnv::new();
+        for entry in &self.entries {
+            hasher.u64(entry.time.timestamp() as u64);
+            for value in entry.values() {
+                hasher.value(value);
+            }
+        }
+        hasher.finish()
+    }
+}
+
+impl ContentHash for MultirangeData {
+    fn content_hash(&self) -> u64 {
+        let mut hasher = Fnv::new();
+        for entry in &self.entries {
+            for point in &entry.column_data {
+                hasher.u64(point.time.timestamp() as u64).value(point.value);
+            }
+        }
+        hasher.finish()
+    }
+}
+
+impl ContentHash for RegionData {
+    fn content_hash(&self) -> u64 {
+        let entries = self.entries.iter().map(|entry| {
+            let mut hasher = Fnv::new();
+            hasher.str(&entry.geo_code);
+            for value in entry.values() {
+                hasher.value(value);
+            }
+            hasher.finish()
+        });
+        Fnv::new().unordered(entries).finish()
+    }
+}
+
+fn ranked_query(query: &RankedQuery) -> u64 {
+    Fnv::new().str(&query.query).u64(u64::from(query.value)).finish()
+}
+
+fn rising_query(query: &RisingQuery) -> u64 {
+    let value = match query.value {
+        RisingValue::Percent(percent) => u64::from(percent),
+        RisingValue::Breakout => u64::MAX,
+    };
+    Fnv::new().str(&query.query).u64(value).finish()
+}
+
+fn ranked_topic(topic: &RankedTopic) -> u64 {
+    Fnv::new().str(&topic.topic.mid).u64(u64::from(topic.value)).finish()
+}
+
+impl ContentHash for RelatedQueries {
+    fn content_hash(&self) -> u64 {
+        Fnv::new()
+            .unordered(self.top.iter().map(ranked_query))
+            .unordered(self.rising.iter().map(rising_query))
+            .finish()
+    }
+}
+
+impl ContentHash for RelatedTopics {
+    fn content_hash(&self) -> u64 {
+        Fnv::new()
+            .unordered(self.top.iter().map(ranked_topic))
+            .unordered(self.rising.iter().map(ranked_topic))
+            .finish()
+    }
+}
+
+impl ContentHash for TrendsReport {
+    fn content_hash(&self) -> u64 {
+        Fnv::new()
+            .u64(self.time_series.content_hash())
+            .u64(self.regions.content_hash())
+            .u64(self.related_topics.content_hash())
+            .u64(self.related_queries.content_hash())
+            .finish()
+    }
+}
+
+impl ContentHash for TrendingDay {
+    fn content_hash(&self) -> u64 {
+        let searches = self.trending_searches.iter().map(|search| {
+            Fnv::new()
+                .str(&search.title.query)
+                .str(&search.formatted_traffic)
+                .finish()
+        });
+        Fnv::new().str(&self.date).unordered(searches).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RegionEntry, TimeSeriesEntry};
+    use chrono::{TimeZone, Utc};
+
+    fn series(values: Vec<TrendValue>) -> TimeSeriesData {
+        TimeSeriesData {
+            entries: vec![TimeSeriesEntry {
+                time: Utc.timestamp(1_609_459_200, 0),
+                formatted_time: "Jan 1, 2021".to_owned(),
+                formatted_axis_time: None,
+                formatted_value: values.iter().map(TrendValue::to_string).collect(),
+                has_data: values.iter().map(|value| !value.is_missing()).collect(),
+                value: values,
+                is_partial: None,
+            }],
+            averages: vec![],
+        }
+    }
+
+    fn region(geo_code: &str, value: f32) -> RegionEntry {
+        RegionEntry {
+            coordinates: None,
+            geo_code: geo_code.to_owned(),
+            geo_name: geo_code.to_owned(),
+            value: vec![TrendValue::Value(value)],
+            formatted_value: vec![value.to_string()],
+            max_value_index: None,
+            has_data: vec![true],
+        }
+    }
+
+    fn query(query: &str, value: u32) -> RankedQuery {
+        RankedQuery {
+            query: query.to_owned(),
+            value,
+            formatted_value: value.to_string(),
+            has_data: Some(true),
+            link: None,
+        }
+    }
+
+    // stored hashes are compared against fresh ones, so this must never change
+    #[test]
+    fn hashes_are_stable() {
+        let data = series(vec![
+            TrendValue::Value(50.0),
+            TrendValue::LessThanOne,
+            TrendValue::Missing,
+        ]);
+        assert_eq!(data.content_hash(), 0x264c_c22c_2513_0961);
+    }
+
+    #[test]
+    fn negative_zero_hashes_like_zero() {
+        assert_eq!(
+            series(vec![TrendValue::Value(-0.0)]).content_hash(),
+            series(vec![TrendValue::Value(0.0)]).content_hash()
+        );
+        assert_ne!(
+            series(vec![TrendValue::Value(0.0)]).content_hash(),
+            series(vec![TrendValue::LessThanOne]).content_hash()
+        );
+    }
+
+    #[test]
+    fn unordered_collections_hash_the_same_in_any_order() {
+        let regions = RegionData {
+            entries: vec![region("DE", 50.0), region("NO", 100.0), region("SE", 30.0)],
+        };
+        let mut shuffled = regions.clone();
+        shuffled.entries.rotate_left(1);
+        assert_eq!(regions.content_hash(), shuffled.content_hash());
+        shuffled.entries[0].value[0] = TrendValue::Value(99.0);
+        assert_ne!(regions.content_hash(), shuffled.content_hash());
+
+        let related = RelatedQueries {
+            top: vec![query("rust lang", 100), query("rust game", 40)],
+            rising: vec![query("rust 1.50", 250).into(), query("rust async", 120).into()],
+        };
+        let shuffled = RelatedQueries {
+            top: related.top.iter().rev().cloned().collect(),
+            rising: related.rising.iter().rev().cloned().collect(),
+        };
+        assert_eq!(related.content_hash(), shuffled.content_hash());
+    }
+}
@@ -0,0 +1,423 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::checkpoint;
+use crate::clock::{Sleeper, SystemClock};
+use crate::export::jsonl;
+use crate::hash::Fnv;
+use crate::progress::Tracker;
+#[cfg(feature = "sqlite")]
+use crate::TrendsStore;
+use crate::{
+    Category, Error, Query, QueryItem, RegionData, RegionOptions, Resolution, Source, TimeSeriesData,
+    TimeSeriesOptions, Timeframe, TrendsClient,
+};
+
+/// A declarative collection job, typically kept in a JSON file next to the schedule that runs it:
+///
+/// ```json
+/// {
+///     "keywords": ["flu", "covid"],
+///     "geos": ["US", "GB"],
+///     "timeframes": ["2021-01-01 2021-12-31"],
+///     "data": ["time_series", "regions"],
+///     "outputs": [{ "kind": "jsonl", "path": "trends.jsonl" }],
+///     "requests_per_minute": 10
+/// }
+/// ```
+///
+/// Every keyword is fetched on its own for every geo and timeframe, an empty `geos` means worldwide.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobSpec {
+    pub keywords: Vec<String>,
+    #[serde(default)]
+    pub geos: Vec<String>,
+    pub timeframes: Vec<Timeframe>,
+    #[serde(default)]
+    pub category: Option<Category>,
+    #[serde(default)]
+    pub source: Option<Source>,
+    #[serde(default = "default_data")]
+    pub data: Vec<JobData>,
+    #[serde(default)]
+    pub outputs: Vec<JobOutput>,
+    #[serde(default)]
+    pub requests_per_minute: Option<u32>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobData {
+    TimeSeries,
+    Regions,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum JobOutput {
+    /// Appends the records described in `export::jsonl`.
+    Jsonl { path: PathBuf },
+    #[cfg(feature = "sqlite")]
+    Sqlite { path: PathBuf },
+}
+
+/// Reported after every keyword, geo and timeframe combination.
+#[derive(Debug, Clone)]
+pub struct JobProgress {
+    pub done: usize,
+    pub total: usize,
+    pub keyword: String,
+    pub geo: Option<String>,
+    pub timeframe: Timeframe,
+    pub failed: bool,
+}
+
+#[derive(Debug)]
+pub struct JobFailure {
+    pub keyword: String,
+    pub geo: Option<String>,
+    pub timeframe: Timeframe,
+    pub error: Error,
+}
+
+#[derive(Debug, Default)]
+pub struct JobSummary {
+    pub completed: usize,
+    /// Combinations skipped because the checkpoint had them as done.
+    pub resumed: usize,
+    pub failures: Vec<JobFailure>,
+}
+
+/// How `run_job_with_opts` waits between combinations and where it keeps track of them.
+#[derive(Clone)]
+pub struct JobOptions {
+    sleeper: Arc<dyn Sleeper>,
+    checkpoint: Option<PathBuf>,
+}
+
+impl Default for JobOptions {
+    fn default() -> Self {
+        JobOptions {
+            sleeper: Arc::new(SystemClock),
+            checkpoint: None,
+        }
+    }
+}
+
+impl JobOptions {
+    /// Waits out `requests_per_minute` with `sleeper` instead of a timer.
+    pub fn sleeper(mut self, sleeper: Arc<dyn Sleeper>) -> Self {
+        self.sleeper = sleeper;
+        self
+    }
+
+    /// Records every finished combination and kind of data in a `JobCheckpoint` at `path` and skips the
+    /// ones already recorded there, so a job that crashed or was cancelled carries on where it stopped.
+    /// The file is removed once a run gets through without failures, after one with failures it stays so
+    /// that the next run only retries those. A checkpoint left by a different spec fails the job with
+    /// `Error::InvalidConfig`, only `requests_per_minute` may change in between.
+    pub fn checkpoint<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.checkpoint = Some(path.into());
+        self
+    }
+
+    pub fn get_checkpoint(&self) -> Option<&Path> {
+        self.checkpoint.as_deref()
+    }
+}
+
+/// The combinations of a job that are done, one task per kind of data.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JobCheckpoint {
+    /// See `JobSpec::hash`.
+    pub spec_hash: u64,
+    pub completed: Vec<JobTask>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobTask {
+    pub keyword: String,
+    pub geo: Option<String>,
+    pub timeframe: Timeframe,
+    pub data: JobData,
+}
+
+impl JobCheckpoint {
+    pub fn new(spec: &JobSpec) -> JobCheckpoint {
+        JobCheckpoint {
+            spec_hash: spec.hash(),
+            completed: vec![],
+        }
+    }
+
+    /// An empty checkpoint when there's no file at `path` yet.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<JobCheckpoint, Error> {
+        match File::open(path) {
+            Ok(file) => Ok(serde_json::from_reader(file)?),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(JobCheckpoint::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Writes a temporary file next to `path` and moves it over, so a crash mid-write can't leave a
+    /// truncated checkpoint behind.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        checkpoint::save(path.as_ref(), self)
+    }
+
+    /// The checkpoint at `path` if it was saved for `spec`, a new one when there's no file yet.
+    pub fn resume<P: AsRef<Path>>(path: P, spec: &JobSpec) -> Result<JobCheckpoint, Error> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(JobCheckpoint::new(spec));
+        }
+        let checkpoint = JobCheckpoint::load(path)?;
+        if checkpoint.spec_hash != spec.hash() {
+            return Err(Error::InvalidConfig(format!(
+                "The checkpoint at {} was saved for a different job",
+                path.display()
+            )));
+        }
+        Ok(checkpoint)
+    }
+
+    pub fn is_done(&self, keyword: &str, geo: Option<&str>, timeframe: &Timeframe, data: JobData) -> bool {
+        self.completed.iter().any(|task| {
+            task.keyword == keyword && task.geo.as_deref() == geo && task.timeframe == *timeframe && task.data == data
+        })
+    }
+}
+
+fn default_data() -> Vec<JobData> {
+    vec![JobData::TimeSeries]
+}
+
+impl JobSpec {
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<JobSpec, Error> {
+        Ok(serde_json::from_reader(File::open(path)?)?)
+    }
+
+    /// Tells specs apart for checkpoints. `requests_per_minute` doesn't change what a job collects and is
+    /// left out.
+    pub fn hash(&self) -> u64 {
+        let spec = JobSpec {
+            requests_per_minute: None,
+            ..self.clone()
+        };
+        let json = serde_json::to_string(&spec).expect("job specs serialize to JSON");
+        Fnv::new().str(&json).finish()
+    }
+
+    fn tasks(&self) -> Vec<(&str, Option<&str>, &Timeframe)> {
+        let geos: Vec<Option<&str>> = if self.geos.is_empty() {
+            vec![None]
+        } else {
+            self.geos.iter().map(|geo| Some(geo.as_str())).collect()
+        };
+        let mut tasks = vec![];
+        for keyword in &self.keywords {
+            for geo in &geos {
+                for timeframe in &self.timeframes {
+                    tasks.push((keyword.as_str(), *geo, timeframe));
+                }
+            }
+        }
+        tasks
+    }
+}
+
+enum Sink {
+    Jsonl(File),
+    #[cfg(feature = "sqlite")]
+    Sqlite(TrendsStore),
+}
+
+impl Sink {
+    fn open(output: &JobOutput) -> Result<Sink, Error> {
+        match output {
+            JobOutput::Jsonl { path } => Ok(Sink::Jsonl(OpenOptions::new().create(true).append(true).open(path)?)),
+            #[cfg(feature = "sqlite")]
+            JobOutput::Sqlite { path } => Ok(Sink::Sqlite(TrendsStore::open(path)?)),
+        }
+    }
+
+    fn time_series(&mut self, query: &Query, data: &TimeSeriesData) -> Result<(), Error> {
+        match self {
+            Sink::Jsonl(file) => jsonl::write(file, vec![(query, data)]),
+            #[cfg(feature = "sqlite")]
+            Sink::Sqlite(store) => store.store_time_series(query, data).map(|_| ()),
+        }
+    }
+
+    fn regions(&mut self, query: &Query, data: &RegionData) -> Result<(), Error> {
+        match self {
+            Sink::Jsonl(file) => jsonl::write(file, vec![(query, data)]),
+            #[cfg(feature = "sqlite")]
+            Sink::Sqlite(store) => store.store_regions(query, data).map(|_| ()),
+        }
+    }
+}
+
+impl TrendsClient {
+    /// Runs `spec` one combination at a time, writing each result to all outputs as soon as it arrives.
+    /// Failed combinations are collected in the summary and the job goes on, failing to open an output
+    /// or `Error::Cancelled` stop it. `requests_per_minute` spaces out the combinations, counting one
+    /// explore plus one widget request per kind of data. Besides `on_progress`, every combination counts
+    /// as a window for the client's `ProgressHandler`.
+    pub async fn run_job<F: FnMut(&JobProgress)>(&self, spec: &JobSpec, on_progress: F) -> Result<JobSummary, Error> {
+        self.run_job_with_opts(spec, &JobOptions::default(), on_progress).await
+    }
+
+    /// Like `run_job`, pausing with the sleeper of `opts` and resuming from its checkpoint. Combinations
+    /// the checkpoint has every kind of data for are reported to `on_progress` without being fetched
+    /// again, for the others only the missing kinds are fetched.
+    pub async fn run_job_with_opts<F: FnMut(&JobProgress)>(
+        &self,
+        spec: &JobSpec,
+        opts: &JobOptions,
+        mut on_progress: F,
+    ) -> Result<JobSummary, Error> {
+        let mut checkpoint = match opts.get_checkpoint() {
+            Some(path) => JobCheckpoint::resume(path, spec)?,
+            None => JobCheckpoint::new(spec),
+        };
+        let mut sinks = spec.outputs.iter().map(Sink::open).collect::<Result<Vec<_>, _>>()?;
+        let pause = spec.requests_per_minute.map(|rpm| {
+            let requests = (1 + spec.data.len()) as u32;
+            Duration::from_secs(60) * requests / rpm.max(1)
+        });
+
+        let tasks = spec.tasks();
+        let progress = Tracker::start(self.progress(), tasks.len());
+        let mut summary = JobSummary::default();
+        let mut fetched = false;
+        for (index, (keyword, geo, timeframe)) in tasks.iter().enumerate() {
+            let done = spec
+                .data
+                .iter()
+                .all(|data| checkpoint.is_done(keyword, *geo, timeframe, *data));
+            if done {
+                on_progress(&JobProgress {
+                    done: index + 1,
+                    total: tasks.len(),
+                    keyword: keyword.to_string(),
+                    geo: geo.map(str::to_owned),
+                    timeframe: (*timeframe).clone(),
+                    failed: false,
+                });
+                progress.window_done();
+                summary.resumed += 1;
+                continue;
+            }
+            if let (Some(pause), true) = (pause, fetched) {
+                opts.sleeper.sleep(pause).await;
+            }
+            fetched = true;
+
+            let item = match geo {
+                Some(geo) => QueryItem::by_keyword_with_geo(*keyword, *geo, (*timeframe).clone()),
+                None => QueryItem::by_keyword(*keyword, (*timeframe).clone()),
+            };
+            let query = Query::new(vec![item]);
+            let task = (*keyword, *geo, *timeframe);
+            let result = self
+                .run_task(spec, &query, task, &mut sinks, &mut checkpoint, opts.get_checkpoint())
+                .await;
+
+            on_progress(&JobProgress {
+                done: index + 1,
+                total: tasks.len(),
+                keyword: keyword.to_string(),
+                geo: geo.map(str::to_owned),
+                timeframe: (*timeframe).clone(),
+                failed: result.is_err(),
+            });
+            progress.window_done();
+            match result {
+                Ok(()) => summary.completed += 1,
+                Err(Error::Cancelled) => return Err(Error::Cancelled),
+                Err(error) => summary.failures.push(JobFailure {
+                    keyword: keyword.to_string(),
+                    geo: geo.map(str::to_owned),
+                    timeframe: (*timeframe).clone(),
+                    error,
+                }),
+            }
+        }
+        progress.finish();
+        if let (Some(path), true) = (opts.get_checkpoint(), summary.failures.is_empty()) {
+            if let Err(err) = fs::remove_file(path) {
+                if err.kind() != ErrorKind::NotFound {
+                    return Err(err.into());
+                }
+            }
+        }
+        Ok(summary)
+    }
+
+    // Saves the checkpoint after every kind of data, so that a failure halfway doesn't write the kinds
+    // before it to the outputs a second time.
+    async fn run_task(
+        &self,
+        spec: &JobSpec,
+        query: &Query<'_>,
+        (keyword, geo, timeframe): (&str, Option<&str>, &Timeframe),
+        sinks: &mut [Sink],
+        checkpoint: &mut JobCheckpoint,
+        path: Option<&Path>,
+    ) -> Result<(), Error> {
+        for data in &spec.data {
+            if checkpoint.is_done(keyword, geo, timeframe, *data) {
+                continue;
+            }
+            match data {
+                JobData::TimeSeries => {
+                    let mut opts = TimeSeriesOptions::default();
+                    if let Some(source) = spec.source {
+                        opts = opts.source(source);
+                    }
+                    if let Some(category) = spec.category {
+                        opts = opts.category(category);
+                    }
+                    let result = self.interest_by_time_with_opts(query, &opts).await?;
+                    for sink in sinks.iter_mut() {
+                        sink.time_series(query, &result)?;
+                    }
+                }
+                JobData::Regions => {
+                    let resolution = if geo.is_some() {
+                        Resolution::Region
+                    } else {
+                        Resolution::Country
+                    };
+                    let mut opts = RegionOptions::default().resolution(resolution);
+                    if let Some(source) = spec.source {
+                        opts = opts.source(source);
+                    }
+                    if let Some(category) = spec.category {
+                        opts = opts.category(category);
+                    }
+                    let result = self.interest_by_region_with_opts(query, &opts).await?;
+                    for sink in sinks.iter_mut() {
+                        sink.regions(query, &result)?;
+                    }
+                }
+            }
+            if let Some(path) = path {
+                checkpoint.completed.push(JobTask {
+                    keyword: keyword.to_owned(),
+                    geo: geo.map(str::to_owned),
+                    timeframe: timeframe.clone(),
+                    data: *data,
+                });
+                checkpoint.save(path)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+const OPERATORS: &[char] = &['"', '+', '~'];
+
+/// Normalizes a keyword the way Google treats it when matching searches: surrounding and repeated
+/// whitespace is dropped, letters are lowercased and search operators (quotes, `+`, `~` and a
+/// leading `-` excluding a term) are removed. Lowercasing follows Unicode but doesn't fold case
+/// beyond that, `Straße` and `strasse` stay different keywords.
+pub fn canonicalize(keyword: &str) -> String {
+    keyword
+        .split(|c: char| c.is_whitespace() || OPERATORS.contains(&c))
+        .map(|word| word.trim_start_matches('-'))
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonicalize_drops_whitespace_and_operators() {
+        assert_eq!(canonicalize("  Rust   Lang\t"), "rust lang");
+        assert_eq!(
+            canonicalize("\"rust lang\" +web ~framework -go"),
+            "rust lang web framework go"
+        );
+        assert_eq!(canonicalize("rust-lang"), "rust-lang");
+        assert_eq!(canonicalize(" \"\" + - "), "");
+    }
+
+    #[test]
+    fn canonicalize_lowercases_unicode() {
+        assert_eq!(canonicalize("ÉCOLE\u{3000}Primaire"), "école primaire");
+        assert_eq!(canonicalize("ΟΔΟΣ"), "οδος");
+        assert_eq!(canonicalize("STRASSE"), "strasse");
+        assert_eq!(canonicalize("Straße"), "straße");
+        assert_ne!(canonicalize("Straße"), canonicalize("STRASSE"));
+    }
+}
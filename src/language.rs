@@ -0,0 +1,39 @@
+use whatlang::Lang;
+
+/// Guesses the `hl` locale for `text`, `None` when the language can't be told reliably or Trends has no
+/// locale for it. Short keywords often aren't reliable, so this mostly kicks in for phrases and scripts
+/// other than Latin.
+pub fn detect_locale(text: &str) -> Option<&'static str> {
+    let info = whatlang::detect(text).filter(|info| info.is_reliable())?;
+    let locale = match info.lang() {
+        Lang::Eng => "en-US",
+        Lang::Deu => "de",
+        Lang::Fra => "fr",
+        Lang::Spa => "es",
+        Lang::Por => "pt-BR",
+        Lang::Ita => "it",
+        Lang::Nld => "nl",
+        Lang::Pol => "pl",
+        Lang::Ces => "cs",
+        Lang::Ron => "ro",
+        Lang::Hun => "hu",
+        Lang::Swe => "sv",
+        Lang::Dan => "da",
+        Lang::Fin => "fi",
+        Lang::Tur => "tr",
+        Lang::Ell => "el",
+        Lang::Rus => "ru",
+        Lang::Ukr => "uk",
+        Lang::Ara => "ar",
+        Lang::Heb => "iw",
+        Lang::Hin => "hi",
+        Lang::Tha => "th",
+        Lang::Vie => "vi",
+        Lang::Ind => "id",
+        Lang::Jpn => "ja",
+        Lang::Kor => "ko",
+        Lang::Cmn => "zh-CN",
+        _ => return None,
+    };
+    Some(locale)
+}
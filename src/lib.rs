@@ -1,5 +1,66 @@
-pub use client::TrendsClient;
+pub use borrowed::RawResponse;
+pub use calendar::{AnnotatedTimeSeries, Calendar, CalendarEvent};
+pub use cancel::CancellationToken;
+pub use category::{CategoryNode, CategoryTree};
+pub use checkpoint::Checkpoint;
+pub use client::{TrendsClient, TrendsClientBuilder};
+pub use clock::{Clock, Sleeper, SystemClock};
+pub use debug::{DebugSink, FileDump};
+pub use dma::Dma;
+pub use hash::ContentHash;
+#[cfg(feature = "jobs")]
+pub use job::JobSpec;
+pub use metrics::{MetricsRecorder, RequestOutcome};
+#[cfg(feature = "plot")]
+pub use plot::{MapOptions, PlotOptions};
+pub use progress::ProgressHandler;
+pub use session::SessionPool;
+#[cfg(feature = "sqlite")]
+pub use store::TrendsStore;
+#[cfg(not(target_arch = "wasm32"))]
+pub use tokens::TokenCache;
 pub use types::*;
+pub use widget::Widget;
 
+pub mod align;
+pub mod batchexecute;
+pub mod borrowed;
+pub mod calendar;
+pub mod cancel;
+pub mod category;
+pub mod checkpoint;
 pub mod client;
+pub mod clock;
+pub mod debug;
+pub mod dedup;
+pub mod dma;
+pub mod export;
+#[cfg(any(feature = "geo-names", feature = "geo-boundaries"))]
+pub mod geo;
+pub mod hash;
+#[cfg(feature = "jobs")]
+pub mod job;
+pub mod keywords;
+#[cfg(feature = "detect-language")]
+pub mod language;
+pub mod metrics;
+#[cfg(feature = "monitor")]
+pub mod monitor;
+#[cfg(feature = "plot")]
+pub mod plot;
+pub mod progress;
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
+#[cfg(feature = "report")]
+pub mod report;
+pub mod session;
+#[cfg(any(feature = "kafka", feature = "nats"))]
+pub mod sink;
+#[cfg(feature = "sqlite")]
+pub mod store;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod tokens;
 pub mod types;
+pub mod widget;
@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+use crate::Operation;
+
+#[derive(Debug, Clone)]
+pub struct RequestOutcome {
+    pub operation: Operation,
+    pub status: Option<StatusCode>,
+    pub attempt: u32,
+    pub latency: Duration,
+}
+
+impl RequestOutcome {
+    pub fn is_rate_limited(&self) -> bool {
+        self.status == Some(StatusCode::TOO_MANY_REQUESTS)
+    }
+}
+
+/// Called by the client once for every HTTP request it sends, including retries.
+/// A `status` of `None` means the request failed before a response was received.
+pub trait MetricsRecorder: Send + Sync {
+    fn record(&self, outcome: &RequestOutcome);
+}
+
+#[cfg(feature = "metrics")]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct MetricsCrateRecorder;
+
+#[cfg(feature = "metrics")]
+impl MetricsRecorder for MetricsCrateRecorder {
+    fn record(&self, outcome: &RequestOutcome) {
+        let operation = outcome.operation.name();
+        let status = match outcome.status {
+            Some(status) => status.as_u16().to_string(),
+            None => "error".to_owned(),
+        };
+
+        ::metrics::increment_counter!("google_trends_requests_total", "operation" => operation, "status" => status);
+        ::metrics::histogram!(
+            "google_trends_request_duration_seconds",
+            outcome.latency.as_secs_f64(),
+            "operation" => operation
+        );
+        if outcome.is_rate_limited() {
+            ::metrics::increment_counter!("google_trends_rate_limited_total", "operation" => operation);
+        }
+    }
+}
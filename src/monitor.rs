@@ -0,0 +1,425 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures_util::stream::{self, Stream};
+use serde_json::{json, Value};
+
+use crate::calendar::{bucket_end, Calendar};
+use crate::clock::{Clock, Sleeper, SystemClock};
+use crate::{Category, Error, Query, RisingValue, Source, TimeSeriesEntry, TrendValue, TrendsClient};
+
+#[derive(Debug, Clone)]
+pub enum TrendEvent {
+    /// Missing points are skipped, `<1` comes as 0.
+    DataPoint {
+        keyword: String,
+        time: DateTime<Utc>,
+        value: f32,
+    },
+    Spike {
+        keyword: String,
+        time: DateTime<Utc>,
+        value: f32,
+        baseline: f64,
+        /// Labels of the monitor's calendar around the spike, see `TrendsMonitor::calendar`.
+        annotations: Vec<String>,
+    },
+    RisingQuery {
+        keyword: String,
+        query: String,
+        value: RisingValue,
+    },
+}
+
+impl TrendEvent {
+    pub fn keyword(&self) -> &str {
+        match self {
+            TrendEvent::DataPoint { keyword, .. }
+            | TrendEvent::Spike { keyword, .. }
+            | TrendEvent::RisingQuery { keyword, .. } => keyword,
+        }
+    }
+
+    /// The event as a JSON object tagged by `type`, times in RFC 3339. A breakout rising value is
+    /// `"breakout"`, a percentage a number.
+    pub fn to_json(&self) -> Value {
+        match self {
+            TrendEvent::DataPoint { keyword, time, value } => json!({
+                "type": "data_point",
+                "keyword": keyword,
+                "time": time.to_rfc3339(),
+                "value": value,
+            }),
+            TrendEvent::Spike {
+                keyword,
+                time,
+                value,
+                baseline,
+                annotations,
+            } => json!({
+                "type": "spike",
+                "keyword": keyword,
+                "time": time.to_rfc3339(),
+                "value": value,
+                "baseline": baseline,
+                "annotations": annotations,
+            }),
+            TrendEvent::RisingQuery { keyword, query, value } => json!({
+                "type": "rising_query",
+                "keyword": keyword,
+                "query": query,
+                "value": match value {
+                    RisingValue::Percent(percent) => json!(percent),
+                    RisingValue::Breakout => json!("breakout"),
+                },
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Settings {
+    source: Option<Source>,
+    category: Option<Category>,
+    spike_ratio: f64,
+    baseline_window: usize,
+    jitter: Duration,
+    track_rising: bool,
+    max_pending: Option<usize>,
+}
+
+/// Live counters of a `watch_with_stats` stream, cheap to clone and read from another task.
+#[derive(Debug, Clone, Default)]
+pub struct WatchStats {
+    inner: Arc<StatsInner>,
+}
+
+#[derive(Debug, Default)]
+struct StatsInner {
+    polls: AtomicU64,
+    pending: AtomicUsize,
+    deferred: AtomicUsize,
+    lag_ms: AtomicU64,
+}
+
+impl WatchStats {
+    pub fn polls(&self) -> u64 {
+        self.inner.polls.load(Ordering::Relaxed)
+    }
+
+    /// Events of the last poll the consumer hasn't taken yet.
+    pub fn pending(&self) -> usize {
+        self.inner.pending.load(Ordering::Relaxed)
+    }
+
+    /// New buckets the last poll left for the next one because it reached `max_pending`.
+    pub fn deferred(&self) -> usize {
+        self.inner.deferred.load(Ordering::Relaxed)
+    }
+
+    /// How late the consumer made the last poll, zero when it kept up with the interval.
+    pub fn lag(&self) -> Duration {
+        Duration::from_millis(self.inner.lag_ms.load(Ordering::Relaxed))
+    }
+}
+
+pub struct TrendsMonitor {
+    client: Arc<TrendsClient>,
+    sleeper: Arc<dyn Sleeper>,
+    calendar: Option<Arc<dyn Calendar>>,
+    settings: Settings,
+}
+
+impl TrendsMonitor {
+    pub fn new(client: Arc<TrendsClient>) -> TrendsMonitor {
+        TrendsMonitor {
+            client,
+            sleeper: Arc::new(SystemClock),
+            calendar: None,
+            settings: Settings {
+                source: None,
+                category: None,
+                spike_ratio: 2.0,
+                baseline_window: 8,
+                jitter: Duration::from_secs(0),
+                track_rising: false,
+                max_pending: None,
+            },
+        }
+    }
+
+    pub fn source(mut self, source: Source) -> Self {
+        self.settings.source = Some(source);
+        self
+    }
+
+    pub fn category(mut self, category: Category) -> Self {
+        self.settings.category = Some(category);
+        self
+    }
+
+    /// A point is reported as a spike when it is at least `ratio` times the mean
+    /// of the preceding `baseline_window` points.
+    pub fn spike_ratio(mut self, ratio: f64) -> Self {
+        self.settings.spike_ratio = ratio;
+        self
+    }
+
+    pub fn baseline_window(mut self, points: usize) -> Self {
+        self.settings.baseline_window = points;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: Duration) -> Self {
+        self.settings.jitter = jitter;
+        self
+    }
+
+    /// Waits between polls with `sleeper` instead of a timer, jitter is derived from the client's clock.
+    pub fn sleeper(mut self, sleeper: Arc<dyn Sleeper>) -> Self {
+        self.sleeper = sleeper;
+        self
+    }
+
+    /// Labels spikes with the events of `calendar` overlapping them, so that expected ones (holidays,
+    /// campaigns) can be told apart.
+    pub fn calendar(mut self, calendar: Arc<dyn Calendar>) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    /// Also fetch related queries on every poll and report rising queries that weren't seen before.
+    pub fn track_rising(mut self, enabled: bool) -> Self {
+        self.settings.track_rising = enabled;
+        self
+    }
+
+    /// Bounds the events a single poll queues: once `max` are pending the poll stops taking new buckets
+    /// (though it always takes at least one), and the rest are fetched again by the next poll, which
+    /// comes as soon as the consumer has taken the queued events instead of after the interval. Nothing
+    /// is dropped, a consumer that falls behind just holds the monitor back.
+    pub fn max_pending(mut self, max: usize) -> Self {
+        self.settings.max_pending = Some(max);
+        self
+    }
+
+    /// Polls the query every `interval` (plus jitter), moving its timeframe forward to end today.
+    /// The first poll only establishes a baseline, events are reported for points that appear later.
+    /// Errors are yielded and polling goes on, except for `Error::Cancelled` which ends the stream.
+    ///
+    /// The stream is driven by its consumer: no poll happens while events are pending, and a consumer
+    /// that falls behind the interval gets the next poll as soon as it asks, without a catch-up burst.
+    pub fn watch(&self, query: Query<'static>, interval: Duration) -> impl Stream<Item = Result<TrendEvent, Error>> {
+        self.watch_with_stats(query, interval).0
+    }
+
+    /// Like `watch`, also returning counters of the stream's backlog and lag.
+    pub fn watch_with_stats(
+        &self,
+        query: Query<'static>,
+        interval: Duration,
+    ) -> (impl Stream<Item = Result<TrendEvent, Error>>, WatchStats) {
+        let stats = WatchStats::default();
+        let state = WatchState {
+            client: self.client.clone(),
+            sleeper: self.sleeper.clone(),
+            calendar: self.calendar.clone(),
+            settings: self.settings,
+            query,
+            interval,
+            last_seen: None,
+            seen_rising: HashSet::new(),
+            pending: VecDeque::new(),
+            next_poll: None,
+            stopped: false,
+            stats: stats.clone(),
+        };
+
+        let stream = stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(event) = state.pending.pop_front() {
+                    state.stats.inner.pending.store(state.pending.len(), Ordering::Relaxed);
+                    return Some((Ok(event), state));
+                }
+                if state.stopped {
+                    return None;
+                }
+                if let Some(due) = state.next_poll {
+                    let now = state.client.clock().now();
+                    match (due - now).to_std() {
+                        Ok(wait) => {
+                            state.stats.inner.lag_ms.store(0, Ordering::Relaxed);
+                            state.sleeper.sleep(wait).await;
+                        }
+                        Err(_) => {
+                            let lag = (now - due).num_milliseconds() as u64;
+                            state.stats.inner.lag_ms.store(lag, Ordering::Relaxed);
+                        }
+                    }
+                }
+                let result = state.poll().await;
+                let deferred = *result.as_ref().unwrap_or(&0);
+                state.next_poll = if deferred > 0 {
+                    None
+                } else {
+                    Some(
+                        state.client.clock().now()
+                            + chrono::Duration::from_std(state.next_delay())
+                                .unwrap_or_else(|_| chrono::Duration::max_value()),
+                    )
+                };
+                state.stats.inner.polls.fetch_add(1, Ordering::Relaxed);
+                state.stats.inner.deferred.store(deferred, Ordering::Relaxed);
+                state.stats.inner.pending.store(state.pending.len(), Ordering::Relaxed);
+                if let Err(err) = result {
+                    state.stopped = matches!(err, Error::Cancelled);
+                    return Some((Err(err), state));
+                }
+            }
+        });
+        (stream, stats)
+    }
+}
+
+struct WatchState {
+    client: Arc<TrendsClient>,
+    sleeper: Arc<dyn Sleeper>,
+    calendar: Option<Arc<dyn Calendar>>,
+    settings: Settings,
+    query: Query<'static>,
+    interval: Duration,
+    last_seen: Option<DateTime<Utc>>,
+    seen_rising: HashSet<String>,
+    pending: VecDeque<TrendEvent>,
+    next_poll: Option<DateTime<Utc>>,
+    stopped: bool,
+    stats: WatchStats,
+}
+
+impl WatchState {
+    fn source(&self) -> Source {
+        self.settings.source.unwrap_or_else(|| self.client.default_source())
+    }
+
+    fn category(&self) -> Category {
+        self.settings.category.unwrap_or_else(|| self.client.default_category())
+    }
+
+    // queues the events of the new buckets and returns how many of them were left for the next poll
+    async fn poll(&mut self) -> Result<usize, Error> {
+        let query = self.query.shifted_to_today_at(self.client.clock());
+        let data = self
+            .client
+            .interest_by_time(&query, self.source(), self.category())
+            .await?;
+
+        if self.settings.track_rising {
+            self.poll_rising(&query).await?;
+        }
+
+        let last_seen = match self.last_seen {
+            Some(time) => time,
+            None => {
+                self.last_seen = data.entries.last().map(|entry| entry.time);
+                return Ok(0);
+            }
+        };
+
+        let new = data
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.time > last_seen);
+        let total = new.clone().count();
+        let mut taken = 0;
+        for (pos, entry) in new {
+            if let Some(max) = self.settings.max_pending {
+                if taken > 0 && self.pending.len() >= max {
+                    break;
+                }
+            }
+            taken += 1;
+            self.last_seen = Some(entry.time);
+            for (index, item) in query.items().iter().enumerate() {
+                let value = match entry.value.get(index).and_then(TrendValue::as_f32) {
+                    Some(value) => value,
+                    None => continue,
+                };
+                self.pending.push_back(TrendEvent::DataPoint {
+                    keyword: item.keyword().to_owned(),
+                    time: entry.time,
+                    value,
+                });
+
+                let baseline = baseline(&data.entries[..pos], index, self.settings.baseline_window);
+                match baseline {
+                    Some(baseline) if baseline > 0.0 && f64::from(value) >= baseline * self.settings.spike_ratio => {
+                        let annotations = match &self.calendar {
+                            Some(calendar) => calendar.labels(entry.time, bucket_end(&data.entries, pos)),
+                            None => vec![],
+                        };
+                        self.pending.push_back(TrendEvent::Spike {
+                            keyword: item.keyword().to_owned(),
+                            time: entry.time,
+                            value,
+                            baseline,
+                            annotations,
+                        })
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(total - taken)
+    }
+
+    async fn poll_rising(&mut self, query: &Query<'_>) -> Result<(), Error> {
+        let related = self
+            .client
+            .related_queries(query, self.source(), self.category())
+            .await?;
+        let keyword = match query.items().first() {
+            Some(item) => item.keyword(),
+            None => return Ok(()),
+        };
+
+        let baseline = self.last_seen.is_none();
+        for rising in related.rising {
+            if self.seen_rising.insert(rising.query.clone()) && !baseline {
+                self.pending.push_back(TrendEvent::RisingQuery {
+                    keyword: keyword.to_owned(),
+                    query: rising.query,
+                    value: rising.value,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn next_delay(&self) -> Duration {
+        let jitter = self.settings.jitter.as_millis() as u64;
+        if jitter == 0 {
+            return self.interval;
+        }
+        let seed = self.client.clock().now().timestamp_subsec_nanos() as u64;
+        self.interval + Duration::from_millis(seed % jitter)
+    }
+}
+
+fn baseline(entries: &[TimeSeriesEntry], index: usize, window: usize) -> Option<f64> {
+    let values: Vec<f64> = entries
+        .iter()
+        .rev()
+        .take(window)
+        .filter_map(|entry| entry.value.get(index)?.as_f32())
+        .map(f64::from)
+        .collect();
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.iter().sum::<f64>() / values.len() as f64)
+    }
+}
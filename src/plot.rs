@@ -0,0 +1,291 @@
+use std::fmt::Write;
+
+use plotters::prelude::*;
+
+use crate::{AnnotatedTimeSeries, Error, RegionData, TimeSeriesData, TrendValue};
+
+#[derive(Debug, Clone)]
+pub struct PlotOptions {
+    width: u32,
+    height: u32,
+    title: Option<String>,
+    labels: Vec<String>,
+}
+
+impl PlotOptions {
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Legend labels in the order of the query's comparison items, unlabeled series are
+    /// shown as "Series N".
+    pub fn labels<I: IntoIterator<Item = S>, S: Into<String>>(mut self, labels: I) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl Default for PlotOptions {
+    fn default() -> Self {
+        PlotOptions {
+            width: 800,
+            height: 400,
+            title: None,
+            labels: vec![],
+        }
+    }
+}
+
+impl TimeSeriesData {
+    /// Draws every keyword as a line on a shared date axis and returns the chart as an SVG document.
+    /// Missing points are skipped.
+    pub fn render_svg(&self, opts: &PlotOptions) -> Result<String, Error> {
+        let mut svg = String::new();
+        self.draw(SVGBackend::with_string(&mut svg, (opts.width, opts.height)), opts, &[])
+            .map_err(|err| Error::PlotError(err.to_string()))?;
+        Ok(svg)
+    }
+
+    fn draw<DB: DrawingBackend>(
+        &self,
+        backend: DB,
+        opts: &PlotOptions,
+        annotations: &[(chrono::DateTime<chrono::Utc>, &[String])],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let root = backend.into_drawing_area();
+        root.fill(&WHITE)?;
+
+        let start = self.entries.first().map_or_else(chrono::Utc::now, |entry| entry.time);
+        let end = self
+            .entries
+            .last()
+            .map(|entry| entry.time)
+            .filter(|end| *end > start)
+            .unwrap_or_else(|| start + chrono::Duration::hours(1));
+
+        let mut builder = ChartBuilder::on(&root);
+        builder.margin(10).x_label_area_size(30).y_label_area_size(40);
+        if let Some(title) = &opts.title {
+            builder.caption(title, ("sans-serif", 20));
+        }
+        let mut chart = builder.build_cartesian_2d(start..end, 0f32..100f32)?;
+        chart
+            .configure_mesh()
+            .x_label_formatter(&|time| time.format("%Y-%m-%d").to_string())
+            .draw()?;
+
+        for (time, labels) in annotations {
+            chart.draw_series(std::iter::once(PathElement::new(
+                vec![(*time, 0f32), (*time, 100f32)],
+                BLACK.mix(0.3),
+            )))?;
+            chart.draw_series(std::iter::once(Text::new(
+                labels.join(", "),
+                (*time, 98f32),
+                ("sans-serif", 12).into_font(),
+            )))?;
+        }
+
+        let columns = self.entries.iter().map(|entry| entry.value.len()).max().unwrap_or(0);
+        for column in 0..columns {
+            let color = Palette99::pick(column).to_rgba();
+            let points = self.entries.iter().filter_map(|entry| {
+                let value = entry.values().get(column).and_then(TrendValue::as_f32)?;
+                Some((entry.time, value))
+            });
+            let label = opts
+                .labels
+                .get(column)
+                .cloned()
+                .unwrap_or_else(|| format!("Series {}", column + 1));
+            chart
+                .draw_series(LineSeries::new(points, color.stroke_width(2)))?
+                .label(label)
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2)));
+        }
+
+        chart
+            .configure_series_labels()
+            .background_style(&WHITE.mix(0.8))
+            .border_style(&BLACK)
+            .draw()?;
+        root.present()
+    }
+}
+
+impl AnnotatedTimeSeries {
+    /// Like `TimeSeriesData::render_svg`, with a labelled vertical marker at every annotated point.
+    pub fn render_svg(&self, opts: &PlotOptions) -> Result<String, Error> {
+        let annotations: Vec<_> = self.annotated().map(|(entry, labels)| (entry.time, labels)).collect();
+        let mut svg = String::new();
+        self.data
+            .draw(
+                SVGBackend::with_string(&mut svg, (opts.width, opts.height)),
+                opts,
+                &annotations,
+            )
+            .map_err(|err| Error::PlotError(err.to_string()))?;
+        Ok(svg)
+    }
+}
+
+/// Options for `RegionData::render_map_svg`. Boundaries are not bundled, they come from a
+/// GeoJSON `FeatureCollection` whose features carry the region's geo code in `id_property`
+/// (`ISO_A2` by default, which matches Natural Earth country files).
+#[derive(Debug, Clone)]
+pub struct MapOptions {
+    width: u32,
+    height: u32,
+    boundaries: serde_json::Value,
+    id_property: String,
+    column: usize,
+}
+
+impl MapOptions {
+    pub fn new(boundaries: serde_json::Value) -> Self {
+        MapOptions {
+            width: 960,
+            height: 500,
+            boundaries,
+            id_property: "ISO_A2".to_owned(),
+            column: 0,
+        }
+    }
+
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    pub fn id_property<S: Into<String>>(mut self, property: S) -> Self {
+        self.id_property = property.into();
+        self
+    }
+
+    /// Index of the compared keyword whose values color the map.
+    pub fn column(mut self, column: usize) -> Self {
+        self.column = column;
+        self
+    }
+}
+
+impl RegionData {
+    /// Renders a choropleth of the regions in an equirectangular projection fitted to the boundaries,
+    /// shaded like the Trends UI from light to dark blue.
+    pub fn render_map_svg(&self, opts: &MapOptions) -> Result<String, Error> {
+        let features = opts.boundaries["features"]
+            .as_array()
+            .ok_or_else(|| Error::PlotError("Boundaries are not a GeoJSON FeatureCollection".to_owned()))?;
+
+        let shapes: Vec<(Option<&str>, Vec<Ring>)> = features
+            .iter()
+            .map(|feature| {
+                let id = feature["properties"][opts.id_property.as_str()].as_str();
+                (id, rings(&feature["geometry"]))
+            })
+            .collect();
+
+        let points = shapes.iter().flat_map(|(_, rings)| rings.iter().flatten());
+        let (min_x, min_y, max_x, max_y) = points.fold(
+            (f64::MAX, f64::MAX, f64::MIN, f64::MIN),
+            |(min_x, min_y, max_x, max_y), (lon, lat)| {
+                (min_x.min(*lon), min_y.min(*lat), max_x.max(*lon), max_y.max(*lat))
+            },
+        );
+        if min_x >= max_x || min_y >= max_y {
+            return Err(Error::PlotError("Boundaries contain no polygons".to_owned()));
+        }
+        let scale = (f64::from(opts.width) / (max_x - min_x)).min(f64::from(opts.height) / (max_y - min_y));
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n",
+            w = opts.width,
+            h = opts.height
+        );
+        for (id, rings) in &shapes {
+            let entry = id.and_then(|id| self.entries.iter().find(|entry| entry.geo_code == id));
+            let value = entry.and_then(|entry| entry.values().get(opts.column).and_then(TrendValue::as_f32));
+
+            let path: String = rings
+                .iter()
+                .map(|ring| {
+                    let coords: Vec<String> = ring
+                        .iter()
+                        .map(|(lon, lat)| format!("{:.1},{:.1}", (lon - min_x) * scale, (max_y - lat) * scale))
+                        .collect();
+                    format!("M{}Z", coords.join("L"))
+                })
+                .collect();
+            let title = match (entry, value) {
+                (Some(entry), Some(value)) => format!("<title>{}: {}</title>", escape(&entry.geo_name), value),
+                (Some(entry), None) => format!("<title>{}</title>", escape(&entry.geo_name)),
+                _ => String::new(),
+            };
+            let _ = writeln!(
+                svg,
+                "<path d=\"{}\" fill=\"{}\" stroke=\"#ffffff\" stroke-width=\"0.5\">{}</path>",
+                path,
+                shade(value),
+                title
+            );
+        }
+        svg.push_str("</svg>\n");
+        Ok(svg)
+    }
+}
+
+type Ring = Vec<(f64, f64)>;
+
+fn rings(geometry: &serde_json::Value) -> Vec<Ring> {
+    let polygons = match geometry["type"].as_str() {
+        Some("Polygon") => vec![&geometry["coordinates"]],
+        Some("MultiPolygon") => geometry["coordinates"]
+            .as_array()
+            .map(|polygons| polygons.iter().collect())
+            .unwrap_or_default(),
+        _ => vec![],
+    };
+    polygons
+        .into_iter()
+        .filter_map(serde_json::Value::as_array)
+        .flatten()
+        .filter_map(|ring| {
+            let ring = ring.as_array()?;
+            Some(
+                ring.iter()
+                    .filter_map(|point| Some((point.get(0)?.as_f64()?, point.get(1)?.as_f64()?)))
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+fn shade(value: Option<f32>) -> String {
+    const LIGHT: (f64, f64, f64) = (232.0, 240.0, 254.0);
+    const DARK: (f64, f64, f64) = (26.0, 115.0, 232.0);
+    match value {
+        Some(value) => {
+            let t = (f64::from(value) / 100.0).clamp(0.0, 1.0);
+            let mix = |light: f64, dark: f64| (light + (dark - light) * t).round() as u8;
+            format!(
+                "#{:02x}{:02x}{:02x}",
+                mix(LIGHT.0, DARK.0),
+                mix(LIGHT.1, DARK.1),
+                mix(LIGHT.2, DARK.2)
+            )
+        }
+        None => "#eeeeee".to_owned(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
@@ -0,0 +1,52 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::Operation;
+
+/// Receives the progress of the calls made through a client from `TrendsClient::with_progress`,
+/// e.g. to render a progress bar or log heartbeats. Calls that fan out into several requests
+/// (`interest_by_time_per_geo`, `drill_down_regions`, `compare_sources`, `seasonality`,
+/// `rising_digest` and jobs) report every finished window, retries are reported for any call.
+/// `on_finished` is only called for calls that succeed.
+pub trait ProgressHandler: Send + Sync {
+    fn on_started(&self, _total: usize) {}
+
+    /// `index` counts finished windows from zero, they can finish out of order.
+    fn on_window_done(&self, _index: usize, _total: usize) {}
+
+    /// A request is about to be sent again, after a 429 or a transient network error.
+    fn on_retry(&self, _operation: Operation) {}
+
+    fn on_finished(&self) {}
+}
+
+pub(crate) struct Tracker<'a> {
+    handler: Option<&'a dyn ProgressHandler>,
+    total: usize,
+    done: AtomicUsize,
+}
+
+impl<'a> Tracker<'a> {
+    pub(crate) fn start(handler: Option<&'a dyn ProgressHandler>, total: usize) -> Tracker<'a> {
+        if let Some(handler) = handler {
+            handler.on_started(total);
+        }
+        Tracker {
+            handler,
+            total,
+            done: AtomicUsize::new(0),
+        }
+    }
+
+    pub(crate) fn window_done(&self) {
+        let index = self.done.fetch_add(1, Ordering::Relaxed);
+        if let Some(handler) = self.handler {
+            handler.on_window_done(index, self.total);
+        }
+    }
+
+    pub(crate) fn finish(self) {
+        if let Some(handler) = self.handler {
+            handler.on_finished();
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+
+use futures_util::stream::{Stream, StreamExt};
+
+use crate::monitor::TrendEvent;
+use crate::Error;
+
+/// The latest value of the keyword as reported by `TrendEvent::DataPoint`.
+pub const INTEREST: &str = "google_trends_interest";
+/// `1` while the latest point of the keyword is a spike, `0` otherwise.
+pub const SPIKE: &str = "google_trends_spike";
+/// The baseline of the latest spike of the keyword.
+pub const SPIKE_BASELINE: &str = "google_trends_spike_baseline";
+/// Unix time of the latest point of the keyword.
+pub const LAST_POINT: &str = "google_trends_last_point_timestamp_seconds";
+
+/// Receives gauge updates, each gauge is labelled with the keyword it belongs to.
+pub trait GaugeSink: Send + Sync {
+    fn set(&self, name: &'static str, keyword: &str, value: f64);
+}
+
+/// Turns the events of a monitor into gauges of a `GaugeSink`.
+#[derive(Clone)]
+pub struct MonitorGauges {
+    sink: Arc<dyn GaugeSink>,
+}
+
+impl MonitorGauges {
+    pub fn new(sink: Arc<dyn GaugeSink>) -> MonitorGauges {
+        MonitorGauges { sink }
+    }
+
+    pub fn observe(&self, event: &TrendEvent) {
+        match event {
+            TrendEvent::DataPoint { keyword, time, value } => {
+                self.sink.set(INTEREST, keyword, f64::from(*value));
+                self.sink.set(SPIKE, keyword, 0.0);
+                self.sink.set(LAST_POINT, keyword, time.timestamp() as f64);
+            }
+            TrendEvent::Spike { keyword, baseline, .. } => {
+                self.sink.set(SPIKE, keyword, 1.0);
+                self.sink.set(SPIKE_BASELINE, keyword, *baseline);
+            }
+            TrendEvent::RisingQuery { .. } => {}
+        }
+    }
+
+    /// Passes `events` through, observing every event on the way.
+    pub fn instrument<S>(&self, events: S) -> impl Stream<Item = Result<TrendEvent, Error>>
+    where
+        S: Stream<Item = Result<TrendEvent, Error>>,
+    {
+        let gauges = self.clone();
+        events.inspect(move |event| {
+            if let Ok(event) = event {
+                gauges.observe(event);
+            }
+        })
+    }
+}
+
+/// Keeps the gauges in memory and renders them in the Prometheus text format, to be served on a
+/// `/metrics` endpoint of your choice.
+#[derive(Debug, Default)]
+pub struct PrometheusRegistry {
+    gauges: Mutex<BTreeMap<&'static str, BTreeMap<String, f64>>>,
+}
+
+impl PrometheusRegistry {
+    pub fn new() -> PrometheusRegistry {
+        PrometheusRegistry::default()
+    }
+
+    pub fn get(&self, name: &str, keyword: &str) -> Option<f64> {
+        self.gauges.lock().unwrap().get(name)?.get(keyword).copied()
+    }
+
+    /// Forgets the gauges of `keyword`, e.g. after it's no longer watched.
+    pub fn remove(&self, keyword: &str) {
+        for values in self.gauges.lock().unwrap().values_mut() {
+            values.remove(keyword);
+        }
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for (name, values) in self.gauges.lock().unwrap().iter() {
+            let _ = writeln!(out, "# TYPE {} gauge", name);
+            for (keyword, value) in values {
+                let _ = writeln!(
+                    out,
+                    "{}{{keyword=\"{}\"}} {}",
+                    name,
+                    escape_label(keyword),
+                    format_value(*value)
+                );
+            }
+        }
+        out
+    }
+}
+
+impl GaugeSink for PrometheusRegistry {
+    fn set(&self, name: &'static str, keyword: &str, value: f64) {
+        self.gauges
+            .lock()
+            .unwrap()
+            .entry(name)
+            .or_default()
+            .insert(keyword.to_owned(), value);
+    }
+}
+
+// Rust prints infinities as `inf`, the text format wants `+Inf`, `-Inf` and `NaN`.
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_owned()
+    } else if value == f64::INFINITY {
+        "+Inf".to_owned()
+    } else if value == f64::NEG_INFINITY {
+        "-Inf".to_owned()
+    } else {
+        value.to_string()
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Sets the gauges through the `metrics` crate, for apps that already install a Prometheus exporter
+/// for it.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct MetricsCrateGauges;
+
+#[cfg(feature = "metrics")]
+impl GaugeSink for MetricsCrateGauges {
+    fn set(&self, name: &'static str, keyword: &str, value: f64) {
+        ::metrics::gauge!(name, value, "keyword" => keyword.to_owned());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_special_values_in_the_text_format() {
+        let registry = PrometheusRegistry::new();
+        registry.set(SPIKE_BASELINE, "a", f64::INFINITY);
+        registry.set(SPIKE_BASELINE, "b", f64::NEG_INFINITY);
+        registry.set(SPIKE_BASELINE, "c", f64::NAN);
+        registry.set(SPIKE_BASELINE, "d", 1.5);
+
+        assert_eq!(
+            registry.render(),
+            "# TYPE google_trends_spike_baseline gauge\n\
+             google_trends_spike_baseline{keyword=\"a\"} +Inf\n\
+             google_trends_spike_baseline{keyword=\"b\"} -Inf\n\
+             google_trends_spike_baseline{keyword=\"c\"} NaN\n\
+             google_trends_spike_baseline{keyword=\"d\"} 1.5\n"
+        );
+    }
+}
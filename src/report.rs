@@ -0,0 +1,229 @@
+use std::fmt::Write;
+
+use crate::{Query, RisingValue, TrendValue, TrendsReport};
+
+const TOP_ROWS: usize = 10;
+const SPARK_BARS: &[char] = &['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const SPARK_WIDTH: f64 = 120.0;
+const SPARK_HEIGHT: f64 = 24.0;
+
+impl TrendsReport {
+    /// Renders the report as Markdown. Markdown renderers rarely allow inline SVG,
+    /// so the interest over time is drawn with unicode block characters instead.
+    pub fn to_markdown(&self, query: &Query) -> String {
+        let mut out = String::new();
+        let labels = query.labels();
+
+        out.push_str("## Interest over time\n\n| Keyword | Trend | Average | Latest |\n|---|---|---|---|\n");
+        for (index, label) in labels.iter().enumerate() {
+            let points = self.series(index);
+            let spark: String = scale(&points, 1.0)
+                .iter()
+                .map(|value| match value {
+                    Some(value) => SPARK_BARS[((value * (SPARK_BARS.len() - 1) as f64).round()) as usize],
+                    None => ' ',
+                })
+                .collect();
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} | {} |",
+                cell(label),
+                spark,
+                self.average(index),
+                latest(&points)
+            );
+        }
+
+        out.push_str("\n## Top regions\n\n| Region |");
+        for label in &labels {
+            let _ = write!(out, " {} |", cell(label));
+        }
+        out.push_str("\n|---|");
+        out.push_str(&"---|".repeat(labels.len()));
+        out.push('\n');
+        for entry in self.regions.entries.iter().take(TOP_ROWS) {
+            let _ = write!(out, "| {} |", cell(&entry.geo_name));
+            for value in &entry.value {
+                let _ = write!(out, " {} |", value);
+            }
+            out.push('\n');
+        }
+
+        out.push_str("\n## Related queries\n\n| Top | Rising |\n|---|---|\n");
+        for (top, rising) in self.related_query_rows() {
+            let _ = writeln!(out, "| {} | {} |", cell(&top), cell(&rising));
+        }
+
+        out.push_str("\n## Related topics\n\n| Top | Rising |\n|---|---|\n");
+        for (top, rising) in self.related_topic_rows() {
+            let _ = writeln!(out, "| {} | {} |", cell(&top), cell(&rising));
+        }
+        out
+    }
+
+    /// Renders the report as a self-contained HTML fragment with an inline SVG sparkline per keyword.
+    pub fn to_html(&self, query: &Query) -> String {
+        let mut out = String::new();
+        let labels = query.labels();
+
+        out.push_str("<h2>Interest over time</h2>\n<table>\n");
+        out.push_str("<tr><th>Keyword</th><th>Trend</th><th>Average</th><th>Latest</th></tr>\n");
+        for (index, label) in labels.iter().enumerate() {
+            let points = self.series(index);
+            let _ = writeln!(
+                out,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape(label),
+                sparkline_svg(&points),
+                self.average(index),
+                escape(&latest(&points))
+            );
+        }
+        out.push_str("</table>\n");
+
+        out.push_str("<h2>Top regions</h2>\n<table>\n<tr><th>Region</th>");
+        for label in &labels {
+            let _ = write!(out, "<th>{}</th>", escape(label));
+        }
+        out.push_str("</tr>\n");
+        for entry in self.regions.entries.iter().take(TOP_ROWS) {
+            let _ = write!(out, "<tr><td>{}</td>", escape(&entry.geo_name));
+            for value in &entry.value {
+                let _ = write!(out, "<td>{}</td>", value);
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</table>\n");
+
+        for (title, rows) in &[
+            ("Related queries", self.related_query_rows()),
+            ("Related topics", self.related_topic_rows()),
+        ] {
+            let _ = writeln!(out, "<h2>{}</h2>\n<table>\n<tr><th>Top</th><th>Rising</th></tr>", title);
+            for (top, rising) in rows {
+                let _ = writeln!(out, "<tr><td>{}</td><td>{}</td></tr>", escape(top), escape(rising));
+            }
+            out.push_str("</table>\n");
+        }
+        out
+    }
+
+    fn series(&self, index: usize) -> Vec<TrendValue> {
+        self.time_series
+            .entries
+            .iter()
+            .map(|entry| entry.values().get(index).copied().unwrap_or(TrendValue::Missing))
+            .collect()
+    }
+
+    fn average(&self, index: usize) -> String {
+        if let Some(average) = self.time_series.averages.get(index) {
+            return average.to_string();
+        }
+        let values: Vec<f32> = self.series(index).iter().filter_map(TrendValue::as_f32).collect();
+        if values.is_empty() {
+            "-".to_owned()
+        } else {
+            format!("{:.0}", values.iter().sum::<f32>() / values.len() as f32)
+        }
+    }
+
+    fn related_query_rows(&self) -> Vec<(String, String)> {
+        let top = self
+            .related_queries
+            .top
+            .iter()
+            .map(|query| format!("{} ({})", query.query, query.value));
+        let rising = self
+            .related_queries
+            .rising
+            .iter()
+            .map(|query| format!("{} ({})", query.query, format_rising(query.value)));
+        zip_padded(top, rising)
+    }
+
+    fn related_topic_rows(&self) -> Vec<(String, String)> {
+        let top = self
+            .related_topics
+            .top
+            .iter()
+            .map(|topic| format!("{} ({})", topic.topic.title, topic.value));
+        let rising = self
+            .related_topics
+            .rising
+            .iter()
+            .map(|topic| format!("{} ({})", topic.topic.title, format_rising(topic.rising_value())));
+        zip_padded(top, rising)
+    }
+}
+
+fn latest(points: &[TrendValue]) -> String {
+    match points.iter().rev().find(|value| !value.is_missing()) {
+        Some(TrendValue::LessThanOne) => "<1".to_owned(),
+        Some(TrendValue::Value(value)) => format!("{:.0}", value),
+        _ => "-".to_owned(),
+    }
+}
+
+fn format_rising(value: RisingValue) -> String {
+    match value {
+        RisingValue::Breakout => "Breakout".to_owned(),
+        RisingValue::Percent(percent) => format!("+{}%", percent),
+    }
+}
+
+fn zip_padded<A, B>(left: A, right: B) -> Vec<(String, String)>
+where
+    A: Iterator<Item = String>,
+    B: Iterator<Item = String>,
+{
+    let (left, right): (Vec<String>, Vec<String>) = (left.take(TOP_ROWS).collect(), right.take(TOP_ROWS).collect());
+    (0..left.len().max(right.len()))
+        .map(|index| {
+            (
+                left.get(index).cloned().unwrap_or_default(),
+                right.get(index).cloned().unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
+/// Scales the points to `0..=max` relative to the largest one.
+fn scale(points: &[TrendValue], max: f64) -> Vec<Option<f64>> {
+    let peak = points
+        .iter()
+        .filter_map(TrendValue::as_f32)
+        .fold(0.0_f32, f32::max)
+        .max(1.0);
+    points
+        .iter()
+        .map(|value| Some(f64::from(value.as_f32()?) * max / f64::from(peak)))
+        .collect()
+}
+
+fn sparkline_svg(points: &[TrendValue]) -> String {
+    let step = SPARK_WIDTH / (points.len().max(2) - 1) as f64;
+    let path: Vec<String> = scale(points, SPARK_HEIGHT)
+        .iter()
+        .enumerate()
+        .filter_map(|(index, value)| Some(format!("{:.1},{:.1}", index as f64 * step, SPARK_HEIGHT - (*value)?)))
+        .collect();
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\
+         <polyline fill=\"none\" stroke=\"#4285f4\" stroke-width=\"1.5\" points=\"{points}\"/></svg>",
+        w = SPARK_WIDTH,
+        h = SPARK_HEIGHT,
+        points = path.join(" ")
+    )
+}
+
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,183 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderValue;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::Proxy;
+use reqwest::{Client, ClientBuilder};
+
+use crate::Error;
+
+#[derive(Debug, Clone)]
+enum SessionSpec {
+    Direct,
+    #[cfg(not(target_arch = "wasm32"))]
+    Proxy(Proxy),
+}
+
+/// A set of independent identities the client rotates through. Every session has its own
+/// connection pool, optional proxy and cookie, a session that gets rate limited is benched
+/// for `bench_duration` and requests are moved over to the remaining ones.
+#[derive(Debug, Clone)]
+pub struct SessionPool {
+    specs: Vec<SessionSpec>,
+    bench_duration: Duration,
+}
+
+impl SessionPool {
+    pub fn new() -> SessionPool {
+        SessionPool {
+            specs: vec![],
+            bench_duration: Duration::from_secs(60),
+        }
+    }
+
+    pub fn direct(mut self) -> Self {
+        self.specs.push(SessionSpec::Direct);
+        self
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.specs.push(SessionSpec::Proxy(proxy));
+        self
+    }
+
+    pub fn bench_duration(mut self, duration: Duration) -> Self {
+        self.bench_duration = duration;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.specs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.specs.is_empty()
+    }
+
+    pub(crate) fn build<F: Fn() -> ClientBuilder>(self, http: F) -> Result<Sessions, Error> {
+        let sessions = self
+            .specs
+            .into_iter()
+            .map(|spec| {
+                let builder = match spec {
+                    SessionSpec::Direct => http(),
+                    #[cfg(not(target_arch = "wasm32"))]
+                    SessionSpec::Proxy(proxy) => http().proxy(proxy),
+                };
+                Ok(Session {
+                    client: builder.build()?,
+                    cookie: Mutex::new(None),
+                    benched_until: Mutex::new(None),
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Sessions {
+            sessions,
+            cursor: AtomicUsize::new(0),
+            bench_duration: chrono::Duration::from_std(self.bench_duration)
+                .unwrap_or_else(|_| chrono::Duration::max_value()),
+        })
+    }
+}
+
+impl Default for SessionPool {
+    fn default() -> Self {
+        SessionPool::new()
+    }
+}
+
+pub(crate) struct Sessions {
+    sessions: Vec<Session>,
+    cursor: AtomicUsize,
+    bench_duration: chrono::Duration,
+}
+
+impl Sessions {
+    pub(crate) fn checkout(&self, now: DateTime<Utc>) -> Result<&Session, Error> {
+        let start = self.cursor.fetch_add(1, Ordering::Relaxed);
+        let count = self.sessions.len();
+        (0..count)
+            .map(|offset| &self.sessions[(start + offset) % count])
+            .find(|session| session.is_available(now))
+            .ok_or(Error::AllSessionsBenched)
+    }
+
+    pub(crate) fn bench(&self, session: &Session, cookie: Option<HeaderValue>, now: DateTime<Utc>) {
+        if let Some(cookie) = cookie {
+            *session.cookie.lock().unwrap() = Some(cookie);
+        }
+        *session.benched_until.lock().unwrap() = Some(now + self.bench_duration);
+    }
+}
+
+pub(crate) struct Session {
+    client: Client,
+    cookie: Mutex<Option<HeaderValue>>,
+    benched_until: Mutex<Option<DateTime<Utc>>>,
+}
+
+impl Session {
+    pub(crate) fn client(&self) -> &Client {
+        &self.client
+    }
+
+    pub(crate) fn cookie(&self) -> Option<HeaderValue> {
+        self.cookie.lock().unwrap().clone()
+    }
+
+    fn is_available(&self, now: DateTime<Utc>) -> bool {
+        self.benched_until.lock().unwrap().map_or(true, |until| until <= now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn sessions() -> Sessions {
+        SessionPool::new()
+            .direct()
+            .direct()
+            .bench_duration(Duration::from_secs(60))
+            .build(Client::builder)
+            .unwrap()
+    }
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp(1_614_556_800 + secs, 0)
+    }
+
+    #[test]
+    fn checkout_skips_benched_sessions_until_the_bench_is_over() {
+        let sessions = sessions();
+        let (first, second) = (&sessions.sessions[0], &sessions.sessions[1]);
+        sessions.bench(first, Some(HeaderValue::from_static("NID=abc")), at(0));
+
+        for secs in &[0, 30, 59] {
+            assert!(std::ptr::eq(sessions.checkout(at(*secs)).unwrap(), second));
+        }
+        assert_eq!(first.cookie().unwrap(), "NID=abc");
+
+        let available: Vec<*const Session> = (0..2)
+            .map(|_| sessions.checkout(at(60)).unwrap() as *const Session)
+            .collect();
+        assert!(available.contains(&(first as *const Session)));
+        assert!(available.contains(&(second as *const Session)));
+    }
+
+    #[test]
+    fn checkout_fails_once_every_session_is_benched() {
+        let sessions = sessions();
+        sessions.bench(&sessions.sessions[0], None, at(0));
+        sessions.bench(&sessions.sessions[1], None, at(10));
+
+        assert!(matches!(sessions.checkout(at(30)), Err(Error::AllSessionsBenched)));
+        assert!(std::ptr::eq(sessions.checkout(at(60)).unwrap(), &sessions.sessions[0]));
+    }
+}
@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use futures_util::stream::{Stream, StreamExt};
+use rdkafka::producer::{FutureProducer, FutureRecord};
+
+use crate::monitor::TrendEvent;
+use crate::Error;
+
+/// Publishes events as JSON (see `TrendEvent::to_json`) to a Kafka topic, keyed by keyword so that the
+/// events of a keyword stay in one partition.
+#[derive(Clone)]
+pub struct KafkaSink {
+    producer: FutureProducer,
+    topic: String,
+    timeout: Duration,
+}
+
+impl KafkaSink {
+    pub fn new<S: Into<String>>(producer: FutureProducer, topic: S) -> KafkaSink {
+        KafkaSink {
+            producer,
+            topic: topic.into(),
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    /// How long a send may wait for room in the producer's queue, 5 seconds by default.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub async fn publish(&self, event: &TrendEvent) -> Result<(), Error> {
+        let payload = serde_json::to_vec(&event.to_json())?;
+        let record = FutureRecord::to(&self.topic).key(event.keyword()).payload(&payload);
+        self.producer
+            .send(record, self.timeout)
+            .await
+            .map_err(|(err, _)| Error::KafkaError(err))?;
+        Ok(())
+    }
+
+    /// Publishes every event of `events`, stopping at the first error of either the stream or Kafka.
+    pub async fn forward<S>(&self, events: S) -> Result<(), Error>
+    where
+        S: Stream<Item = Result<TrendEvent, Error>>,
+    {
+        futures_util::pin_mut!(events);
+        while let Some(event) = events.next().await {
+            self.publish(&event?).await?;
+        }
+        Ok(())
+    }
+}
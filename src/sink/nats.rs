@@ -0,0 +1,86 @@
+use futures_util::stream::{Stream, StreamExt};
+
+use crate::monitor::TrendEvent;
+use crate::Error;
+
+/// Publishes events as JSON (see `TrendEvent::to_json`) to NATS subjects.
+#[derive(Clone)]
+pub struct NatsSink {
+    connection: async_nats::Connection,
+    subject: String,
+    per_keyword: bool,
+}
+
+impl NatsSink {
+    pub fn new<S: Into<String>>(connection: async_nats::Connection, subject: S) -> NatsSink {
+        NatsSink {
+            connection,
+            subject: subject.into(),
+            per_keyword: false,
+        }
+    }
+
+    /// Publishes to `{subject}.{keyword}` instead, with the characters NATS doesn't allow in a token
+    /// (dots, whitespace, `*` and `>`) replaced by `_`, so that consumers can subscribe to single keywords.
+    /// Events of an empty keyword fail with `Error::InvalidQuery`, they'd end the subject with a dot.
+    pub fn per_keyword(mut self, per_keyword: bool) -> Self {
+        self.per_keyword = per_keyword;
+        self
+    }
+
+    pub async fn publish(&self, event: &TrendEvent) -> Result<(), Error> {
+        let payload = serde_json::to_vec(&event.to_json())?;
+        let subject = if self.per_keyword {
+            format!("{}.{}", self.subject, subject_token(event.keyword())?)
+        } else {
+            self.subject.clone()
+        };
+        self.connection.publish(&subject, payload).await?;
+        Ok(())
+    }
+
+    /// Publishes every event of `events`, stopping at the first error of either the stream or NATS.
+    pub async fn forward<S>(&self, events: S) -> Result<(), Error>
+    where
+        S: Stream<Item = Result<TrendEvent, Error>>,
+    {
+        futures_util::pin_mut!(events);
+        while let Some(event) = events.next().await {
+            self.publish(&event?).await?;
+        }
+        Ok(())
+    }
+}
+
+fn subject_token(keyword: &str) -> Result<String, Error> {
+    if keyword.is_empty() {
+        return Err(Error::InvalidQuery(
+            "an empty keyword can't be a NATS subject token".to_owned(),
+        ));
+    }
+    Ok(keyword
+        .chars()
+        .map(|c| match c {
+            '.' | '*' | '>' => '_',
+            c if c.is_whitespace() => '_',
+            c => c,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subject_tokens_replace_reserved_characters() {
+        assert_eq!(subject_token("rust lang").unwrap(), "rust_lang");
+        assert_eq!(subject_token("a.b*c>d\te\nf").unwrap(), "a_b_c_d_e_f");
+        assert_eq!(subject_token("café").unwrap(), "café");
+    }
+
+    #[test]
+    fn subject_tokens_reject_empty_keywords() {
+        assert!(matches!(subject_token(""), Err(Error::InvalidQuery(_))));
+    }
+}
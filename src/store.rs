@@ -0,0 +1,174 @@
+use std::path::Path;
+
+use chrono::{Date, DateTime, TimeZone, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::{Error, Query, RegionData, RelatedQueries, RisingValue, TimeSeriesData};
+
+const DATE_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS time_series (
+    keyword TEXT NOT NULL,
+    geo TEXT NOT NULL,
+    date TEXT NOT NULL,
+    value REAL,
+    partial INTEGER NOT NULL,
+    PRIMARY KEY (keyword, geo, date)
+);
+CREATE TABLE IF NOT EXISTS regions (
+    keyword TEXT NOT NULL,
+    geo TEXT NOT NULL,
+    date TEXT NOT NULL,
+    region TEXT NOT NULL,
+    region_name TEXT NOT NULL,
+    value REAL,
+    PRIMARY KEY (keyword, geo, date, region)
+);
+CREATE TABLE IF NOT EXISTS related_queries (
+    keyword TEXT NOT NULL,
+    geo TEXT NOT NULL,
+    date TEXT NOT NULL,
+    list TEXT NOT NULL,
+    query TEXT NOT NULL,
+    value INTEGER,
+    breakout INTEGER NOT NULL,
+    PRIMARY KEY (keyword, geo, date, list, query)
+);
+";
+
+/// SQLite storage for pulled data. Rows are keyed by keyword, geo (empty for worldwide) and date,
+/// storing a pull again replaces the values of the rows it overlaps. Region and related query pulls
+/// are dated by the end of their timeframe.
+pub struct TrendsStore {
+    conn: Connection,
+}
+
+impl TrendsStore {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<TrendsStore, Error> {
+        TrendsStore::with_connection(Connection::open(path)?)
+    }
+
+    pub fn in_memory() -> Result<TrendsStore, Error> {
+        TrendsStore::with_connection(Connection::open_in_memory()?)
+    }
+
+    pub fn with_connection(conn: Connection) -> Result<TrendsStore, Error> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(TrendsStore { conn })
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    pub fn store_time_series(&mut self, query: &Query, data: &TimeSeriesData) -> Result<usize, Error> {
+        let tx = self.conn.transaction()?;
+        let mut rows = 0;
+        {
+            let mut stmt = tx.prepare_cached(
+                "INSERT INTO time_series (keyword, geo, date, value, partial) VALUES (?1, ?2, ?3, ?4, ?5)
+                 ON CONFLICT (keyword, geo, date) DO UPDATE SET value = excluded.value, partial = excluded.partial",
+            )?;
+            for entry in &data.entries {
+                let date = entry.time.format(DATE_FORMAT).to_string();
+                for (item, value) in query.items().iter().zip(entry.values()) {
+                    rows += stmt.execute(params![
+                        item.keyword(),
+                        query.geo_of(item).unwrap_or_default(),
+                        date,
+                        value.as_f32().map(f64::from),
+                        entry.is_partial.unwrap_or(false),
+                    ])?;
+                }
+            }
+        }
+        tx.commit()?;
+        Ok(rows)
+    }
+
+    pub fn store_regions(&mut self, query: &Query, data: &RegionData) -> Result<usize, Error> {
+        let tx = self.conn.transaction()?;
+        let mut rows = 0;
+        {
+            let mut stmt = tx.prepare_cached(
+                "INSERT INTO regions (keyword, geo, date, region, region_name, value) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                 ON CONFLICT (keyword, geo, date, region) DO UPDATE SET
+                     region_name = excluded.region_name, value = excluded.value",
+            )?;
+            for entry in &data.entries {
+                for (item, value) in query.items().iter().zip(entry.values()) {
+                    rows += stmt.execute(params![
+                        item.keyword(),
+                        query.geo_of(item).unwrap_or_default(),
+                        end_date(item.time().end()),
+                        entry.geo_code,
+                        entry.geo_name,
+                        value.as_f32().map(f64::from),
+                    ])?;
+                }
+            }
+        }
+        tx.commit()?;
+        Ok(rows)
+    }
+
+    /// Stores the related queries of the query's first comparison item, which is the one Google
+    /// returns them for.
+    pub fn store_related_queries(&mut self, query: &Query, related: &RelatedQueries) -> Result<usize, Error> {
+        let item = match query.items().first() {
+            Some(item) => item,
+            None => return Ok(0),
+        };
+        let date = end_date(item.time().end());
+        let tx = self.conn.transaction()?;
+        let mut rows = 0;
+        {
+            let mut stmt = tx.prepare_cached(
+                "INSERT INTO related_queries (keyword, geo, date, list, query, value, breakout)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                 ON CONFLICT (keyword, geo, date, list, query) DO UPDATE SET
+                     value = excluded.value, breakout = excluded.breakout",
+            )?;
+            let geo = query.geo_of(item).unwrap_or_default();
+            for top in &related.top {
+                rows += stmt.execute(params![item.keyword(), geo, date, "top", top.query, top.value, false])?;
+            }
+            for rising in &related.rising {
+                let (value, breakout) = match rising.value {
+                    RisingValue::Percent(percent) => (Some(percent), false),
+                    RisingValue::Breakout => (None, true),
+                };
+                rows += stmt.execute(params![
+                    item.keyword(),
+                    geo,
+                    date,
+                    "rising",
+                    rising.query,
+                    value,
+                    breakout
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(rows)
+    }
+
+    /// Date of the most recent complete time series point stored for `keyword` in `geo`.
+    pub fn last_complete(&self, keyword: &str, geo: Option<&str>) -> Result<Option<DateTime<Utc>>, Error> {
+        let date: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT MAX(date) FROM time_series WHERE keyword = ?1 AND geo = ?2 AND partial = 0",
+                params![keyword, geo.unwrap_or_default()],
+                |row| row.get(0),
+            )
+            .optional()?
+            .flatten();
+        Ok(date.and_then(|date| Utc.datetime_from_str(&date, DATE_FORMAT).ok()))
+    }
+}
+
+fn end_date(end: Date<Utc>) -> String {
+    end.and_hms(0, 0, 0).format(DATE_FORMAT).to_string()
+}
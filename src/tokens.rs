@@ -0,0 +1,104 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::hash::Fnv;
+use crate::{Error, WidgetDescriptor};
+
+/// Keeps the widgets returned by explore on disk, so that processes that only live for a single call
+/// (like the CLI) can skip the explore request when they repeat a query. Entries are keyed by the
+/// query, locale, timezone and base URL, and are used for `ttl` after they were fetched. Google
+/// doesn't publish how long tokens stay valid, a few minutes is a safe choice.
+#[derive(Debug, Clone)]
+pub struct TokenCache {
+    dir: PathBuf,
+    ttl: chrono::Duration,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    fetched: i64,
+    widgets: Vec<WidgetDescriptor>,
+}
+
+impl TokenCache {
+    pub fn new<P: Into<PathBuf>>(dir: P, ttl: Duration) -> TokenCache {
+        TokenCache {
+            dir: dir.into(),
+            ttl: chrono::Duration::from_std(ttl).unwrap_or_else(|_| chrono::Duration::max_value()),
+        }
+    }
+
+    /// A cache in the `google-trends` directory of the platform cache dir (`$XDG_CACHE_HOME` or
+    /// `~/.cache`, `~/Library/Caches` on macOS, `%LOCALAPPDATA%` on Windows), `None` if it can't be
+    /// determined from the environment.
+    pub fn in_cache_dir(ttl: Duration) -> Option<TokenCache> {
+        Some(TokenCache::new(platform_cache_dir()?.join("google-trends"), ttl))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Removes every cached entry.
+    pub fn clear(&self) -> Result<(), Error> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn key(parts: &[&str]) -> String {
+        let mut hasher = Fnv::new();
+        for part in parts {
+            hasher.str(part);
+        }
+        format!("{:016x}", hasher.finish())
+    }
+
+    pub(crate) fn get(&self, key: &str, now: DateTime<Utc>) -> Option<Vec<WidgetDescriptor>> {
+        let entry: Entry = serde_json::from_slice(&fs::read(self.path(key)).ok()?).ok()?;
+        if now - Utc.timestamp(entry.fetched, 0) < self.ttl {
+            Some(entry.widgets)
+        } else {
+            None
+        }
+    }
+
+    /// Failing to write only means the next process pays for explore again, so errors are ignored.
+    pub(crate) fn put(&self, key: &str, widgets: &[WidgetDescriptor], now: DateTime<Utc>) {
+        let entry = Entry {
+            fetched: now.timestamp(),
+            widgets: widgets.to_vec(),
+        };
+        if let (Ok(json), Ok(())) = (serde_json::to_vec(&entry), fs::create_dir_all(&self.dir)) {
+            // written aside and renamed, so a concurrent reader never sees half a file
+            let temp = self.dir.join(format!("{}.{}.tmp", key, std::process::id()));
+            if fs::write(&temp, json).is_ok() && fs::rename(&temp, self.path(key)).is_err() {
+                let _ = fs::remove_file(&temp);
+            }
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+}
+
+fn platform_cache_dir() -> Option<PathBuf> {
+    let var = |name: &str| {
+        std::env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    };
+    if cfg!(windows) {
+        var("LOCALAPPDATA")
+    } else if cfg!(target_os = "macos") {
+        var("HOME").map(|home| home.join("Library").join("Caches"))
+    } else {
+        var("XDG_CACHE_HOME").or_else(|| var("HOME").map(|home| home.join(".cache")))
+    }
+}
@@ -1,13 +1,127 @@
 use std::borrow::Cow;
+use std::fmt;
 
-use chrono::{Date, DateTime, TimeZone};
-use serde::{Deserialize, Serialize, Serializer};
+use chrono::{Date, DateTime, Datelike, TimeZone};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::clock::{Clock, SystemClock};
+use crate::keywords;
 
 #[derive(Debug)]
 pub enum Error {
     JsonError(serde_json::Error),
     RequestError(reqwest::Error),
+    IoError(std::io::Error),
     UnexpectedResponse(String),
+    /// A response with a status the client doesn't handle. `body` is cut to its first kilobyte or so.
+    UnexpectedStatus {
+        status: u16,
+        retry_after: Option<String>,
+        content_type: Option<String>,
+        body: String,
+    },
+    Timeout,
+    ResponseTooLarge(usize),
+    InvalidQuery(String),
+    InvalidConfig(String),
+    /// The deadline of a whole call ran out, see `TimeSeriesOptions::deadline`.
+    DeadlineExceeded,
+    SchemaDrift(Vec<String>),
+    AllSessionsBenched,
+    /// The circuit breaker tripped on repeated blocking, no requests are sent before `until`. An `until`
+    /// in the past means another request is probing whether the block has lifted.
+    CircuitOpen {
+        until: DateTime<chrono::offset::Utc>,
+    },
+    Cancelled,
+    RetryNotPossible,
+    RateLimited {
+        cookie: Option<String>,
+        /// How long Google asked to wait before trying again, when it said so.
+        retry_after: Option<std::time::Duration>,
+    },
+    UnsupportedResolution {
+        geo: Option<String>,
+        resolution: Resolution,
+        suggestion: Resolution,
+    },
+    #[cfg(feature = "plot")]
+    PlotError(String),
+    #[cfg(feature = "sqlite")]
+    SqliteError(rusqlite::Error),
+    #[cfg(feature = "simd-json")]
+    SimdJsonError(simd_json::Error),
+    #[cfg(feature = "kafka")]
+    KafkaError(rdkafka::error::KafkaError),
+    /// An error of the request described by the context, see `Error::context`.
+    Context(Box<Error>, ErrorContext),
+}
+
+impl Error {
+    /// The request an error came from, if it came from one.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Error::Context(_, context) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// The error without its context, for matching on what went wrong.
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Context(err, _) => err.root(),
+            err => err,
+        }
+    }
+
+    /// Whether the error is a network failure that may go away on its own: a connection that couldn't be
+    /// made (DNS included) or was reset, or a timeout. See `TrendsClientBuilder::retry_transient`.
+    pub fn is_transient(&self) -> bool {
+        match self.root() {
+            Error::Timeout => true,
+            #[cfg(not(target_arch = "wasm32"))]
+            Error::RequestError(err) => err.is_connect() || is_transient_io(err),
+            _ => false,
+        }
+    }
+
+    // cancellation isn't tied to a request, so it stays matchable as is
+    pub(crate) fn with_context(self, context: ErrorContext) -> Error {
+        match self {
+            Error::Context(..) | Error::Cancelled => self,
+            err => Error::Context(Box::new(err), context),
+        }
+    }
+}
+
+// hyper reports dropped connections as request errors, the cause is somewhere down the source chain
+#[cfg(not(target_arch = "wasm32"))]
+fn is_transient_io(err: &reqwest::Error) -> bool {
+    use std::io::ErrorKind;
+
+    let mut source = std::error::Error::source(err);
+    while let Some(err) = source {
+        if let Some(io) = err.downcast_ref::<std::io::Error>() {
+            return matches!(
+                io.kind(),
+                ErrorKind::ConnectionReset
+                    | ErrorKind::ConnectionAborted
+                    | ErrorKind::BrokenPipe
+                    | ErrorKind::UnexpectedEof
+                    | ErrorKind::TimedOut
+            );
+        }
+        source = err.source();
+    }
+    false
+}
+
+#[derive(Debug, Clone)]
+pub struct ErrorContext {
+    /// Unique within the process, made of a millisecond timestamp and a counter.
+    pub request_id: String,
+    pub operation: Operation,
+    pub keywords: usize,
 }
 
 impl From<serde_json::Error> for Error {
@@ -16,23 +130,76 @@ impl From<serde_json::Error> for Error {
     }
 }
 
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for Error {
+    fn from(err: rusqlite::Error) -> Self {
+        Error::SqliteError(err)
+    }
+}
+
+#[cfg(feature = "simd-json")]
+impl From<simd_json::Error> for Error {
+    fn from(err: simd_json::Error) -> Self {
+        Error::SimdJsonError(err)
+    }
+}
+
+#[cfg(feature = "kafka")]
+impl From<rdkafka::error::KafkaError> for Error {
+    fn from(err: rdkafka::error::KafkaError) -> Self {
+        Error::KafkaError(err)
+    }
+}
+
 impl From<reqwest::Error> for Error {
     fn from(err: reqwest::Error) -> Self {
-        Error::RequestError(err)
+        if err.is_timeout() {
+            Error::Timeout
+        } else {
+            Error::RequestError(err)
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::IoError(err)
     }
 }
 
 #[derive(Debug, Copy, Clone)]
 pub enum Resolution {
     Country,
+    Region,
     City,
     Dma,
 }
 
+impl Resolution {
+    /// Checks the resolution against the geo restrictions Google applies, metro areas only
+    /// exist in the US and worldwide queries can't be broken down below the country level.
+    pub fn check_supported(self, geo: Option<&str>) -> Result<(), Error> {
+        let suggestion = match (self, geo) {
+            (Resolution::Region, None) | (Resolution::Dma, None) => Some(Resolution::Country),
+            (Resolution::Dma, Some(geo)) if !geo.starts_with("US") => Some(Resolution::Region),
+            _ => None,
+        };
+        match suggestion {
+            Some(suggestion) => Err(Error::UnsupportedResolution {
+                geo: geo.map(str::to_owned),
+                resolution: self,
+                suggestion,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
 impl Serialize for Resolution {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let formatted = match self {
             Resolution::Country => "COUNTRY",
+            Resolution::Region => "REGION",
             Resolution::City => "CITY",
             Resolution::Dma => "DMA",
         };
@@ -40,6 +207,18 @@ impl Serialize for Resolution {
     }
 }
 
+impl<'de> Deserialize<'de> for Resolution {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match <Cow<'de, str>>::deserialize(deserializer)?.as_ref() {
+            "COUNTRY" => Ok(Resolution::Country),
+            "REGION" => Ok(Resolution::Region),
+            "CITY" => Ok(Resolution::City),
+            "DMA" => Ok(Resolution::Dma),
+            other => Err(de::Error::unknown_variant(other, &["COUNTRY", "REGION", "CITY", "DMA"])),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub enum Source {
     Search,
@@ -62,44 +241,319 @@ impl Serialize for Source {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
-#[repr(u32)]
+impl Source {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Source::Search => "web",
+            Source::Images => "images",
+            Source::News => "news",
+            Source::Videos => "youtube",
+            Source::Shopping => "shopping",
+        }
+    }
+
+    /// First day Google has data for, web search goes back to 2004, the other sources to 2008.
+    pub fn data_start(&self) -> Date<chrono::offset::Utc> {
+        match self {
+            Source::Search => chrono::Utc.ymd(2004, 1, 1),
+            _ => chrono::Utc.ymd(2008, 1, 1),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Source {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match <Cow<'de, str>>::deserialize(deserializer)?.as_ref() {
+            "" => Ok(Source::Search),
+            "images" => Ok(Source::Images),
+            "news" => Ok(Source::News),
+            "youtube" => Ok(Source::Videos),
+            "froogle" => Ok(Source::Shopping),
+            other => Err(de::Error::unknown_variant(
+                other,
+                &["", "images", "news", "youtube", "froogle"],
+            )),
+        }
+    }
+}
+
+/// A category to restrict queries to. `Custom` carries any other id of the category picker, see
+/// `TrendsClient::categories`. Up to 0.1 this enum was `#[repr(u32)]`, `category as u32` casts have
+/// to become `category.id()` or `u32::from(category)`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Category {
-    All = 0,
-    Entertainment = 3,
-    Electronics = 5,
-    Finance = 7,
-    Games = 8,
-    Home = 11,
-    Business = 12,
-    Internet = 13,
-    Society = 14,
-    News = 16,
-    Shopping = 18,
-    Law = 19,
-    Sports = 20,
-    Literature = 22,
-    RealEstate = 29,
-    Fitness = 44,
-    Health = 45,
-    Vehicles = 47,
-    Hobbies = 65,
-    Pets = 66,
-    Travel = 67,
-    Food = 71,
-    Science = 174,
-    Communities = 299,
-    Reference = 533,
-    Education = 958,
+    All,
+    Entertainment,
+    Electronics,
+    Finance,
+    Games,
+    Home,
+    Business,
+    Internet,
+    Society,
+    News,
+    Shopping,
+    Law,
+    Sports,
+    Literature,
+    RealEstate,
+    Fitness,
+    Health,
+    Vehicles,
+    Hobbies,
+    Pets,
+    Travel,
+    Food,
+    Science,
+    Communities,
+    Reference,
+    Education,
+    Custom(u32),
+}
+
+impl Category {
+    pub fn from_id(id: u32) -> Category {
+        match id {
+            0 => Category::All,
+            3 => Category::Entertainment,
+            5 => Category::Electronics,
+            7 => Category::Finance,
+            8 => Category::Games,
+            11 => Category::Home,
+            12 => Category::Business,
+            13 => Category::Internet,
+            14 => Category::Society,
+            16 => Category::News,
+            18 => Category::Shopping,
+            19 => Category::Law,
+            20 => Category::Sports,
+            22 => Category::Literature,
+            29 => Category::RealEstate,
+            44 => Category::Fitness,
+            45 => Category::Health,
+            47 => Category::Vehicles,
+            65 => Category::Hobbies,
+            66 => Category::Pets,
+            67 => Category::Travel,
+            71 => Category::Food,
+            174 => Category::Science,
+            299 => Category::Communities,
+            533 => Category::Reference,
+            958 => Category::Education,
+            id => Category::Custom(id),
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        match self {
+            Category::All => 0,
+            Category::Entertainment => 3,
+            Category::Electronics => 5,
+            Category::Finance => 7,
+            Category::Games => 8,
+            Category::Home => 11,
+            Category::Business => 12,
+            Category::Internet => 13,
+            Category::Society => 14,
+            Category::News => 16,
+            Category::Shopping => 18,
+            Category::Law => 19,
+            Category::Sports => 20,
+            Category::Literature => 22,
+            Category::RealEstate => 29,
+            Category::Fitness => 44,
+            Category::Health => 45,
+            Category::Vehicles => 47,
+            Category::Hobbies => 65,
+            Category::Pets => 66,
+            Category::Travel => 67,
+            Category::Food => 71,
+            Category::Science => 174,
+            Category::Communities => 299,
+            Category::Reference => 533,
+            Category::Education => 958,
+            Category::Custom(id) => *id,
+        }
+    }
+}
+
+impl From<u32> for Category {
+    fn from(id: u32) -> Self {
+        Category::from_id(id)
+    }
+}
+
+impl From<Category> for u32 {
+    fn from(category: Category) -> Self {
+        category.id()
+    }
 }
 
 impl Serialize for Category {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_u32(*self as u32)
+        serializer.serialize_u32(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for Category {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(Category::from_id)
+    }
+}
+
+/// Unset source and category fall back to the client defaults, see `TrendsClientBuilder::default_source`.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TimeSeriesOptions {
+    source: Option<Source>,
+    category: Option<Category>,
+    deadline: Option<std::time::Duration>,
+}
+
+impl TimeSeriesOptions {
+    pub fn source(mut self, source: Source) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn category(mut self, category: Category) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Time allowed for the whole call, the explore request and the widget request after it included.
+    /// Running out of it fails with `Error::DeadlineExceeded`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn deadline(mut self, deadline: std::time::Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    pub fn get_source(&self) -> Source {
+        self.source_or(Source::Search)
+    }
+
+    pub fn get_category(&self) -> Category {
+        self.category_or(Category::All)
+    }
+
+    pub fn get_deadline(&self) -> Option<std::time::Duration> {
+        self.deadline
+    }
+
+    pub(crate) fn source_or(&self, default: Source) -> Source {
+        self.source.unwrap_or(default)
+    }
+
+    pub(crate) fn category_or(&self, default: Category) -> Category {
+        self.category.unwrap_or(default)
     }
 }
 
+/// Unset source and category fall back to the client defaults, like in `TimeSeriesOptions`.
 #[derive(Debug, Copy, Clone)]
+pub struct RegionOptions {
+    resolution: Resolution,
+    source: Option<Source>,
+    category: Option<Category>,
+    include_low_volume_regions: bool,
+    deadline: Option<std::time::Duration>,
+    order: RegionOrder,
+}
+
+/// How the client orders the entries of region results, see `RegionOptions::order`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RegionOrder {
+    /// As Google returned them, which is not always the same order for the same data.
+    Google,
+    /// By name, ties by geo code. The default.
+    GeoName,
+    GeoCode,
+    /// By total value, highest first and ties by geo code.
+    ValueDesc,
+}
+
+impl RegionOptions {
+    pub fn resolution(mut self, resolution: Resolution) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    pub fn source(mut self, source: Source) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn category(mut self, category: Category) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Like `TimeSeriesOptions::deadline`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn deadline(mut self, deadline: std::time::Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    pub fn include_low_volume_regions(mut self, include: bool) -> Self {
+        self.include_low_volume_regions = include;
+        self
+    }
+
+    /// Sorts the entries of the results, by name by default so that the same data always comes back in
+    /// the same order. `RegionOrder::Google` keeps them as they were returned.
+    pub fn order(mut self, order: RegionOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub fn get_resolution(&self) -> Resolution {
+        self.resolution
+    }
+
+    pub fn get_source(&self) -> Source {
+        self.source_or(Source::Search)
+    }
+
+    pub fn get_category(&self) -> Category {
+        self.category_or(Category::All)
+    }
+
+    pub fn get_deadline(&self) -> Option<std::time::Duration> {
+        self.deadline
+    }
+
+    pub fn get_include_low_volume_regions(&self) -> bool {
+        self.include_low_volume_regions
+    }
+
+    pub fn get_order(&self) -> RegionOrder {
+        self.order
+    }
+
+    pub(crate) fn source_or(&self, default: Source) -> Source {
+        self.source.unwrap_or(default)
+    }
+
+    pub(crate) fn category_or(&self, default: Category) -> Category {
+        self.category.unwrap_or(default)
+    }
+}
+
+impl Default for RegionOptions {
+    fn default() -> Self {
+        RegionOptions {
+            resolution: Resolution::Country,
+            source: None,
+            category: None,
+            include_low_volume_regions: false,
+            deadline: None,
+            order: RegionOrder::GeoName,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum SearchType {
     TimeSeries,
     Region,
@@ -107,18 +561,135 @@ pub enum SearchType {
     RelatedQueries,
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
+impl SearchType {
+    /// Every widget of the explore page, in the order of `TrendsReport`.
+    pub const ALL: [SearchType; 4] = [
+        SearchType::TimeSeries,
+        SearchType::Region,
+        SearchType::RelatedTopics,
+        SearchType::RelatedQueries,
+    ];
+
+    pub fn widget_id(&self) -> &'static str {
+        match self {
+            SearchType::TimeSeries => "TIMESERIES",
+            SearchType::Region => "GEO_MAP",
+            SearchType::RelatedTopics => "RELATED_TOPICS",
+            SearchType::RelatedQueries => "RELATED_QUERIES",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WidgetDescriptor {
+    pub id: String,
+    pub token: Option<String>,
+    pub request: Option<serde_json::Value>,
+    pub title: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    /// Presentation fields such as `helpDialog` or `bullets`, they differ between widget types.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operation {
+    Explore,
+    Widget(SearchType),
+    CustomWidget(&'static str),
+    RawWidget,
+    DailyTrends,
+    RealtimeTrends,
+    Autocomplete,
+    BatchExecute,
+    Ping,
+}
+
+impl Operation {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Operation::Explore => "explore",
+            Operation::Widget(SearchType::TimeSeries) => "timeseries",
+            Operation::Widget(SearchType::Region) => "region",
+            Operation::Widget(SearchType::RelatedTopics) => "related_topics",
+            Operation::Widget(SearchType::RelatedQueries) => "related_queries",
+            Operation::CustomWidget(id) => *id,
+            Operation::RawWidget => "raw_widget",
+            Operation::DailyTrends => "daily_trends",
+            Operation::RealtimeTrends => "realtime_trends",
+            Operation::Autocomplete => "autocomplete",
+            Operation::BatchExecute => "batchexecute",
+            Operation::Ping => "ping",
+        }
+    }
+}
+
+/// The outcome of `TrendsClient::ping`.
+#[derive(Debug)]
+pub enum PingStatus {
+    Ok {
+        latency: std::time::Duration,
+    },
+    RateLimited {
+        retry_after: Option<std::time::Duration>,
+    },
+    /// Google refused the request (403), or the client's circuit breaker is open.
+    Blocked,
+    /// Any other status, e.g. a consent redirect page or a server error.
+    Unexpected(u16),
+    /// The request didn't get a response: DNS, connection, TLS or timeout.
+    NetworkError(Error),
+}
+
+impl PingStatus {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, PingStatus::Ok { .. })
+    }
+}
+
+/// A set of comparison items. A geo set on the query applies to every item without a geo of its
+/// own, items that do have one must lie within it.
+#[derive(Debug, Clone)]
 pub struct Query<'a> {
     comparison_item: Vec<QueryItem<'a>>,
+    geo: Option<Cow<'a, str>>,
     category: Category,
     property: Source,
 }
 
+impl Serialize for Query<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Explore<'q> {
+            comparison_item: Vec<QueryItem<'q>>,
+            category: Category,
+            property: Source,
+        }
+
+        let items = self
+            .comparison_item
+            .iter()
+            .map(|item| QueryItem {
+                geo: item.geo.clone().or_else(|| self.geo.clone()),
+                ..item.clone()
+            })
+            .collect();
+        Explore {
+            comparison_item: items,
+            category: self.category,
+            property: self.property,
+        }
+        .serialize(serializer)
+    }
+}
+
 impl<'a> Query<'a> {
     pub fn new(items: Vec<QueryItem>) -> Query {
         Query {
             comparison_item: items,
+            geo: None,
             category: Category::All,
             property: Source::Search,
         }
@@ -128,9 +699,276 @@ impl<'a> Query<'a> {
         Query::new(vec![QueryItem::by_keyword(keyword, time)])
     }
 
+    /// Compares one keyword across regions, the labeled series are named after the geo codes.
+    pub fn by_keyword_across_geos<S: Into<Cow<'a, str>>>(keyword: S, geos: &[&'a str], time: Timeframe) -> Self {
+        let keyword = keyword.into();
+        let items = geos
+            .iter()
+            .map(|geo| QueryItem::by_keyword_with_geo(keyword.clone(), Cow::Borrowed(*geo), time.clone()))
+            .collect();
+        Query::new(items)
+    }
+
+    /// Splits `keywords` into queries of at most `chunk_size` keywords each, in order, the last one taking
+    /// what is left. `chunk_size` must be between 1 and 5. Every query is scaled on its own, see
+    /// `chunked_with_anchor` to bring them onto one scale.
+    pub fn chunked<I, S>(keywords: I, chunk_size: usize, time: Timeframe) -> Result<Vec<Self>, Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'a, str>>,
+    {
+        if chunk_size == 0 || chunk_size > Self::MAX_ITEMS {
+            return Err(Error::InvalidQuery(format!(
+                "Chunk size must be between 1 and {}, got {}",
+                Self::MAX_ITEMS,
+                chunk_size
+            )));
+        }
+        let items: Vec<QueryItem<'a>> = keywords
+            .into_iter()
+            .map(|keyword| QueryItem::by_keyword(keyword, time.clone()))
+            .collect();
+        Ok(items
+            .chunks(chunk_size)
+            .map(|chunk| Query::new(chunk.to_vec()))
+            .collect())
+    }
+
+    /// Like `chunked`, with `anchor` as the first item of every query and up to four of `keywords` after
+    /// it. The anchor is what `LabeledTimeSeries::joined_on` rescales the results of the chunks by, pick
+    /// a term that is searched steadily over `time`. Keywords equal to the anchor are left out.
+    pub fn chunked_with_anchor<A, I, S>(anchor: A, keywords: I, time: Timeframe) -> Vec<Self>
+    where
+        A: Into<Cow<'a, str>>,
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'a, str>>,
+    {
+        let anchor = QueryItem::by_keyword(anchor, time.clone());
+        let items: Vec<QueryItem<'a>> = keywords
+            .into_iter()
+            .map(|keyword| -> Cow<'a, str> { keyword.into() })
+            .filter(|keyword| *keyword != anchor.keyword)
+            .map(|keyword| QueryItem::by_keyword(keyword, time.clone()))
+            .collect();
+        items
+            .chunks(Self::MAX_ITEMS - 1)
+            .map(|chunk| {
+                let mut items = vec![anchor.clone()];
+                items.extend_from_slice(chunk);
+                Query::new(items)
+            })
+            .collect()
+    }
+
+    /// Compares one keyword across several timeframes, e.g. this December against the last one.
+    /// Such queries are answered as a multirange timeline, see `TrendsClient::interest_by_time_multirange`.
+    pub fn by_keyword_across_timeframes<S: Into<Cow<'a, str>>>(keyword: S, timeframes: &[Timeframe]) -> Self {
+        let keyword = keyword.into();
+        let items = timeframes
+            .iter()
+            .map(|time| QueryItem::by_keyword(keyword.clone(), time.clone()))
+            .collect();
+        Query::new(items)
+    }
+
+    /// Whether the items cover different timeframes, which Google only answers with a multirange timeline.
+    pub fn is_multirange(&self) -> bool {
+        self.comparison_item.windows(2).any(|pair| pair[0].time != pair[1].time)
+    }
+
+    /// Labels for the comparison items: the keywords, or when a keyword repeats, whatever tells the
+    /// items apart (their geo, then their timeframe).
+    pub fn labels(&self) -> Vec<String> {
+        fn distinct<T: PartialEq>(values: &[T]) -> bool {
+            values
+                .iter()
+                .enumerate()
+                .all(|(index, value)| !values[..index].contains(value))
+        }
+
+        let keywords: Vec<&str> = self.comparison_item.iter().map(QueryItem::keyword).collect();
+        let geos: Vec<Option<&str>> = self.comparison_item.iter().map(|item| self.geo_of(item)).collect();
+        let times: Vec<String> = self.comparison_item.iter().map(|item| item.time.formatted()).collect();
+
+        if distinct(&keywords) {
+            keywords.into_iter().map(str::to_owned).collect()
+        } else if distinct(&geos) {
+            geos.into_iter()
+                .map(|geo| geo.unwrap_or("Worldwide").to_owned())
+                .collect()
+        } else if distinct(&times) {
+            times
+        } else {
+            keywords.into_iter().map(str::to_owned).collect()
+        }
+    }
+
     pub fn items(&self) -> &[QueryItem] {
         &self.comparison_item
     }
+
+    pub fn geo(&self) -> Option<&str> {
+        self.geo.as_deref()
+    }
+
+    /// Sets the query-level geo, see the type's documentation.
+    pub fn with_default_geo<S: Into<Cow<'a, str>>>(mut self, geo: S) -> Self {
+        self.geo = Some(geo.into());
+        self
+    }
+
+    /// The geo `item` is actually requested for, its own or the query's.
+    pub fn geo_of<'q>(&'q self, item: &'q QueryItem) -> Option<&'q str> {
+        item.geo().or_else(|| self.geo())
+    }
+
+    pub const MAX_ITEMS: usize = 5;
+
+    pub fn validate(&self) -> Result<(), Error> {
+        let fail = |reason: String| Err(Error::InvalidQuery(reason));
+
+        if self.comparison_item.is_empty() {
+            return fail("Query has no comparison items".to_owned());
+        }
+        if self.comparison_item.len() > Self::MAX_ITEMS {
+            return fail(format!(
+                "Query has {} comparison items, at most {} are allowed",
+                self.comparison_item.len(),
+                Self::MAX_ITEMS
+            ));
+        }
+        if let Some(geo) = self.geo() {
+            if !is_valid_geo(geo) {
+                return fail(format!("Invalid query geo code '{}'", geo));
+            }
+        }
+        for item in &self.comparison_item {
+            if let (Some(query_geo), Some(geo)) = (self.geo(), item.geo()) {
+                if geo != query_geo && !geo.starts_with(&format!("{}-", query_geo)) {
+                    return fail(format!(
+                        "Geo '{}' for '{}' lies outside of the query geo '{}'",
+                        geo, item.keyword, query_geo
+                    ));
+                }
+            }
+            if item.keyword.trim().is_empty() {
+                return fail("Query contains an empty keyword".to_owned());
+            }
+            if let Some(geo) = item.geo() {
+                if !is_valid_geo(geo) {
+                    return fail(format!("Invalid geo code '{}' for '{}'", geo, item.keyword));
+                }
+            }
+            if item.time.start > item.time.end {
+                return fail(format!(
+                    "Timeframe {} for '{}' ends before it starts",
+                    item.time.formatted(),
+                    item.keyword
+                ));
+            }
+        }
+
+        let first = &self.comparison_item[0].time;
+        if let Some(item) = self
+            .comparison_item
+            .iter()
+            .find(|item| item.time.length() != first.length())
+        {
+            return fail(format!(
+                "Timeframe {} for '{}' differs in length from {}, compared timeframes must be equally long",
+                item.time.formatted(),
+                item.keyword,
+                first.formatted()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Canonicalizes every keyword with `keywords::canonicalize` and drops the items that end up
+    /// empty or identical to an earlier one.
+    pub fn dedup_items(&mut self) {
+        let mut items: Vec<QueryItem<'a>> = Vec::with_capacity(self.comparison_item.len());
+        for item in self.comparison_item.drain(..) {
+            let keyword = keywords::canonicalize(&item.keyword);
+            let duplicate = items
+                .iter()
+                .any(|other| other.keyword == keyword && other.geo == item.geo && other.time == item.time);
+            if !keyword.is_empty() && !duplicate {
+                items.push(QueryItem {
+                    keyword: keyword.into(),
+                    ..item
+                });
+            }
+        }
+        self.comparison_item = items;
+    }
+
+    /// Rejects comparison items whose timeframe starts before `source` has any data.
+    pub fn check_source(&self, source: Source) -> Result<(), Error> {
+        let start = source.data_start();
+        match self.comparison_item.iter().find(|item| item.time.start < start) {
+            Some(item) => Err(Error::InvalidQuery(format!(
+                "Timeframe {} for '{}' starts before {} data is available ({})",
+                item.time.formatted(),
+                item.keyword,
+                source.name(),
+                start.format("%Y-%m-%d")
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn with_geo<S: Into<Cow<'a, str>>>(&self, geo: S) -> Query<'a> {
+        let geo = geo.into();
+        let items = self
+            .comparison_item
+            .iter()
+            .map(|item| QueryItem {
+                geo: Some(geo.clone()),
+                ..item.clone()
+            })
+            .collect();
+        Query {
+            comparison_item: items,
+            ..self.clone()
+        }
+    }
+
+    pub fn with_timeframe(&self, time: Timeframe) -> Query<'a> {
+        let items = self
+            .comparison_item
+            .iter()
+            .map(|item| QueryItem {
+                time: time.clone(),
+                ..item.clone()
+            })
+            .collect();
+        Query {
+            comparison_item: items,
+            ..self.clone()
+        }
+    }
+
+    /// Moves every timeframe to end today, keeping its length.
+    pub fn shifted_to_today(&self) -> Query<'a> {
+        self.shifted_to_today_at(&SystemClock)
+    }
+
+    /// Like `shifted_to_today`, with today being the current day of `clock`.
+    pub fn shifted_to_today_at(&self, clock: &dyn Clock) -> Query<'a> {
+        let items = self
+            .comparison_item
+            .iter()
+            .map(|item| QueryItem {
+                time: item.time.shifted_to_today_at(clock),
+                ..item.clone()
+            })
+            .collect();
+        Query {
+            comparison_item: items,
+            ..self.clone()
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -160,9 +998,32 @@ impl<'a> QueryItem<'a> {
     pub fn keyword(&self) -> &str {
         &self.keyword
     }
+
+    pub fn geo(&self) -> Option<&str> {
+        self.geo.as_deref()
+    }
+
+    pub fn time(&self) -> &Timeframe {
+        &self.time
+    }
 }
 
-#[derive(Debug, Clone)]
+/// Accepts ISO 3166 country codes optionally followed by up to two subdivision segments,
+/// e.g. "US", "US-CA" or "US-CA-807".
+fn is_valid_geo(geo: &str) -> bool {
+    let mut segments = geo.split('-');
+    let country = segments.next().unwrap_or_default();
+    let country_valid = country.len() == 2 && country.chars().all(|c| c.is_ascii_uppercase());
+    let rest: Vec<&str> = segments.collect();
+
+    country_valid
+        && rest.len() <= 2
+        && rest
+            .iter()
+            .all(|segment| (1..=3).contains(&segment.len()) && segment.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Timeframe {
     start: Date<chrono::offset::Utc>,
     end: Date<chrono::offset::Utc>,
@@ -174,33 +1035,247 @@ impl Timeframe {
     }
 
     pub fn default() -> Timeframe {
+        Timeframe::default_at(&SystemClock)
+    }
+
+    /// From 2014-01-01 until the current day of `clock`, like `default` for a monitor or test running on
+    /// a `MockClock`.
+    pub fn default_at(clock: &dyn Clock) -> Timeframe {
         Timeframe {
             start: chrono::Utc.ymd(2014, 1, 1),
-            end: chrono::Utc::now().date(),
+            end: clock.now().date(),
+        }
+    }
+
+    pub fn start(&self) -> Date<chrono::offset::Utc> {
+        self.start
+    }
+
+    pub fn end(&self) -> Date<chrono::offset::Utc> {
+        self.end
+    }
+
+    pub fn length(&self) -> chrono::Duration {
+        self.end - self.start
+    }
+
+    pub fn shifted_to_today(&self) -> Timeframe {
+        self.shifted_to_today_at(&SystemClock)
+    }
+
+    pub fn shifted_to_today_at(&self, clock: &dyn Clock) -> Timeframe {
+        let end = clock.now().date();
+        Timeframe {
+            start: end - self.length(),
+            end,
         }
     }
 
     pub fn formatted(&self) -> String {
         format!("{} {}", self.start.format("%Y-%m-%d"), self.end.format("%Y-%m-%d"))
     }
-}
 
-impl Serialize for Timeframe {
-    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_str(&self.formatted())
+    pub fn split(&self, granularity: Granularity) -> TimeframeSplit {
+        self.split_with_overlap(granularity, chrono::Duration::zero())
+    }
+
+    /// Splits the timeframe into consecutive windows that are short enough for Google to return data at
+    /// `granularity`. Every window after the first starts on a boundary of the granularity (Sunday for weeks,
+    /// the first day for months) and overlaps the previous one by at most `overlap`. Without overlap the
+    /// windows are disjoint and cover the whole timeframe.
+    pub fn split_with_overlap(&self, granularity: Granularity, overlap: chrono::Duration) -> TimeframeSplit {
+        TimeframeSplit {
+            next: Some(self.start),
+            end: self.end,
+            granularity,
+            overlap,
+        }
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Granularity {
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl Granularity {
+    /// The longest timeframe for which Google still returns data at this granularity.
+    pub fn max_window(&self) -> chrono::Duration {
+        match self {
+            Granularity::Hourly => chrono::Duration::days(7),
+            Granularity::Daily => chrono::Duration::days(269),
+            Granularity::Weekly => chrono::Duration::days(5 * 365),
+            Granularity::Monthly => chrono::Duration::days(100 * 365),
+        }
+    }
+
+    /// The shortest timeframe for which Google returns data at this granularity rather than a finer one.
+    pub fn min_window(&self) -> chrono::Duration {
+        let day = chrono::Duration::days(1);
+        match self {
+            Granularity::Hourly => day * 2,
+            Granularity::Daily => Granularity::Hourly.max_window() + day,
+            Granularity::Weekly => Granularity::Daily.max_window() + day,
+            Granularity::Monthly => Granularity::Weekly.max_window() + day,
+        }
+    }
+
+    // the granularity of buckets `step` apart, with some slack for DST and missing buckets
+    pub(crate) fn from_step(step: chrono::Duration) -> Granularity {
+        if step < chrono::Duration::hours(20) {
+            Granularity::Hourly
+        } else if step < chrono::Duration::days(4) {
+            Granularity::Daily
+        } else if step < chrono::Duration::days(20) {
+            Granularity::Weekly
+        } else {
+            Granularity::Monthly
+        }
+    }
+
+    pub fn align(&self, date: Date<chrono::offset::Utc>) -> Date<chrono::offset::Utc> {
+        match self {
+            Granularity::Hourly | Granularity::Daily => date,
+            Granularity::Weekly => date - chrono::Duration::days(date.weekday().num_days_from_sunday() as i64),
+            Granularity::Monthly => chrono::Utc.ymd(date.year(), date.month(), 1),
+        }
+    }
+
+    /// Like `align`, but moves to the first boundary on or after `date`.
+    pub fn align_forward(&self, date: Date<chrono::offset::Utc>) -> Date<chrono::offset::Utc> {
+        let aligned = self.align(date);
+        match self {
+            _ if aligned == date => date,
+            Granularity::Weekly => aligned + chrono::Duration::weeks(1),
+            Granularity::Monthly if date.month() == 12 => chrono::Utc.ymd(date.year() + 1, 1, 1),
+            Granularity::Monthly => chrono::Utc.ymd(date.year(), date.month() + 1, 1),
+            Granularity::Hourly | Granularity::Daily => date,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TimeframeSplit {
+    next: Option<Date<chrono::offset::Utc>>,
+    end: Date<chrono::offset::Utc>,
+    granularity: Granularity,
+    overlap: chrono::Duration,
+}
+
+impl Iterator for TimeframeSplit {
+    type Item = Timeframe;
+
+    fn next(&mut self) -> Option<Timeframe> {
+        let start = self.next.filter(|start| *start <= self.end)?;
+        let day = chrono::Duration::days(1);
+        let mut end = (start + self.granularity.max_window() - day).min(self.end);
+        if end < self.end {
+            // end right before a boundary, the next window can then start on one without a gap
+            let boundary = self.granularity.align(end + day);
+            if boundary > start {
+                end = boundary - day;
+            }
+        }
+
+        self.next = if end >= self.end {
+            None
+        } else {
+            let next = self.granularity.align_forward(end + day - self.overlap);
+            Some(next.max(start + day))
+        };
+        Some(Timeframe::new(start, end))
+    }
+}
+
+impl Serialize for Timeframe {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.formatted())
+    }
+}
+
+impl<'de> Deserialize<'de> for Timeframe {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let str = <Cow<'de, str>>::deserialize(deserializer)?;
+        let date = |part: &str| {
+            chrono::NaiveDate::parse_from_str(part, "%Y-%m-%d")
+                .map(|date| chrono::Utc.from_utc_date(&date))
+                .map_err(de::Error::custom)
+        };
+        match str.split_whitespace().collect::<Vec<_>>().as_slice() {
+            [start, end] => Ok(Timeframe::new(date(start)?, date(end)?)),
+            _ => Err(de::Error::custom(format!(
+                "expected \"YYYY-MM-DD YYYY-MM-DD\", got '{}'",
+                str
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "RawRegionEntry")]
 pub struct RegionEntry {
     pub coordinates: Option<Coordinates>,
     pub geo_code: String,
     pub geo_name: String,
-    pub value: Vec<u8>,
+    /// One value per keyword, combined from Google's `value`, `hasData` and `formattedValue`.
+    pub value: Vec<TrendValue>,
+    pub formatted_value: Vec<String>,
+    pub max_value_index: Option<usize>,
     pub has_data: Vec<bool>,
 }
 
+impl RegionEntry {
+    /// The sum of the rounded values, missing ones counting as zero.
+    pub fn total_value(&self) -> u32 {
+        self.value
+            .iter()
+            .filter_map(TrendValue::as_f32)
+            .map(|val| val.round().max(0.0) as u32)
+            .sum()
+    }
+
+    pub fn values(&self) -> Vec<TrendValue> {
+        self.value.clone()
+    }
+
+    /// The values the way Google sends them, see `TrendValue::as_raw`.
+    pub fn raw_values(&self) -> Vec<Option<u8>> {
+        self.value.iter().map(TrendValue::as_raw).collect()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawRegionEntry {
+    coordinates: Option<Coordinates>,
+    #[serde(default)]
+    geo_code: String,
+    geo_name: String,
+    value: Vec<u8>,
+    #[serde(default)]
+    formatted_value: Vec<String>,
+    max_value_index: Option<usize>,
+    #[serde(default)]
+    has_data: Vec<bool>,
+}
+
+impl From<RawRegionEntry> for RegionEntry {
+    fn from(raw: RawRegionEntry) -> Self {
+        RegionEntry {
+            value: TrendValue::from_raw(&raw.value, &raw.has_data, &raw.formatted_value),
+            coordinates: raw.coordinates,
+            geo_code: raw.geo_code,
+            geo_name: raw.geo_name,
+            formatted_value: raw.formatted_value,
+            max_value_index: raw.max_value_index,
+            has_data: raw.has_data,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Coordinates {
     pub lat: f64,
@@ -208,13 +1283,115 @@ pub struct Coordinates {
 }
 
 #[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(from = "RawTimeSeriesEntry")]
 pub struct TimeSeriesEntry {
-    #[serde(with = "trends_time_format")]
     pub time: DateTime<chrono::offset::Utc>,
     pub formatted_time: String,
-    pub value: Vec<u8>,
+    pub formatted_axis_time: Option<String>,
+    /// One value per keyword, like `RegionEntry::value`.
+    pub value: Vec<TrendValue>,
+    pub formatted_value: Vec<String>,
     pub has_data: Vec<bool>,
+    pub is_partial: Option<bool>,
+}
+
+impl TimeSeriesEntry {
+    pub fn values(&self) -> Vec<TrendValue> {
+        self.value.clone()
+    }
+
+    pub fn raw_values(&self) -> Vec<Option<u8>> {
+        self.value.iter().map(TrendValue::as_raw).collect()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawTimeSeriesEntry {
+    #[serde(with = "trends_time_format")]
+    time: DateTime<chrono::offset::Utc>,
+    formatted_time: String,
+    formatted_axis_time: Option<String>,
+    value: Vec<u8>,
+    #[serde(default)]
+    formatted_value: Vec<String>,
+    #[serde(default)]
+    has_data: Vec<bool>,
+    is_partial: Option<bool>,
+}
+
+impl From<RawTimeSeriesEntry> for TimeSeriesEntry {
+    fn from(raw: RawTimeSeriesEntry) -> Self {
+        TimeSeriesEntry {
+            value: TrendValue::from_raw(&raw.value, &raw.has_data, &raw.formatted_value),
+            time: raw.time,
+            formatted_time: raw.formatted_time,
+            formatted_axis_time: raw.formatted_axis_time,
+            formatted_value: raw.formatted_value,
+            has_data: raw.has_data,
+            is_partial: raw.is_partial,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TrendValue {
+    Missing,
+    LessThanOne,
+    Value(f32),
+}
+
+impl TrendValue {
+    pub(crate) fn from_raw<S: AsRef<str>>(values: &[u8], has_data: &[bool], formatted: &[S]) -> Vec<TrendValue> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                if !has_data.get(index).copied().unwrap_or(true) {
+                    TrendValue::Missing
+                } else if formatted.get(index).map_or(false, |str| str.as_ref() == "<1") {
+                    TrendValue::LessThanOne
+                } else {
+                    TrendValue::Value(*value as f32)
+                }
+            })
+            .collect()
+    }
+
+    /// Numeric value of the point, with `LessThanOne` treated as zero the way Google's `value` array does.
+    pub fn as_f32(&self) -> Option<f32> {
+        match self {
+            TrendValue::Missing => None,
+            TrendValue::LessThanOne => Some(0.0),
+            TrendValue::Value(value) => Some(*value),
+        }
+    }
+
+    pub fn is_missing(&self) -> bool {
+        matches!(self, TrendValue::Missing)
+    }
+
+    /// The value as a whole number in Google's `value` array: missing and `<1` points are 0, other values
+    /// are rounded. `None` for values that don't fit, which rescaled or normalized series can hold.
+    pub fn as_raw(&self) -> Option<u8> {
+        let value = self.as_f32().map_or(0.0, f32::round);
+        if (0.0..=255.0).contains(&value) {
+            Some(value as u8)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for TrendValue {
+    /// Like Google's `formattedValue`, empty for missing points.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrendValue::Missing => Ok(()),
+            TrendValue::LessThanOne => f.write_str("<1"),
+            TrendValue::Value(value) => write!(f, "{}", value),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -223,25 +1400,1869 @@ pub struct RegionData {
     pub entries: Vec<RegionEntry>,
 }
 
+impl RegionData {
+    /// Orders the regions by total value, highest first and ties by geo code.
+    pub fn sort_by_value_desc(&mut self) {
+        self.entries.sort_by(|a, b| {
+            b.total_value()
+                .cmp(&a.total_value())
+                .then_with(|| a.geo_code.cmp(&b.geo_code))
+        });
+    }
+
+    pub fn sort_by_geo_code(&mut self) {
+        self.entries.sort_by(|a, b| a.geo_code.cmp(&b.geo_code));
+    }
+
+    /// Orders the regions by name, ties by geo code.
+    pub fn sort_by_geo_name(&mut self) {
+        self.entries
+            .sort_by(|a, b| a.geo_name.cmp(&b.geo_name).then_with(|| a.geo_code.cmp(&b.geo_code)));
+    }
+
+    pub fn sort(&mut self, order: RegionOrder) {
+        match order {
+            RegionOrder::Google => {}
+            RegionOrder::GeoName => self.sort_by_geo_name(),
+            RegionOrder::GeoCode => self.sort_by_geo_code(),
+            RegionOrder::ValueDesc => self.sort_by_value_desc(),
+        }
+    }
+
+    /// The `n` regions with the highest total value (see `RegionEntry::total_value`), regions with the same
+    /// total in geo code order so that the cut doesn't depend on the order of the entries.
+    pub fn top_n(&self, n: usize) -> Vec<&RegionEntry> {
+        let mut entries: Vec<&RegionEntry> = self.entries.iter().collect();
+        entries.sort_by(|a, b| {
+            b.total_value()
+                .cmp(&a.total_value())
+                .then_with(|| a.geo_code.cmp(&b.geo_code))
+        });
+        entries.truncate(n);
+        entries
+    }
+
+    /// Percentage (0 to 100) of regions whose total value is below that of `geo_code`, other regions
+    /// with the same value counting half so that tied regions share a rank. `None` for a region that
+    /// isn't in the data.
+    pub fn percentile_rank(&self, geo_code: &str) -> Option<f64> {
+        let value = self
+            .entries
+            .iter()
+            .find(|entry| entry.geo_code == geo_code)?
+            .total_value();
+        let (below, equal) =
+            self.entries
+                .iter()
+                .fold((0, 0), |(below, equal), entry| match entry.total_value().cmp(&value) {
+                    std::cmp::Ordering::Less => (below + 1, equal),
+                    std::cmp::Ordering::Equal => (below, equal + 1),
+                    std::cmp::Ordering::Greater => (below, equal),
+                });
+        Some((below as f64 + (equal - 1) as f64 / 2.0) / self.entries.len() as f64 * 100.0)
+    }
+
+    /// Keeps the regions with a total value of at least `min`.
+    pub fn filter_min_value(&self, min: u32) -> RegionData {
+        RegionData {
+            entries: self
+                .entries
+                .iter()
+                .filter(|entry| entry.total_value() >= min)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Divides every region's values by the denominator `denominator` returns for its geo code,
+    /// e.g. a population figure. Regions without a (non-zero) denominator end up with no values.
+    pub fn normalize_by<F: FnMut(&str) -> Option<f64>>(&self, mut denominator: F) -> NormalizedRegionData {
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let denominator = denominator(&entry.geo_code).filter(|value| *value != 0.0);
+                let values = entry
+                    .values()
+                    .iter()
+                    .map(|value| Some(f64::from(value.as_f32()?) / denominator?))
+                    .collect();
+                NormalizedRegionEntry {
+                    geo_code: entry.geo_code.clone(),
+                    geo_name: entry.geo_name.clone(),
+                    denominator,
+                    values,
+                }
+            })
+            .collect();
+        NormalizedRegionData { entries }
+    }
+
+    /// Compares an earlier pull (`self`) with a later one of the same query, matching regions by geo code.
+    pub fn diff(&self, newer: &RegionData, tolerance: f32) -> RegionDiff {
+        let find = |data: &'_ RegionData, code: &str| data.entries.iter().position(|entry| entry.geo_code == code);
+        let mut diff = RegionDiff::default();
+        for entry in &self.entries {
+            match find(newer, &entry.geo_code) {
+                Some(pos) => {
+                    let changes = value_changes(&entry.values(), &newer.entries[pos].values(), tolerance);
+                    diff.changed.extend(changes.map(|(index, before, after)| RegionChange {
+                        geo_code: entry.geo_code.clone(),
+                        index,
+                        before,
+                        after,
+                    }))
+                }
+                None => diff.removed.push(entry.clone()),
+            }
+        }
+        diff.added = newer
+            .entries
+            .iter()
+            .filter(|entry| find(self, &entry.geo_code).is_none())
+            .cloned()
+            .collect();
+        diff
+    }
+}
+
+/// Pairs of values that differ by more than `tolerance`, or where only one side has data.
+fn value_changes<'a>(
+    before: &'a [TrendValue],
+    after: &'a [TrendValue],
+    tolerance: f32,
+) -> impl Iterator<Item = (usize, TrendValue, TrendValue)> + 'a {
+    before
+        .iter()
+        .zip(after)
+        .enumerate()
+        .filter(move |(_, (before, after))| match (before.as_f32(), after.as_f32()) {
+            (Some(before), Some(after)) => (before - after).abs() > tolerance,
+            (None, None) => false,
+            _ => true,
+        })
+        .map(|(index, (before, after))| (index, *before, *after))
+}
+
+/// Changes between two pulls of the same region query, see `RegionData::diff`.
+#[derive(Debug, Clone, Default)]
+pub struct RegionDiff {
+    pub added: Vec<RegionEntry>,
+    pub removed: Vec<RegionEntry>,
+    pub changed: Vec<RegionChange>,
+}
+
+impl RegionDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RegionChange {
+    pub geo_code: String,
+    /// Position of the comparison item the value belongs to.
+    pub index: usize,
+    pub before: TrendValue,
+    pub after: TrendValue,
+}
+
+#[derive(Debug, Clone)]
+pub struct NormalizedRegionEntry {
+    pub geo_code: String,
+    pub geo_name: String,
+    pub denominator: Option<f64>,
+    pub values: Vec<Option<f64>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NormalizedRegionData {
+    pub entries: Vec<NormalizedRegionEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RankedQuery {
+    pub query: String,
+    pub value: u32,
+    #[serde(default)]
+    pub formatted_value: String,
+    pub has_data: Option<bool>,
+    pub link: Option<String>,
+}
+
+impl RankedQuery {
+    pub fn rising_value(&self) -> RisingValue {
+        RisingValue::parse(&self.formatted_value, self.value)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RisingValue {
+    Percent(u32),
+    Breakout,
+}
+
+impl RisingValue {
+    const BREAKOUT_THRESHOLD: u32 = 5000;
+
+    /// `formatted` is either a percentage like "+1,250%" or a localized "Breakout" label,
+    /// the raw value is used to recognise the latter when the label isn't in English.
+    fn parse(formatted: &str, value: u32) -> RisingValue {
+        if formatted.eq_ignore_ascii_case("breakout") {
+            return RisingValue::Breakout;
+        }
+        let digits: String = formatted.chars().filter(char::is_ascii_digit).collect();
+        match digits.parse() {
+            Ok(percent) => RisingValue::Percent(percent),
+            Err(_) if value >= Self::BREAKOUT_THRESHOLD => RisingValue::Breakout,
+            Err(_) => RisingValue::Percent(value),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RisingQuery {
+    pub query: String,
+    pub value: RisingValue,
+    pub link: Option<String>,
+}
+
+impl From<RankedQuery> for RisingQuery {
+    fn from(ranked: RankedQuery) -> Self {
+        RisingQuery {
+            value: ranked.rising_value(),
+            query: ranked.query,
+            link: ranked.link,
+        }
+    }
+}
+
+impl RisingValue {
+    fn rank(self) -> u32 {
+        match self {
+            RisingValue::Breakout => u32::MAX,
+            RisingValue::Percent(percent) => percent,
+        }
+    }
+}
+
+/// A rising query aggregated over several seed keywords, `seeds` lists the ones it was rising for.
+#[derive(Debug, Clone)]
+pub struct DigestEntry {
+    pub query: String,
+    pub value: RisingValue,
+    pub seeds: Vec<String>,
+}
+
+impl DigestEntry {
+    pub(crate) fn collect(results: Vec<(String, Vec<RisingQuery>)>) -> Vec<DigestEntry> {
+        let mut entries: Vec<DigestEntry> = vec![];
+        for (seed, rising) in results {
+            for query in rising {
+                let key = query.query.trim().to_lowercase();
+                match entries.iter_mut().find(|entry| entry.query.to_lowercase() == key) {
+                    Some(entry) => {
+                        if query.value.rank() > entry.value.rank() {
+                            entry.value = query.value;
+                        }
+                        if !entry.seeds.contains(&seed) {
+                            entry.seeds.push(seed.clone());
+                        }
+                    }
+                    None => entries.push(DigestEntry {
+                        query: query.query.trim().to_owned(),
+                        value: query.value,
+                        seeds: vec![seed.clone()],
+                    }),
+                }
+            }
+        }
+        entries.sort_by(|a, b| {
+            b.value
+                .rank()
+                .cmp(&a.value.rank())
+                .then_with(|| b.seeds.len().cmp(&a.seeds.len()))
+        });
+        entries
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RelatedQueries {
+    pub top: Vec<RankedQuery>,
+    pub rising: Vec<RisingQuery>,
+}
+
+impl RelatedQueries {
+    /// Orders both lists by value, highest (breakouts) first and ties alphabetically. Results of the client
+    /// already come in this order.
+    pub fn sort_by_value_desc(&mut self) {
+        self.top
+            .sort_by(|a, b| b.value.cmp(&a.value).then_with(|| a.query.cmp(&b.query)));
+        self.rising
+            .sort_by(|a, b| b.value.rank().cmp(&a.value.rank()).then_with(|| a.query.cmp(&b.query)));
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Topic {
+    pub mid: String,
+    pub title: String,
+    #[serde(rename = "type", default)]
+    pub kind: String,
+}
+
+/// A topic suggested for the same keyword in several locales, with its title in each of them.
+#[derive(Debug, Clone)]
+pub struct SharedTopic {
+    pub mid: String,
+    pub kind: String,
+    /// `(locale, title)` pairs in the order the locales were given.
+    pub titles: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RankedTopic {
+    pub topic: Topic,
+    pub value: u32,
+    #[serde(default)]
+    pub formatted_value: String,
+    pub has_data: Option<bool>,
+    pub link: Option<String>,
+}
+
+impl RankedTopic {
+    pub fn rising_value(&self) -> RisingValue {
+        RisingValue::parse(&self.formatted_value, self.value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RelatedTopics {
+    pub top: Vec<RankedTopic>,
+    pub rising: Vec<RankedTopic>,
+}
+
+impl RelatedTopics {
+    /// Orders both lists by value, highest first and ties by topic id. Results of the client already come
+    /// in this order.
+    pub fn sort_by_value_desc(&mut self) {
+        for list in [&mut self.top, &mut self.rising].iter_mut() {
+            list.sort_by(|a, b| b.value.cmp(&a.value).then_with(|| a.topic.mid.cmp(&b.topic.mid)));
+        }
+    }
+}
+
+/// Everything the explore page shows for a query.
+#[derive(Debug, Clone)]
+pub struct TrendsReport {
+    pub time_series: TimeSeriesData,
+    pub regions: RegionData,
+    pub related_topics: RelatedTopics,
+    pub related_queries: RelatedQueries,
+}
+
+/// A `TrendsReport` whose sections succeed or fail on their own, see `TrendsClient::full_report_partial`.
+#[derive(Debug)]
+pub struct PartialTrendsReport {
+    pub time_series: Result<TimeSeriesData, Error>,
+    pub regions: Result<RegionData, Error>,
+    pub related_topics: Result<RelatedTopics, Error>,
+    pub related_queries: Result<RelatedQueries, Error>,
+}
+
+impl PartialTrendsReport {
+    pub fn all_ok(&self) -> bool {
+        self.time_series.is_ok() && self.regions.is_ok() && self.related_topics.is_ok() && self.related_queries.is_ok()
+    }
+
+    /// The complete report, or the error of the first failed section.
+    pub fn into_report(self) -> Result<TrendsReport, Error> {
+        Ok(TrendsReport {
+            time_series: self.time_series?,
+            regions: self.regions?,
+            related_topics: self.related_topics?,
+            related_queries: self.related_queries?,
+        })
+    }
+}
+
+/// A day of trending searches, `date` is formatted as `YYYYMMDD`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrendingDay {
+    pub date: String,
+    #[serde(default)]
+    pub formatted_date: String,
+    pub trending_searches: Vec<TrendingSearch>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrendingSearch {
+    pub title: TrendingQuery,
+    #[serde(default)]
+    pub formatted_traffic: String,
+    #[serde(default)]
+    pub related_queries: Vec<TrendingQuery>,
+    pub image: Option<ArticleImage>,
+    #[serde(default)]
+    pub articles: Vec<Article>,
+    pub share_url: Option<String>,
+}
+
+/// A search on the Trending Now page, see `TrendsClient::trending_now`.
+#[derive(Debug, Clone)]
+pub struct TrendingNow {
+    pub title: String,
+    /// Lower bound of the search volume bucket, e.g. 20000 for "20K+".
+    pub search_volume: u64,
+    pub started: DateTime<chrono::offset::Utc>,
+    /// `None` while the search is still trending.
+    pub ended: Option<DateTime<chrono::offset::Utc>>,
+    pub related_queries: Vec<String>,
+}
+
+/// News article attached to a trending search. Google omits fields freely, hence the defaults;
+/// `title` and `snippet` may contain HTML entities.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Article {
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub source: String,
+    #[serde(default)]
+    pub snippet: String,
+    #[serde(default)]
+    pub time_ago: String,
+    pub image: Option<ArticleImage>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleImage {
+    pub image_url: Option<String>,
+    pub news_url: Option<String>,
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrendingQuery {
+    pub query: String,
+    pub explore_link: Option<String>,
+}
+
+/// A story of the realtime trends, see `TrendsClient::realtime_trends_stream`. The title joins the
+/// `entity_names` the story is about.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RealtimeStory {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub entity_names: Vec<String>,
+    #[serde(default)]
+    pub articles: Vec<RealtimeArticle>,
+    pub image: Option<RealtimeImage>,
+    pub share_url: Option<String>,
+    /// Ids of other stories this one stands for.
+    #[serde(default)]
+    pub ids_for_dedup: Vec<String>,
+}
+
+/// `time` is relative, like "2 hours ago".
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RealtimeArticle {
+    #[serde(default)]
+    pub article_title: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub source: String,
+    #[serde(default)]
+    pub time: String,
+    #[serde(default)]
+    pub snippet: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RealtimeImage {
+    pub img_url: Option<String>,
+    pub news_url: Option<String>,
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GeoNode {
+    pub entry: RegionEntry,
+    pub children: Vec<GeoNode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GeoTree {
+    pub nodes: Vec<GeoNode>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct TimeSeriesData {
     #[serde(rename = "timelineData")]
     pub entries: Vec<TimeSeriesEntry>,
+    #[serde(default)]
+    pub averages: Vec<u8>,
 }
 
-mod trends_time_format {
-    use serde::de::Error;
-    use serde::{self, Deserialize, Deserializer};
+impl TimeSeriesData {
+    /// Converts each bucket into percentage shares of the summed interest across keywords, missing and
+    /// `<1` values counting as zero. Buckets where every keyword is at zero get a share of zero for all of them.
+    pub fn share_of_voice(&self) -> Vec<ShareOfVoice> {
+        self.entries
+            .iter()
+            .map(|entry| {
+                let values: Vec<f64> = entry
+                    .value
+                    .iter()
+                    .map(|val| val.as_f32().map_or(0.0, f64::from))
+                    .collect();
+                let total: f64 = values.iter().sum();
+                let shares = values
+                    .iter()
+                    .map(|val| if total > 0.0 { val * 100.0 / total } else { 0.0 })
+                    .collect();
+                ShareOfVoice {
+                    time: entry.time,
+                    shares,
+                }
+            })
+            .collect()
+    }
 
-    pub fn deserialize<'de, D: Deserializer<'de>>(
-        deserializer: D,
-    ) -> Result<chrono::DateTime<chrono::offset::Utc>, D::Error>
-    where
-        D::Error: serde::de::Error,
-    {
-        let str = String::deserialize(deserializer)?;
-        let secs: i64 = str.parse().map_err(D::Error::custom)?;
-        let ndt = chrono::NaiveDateTime::from_timestamp(secs, 0);
-        Ok(chrono::DateTime::from_utc(ndt, chrono::offset::Utc))
+    /// Returns a time × keyword matrix, with missing points as NaN, along with the timestamp of every row.
+    #[cfg(feature = "ndarray")]
+    pub fn to_array2(&self) -> (ndarray::Array2<f64>, Vec<DateTime<chrono::offset::Utc>>) {
+        let values: Vec<Vec<TrendValue>> = self.entries.iter().map(TimeSeriesEntry::values).collect();
+        let columns = values.iter().map(Vec::len).max().unwrap_or(0);
+        let array = ndarray::Array2::from_shape_fn((values.len(), columns), |(row, col)| {
+            values[row]
+                .get(col)
+                .and_then(TrendValue::as_f32)
+                .map_or(f64::NAN, f64::from)
+        });
+        let index = self.entries.iter().map(|entry| entry.time).collect();
+        (array, index)
+    }
+
+    /// The bucket size Google picked for the timeframe, inferred from the median step between timestamps.
+    /// Steps under an hour, as returned for the shortest realtime windows, are reported as `Hourly`.
+    /// Returns `None` when there are fewer than two entries to tell from.
+    pub fn granularity(&self) -> Option<Granularity> {
+        self.median_step().map(Granularity::from_step)
+    }
+
+    /// Inserts the buckets missing between the returned ones, so the entries are evenly spaced at the
+    /// inferred granularity (calendar months for monthly data). The inserted entries are filled in
+    /// according to `fill`, interpolation only happens between neighbours that both have data.
+    /// Entries are expected in time order, nothing is inserted between out of order or repeated timestamps.
+    pub fn fill_gaps(&self, fill: GapFill) -> TimeSeriesData {
+        let (granularity, step) = match (self.granularity(), self.median_step()) {
+            (Some(granularity), Some(step)) if step > chrono::Duration::zero() => (granularity, step),
+            _ => return self.clone(),
+        };
+        let advance = |time: DateTime<chrono::offset::Utc>| match granularity {
+            Granularity::Monthly if time.month() == 12 => chrono::Utc.ymd(time.year() + 1, 1, 1).and_hms(0, 0, 0),
+            Granularity::Monthly => chrono::Utc.ymd(time.year(), time.month() + 1, 1).and_hms(0, 0, 0),
+            _ => time + step,
+        };
+
+        let mut entries = Vec::with_capacity(self.entries.len());
+        for pair in self.entries.windows(2) {
+            let (prev, next) = (&pair[0], &pair[1]);
+            entries.push(prev.clone());
+            if next.time <= prev.time {
+                continue;
+            }
+
+            let mut gap = vec![];
+            let mut time = advance(prev.time);
+            while next.time - time >= step / 2 {
+                gap.push(time);
+                time = advance(time);
+            }
+            let (from, to) = (prev.values(), next.values());
+            let intervals = gap.len() as f32 + 1.0;
+            for (index, time) in gap.into_iter().enumerate() {
+                let weight = (index as f32 + 1.0) / intervals;
+                let values: Vec<TrendValue> = (0..prev.value.len())
+                    .map(|column| match fill {
+                        GapFill::Missing => TrendValue::Missing,
+                        GapFill::Zero => TrendValue::Value(0.0),
+                        GapFill::Interpolate => match (
+                            from.get(column).and_then(TrendValue::as_f32),
+                            to.get(column).and_then(TrendValue::as_f32),
+                        ) {
+                            (Some(from), Some(to)) => TrendValue::Value((from + (to - from) * weight).round()),
+                            _ => TrendValue::Missing,
+                        },
+                    })
+                    .collect();
+                entries.push(TimeSeriesEntry {
+                    time,
+                    formatted_time: time.format("%b %-d, %Y").to_string(),
+                    formatted_axis_time: None,
+                    formatted_value: values.iter().map(TrendValue::to_string).collect(),
+                    has_data: values.iter().map(|value| !value.is_missing()).collect(),
+                    value: values,
+                    is_partial: None,
+                });
+            }
+        }
+        entries.extend(self.entries.last().cloned());
+
+        TimeSeriesData {
+            entries,
+            averages: self.averages.clone(),
+        }
+    }
+
+    /// Compares an earlier pull (`self`) with a later one of the same query, matching buckets by timestamp.
+    /// Google revises recent data and rescales whole series, so `changed` can cover much of the range.
+    pub fn diff(&self, newer: &TimeSeriesData, tolerance: f32) -> TimeSeriesDiff {
+        let find = |data: &'_ TimeSeriesData, time| data.entries.iter().position(|entry| entry.time == time);
+        let mut diff = TimeSeriesDiff::default();
+        for entry in &self.entries {
+            match find(newer, entry.time) {
+                Some(pos) => {
+                    let changes = value_changes(&entry.values(), &newer.entries[pos].values(), tolerance);
+                    diff.changed.extend(changes.map(|(index, before, after)| PointChange {
+                        time: entry.time,
+                        index,
+                        before,
+                        after,
+                    }))
+                }
+                None => diff.removed.push(entry.clone()),
+            }
+        }
+        diff.added = newer
+            .entries
+            .iter()
+            .filter(|entry| find(self, entry.time).is_none())
+            .cloned()
+            .collect();
+        diff
+    }
+
+    fn median_step(&self) -> Option<chrono::Duration> {
+        let mut steps: Vec<chrono::Duration> = self
+            .entries
+            .windows(2)
+            .map(|pair| pair[1].time - pair[0].time)
+            .collect();
+        steps.sort();
+        steps.get(steps.len() / 2).copied()
+    }
+
+    /// Combines the points of every ISO week (Monday to Sunday) or calendar month into one, e.g. for a
+    /// monthly report off weekly data. Weekly points fall into the period of their first day. Missing
+    /// points are skipped, `LessThanOne` counts as zero. The first and last periods may only be partly
+    /// covered by the data, see `AggregatedEntry::points`.
+    pub fn aggregate(&self, period: AggregationPeriod, aggregation: Aggregation) -> AggregatedTimeSeries {
+        // (period start, points, non-missing values per item)
+        let mut periods: Vec<(Date<chrono::offset::Utc>, usize, Vec<Vec<TrendValue>>)> = vec![];
+        for entry in &self.entries {
+            let start = period.start(entry.time.date());
+            if periods.last().map_or(true, |(last, _, _)| *last != start) {
+                periods.push((start, 0, vec![]));
+            }
+            if let Some((_, points, columns)) = periods.last_mut() {
+                *points += 1;
+                let values = entry.values();
+                columns.resize(columns.len().max(values.len()), vec![]);
+                for (column, value) in columns.iter_mut().zip(values) {
+                    if !value.is_missing() {
+                        column.push(value);
+                    }
+                }
+            }
+        }
+        let entries = periods
+            .into_iter()
+            .map(|(start, points, columns)| AggregatedEntry {
+                start,
+                values: columns.iter().map(|column| aggregation.apply(column)).collect(),
+                points,
+            })
+            .collect();
+        AggregatedTimeSeries { period, entries }
+    }
+
+    pub fn labeled(&self, query: &Query) -> LabeledTimeSeries {
+        let series = query
+            .labels()
+            .into_iter()
+            .enumerate()
+            .map(|(index, label)| LabeledSeries {
+                label,
+                points: self
+                    .entries
+                    .iter()
+                    .filter_map(|entry| {
+                        Some(SeriesPoint {
+                            time: entry.time,
+                            value: *entry.values().get(index)?,
+                        })
+                    })
+                    .collect(),
+            })
+            .collect();
+        LabeledTimeSeries { series }
+    }
+}
+
+/// Timeline of a query whose items cover different timeframes. Every row holds one point per item,
+/// each with its own timestamp, so the n-th rows line up by offset into the timeframes instead of by date.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MultirangeData {
+    #[serde(rename = "timelineData")]
+    pub entries: Vec<MultirangeEntry>,
+    #[serde(default)]
+    pub averages: Vec<u8>,
+}
+
+impl MultirangeData {
+    /// Splits the timeline into one series per item, each keeping the timestamps of its own timeframe.
+    pub fn labeled(&self, query: &Query) -> LabeledTimeSeries {
+        let series = query
+            .labels()
+            .into_iter()
+            .enumerate()
+            .map(|(index, label)| LabeledSeries {
+                label,
+                points: self
+                    .entries
+                    .iter()
+                    .filter_map(|entry| {
+                        let point = entry.column_data.get(index)?;
+                        Some(SeriesPoint {
+                            time: point.time,
+                            value: point.value,
+                        })
+                    })
+                    .collect(),
+            })
+            .collect();
+        LabeledTimeSeries { series }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultirangeEntry {
+    pub column_data: Vec<MultirangePoint>,
+    pub index: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "RawMultirangePoint")]
+pub struct MultirangePoint {
+    pub time: DateTime<chrono::offset::Utc>,
+    pub formatted_time: String,
+    pub value: TrendValue,
+    pub formatted_value: String,
+    pub has_data: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawMultirangePoint {
+    #[serde(with = "trends_time_format")]
+    time: DateTime<chrono::offset::Utc>,
+    formatted_time: String,
+    value: u8,
+    #[serde(default)]
+    formatted_value: String,
+    #[serde(default = "default_has_data")]
+    has_data: bool,
+}
+
+impl From<RawMultirangePoint> for MultirangePoint {
+    fn from(raw: RawMultirangePoint) -> Self {
+        let value = if !raw.has_data {
+            TrendValue::Missing
+        } else if raw.formatted_value == "<1" {
+            TrendValue::LessThanOne
+        } else {
+            TrendValue::Value(f32::from(raw.value))
+        };
+        MultirangePoint {
+            time: raw.time,
+            formatted_time: raw.formatted_time,
+            value,
+            formatted_value: raw.formatted_value,
+            has_data: raw.has_data,
+        }
+    }
+}
+
+fn default_has_data() -> bool {
+    true
+}
+
+/// Changes between two pulls of the same time series query, see `TimeSeriesData::diff`.
+#[derive(Debug, Clone, Default)]
+pub struct TimeSeriesDiff {
+    pub added: Vec<TimeSeriesEntry>,
+    pub removed: Vec<TimeSeriesEntry>,
+    pub changed: Vec<PointChange>,
+}
+
+impl TimeSeriesDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PointChange {
+    pub time: DateTime<chrono::offset::Utc>,
+    pub index: usize,
+    pub before: TrendValue,
+    pub after: TrendValue,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AggregationPeriod {
+    IsoWeek,
+    Month,
+}
+
+impl AggregationPeriod {
+    fn start(self, date: Date<chrono::offset::Utc>) -> Date<chrono::offset::Utc> {
+        match self {
+            AggregationPeriod::IsoWeek => date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64),
+            AggregationPeriod::Month => chrono::Utc.ymd(date.year(), date.month(), 1),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Aggregation {
+    Mean,
+    Max,
+    Sum,
+}
+
+impl Aggregation {
+    // `values` holds no missing points, a period with only `LessThanOne` stays that way
+    fn apply(self, values: &[TrendValue]) -> TrendValue {
+        if values.is_empty() {
+            return TrendValue::Missing;
+        }
+        if values.iter().all(|value| *value == TrendValue::LessThanOne) {
+            return TrendValue::LessThanOne;
+        }
+        let numbers = values.iter().filter_map(TrendValue::as_f32);
+        TrendValue::Value(match self {
+            Aggregation::Mean => numbers.sum::<f32>() / values.len() as f32,
+            Aggregation::Max => numbers.fold(0.0, f32::max),
+            Aggregation::Sum => numbers.sum(),
+        })
+    }
+}
+
+/// The result of `TimeSeriesData::aggregate`, one entry per period in time order.
+#[derive(Debug, Clone)]
+pub struct AggregatedTimeSeries {
+    pub period: AggregationPeriod,
+    pub entries: Vec<AggregatedEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AggregatedEntry {
+    /// The Monday of the week or the first of the month.
+    pub start: Date<chrono::offset::Utc>,
+    /// One value per comparison item.
+    pub values: Vec<TrendValue>,
+    /// How many points of the input fell into the period.
+    pub points: usize,
+}
+
+/// How `TimeSeriesData::fill_gaps` fills in the buckets it inserts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GapFill {
+    /// Marks the buckets as having no data, they read as `TrendValue::Missing`.
+    Missing,
+    Zero,
+    /// Linear interpolation between the neighbouring buckets, rounded to whole values.
+    Interpolate,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SeasonalWeek {
+    pub week: u32,
+    pub average: f64,
+}
+
+/// Average interest per ISO week of the year relative to each year's mean (100 is an average week).
+#[derive(Debug, Clone)]
+pub struct Seasonality {
+    pub curve: Vec<SeasonalWeek>,
+    pub peak_weeks: Vec<u32>,
+}
+
+impl Seasonality {
+    const PEAKS: usize = 5;
+
+    pub(crate) fn from_years(years: &[TimeSeriesData]) -> Seasonality {
+        let mut sums = [(0.0, 0usize); 53];
+        for data in years {
+            let values: Vec<(u32, f64)> = data
+                .entries
+                .iter()
+                .filter_map(|entry| {
+                    let value = entry.values().first()?.as_f32()?;
+                    Some((entry.time.iso_week().week(), f64::from(value)))
+                })
+                .collect();
+            let mean = values.iter().map(|(_, value)| value).sum::<f64>() / values.len().max(1) as f64;
+            if mean <= 0.0 {
+                continue;
+            }
+            for (week, value) in values {
+                let (sum, count) = &mut sums[(week - 1) as usize];
+                *sum += value * 100.0 / mean;
+                *count += 1;
+            }
+        }
+
+        let curve: Vec<SeasonalWeek> = sums
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, count))| *count > 0)
+            .map(|(index, (sum, count))| SeasonalWeek {
+                week: index as u32 + 1,
+                average: sum / *count as f64,
+            })
+            .collect();
+        let mut peaks = curve.clone();
+        peaks.sort_by(|a, b| b.average.partial_cmp(&a.average).unwrap_or(std::cmp::Ordering::Equal));
+        Seasonality {
+            peak_weeks: peaks.iter().take(Self::PEAKS).map(|week| week.week).collect(),
+            curve,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ShareOfVoice {
+    pub time: DateTime<chrono::offset::Utc>,
+    pub shares: Vec<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SeriesPoint {
+    pub time: DateTime<chrono::offset::Utc>,
+    pub value: TrendValue,
+}
+
+#[derive(Debug, Clone)]
+pub struct LabeledSeries {
+    pub label: String,
+    pub points: Vec<SeriesPoint>,
+}
+
+impl LabeledSeries {
+    /// Whether this series and `reference` both have data for at least one timestamp, which is what
+    /// `rescaled_to` scales by.
+    pub fn overlaps(&self, reference: &LabeledSeries) -> bool {
+        self.points
+            .iter()
+            .filter(|point| point.value.as_f32().is_some())
+            .any(|point| {
+                reference
+                    .points
+                    .iter()
+                    .any(|other| other.time == point.time && other.value.as_f32().is_some())
+            })
+    }
+
+    /// Scales the values so that the buckets this series shares with `reference` (by timestamp)
+    /// sum up to the same interest. Series without overlapping data are returned unchanged.
+    pub fn rescaled_to(&self, reference: &LabeledSeries) -> LabeledSeries {
+        self.scaled(self.ratio_to(reference))
+    }
+
+    fn ratio_to(&self, reference: &LabeledSeries) -> f32 {
+        let (own, other) = self
+            .points
+            .iter()
+            .filter_map(|point| {
+                let other = reference.points.iter().find(|other| other.time == point.time)?;
+                Some((point.value.as_f32()?, other.value.as_f32()?))
+            })
+            .fold((0.0, 0.0), |(a, b), (own, other)| (a + own, b + other));
+        if own > 0.0 {
+            other / own
+        } else {
+            1.0
+        }
+    }
+
+    fn scaled(&self, ratio: f32) -> LabeledSeries {
+        let points = self
+            .points
+            .iter()
+            .map(|point| SeriesPoint {
+                time: point.time,
+                value: match point.value {
+                    TrendValue::Value(value) => TrendValue::Value(value * ratio),
+                    value => value,
+                },
+            })
+            .collect();
+        LabeledSeries {
+            label: self.label.clone(),
+            points,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LabeledTimeSeries {
+    pub series: Vec<LabeledSeries>,
+}
+
+impl LabeledTimeSeries {
+    /// Puts all series on the timestamps of the first one, points the others don't have become `Missing`.
+    pub fn aligned(&self) -> LabeledTimeSeries {
+        let times: Vec<DateTime<chrono::offset::Utc>> = match self.series.first() {
+            Some(first) => first.points.iter().map(|point| point.time).collect(),
+            None => return self.clone(),
+        };
+        let series = self
+            .series
+            .iter()
+            .map(|series| LabeledSeries {
+                label: series.label.clone(),
+                points: times
+                    .iter()
+                    .map(|time| SeriesPoint {
+                        time: *time,
+                        value: series
+                            .points
+                            .iter()
+                            .find(|point| point.time == *time)
+                            .map_or(TrendValue::Missing, |point| point.value),
+                    })
+                    .collect(),
+            })
+            .collect();
+        LabeledTimeSeries { series }
+    }
+
+    /// Puts the results of `Query::chunked_with_anchor` on the scale of the first chunk: every chunk is
+    /// multiplied by the ratio its `anchor` series has to the anchor of the first one, as with
+    /// `LabeledSeries::rescaled_to`. The anchor comes once, first, followed by the other series in order.
+    /// Chunks without an anchor series fail with `Error::InvalidQuery`.
+    pub fn joined_on(anchor: &str, chunks: &[LabeledTimeSeries]) -> Result<LabeledTimeSeries, Error> {
+        let anchors = chunks
+            .iter()
+            .map(|chunk| {
+                chunk
+                    .get(anchor)
+                    .ok_or_else(|| Error::InvalidQuery(format!("A chunk has no series for the anchor '{}'", anchor)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let reference = match anchors.first() {
+            Some(reference) => *reference,
+            None => return Ok(LabeledTimeSeries { series: vec![] }),
+        };
+
+        let mut series = vec![reference.clone()];
+        for (chunk, own) in chunks.iter().zip(&anchors) {
+            let ratio = own.ratio_to(reference);
+            series.extend(
+                chunk
+                    .series
+                    .iter()
+                    .filter(|series| series.label != anchor)
+                    .map(|series| series.scaled(ratio)),
+            );
+        }
+        Ok(LabeledTimeSeries { series })
+    }
+
+    pub fn get(&self, label: &str) -> Option<&LabeledSeries> {
+        self.series.iter().find(|series| series.label == label)
+    }
+
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.series.iter().map(|series| series.label.as_str())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PeriodDelta {
+    /// Position of the bucket within both periods.
+    pub offset: usize,
+    /// Start of the bucket in the first period.
+    pub time: DateTime<chrono::offset::Utc>,
+    pub formatted_time: String,
+    /// Start of the bucket in the second period.
+    pub second_time: DateTime<chrono::offset::Utc>,
+    pub first: TrendValue,
+    pub second: TrendValue,
+    pub change: Option<f64>,
+}
+
+/// Comparison of one keyword over two timeframes of equal length, the n-th bucket of one period
+/// against the n-th of the other, with the change expressed in percent relative to the first period.
+#[derive(Debug, Clone)]
+pub struct PeriodComparison {
+    pub buckets: Vec<PeriodDelta>,
+    pub change: Option<f64>,
+}
+
+impl PeriodComparison {
+    pub(crate) fn new(data: &MultirangeData) -> Self {
+        let buckets: Vec<PeriodDelta> = data
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(offset, entry)| {
+                let (first, second) = (entry.column_data.get(0)?, entry.column_data.get(1)?);
+                Some(PeriodDelta {
+                    offset,
+                    time: first.time,
+                    formatted_time: first.formatted_time.clone(),
+                    second_time: second.time,
+                    first: first.value,
+                    second: second.value,
+                    change: percent_change(first.value.as_f32(), second.value.as_f32()),
+                })
+            })
+            .collect();
+
+        let (first, second) = buckets
+            .iter()
+            .filter_map(|bucket| Some((bucket.first.as_f32()?, bucket.second.as_f32()?)))
+            .fold((0.0, 0.0), |(a, b), (first, second)| (a + first, b + second));
+        PeriodComparison {
+            buckets,
+            change: percent_change(Some(first), Some(second)),
+        }
+    }
+}
+
+fn percent_change(first: Option<f32>, second: Option<f32>) -> Option<f64> {
+    let (first, second) = (f64::from(first?), f64::from(second?));
+    if first > 0.0 {
+        Some((second - first) * 100.0 / first)
+    } else {
+        None
+    }
+}
+
+pub(crate) mod trends_time_format {
+    use serde::de::Error;
+    use serde::{self, Deserialize, Deserializer};
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<chrono::DateTime<chrono::offset::Utc>, D::Error>
+    where
+        D::Error: serde::de::Error,
+    {
+        let str = String::deserialize(deserializer)?;
+        let secs: i64 = str.parse().map_err(D::Error::custom)?;
+        let ndt = chrono::NaiveDateTime::from_timestamp(secs, 0);
+        Ok(chrono::DateTime::from_utc(ndt, chrono::offset::Utc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    const GRANULARITIES: [Granularity; 4] = [
+        Granularity::Hourly,
+        Granularity::Daily,
+        Granularity::Weekly,
+        Granularity::Monthly,
+    ];
+
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    #[test]
+    fn raw_values_only_hold_whole_numbers_google_could_send() {
+        assert_eq!(TrendValue::Missing.as_raw(), Some(0));
+        assert_eq!(TrendValue::LessThanOne.as_raw(), Some(0));
+        assert_eq!(TrendValue::Value(41.6).as_raw(), Some(42));
+        assert_eq!(TrendValue::Value(255.4).as_raw(), Some(255));
+        assert_eq!(TrendValue::Value(312.0).as_raw(), None);
+        assert_eq!(TrendValue::Value(-3.0).as_raw(), None);
+        assert_eq!(TrendValue::Value(f32::NAN).as_raw(), None);
+
+        let entry = region("DE", "Germany", vec![TrendValue::Value(312.0), TrendValue::LessThanOne]);
+        assert_eq!(entry.raw_values(), [None, Some(0)]);
+        assert_eq!(entry.total_value(), 312);
+    }
+
+    #[test]
+    fn timeouts_are_transient_with_or_without_context() {
+        let context = ErrorContext {
+            request_id: "1-0".to_owned(),
+            operation: Operation::Autocomplete,
+            keywords: 1,
+        };
+        assert!(Error::Timeout.is_transient());
+        assert!(Error::Timeout.with_context(context.clone()).is_transient());
+        assert!(!Error::InvalidQuery("no items".to_owned())
+            .with_context(context)
+            .is_transient());
+        assert!(!Error::UnexpectedStatus {
+            status: 503,
+            retry_after: None,
+            content_type: None,
+            body: String::new(),
+        }
+        .is_transient());
+    }
+
+    #[test]
+    fn timeframes_end_on_the_day_of_the_clock() {
+        let clock = FixedClock(Utc.ymd(2021, 3, 1).and_hms(12, 0, 0));
+        assert_eq!(Timeframe::default_at(&clock).end(), Utc.ymd(2021, 3, 1));
+
+        let shifted = Timeframe::new(Utc.ymd(2020, 1, 1), Utc.ymd(2020, 1, 31)).shifted_to_today_at(&clock);
+        assert_eq!(shifted, Timeframe::new(Utc.ymd(2021, 1, 30), Utc.ymd(2021, 3, 1)));
+    }
+
+    fn timeframe() -> Timeframe {
+        Timeframe::new(Utc.ymd(2004, 3, 17), Utc.ymd(2021, 2, 10))
+    }
+
+    #[test]
+    fn split_without_overlap_is_disjoint_and_complete() {
+        let day = chrono::Duration::days(1);
+        for &granularity in &GRANULARITIES {
+            let windows: Vec<Timeframe> = timeframe().split(granularity).collect();
+
+            assert_eq!(windows.first().unwrap().start(), timeframe().start());
+            assert_eq!(windows.last().unwrap().end(), timeframe().end());
+            for window in &windows {
+                assert!(window.start() <= window.end());
+                assert!(window.end() - window.start() < granularity.max_window(), "{:?}", window);
+            }
+            for pair in windows.windows(2) {
+                assert_eq!(pair[1].start(), pair[0].end() + day, "{:?}", granularity);
+                assert_eq!(granularity.align(pair[1].start()), pair[1].start());
+            }
+        }
+    }
+
+    #[test]
+    fn split_overlap_stays_within_the_request() {
+        let overlap = chrono::Duration::days(30);
+        for &granularity in &[Granularity::Daily, Granularity::Weekly] {
+            let windows: Vec<Timeframe> = timeframe().split_with_overlap(granularity, overlap).collect();
+            assert_eq!(windows.last().unwrap().end(), timeframe().end());
+            for pair in windows.windows(2) {
+                let shared = pair[0].end() - pair[1].start() + chrono::Duration::days(1);
+                assert!(shared > chrono::Duration::zero() && shared <= overlap, "{:?}", pair);
+                assert_eq!(granularity.align(pair[1].start()), pair[1].start());
+            }
+        }
+    }
+
+    #[test]
+    fn align_forward_moves_to_the_next_boundary() {
+        let sunday = Utc.ymd(2021, 2, 7);
+        assert_eq!(Granularity::Weekly.align_forward(sunday), sunday);
+        assert_eq!(
+            Granularity::Weekly.align_forward(Utc.ymd(2021, 2, 8)),
+            Utc.ymd(2021, 2, 14)
+        );
+        assert_eq!(
+            Granularity::Monthly.align_forward(Utc.ymd(2020, 12, 2)),
+            Utc.ymd(2021, 1, 1)
+        );
+        assert_eq!(
+            Granularity::Monthly.align_forward(Utc.ymd(2021, 1, 1)),
+            Utc.ymd(2021, 1, 1)
+        );
+        assert_eq!(Granularity::Daily.align_forward(sunday), sunday);
+    }
+
+    fn entry(time: DateTime<Utc>, value: f32) -> TimeSeriesEntry {
+        entry_of(time, vec![TrendValue::Value(value)])
+    }
+
+    fn entry_of(time: DateTime<Utc>, values: Vec<TrendValue>) -> TimeSeriesEntry {
+        TimeSeriesEntry {
+            time,
+            formatted_time: String::new(),
+            formatted_axis_time: None,
+            formatted_value: values.iter().map(TrendValue::to_string).collect(),
+            has_data: values.iter().map(|value| !value.is_missing()).collect(),
+            value: values,
+            is_partial: None,
+        }
+    }
+
+    fn series(entries: Vec<TimeSeriesEntry>) -> TimeSeriesData {
+        TimeSeriesData {
+            entries,
+            averages: vec![],
+        }
+    }
+
+    fn times(data: &TimeSeriesData) -> Vec<DateTime<Utc>> {
+        data.entries.iter().map(|entry| entry.time).collect()
+    }
+
+    fn spaced(step: chrono::Duration, count: i32) -> TimeSeriesData {
+        let start = Utc.ymd(2021, 1, 3).and_hms(0, 0, 0);
+        series((0..count).map(|index| entry(start + step * index, 1.0)).collect())
+    }
+
+    #[test]
+    fn granularity_is_inferred_from_the_median_step() {
+        let hour = chrono::Duration::hours(1);
+        assert_eq!(spaced(hour, 24).granularity(), Some(Granularity::Hourly));
+        assert_eq!(
+            spaced(chrono::Duration::minutes(8), 24).granularity(),
+            Some(Granularity::Hourly)
+        );
+        assert_eq!(spaced(hour * 24, 30).granularity(), Some(Granularity::Daily));
+        assert_eq!(spaced(hour * 24 * 7, 52).granularity(), Some(Granularity::Weekly));
+        assert_eq!(spaced(hour * 24 * 30, 12).granularity(), Some(Granularity::Monthly));
+        assert_eq!(spaced(hour, 1).granularity(), None);
+    }
+
+    #[test]
+    fn granularity_ignores_a_single_gap() {
+        let day = |d| Utc.ymd(2021, 1, d).and_hms(0, 0, 0);
+        let data = series(vec![
+            entry(day(1), 1.0),
+            entry(day(2), 1.0),
+            entry(day(20), 1.0),
+            entry(day(21), 1.0),
+        ]);
+        assert_eq!(data.granularity(), Some(Granularity::Daily));
+    }
+
+    #[test]
+    fn fill_gaps_inserts_skipped_days() {
+        let day = |d| Utc.ymd(2021, 1, d).and_hms(0, 0, 0);
+        let data = series(vec![
+            entry(day(1), 10.0),
+            entry(day(2), 20.0),
+            entry(day(5), 50.0),
+            entry(day(6), 60.0),
+        ]);
+
+        let filled = data.fill_gaps(GapFill::Interpolate);
+        assert_eq!(times(&filled), (1..=6).map(day).collect::<Vec<_>>());
+        assert_eq!(filled.entries[2].value, vec![TrendValue::Value(30.0)]);
+        assert_eq!(filled.entries[3].value, vec![TrendValue::Value(40.0)]);
+
+        let filled = data.fill_gaps(GapFill::Missing);
+        assert_eq!(filled.entries[2].value, vec![TrendValue::Missing]);
+        assert_eq!(filled.entries[2].has_data, vec![false]);
+        assert_eq!(
+            data.fill_gaps(GapFill::Zero).entries[3].value,
+            vec![TrendValue::Value(0.0)]
+        );
+    }
+
+    #[test]
+    fn fill_gaps_steps_by_calendar_month_across_years() {
+        let month = |y, m| Utc.ymd(y, m, 1).and_hms(0, 0, 0);
+        let data = series(vec![
+            entry(month(2020, 10), 1.0),
+            entry(month(2020, 11), 1.0),
+            entry(month(2021, 2), 1.0),
+            entry(month(2021, 3), 1.0),
+        ]);
+
+        assert_eq!(
+            times(&data.fill_gaps(GapFill::Missing)),
+            vec![
+                month(2020, 10),
+                month(2020, 11),
+                month(2020, 12),
+                month(2021, 1),
+                month(2021, 2),
+                month(2021, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn fill_gaps_leaves_repeated_timestamps_alone() {
+        let time = Utc.ymd(2021, 1, 1).and_hms(0, 0, 0);
+        let data = series(vec![entry(time, 1.0), entry(time, 2.0), entry(time, 3.0)]);
+
+        assert_eq!(times(&data.fill_gaps(GapFill::Interpolate)), vec![time; 3]);
+    }
+
+    #[test]
+    fn fill_gaps_skips_out_of_order_entries() {
+        let day = |d| Utc.ymd(2021, 1, d).and_hms(0, 0, 0);
+        let data = series(vec![
+            entry(day(1), 1.0),
+            entry(day(2), 1.0),
+            entry(day(3), 1.0),
+            entry(day(1), 1.0),
+            entry(day(4), 1.0),
+        ]);
+
+        assert_eq!(
+            times(&data.fill_gaps(GapFill::Zero)),
+            vec![day(1), day(2), day(3), day(1), day(2), day(3), day(4)]
+        );
+    }
+
+    fn daily(from: Date<Utc>, days: i64) -> TimeSeriesData {
+        series(
+            (0..days)
+                .map(|day| entry((from + chrono::Duration::days(day)).and_hms(0, 0, 0), 1.0))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn iso_weeks_cross_the_year_boundary() {
+        // 2020 has 53 ISO weeks, the last one runs from Monday Dec 28 to Sunday Jan 3
+        let weeks = daily(Utc.ymd(2020, 12, 27), 9).aggregate(AggregationPeriod::IsoWeek, Aggregation::Sum);
+        let starts: Vec<Date<Utc>> = weeks.entries.iter().map(|entry| entry.start).collect();
+        assert_eq!(
+            starts,
+            [Utc.ymd(2020, 12, 21), Utc.ymd(2020, 12, 28), Utc.ymd(2021, 1, 4)]
+        );
+        assert_eq!(weeks.entries[1].start.iso_week().week(), 53);
+        assert_eq!(weeks.entries[2].start.iso_week().week(), 1);
+        assert_eq!(weeks.entries[2].start.iso_week().year(), 2021);
+
+        let points: Vec<usize> = weeks.entries.iter().map(|entry| entry.points).collect();
+        assert_eq!(points, [1, 7, 1]);
+        assert_eq!(weeks.entries[1].values, [TrendValue::Value(7.0)]);
+    }
+
+    #[test]
+    fn partly_covered_months_report_their_points() {
+        let months = daily(Utc.ymd(2021, 1, 1), 40).aggregate(AggregationPeriod::Month, Aggregation::Mean);
+        let entries: Vec<(Date<Utc>, usize)> = months.entries.iter().map(|entry| (entry.start, entry.points)).collect();
+        assert_eq!(entries, [(Utc.ymd(2021, 1, 1), 31), (Utc.ymd(2021, 2, 1), 9)]);
+        assert_eq!(months.entries[1].values, [TrendValue::Value(1.0)]);
+    }
+
+    #[test]
+    fn aggregation_skips_missing_values() {
+        let day = |d| Utc.ymd(2021, 1, d).and_hms(0, 0, 0);
+        let data = series(vec![
+            entry_of(
+                day(4),
+                vec![TrendValue::Value(10.0), TrendValue::LessThanOne, TrendValue::Missing],
+            ),
+            entry_of(
+                day(5),
+                vec![TrendValue::Missing, TrendValue::LessThanOne, TrendValue::Missing],
+            ),
+            entry_of(
+                day(6),
+                vec![TrendValue::Value(20.0), TrendValue::Missing, TrendValue::Missing],
+            ),
+        ]);
+
+        let mean = data.aggregate(AggregationPeriod::IsoWeek, Aggregation::Mean);
+        assert_eq!(mean.entries[0].points, 3);
+        assert_eq!(
+            mean.entries[0].values,
+            [TrendValue::Value(15.0), TrendValue::LessThanOne, TrendValue::Missing]
+        );
+
+        let max = data.aggregate(AggregationPeriod::IsoWeek, Aggregation::Max);
+        assert_eq!(max.entries[0].values[0], TrendValue::Value(20.0));
+        let sum = data.aggregate(AggregationPeriod::IsoWeek, Aggregation::Sum);
+        assert_eq!(sum.entries[0].values[0], TrendValue::Value(30.0));
+    }
+
+    fn region(geo_code: &str, geo_name: &str, values: Vec<TrendValue>) -> RegionEntry {
+        RegionEntry {
+            coordinates: None,
+            geo_code: geo_code.to_owned(),
+            geo_name: geo_name.to_owned(),
+            formatted_value: values.iter().map(TrendValue::to_string).collect(),
+            has_data: values.iter().map(|value| !value.is_missing()).collect(),
+            max_value_index: None,
+            value: values,
+        }
+    }
+
+    fn regions(entries: &[(&str, f32)]) -> RegionData {
+        RegionData {
+            entries: entries
+                .iter()
+                .map(|&(code, value)| region(code, code, vec![TrendValue::Value(value)]))
+                .collect(),
+        }
+    }
+
+    fn codes(entries: &[RegionEntry]) -> Vec<&str> {
+        entries.iter().map(|entry| entry.geo_code.as_str()).collect()
+    }
+
+    #[test]
+    fn region_diff_reports_added_removed_and_changed_regions() {
+        let before = regions(&[("DE", 50.0), ("NO", 100.0), ("SE", 30.0)]);
+        let after = regions(&[("NO", 100.0), ("SE", 40.0), ("FI", 20.0)]);
+
+        let diff = before.diff(&after, 0.0);
+        assert_eq!(codes(&diff.added), ["FI"]);
+        assert_eq!(codes(&diff.removed), ["DE"]);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].geo_code, "SE");
+        assert_eq!(diff.changed[0].index, 0);
+        assert_eq!(
+            (diff.changed[0].before, diff.changed[0].after),
+            (TrendValue::Value(30.0), TrendValue::Value(40.0))
+        );
+        assert!(before.diff(&before, 0.0).is_empty());
+    }
+
+    #[test]
+    fn region_diff_ignores_changes_within_the_tolerance() {
+        let before = RegionData {
+            entries: vec![region(
+                "DE",
+                "Germany",
+                vec![TrendValue::Value(50.0), TrendValue::Missing],
+            )],
+        };
+        let after = RegionData {
+            entries: vec![region(
+                "DE",
+                "Germany",
+                vec![TrendValue::Value(52.0), TrendValue::LessThanOne],
+            )],
+        };
+
+        assert!(before.diff(&after, 0.0).changed.iter().any(|change| change.index == 0));
+        let diff = before.diff(&after, 2.0);
+        // a value appearing counts as a change whatever the tolerance
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].index, 1);
+        assert_eq!(diff.changed[0].after, TrendValue::LessThanOne);
+    }
+
+    #[test]
+    fn time_series_diff_matches_points_by_time() {
+        let day = |d| Utc.ymd(2021, 1, d).and_hms(0, 0, 0);
+        let before = series(vec![entry(day(1), 10.0), entry(day(2), 20.0), entry(day(3), 30.0)]);
+        let after = series(vec![entry(day(2), 21.0), entry(day(3), 35.0), entry(day(4), 40.0)]);
+
+        let diff = before.diff(&after, 1.0);
+        assert_eq!(times(&series(diff.added.clone())), [day(4)]);
+        assert_eq!(times(&series(diff.removed.clone())), [day(1)]);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].time, day(3));
+        assert_eq!(
+            (diff.changed[0].before, diff.changed[0].after),
+            (TrendValue::Value(30.0), TrendValue::Value(35.0))
+        );
+
+        assert_eq!(before.diff(&after, 0.0).changed.len(), 2);
+        assert!(after.diff(&after, 0.0).is_empty());
+    }
+
+    #[test]
+    fn top_n_breaks_ties_by_geo_code() {
+        let mut data = regions(&[("SE", 50.0), ("NO", 100.0), ("FI", 10.0), ("DE", 50.0)]);
+        data.entries.push(region("IS", "Iceland", vec![TrendValue::Missing]));
+
+        let top: Vec<&str> = data.top_n(3).iter().map(|entry| entry.geo_code.as_str()).collect();
+        assert_eq!(top, ["NO", "DE", "SE"]);
+        // missing values count as zero
+        assert_eq!(data.top_n(10).last().unwrap().geo_code, "IS");
+        assert!(data.top_n(0).is_empty());
+    }
+
+    #[test]
+    fn percentile_rank_shares_ranks_between_ties() {
+        let mut data = regions(&[("SE", 50.0), ("NO", 100.0), ("FI", 10.0), ("DE", 50.0)]);
+        data.entries.push(region("IS", "Iceland", vec![TrendValue::Missing]));
+
+        assert_eq!(data.percentile_rank("NO"), Some(80.0));
+        assert_eq!(data.percentile_rank("DE"), Some(50.0));
+        assert_eq!(data.percentile_rank("SE"), data.percentile_rank("DE"));
+        assert_eq!(data.percentile_rank("IS"), Some(0.0));
+        assert_eq!(data.percentile_rank("XX"), None);
+        assert_eq!(RegionData { entries: vec![] }.percentile_rank("DE"), None);
+    }
+
+    #[test]
+    fn share_of_voice_treats_missing_values_as_zero() {
+        let day = |d| Utc.ymd(2021, 1, d).and_hms(0, 0, 0);
+        let data = series(vec![
+            entry_of(day(1), vec![TrendValue::Value(25.0), TrendValue::Value(75.0)]),
+            entry_of(day(2), vec![TrendValue::Value(0.0), TrendValue::Value(0.0)]),
+            entry_of(day(3), vec![TrendValue::Value(30.0), TrendValue::Missing]),
+            entry_of(day(4), vec![TrendValue::LessThanOne, TrendValue::Missing]),
+        ]);
+
+        let shares: Vec<Vec<f64>> = data.share_of_voice().into_iter().map(|share| share.shares).collect();
+        assert_eq!(
+            shares,
+            [vec![25.0, 75.0], vec![0.0, 0.0], vec![100.0, 0.0], vec![0.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn normalize_by_skips_regions_without_a_denominator() {
+        let mut data = regions(&[("DE", 50.0), ("NO", 100.0), ("SE", 30.0)]);
+        data.entries.push(region(
+            "FI",
+            "Finland",
+            vec![TrendValue::Missing, TrendValue::Value(10.0)],
+        ));
+
+        let normalized = data.normalize_by(|code| match code {
+            "DE" => Some(2.0),
+            "SE" => Some(0.0),
+            "FI" => Some(5.0),
+            _ => None,
+        });
+        let values: Vec<(&str, Option<f64>, Vec<Option<f64>>)> = normalized
+            .entries
+            .iter()
+            .map(|entry| (entry.geo_code.as_str(), entry.denominator, entry.values.clone()))
+            .collect();
+        assert_eq!(
+            values,
+            [
+                ("DE", Some(2.0), vec![Some(25.0)]),
+                ("NO", None, vec![None]),
+                ("SE", None, vec![None]),
+                ("FI", Some(5.0), vec![None, Some(2.0)]),
+            ]
+        );
+    }
+
+    fn invalid_query(query: &Query, reason: &str) {
+        match query.validate() {
+            Err(Error::InvalidQuery(message)) => assert!(message.contains(reason), "{}", message),
+            result => panic!("expected InvalidQuery({:?}), got {:?}", reason, result),
+        }
+    }
+
+    fn item(keyword: &str) -> QueryItem<'_> {
+        QueryItem::by_keyword(keyword, timeframe())
+    }
+
+    fn item_in<'a>(keyword: &'a str, geo: &'a str) -> QueryItem<'a> {
+        QueryItem::by_keyword_with_geo(keyword, geo, timeframe())
+    }
+
+    #[test]
+    fn validate_accepts_a_regular_query() {
+        let query = Query::new(vec![item_in("rust", "US-CA"), item_in("go", "US-NY-501")]).with_default_geo("US");
+        assert!(query.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_a_query_without_items() {
+        invalid_query(&Query::new(vec![]), "no comparison items");
+    }
+
+    #[test]
+    fn validate_rejects_more_than_five_items() {
+        let items = ["a", "b", "c", "d", "e", "f"]
+            .iter()
+            .map(|keyword| item(keyword))
+            .collect();
+        invalid_query(&Query::new(items), "6 comparison items");
+        let items = ["a", "b", "c", "d", "e"].iter().map(|keyword| item(keyword)).collect();
+        assert!(Query::new(items).validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_an_invalid_query_geo() {
+        invalid_query(
+            &Query::new(vec![item("rust")]).with_default_geo("usa"),
+            "Invalid query geo code 'usa'",
+        );
+    }
+
+    #[test]
+    fn validate_rejects_item_geos_outside_of_the_query_geo() {
+        let query = Query::new(vec![item_in("rust", "DE-BY")]).with_default_geo("US");
+        invalid_query(&query, "lies outside of the query geo");
+        // sharing a prefix isn't enough, the item has to be a subdivision
+        let query = Query::new(vec![item_in("rust", "USX")]).with_default_geo("US");
+        invalid_query(&query, "lies outside of the query geo");
+    }
+
+    #[test]
+    fn validate_rejects_empty_keywords() {
+        invalid_query(&Query::new(vec![item("rust"), item("  ")]), "empty keyword");
+    }
+
+    #[test]
+    fn validate_rejects_invalid_item_geos() {
+        invalid_query(
+            &Query::new(vec![item_in("rust", "US-CALIF")]),
+            "Invalid geo code 'US-CALIF'",
+        );
+        invalid_query(&Query::new(vec![item_in("rust", "us")]), "Invalid geo code 'us'");
+    }
+
+    #[test]
+    fn validate_rejects_timeframes_ending_before_they_start() {
+        let time = Timeframe::new(Utc.ymd(2021, 3, 1), Utc.ymd(2021, 1, 1));
+        invalid_query(
+            &Query::new(vec![QueryItem::by_keyword("rust", time)]),
+            "ends before it starts",
+        );
+    }
+
+    #[test]
+    fn validate_rejects_timeframes_of_different_lengths() {
+        let shorter = Timeframe::new(Utc.ymd(2021, 1, 1), Utc.ymd(2021, 2, 1));
+        let query = Query::new(vec![item("rust"), QueryItem::by_keyword("go", shorter)]);
+        invalid_query(&query, "differs in length");
+    }
+
+    fn keywords<'q>(queries: &'q [Query]) -> Vec<Vec<&'q str>> {
+        queries
+            .iter()
+            .map(|query| query.items().iter().map(QueryItem::keyword).collect())
+            .collect()
+    }
+
+    #[test]
+    fn chunked_rejects_chunk_sizes_outside_of_one_to_five() {
+        for size in &[0, 6] {
+            match Query::chunked(vec!["rust"], *size, timeframe()) {
+                Err(Error::InvalidQuery(message)) => assert!(message.contains("between 1 and 5"), "{}", message),
+                result => panic!("expected InvalidQuery, got {:?}", result),
+            }
+        }
+    }
+
+    #[test]
+    fn chunked_fills_queries_of_five() {
+        let names = ["a", "b", "c", "d", "e", "f", "g"];
+        let queries = Query::chunked(names.iter().copied(), 5, timeframe()).unwrap();
+        assert_eq!(keywords(&queries), [vec!["a", "b", "c", "d", "e"], vec!["f", "g"]]);
+        assert!(queries.iter().all(|query| query.validate().is_ok()));
+
+        let queries = Query::chunked(names[..5].iter().copied(), 5, timeframe()).unwrap();
+        assert_eq!(keywords(&queries), [vec!["a", "b", "c", "d", "e"]]);
+    }
+
+    #[test]
+    fn chunked_with_anchor_starts_every_query_with_the_anchor() {
+        let names = ["a", "b", "anchor", "c", "d", "e"];
+        let queries = Query::chunked_with_anchor("anchor", names.iter().copied(), timeframe());
+        assert_eq!(
+            keywords(&queries),
+            [vec!["anchor", "a", "b", "c", "d"], vec!["anchor", "e"]]
+        );
+        assert!(Query::chunked_with_anchor("anchor", vec!["anchor"], timeframe()).is_empty());
+    }
+
+    fn labeled(label: &str, values: &[f32]) -> LabeledSeries {
+        LabeledSeries {
+            label: label.to_owned(),
+            points: values
+                .iter()
+                .enumerate()
+                .map(|(index, value)| SeriesPoint {
+                    time: Utc.timestamp(index as i64 * 86400, 0),
+                    value: TrendValue::Value(*value),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn joined_on_rescales_chunks_by_their_anchor() {
+        let first = LabeledTimeSeries {
+            series: vec![labeled("anchor", &[10.0, 30.0]), labeled("a", &[100.0, 50.0])],
+        };
+        // the anchor is at half of its interest in the first chunk, everything doubles
+        let second = LabeledTimeSeries {
+            series: vec![labeled("anchor", &[5.0, 15.0]), labeled("b", &[100.0, 20.0])],
+        };
+        let joined = LabeledTimeSeries::joined_on("anchor", &[first, second]).unwrap();
+
+        assert_eq!(joined.labels().collect::<Vec<_>>(), ["anchor", "a", "b"]);
+        let values = |label: &str| -> Vec<Option<f32>> {
+            joined
+                .get(label)
+                .unwrap()
+                .points
+                .iter()
+                .map(|point| point.value.as_f32())
+                .collect()
+        };
+        assert_eq!(values("anchor"), [Some(10.0), Some(30.0)]);
+        assert_eq!(values("a"), [Some(100.0), Some(50.0)]);
+        assert_eq!(values("b"), [Some(200.0), Some(40.0)]);
+    }
+
+    #[test]
+    fn joined_on_fails_without_the_anchor() {
+        let chunk = LabeledTimeSeries {
+            series: vec![labeled("a", &[1.0])],
+        };
+        assert!(matches!(
+            LabeledTimeSeries::joined_on("anchor", &[chunk]),
+            Err(Error::InvalidQuery(_))
+        ));
+        assert!(LabeledTimeSeries::joined_on("anchor", &[]).unwrap().series.is_empty());
+    }
+
+    #[test]
+    fn period_comparison_pairs_buckets_by_offset() {
+        let point = |time: &str, value: u8| {
+            format!(
+                r#"{{"time":"{}","value":{},"formattedValue":"{}","formattedTime":"{}"}}"#,
+                time, value, value, time
+            )
+        };
+        let json = format!(
+            r#"{{"timelineData":[{{"columnData":[{},{}],"index":0}},{{"columnData":[{},{}],"index":1}}]}}"#,
+            point("1609459200", 50),
+            point("1640995200", 75),
+            point("1609545600", 0),
+            point("1641081600", 20),
+        );
+        let data: MultirangeData = serde_json::from_str(&json).unwrap();
+        let comparison = PeriodComparison::new(&data);
+
+        assert_eq!(comparison.buckets.len(), 2);
+        assert_eq!(comparison.buckets[0].offset, 0);
+        assert_eq!(comparison.buckets[0].time, Utc.timestamp(1609459200, 0));
+        assert_eq!(comparison.buckets[0].second_time, Utc.timestamp(1640995200, 0));
+        assert_eq!(comparison.buckets[0].change, Some(50.0));
+        assert_eq!(comparison.buckets[1].offset, 1);
+        assert_eq!(comparison.buckets[1].change, None);
     }
 }
@@ -0,0 +1,18 @@
+use serde::de::DeserializeOwned;
+
+use crate::Error;
+
+/// A widget of the explore page the crate doesn't model itself. `ID` is matched against the ids of
+/// the widgets in the explore response and `PATH` is the endpoint under `/trends/api/` the widget
+/// request is sent to, e.g. `widgetdata/multiline`.
+pub trait Widget {
+    const ID: &'static str;
+    const PATH: &'static str;
+
+    type Output: DeserializeOwned;
+
+    /// Adjusts the widget request returned by explore before it's sent.
+    fn prepare(&self, _request: &mut serde_json::Value) -> Result<(), Error> {
+        Ok(())
+    }
+}
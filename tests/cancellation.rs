@@ -0,0 +1,64 @@
+#![cfg(feature = "testing")]
+
+use std::sync::Arc;
+
+use chrono::{TimeZone, Utc};
+use futures_util::StreamExt;
+use google_trends::testing::Cassette;
+use google_trends::{CancellationToken, Error, TrendsClient};
+
+#[tokio::test]
+async fn cancelled_token_fails_every_request() {
+    let token = CancellationToken::new();
+    token.cancel();
+    // no cassette: a request that got past the token would hit the network
+    let client = TrendsClient::builder().cancellation(token).build().unwrap();
+
+    let result = client.daily_trends("US", Utc.ymd(2021, 3, 1)).await;
+    assert!(matches!(result, Err(Error::Cancelled)));
+}
+
+#[tokio::test]
+async fn daily_trends_range_keeps_partial_results() {
+    let token = CancellationToken::new();
+    let client = TrendsClient::builder()
+        .cassette(Arc::new(Cassette::bundled()))
+        .cancellation(token.clone())
+        .build()
+        .unwrap();
+
+    let days = client.daily_trends_range("US", Utc.ymd(2021, 3, 1), Utc.ymd(2021, 3, 3));
+    futures_util::pin_mut!(days);
+
+    let first = days.next().await.unwrap().unwrap();
+    assert_eq!(first.date, "20210301");
+    assert_eq!(first.trending_searches.len(), 2);
+
+    token.cancel();
+    assert!(matches!(days.next().await, Some(Err(Error::Cancelled))));
+    assert!(days.next().await.is_none());
+}
+
+#[cfg(feature = "monitor")]
+#[tokio::test]
+async fn monitor_stops_after_cancellation() {
+    use std::time::Duration;
+
+    use google_trends::monitor::TrendsMonitor;
+    use google_trends::testing::MockClock;
+    use google_trends::{Query, Timeframe};
+
+    let token = CancellationToken::new();
+    token.cancel();
+    let client = TrendsClient::builder().cancellation(token).build().unwrap();
+    let clock = Arc::new(MockClock::new(Utc.ymd(2021, 3, 1).and_hms(0, 0, 0)));
+    let monitor = TrendsMonitor::new(Arc::new(client)).sleeper(clock.clone());
+
+    let time = Timeframe::new(Utc.ymd(2021, 2, 1), Utc.ymd(2021, 3, 1));
+    let events = monitor.watch(Query::by_keyword("rust".to_owned(), time), Duration::from_secs(60));
+    futures_util::pin_mut!(events);
+
+    assert!(matches!(events.next().await, Some(Err(Error::Cancelled))));
+    assert!(events.next().await.is_none());
+    assert!(clock.sleeps().is_empty());
+}
@@ -0,0 +1,254 @@
+#![cfg(feature = "testing")]
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{TimeZone, Utc};
+use common::{response, Reply, Server};
+use google_trends::testing::MockClock;
+use google_trends::{Error, TrendsClient};
+
+const SUGGESTIONS: &str = r#")]}',
+{"default":{"topics":[{"mid":"/m/0dsbpg6","title":"Rust","type":"Programming language"}]}}"#;
+
+fn clock() -> Arc<MockClock> {
+    Arc::new(MockClock::new(Utc.ymd(2021, 3, 1).and_hms(0, 0, 0)))
+}
+
+#[tokio::test]
+async fn rate_limited_retries_wait_for_retry_after() {
+    let server = Server::start(vec![
+        response(
+            "429 Too Many Requests",
+            &[("retry-after", "7"), ("set-cookie", "NID=abc; Path=/")],
+            "",
+        ),
+        response("200 OK", &[], SUGGESTIONS),
+    ]);
+    let clock = clock();
+    let client = server
+        .builder()
+        .clock(clock.clone())
+        .rate_limited_backoff(Duration::from_secs(30), clock.clone())
+        .build()
+        .unwrap();
+
+    let topics = client.suggestions("rust").await.unwrap();
+    assert_eq!(topics[0].title, "Rust");
+    assert_eq!(clock.sleeps(), [Duration::from_secs(7)]);
+    assert_eq!(server.requests()[1].header("cookie"), Some("NID=abc"));
+}
+
+#[tokio::test]
+async fn rate_limited_retries_fall_back_to_the_backoff() {
+    let server = Server::start(vec![
+        response("429 Too Many Requests", &[], ""),
+        response("200 OK", &[], SUGGESTIONS),
+    ]);
+    let clock = clock();
+    let client = server
+        .builder()
+        .clock(clock.clone())
+        .rate_limited_backoff(Duration::from_secs(30), clock.clone())
+        .build()
+        .unwrap();
+
+    client.suggestions("rust").await.unwrap();
+    assert_eq!(clock.sleeps(), [Duration::from_secs(30)]);
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn rate_limited_responses_carry_the_cookie_and_retry_after() {
+    let server = Server::start(vec![response(
+        "429 Too Many Requests",
+        &[("retry-after", "7"), ("set-cookie", "NID=abc; Path=/")],
+        "",
+    )]);
+    let client = server.builder().retry_rate_limited(false).build().unwrap();
+
+    match client.suggestions("rust").await.unwrap_err().root() {
+        Error::RateLimited { cookie, retry_after } => {
+            assert_eq!(cookie.as_deref(), Some("NID=abc"));
+            assert_eq!(*retry_after, Some(Duration::from_secs(7)));
+        }
+        err => panic!("unexpected error: {:?}", err),
+    }
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn retry_after_is_read_as_an_http_date() {
+    let server = Server::start(vec![response(
+        "429 Too Many Requests",
+        &[("retry-after", "Mon, 01 Mar 2021 00:02:00 GMT")],
+        "",
+    )]);
+    let client = server
+        .builder()
+        .clock(clock())
+        .retry_rate_limited(false)
+        .build()
+        .unwrap();
+
+    match client.suggestions("rust").await.unwrap_err().root() {
+        Error::RateLimited { retry_after, .. } => assert_eq!(*retry_after, Some(Duration::from_secs(120))),
+        err => panic!("unexpected error: {:?}", err),
+    }
+}
+
+#[tokio::test]
+async fn unexpected_statuses_keep_headers_and_a_truncated_body() {
+    let body = "x".repeat(4096);
+    let server = Server::start(vec![response(
+        "503 Service Unavailable",
+        &[("content-type", "text/html; charset=UTF-8"), ("retry-after", "60")],
+        &body,
+    )]);
+    let client = server.builder().build().unwrap();
+
+    match client.suggestions("rust").await.unwrap_err().root() {
+        Error::UnexpectedStatus {
+            status,
+            retry_after,
+            content_type,
+            body,
+        } => {
+            assert_eq!(*status, 503);
+            assert_eq!(retry_after.as_deref(), Some("60"));
+            assert_eq!(content_type.as_deref(), Some("text/html; charset=UTF-8"));
+            assert_eq!(body.len(), 1024);
+        }
+        err => panic!("unexpected error: {:?}", err),
+    }
+}
+
+#[tokio::test]
+async fn any_success_status_is_accepted() {
+    let server = Server::start(vec![response("203 Non-Authoritative Information", &[], SUGGESTIONS)]);
+    let client = server.builder().build().unwrap();
+
+    assert_eq!(client.suggestions("rust").await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn consent_redirects_are_retried_with_consent_cookies() {
+    let server = Server::start(vec![
+        response(
+            "302 Found",
+            &[(
+                "location",
+                "http://consent.google.test/ml?continue=http://trends.google.test/",
+            )],
+            "",
+        ),
+        response(
+            "200 OK",
+            &[("content-type", "text/html")],
+            "<html>Before you continue</html>",
+        ),
+        response("200 OK", &[], SUGGESTIONS),
+        response("200 OK", &[], SUGGESTIONS),
+    ]);
+    let client = server.builder().build().unwrap();
+
+    assert_eq!(client.suggestions("rust").await.unwrap()[0].title, "Rust");
+    assert_eq!(client.suggestions("rust").await.unwrap()[0].title, "Rust");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 4);
+    assert_eq!(requests[0].header("cookie"), None);
+    assert!(requests[1].line.contains("consent.google.test"), "{}", requests[1].line);
+    // the retry and every request after it send the consent cookies
+    for request in &requests[2..] {
+        assert!(request
+            .header("cookie")
+            .map_or(false, |cookie| cookie.contains("SOCS=")));
+        assert!(request.line.contains("trends.google.test/trends/api/autocomplete/rust"));
+    }
+}
+
+#[tokio::test]
+async fn refused_connections_are_transient() {
+    // a port that was just free, nothing listens on it anymore
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let client = TrendsClient::builder()
+        .base_url(format!("http://127.0.0.1:{}", port))
+        .build()
+        .unwrap();
+
+    let err = client.suggestions("rust").await.unwrap_err();
+    assert!(err.is_transient(), "{:?}", err);
+}
+
+#[tokio::test]
+async fn reset_connections_are_transient() {
+    let server = Server::with_replies(vec![Reply::Reset]);
+    let client = server.builder().build().unwrap();
+
+    let err = client.suggestions("rust").await.unwrap_err();
+    assert!(err.is_transient(), "{:?}", err);
+}
+
+#[tokio::test]
+async fn transient_errors_are_retried_with_backoff() {
+    let server = Server::with_replies(vec![Reply::Reset, Reply::Send(response("200 OK", &[], SUGGESTIONS))]);
+    let clock = clock();
+    let client = server
+        .builder()
+        .retry_transient(2, Duration::from_secs(1), clock.clone())
+        .build()
+        .unwrap();
+
+    assert_eq!(client.suggestions("rust").await.unwrap().len(), 1);
+    assert_eq!(clock.sleeps(), [Duration::from_secs(1)]);
+}
+
+#[tokio::test]
+async fn client_errors_are_not_retried() {
+    let server = Server::start(vec![response("400 Bad Request", &[], "")]);
+    let clock = clock();
+    let client = server
+        .builder()
+        .retry_transient(2, Duration::from_secs(1), clock.clone())
+        .build()
+        .unwrap();
+
+    let err = client.suggestions("rust").await.unwrap_err();
+    assert!(matches!(err.root(), Error::UnexpectedStatus { status: 400, .. }));
+    assert!(clock.sleeps().is_empty());
+}
+
+#[tokio::test]
+async fn circuit_breaker_probes_after_the_cooldown() {
+    let server = Server::start(vec![
+        response("429 Too Many Requests", &[], ""),
+        response("200 OK", &[], SUGGESTIONS),
+        response("200 OK", &[], SUGGESTIONS),
+    ]);
+    let clock = clock();
+    let client = server
+        .builder()
+        .clock(clock.clone())
+        .retry_rate_limited(false)
+        .circuit_breaker(1, Duration::from_secs(60))
+        .build()
+        .unwrap();
+
+    let err = client.suggestions("rust").await.unwrap_err();
+    assert!(matches!(err.root(), Error::RateLimited { .. }));
+    let err = client.suggestions("rust").await.unwrap_err();
+    assert!(matches!(err.root(), Error::CircuitOpen { .. }));
+    assert_eq!(server.requests().len(), 1);
+
+    clock.advance(Duration::from_secs(60));
+    client.suggestions("rust").await.unwrap();
+    client.suggestions("rust").await.unwrap();
+    assert_eq!(server.requests().len(), 3);
+}
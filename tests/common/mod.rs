@@ -0,0 +1,131 @@
+// Not every test file uses every helper.
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use google_trends::{TrendsClient, TrendsClientBuilder};
+
+/// Requests go to this host through the proxy of `Server::builder`, so redirects to other made up
+/// hosts (e.g. `consent.google.test`) end up at the same server.
+pub const BASE_URL: &str = "http://trends.google.test";
+
+/// A response with the given status line (e.g. `"429 Too Many Requests"`) that closes its connection.
+pub fn response(status: &str, headers: &[(&str, &str)], body: &str) -> String {
+    let mut resp = format!(
+        "HTTP/1.1 {}\r\ncontent-length: {}\r\nconnection: close\r\n",
+        status,
+        body.len()
+    );
+    for (name, value) in headers {
+        resp.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    resp.push_str("\r\n");
+    resp.push_str(body);
+    resp
+}
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    /// e.g. `GET http://trends.google.test/trends/api/autocomplete/rust?hl=en-US&tz=0 HTTP/1.1`
+    pub line: String,
+    pub headers: Vec<(String, String)>,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+pub enum Reply {
+    Send(String),
+    /// Reads a byte of the request and closes the connection with the rest unread, which resets it.
+    Reset,
+}
+
+/// An HTTP proxy on localhost answering one connection per response, in order.
+pub struct Server {
+    addr: SocketAddr,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl Server {
+    pub fn start(responses: Vec<String>) -> Server {
+        Server::with_replies(responses.into_iter().map(Reply::Send).collect())
+    }
+
+    pub fn with_replies(replies: Vec<Reply>) -> Server {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for reply in replies {
+                let mut stream = match listener.accept() {
+                    Ok((stream, _)) => stream,
+                    Err(_) => return,
+                };
+                let response = match reply {
+                    Reply::Send(response) => response,
+                    Reply::Reset => {
+                        let _ = stream.read(&mut [0]);
+                        continue;
+                    }
+                };
+                let mut reader = BufReader::new(stream);
+                if let Some(request) = read_request(&mut reader) {
+                    recorded.lock().unwrap().push(request);
+                }
+                let _ = reader.get_mut().write_all(response.as_bytes());
+            }
+        });
+        Server { addr, requests }
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    pub fn builder(&self) -> TrendsClientBuilder {
+        let proxy = reqwest::Proxy::http(format!("http://{}", self.addr)).unwrap();
+        TrendsClient::builder().base_url(BASE_URL).proxy(proxy)
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn read_request<R: Read>(reader: &mut BufReader<R>) -> Option<Request> {
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut headers = vec![];
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).ok()?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_at(header.find(':')?);
+        headers.push((name.to_owned(), value[1..].trim().to_owned()));
+    }
+    let request = Request {
+        line: line.trim_end().to_owned(),
+        headers,
+    };
+    // the body is read so that closing the connection doesn't reset it
+    let length = request
+        .header("content-length")
+        .and_then(|len| len.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+    Some(request)
+}
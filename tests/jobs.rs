@@ -0,0 +1,153 @@
+#![cfg(all(feature = "testing", feature = "jobs"))]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{TimeZone, Utc};
+use google_trends::job::{JobCheckpoint, JobData, JobOptions, JobOutput, JobSpec};
+use google_trends::testing::{Cassette, MockClock};
+use google_trends::{Error, Timeframe, TrendsClient};
+
+fn client() -> TrendsClient {
+    client_from(Cassette::bundled())
+}
+
+fn client_from(cassette: Cassette) -> TrendsClient {
+    TrendsClient::builder()
+        .locale("en-US")
+        .cassette(Arc::new(cassette))
+        .build()
+        .unwrap()
+}
+
+// "rust" is in the recorded responses, "go" isn't and fails
+fn spec(dir: &std::path::Path) -> JobSpec {
+    JobSpec {
+        keywords: vec!["rust".to_owned(), "go".to_owned()],
+        geos: vec![],
+        timeframes: vec![Timeframe::new(Utc.ymd(2021, 1, 1), Utc.ymd(2021, 3, 1))],
+        category: None,
+        source: None,
+        data: vec![JobData::TimeSeries],
+        outputs: vec![JobOutput::Jsonl {
+            path: dir.join("trends.jsonl"),
+        }],
+        requests_per_minute: Some(60),
+    }
+}
+
+fn temp_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("google-trends-{}-{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[tokio::test]
+async fn jobs_pause_with_the_sleeper_and_resume_from_the_checkpoint() {
+    let dir = temp_dir("job");
+    let checkpoint = dir.join("checkpoint.json");
+    let clock = Arc::new(MockClock::new(Utc.ymd(2021, 3, 1).and_hms(0, 0, 0)));
+    let opts = JobOptions::default().sleeper(clock.clone()).checkpoint(&checkpoint);
+
+    let summary = client().run_job_with_opts(&spec(&dir), &opts, |_| {}).await.unwrap();
+    assert_eq!((summary.completed, summary.resumed, summary.failures.len()), (1, 0, 1));
+    assert_eq!(clock.sleeps(), [Duration::from_secs(2)]);
+
+    // the failure keeps the checkpoint around, the next run only retries "go"
+    let saved = JobCheckpoint::load(&checkpoint).unwrap();
+    assert_eq!(saved.completed.len(), 1);
+    assert_eq!(saved.completed[0].keyword, "rust");
+
+    let summary = client().run_job_with_opts(&spec(&dir), &opts, |_| {}).await.unwrap();
+    assert_eq!((summary.completed, summary.resumed, summary.failures.len()), (0, 1, 1));
+    assert_eq!(clock.sleeps().len(), 1);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn finished_jobs_remove_the_checkpoint() {
+    let dir = temp_dir("finished-job");
+    let checkpoint = dir.join("checkpoint.json");
+    let opts = JobOptions::default().checkpoint(&checkpoint);
+    let spec = JobSpec {
+        keywords: vec!["rust".to_owned()],
+        ..spec(&dir)
+    };
+
+    let summary = client().run_job_with_opts(&spec, &opts, |_| {}).await.unwrap();
+    assert_eq!((summary.completed, summary.failures.len()), (1, 0));
+    assert!(!checkpoint.exists());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn checkpoints_of_another_spec_are_refused() {
+    let dir = temp_dir("other-job");
+    let checkpoint = dir.join("checkpoint.json");
+    let clock = Arc::new(MockClock::new(Utc.ymd(2021, 3, 1).and_hms(0, 0, 0)));
+    let opts = JobOptions::default().sleeper(clock).checkpoint(&checkpoint);
+
+    let summary = client().run_job_with_opts(&spec(&dir), &opts, |_| {}).await.unwrap();
+    assert_eq!(summary.failures.len(), 1);
+
+    // a different pace is still the same job
+    let slower = JobSpec {
+        requests_per_minute: Some(30),
+        ..spec(&dir)
+    };
+    let summary = client().run_job_with_opts(&slower, &opts, |_| {}).await.unwrap();
+    assert_eq!(summary.resumed, 1);
+
+    let other = JobSpec {
+        keywords: vec!["rust".to_owned(), "zig".to_owned()],
+        ..spec(&dir)
+    };
+    let err = client().run_job_with_opts(&other, &opts, |_| {}).await.unwrap_err();
+    assert!(matches!(err, Error::InvalidConfig(_)), "{:?}", err);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn resumed_jobs_only_fetch_the_missing_data() {
+    let dir = temp_dir("partial-job");
+    let checkpoint = dir.join("checkpoint.json");
+    let opts = JobOptions::default().checkpoint(&checkpoint);
+    let spec = JobSpec {
+        keywords: vec!["rust".to_owned()],
+        data: vec![JobData::TimeSeries, JobData::Regions],
+        ..spec(&dir)
+    };
+
+    // without the region widget the regions fail after the time series were written
+    let without_regions: Vec<_> = Cassette::bundled()
+        .interactions()
+        .into_iter()
+        .filter(|interaction| !interaction.url.contains("/comparedgeo"))
+        .collect();
+
+    let summary = client_from(Cassette::replay_interactions(without_regions))
+        .run_job_with_opts(&spec, &opts, |_| {})
+        .await
+        .unwrap();
+    assert_eq!((summary.completed, summary.resumed, summary.failures.len()), (0, 0, 1));
+    let saved = JobCheckpoint::load(&checkpoint).unwrap();
+    let done: Vec<JobData> = saved.completed.iter().map(|task| task.data).collect();
+    assert_eq!(done, [JobData::TimeSeries]);
+
+    let summary = client().run_job_with_opts(&spec, &opts, |_| {}).await.unwrap();
+    assert_eq!((summary.completed, summary.resumed, summary.failures.len()), (1, 0, 0));
+    assert!(!checkpoint.exists());
+
+    let output = std::fs::read_to_string(dir.join("trends.jsonl")).unwrap();
+    let series = output.lines().filter(|line| line.contains("\"time\":")).count();
+    let regions = output.lines().filter(|line| line.contains("\"region\":")).count();
+    assert!(series > 0 && regions > 0);
+    // every point was written once
+    let rows: std::collections::HashSet<&str> = output.lines().collect();
+    assert_eq!(rows.len(), series + regions);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,415 @@
+#![cfg(feature = "testing")]
+
+use std::sync::Arc;
+
+use chrono::{TimeZone, Utc};
+use futures_util::TryStreamExt;
+use google_trends::testing::{Cassette, MockClock};
+use google_trends::*;
+
+// Every endpoint the client talks to, answered from the bundled fixtures.
+fn builder() -> TrendsClientBuilder {
+    TrendsClient::builder()
+        .locale("en-US")
+        .cassette(Arc::new(Cassette::bundled()))
+}
+
+fn client() -> TrendsClient {
+    builder().build().unwrap()
+}
+
+fn query() -> Query<'static> {
+    let time = Timeframe::new(Utc.ymd(2021, 1, 1), Utc.ymd(2021, 3, 1));
+    Query::by_keyword("rust".to_owned(), time)
+}
+
+#[tokio::test]
+async fn explore_widgets() {
+    let widgets = client().available_widgets(&query()).await.unwrap();
+    assert_eq!(widgets.len(), 4);
+}
+
+#[tokio::test]
+async fn interest_by_time() {
+    let data = client()
+        .interest_by_time(&query(), Source::Search, Category::All)
+        .await
+        .unwrap();
+    let values: Vec<Option<u8>> = data.entries.iter().map(|entry| entry.value[0].as_raw()).collect();
+    assert_eq!(values, [Some(64), Some(71), Some(100), Some(0), Some(83)]);
+    assert_eq!(data.entries[3].value, [TrendValue::Missing]);
+    assert_eq!(data.entries[4].is_partial, Some(true));
+}
+
+#[tokio::test]
+async fn interest_by_time_multirange() {
+    let data = client()
+        .interest_by_time_multirange(&query(), &TimeSeriesOptions::default())
+        .await
+        .unwrap();
+    let values: Vec<Option<u8>> = data
+        .entries
+        .iter()
+        .map(|entry| entry.column_data[0].value.as_raw())
+        .collect();
+    assert_eq!(values, [Some(64), Some(71), Some(100)]);
+}
+
+fn weekly(label: &str, points: &[(i64, f32)]) -> LabeledTimeSeries {
+    let points = points
+        .iter()
+        .map(|&(time, value)| SeriesPoint {
+            time: Utc.timestamp(time, 0),
+            value: TrendValue::Value(value),
+        })
+        .collect();
+    LabeledTimeSeries {
+        series: vec![LabeledSeries {
+            label: label.to_owned(),
+            points,
+        }],
+    }
+}
+
+#[tokio::test]
+async fn interest_by_time_since() {
+    let clock = Arc::new(MockClock::new(Utc.ymd(2021, 3, 1).and_hms(0, 0, 0)));
+    let client = builder().clock(clock).build().unwrap();
+    let label = query().labels().remove(0);
+    let known = weekly(&label, &[(1609632000, 32.0), (1610236800, 35.5), (1610841600, 50.0)]);
+
+    // weekly data only comes back for windows longer than 269 days, so the request covers more than the gap
+    let fresh = client
+        .interest_by_time_since(
+            &query(),
+            Utc.timestamp(1610841600, 0),
+            &known,
+            Source::Search,
+            Category::All,
+        )
+        .await
+        .unwrap();
+    let points = &fresh.get(&label).unwrap().points;
+    let times: Vec<i64> = points.iter().map(|point| point.time.timestamp()).collect();
+    let values: Vec<TrendValue> = points.iter().map(|point| point.value).collect();
+    assert_eq!(times, [1611446400, 1612051200]);
+    assert_eq!(values, [TrendValue::Value(40.0), TrendValue::Value(30.0)]);
+}
+
+#[tokio::test]
+async fn interest_by_time_since_needs_an_overlap() {
+    let clock = Arc::new(MockClock::new(Utc.ymd(2021, 3, 1).and_hms(0, 0, 0)));
+    let client = builder().clock(clock).build().unwrap();
+    let label = query().labels().remove(0);
+    let known = weekly(&label, &[(1608422400, 50.0), (1609027200, 60.0)]);
+
+    let result = client
+        .interest_by_time_since(
+            &query(),
+            Utc.timestamp(1610841600, 0),
+            &known,
+            Source::Search,
+            Category::All,
+        )
+        .await;
+    assert!(matches!(result, Err(Error::UnexpectedResponse(_))));
+}
+
+#[tokio::test]
+async fn interest_by_time_since_rejects_gaps_beyond_the_granularity() {
+    let clock = Arc::new(MockClock::new(Utc.ymd(2021, 3, 1).and_hms(0, 0, 0)));
+    let client = builder().clock(clock).build().unwrap();
+    let label = query().labels().remove(0);
+    let daily = weekly(&label, &[(1577836800, 50.0), (1577923200, 60.0)]);
+
+    let result = client
+        .interest_by_time_since(
+            &query(),
+            Utc.timestamp(1577923200, 0),
+            &daily,
+            Source::Search,
+            Category::All,
+        )
+        .await;
+    assert!(matches!(result, Err(Error::InvalidQuery(_))));
+}
+
+#[tokio::test]
+async fn interest_by_region() {
+    let data = client()
+        .interest_by_region(&query(), Resolution::Country, Source::Search, Category::All, false)
+        .await
+        .unwrap();
+    let codes: Vec<&str> = data.entries.iter().map(|entry| entry.geo_code.as_str()).collect();
+    assert_eq!(codes, ["DE", "NO", "SE"]);
+}
+
+#[tokio::test]
+async fn interest_by_region_in_google_order() {
+    let opts = RegionOptions::default().order(RegionOrder::Google);
+    let data = client().interest_by_region_with_opts(&query(), &opts).await.unwrap();
+    let codes: Vec<&str> = data.entries.iter().map(|entry| entry.geo_code.as_str()).collect();
+    assert_eq!(codes, ["NO", "SE", "DE"]);
+}
+
+#[tokio::test]
+async fn interest_by_region_sorted() {
+    let opts = RegionOptions::default().order(RegionOrder::ValueDesc);
+    let data = client().interest_by_region_with_opts(&query(), &opts).await.unwrap();
+    let codes: Vec<&str> = data.entries.iter().map(|entry| entry.geo_code.as_str()).collect();
+    assert_eq!(codes, ["NO", "DE", "SE"]);
+}
+
+#[tokio::test]
+async fn related_queries() {
+    let related = client()
+        .related_queries(&query(), Source::Search, Category::All)
+        .await
+        .unwrap();
+    assert_eq!(related.top[0].query, "rust lang");
+    assert_eq!(related.rising[0].value, RisingValue::Breakout);
+    assert_eq!(related.rising[1].value, RisingValue::Percent(250));
+}
+
+#[tokio::test]
+async fn related_topics() {
+    let related = client()
+        .related_topics(&query(), Source::Search, Category::All)
+        .await
+        .unwrap();
+    assert_eq!(related.top[0].topic.kind, "Programming language");
+    assert_eq!(related.rising[0].topic.title, "Rust Console");
+}
+
+#[tokio::test]
+async fn suggestions() {
+    let topic = client().resolve_topic("rust").await.unwrap().unwrap();
+    assert_eq!(topic.mid, "/m/0dsbpg6");
+}
+
+#[tokio::test]
+async fn ping() {
+    assert!(client().ping().await.is_ok());
+}
+
+#[tokio::test]
+async fn rate_limited_responses() {
+    let client = builder().retry_rate_limited(false).build().unwrap();
+    let err = client.suggestions("blocked").await.unwrap_err();
+    match err.root() {
+        Error::RateLimited { cookie, retry_after } => {
+            assert_eq!(cookie.as_deref(), Some("NID=511=blocked"));
+            assert_eq!(*retry_after, Some(std::time::Duration::from_secs(30)));
+        }
+        err => panic!("expected RateLimited, got {:?}", err),
+    }
+}
+
+// recorded as a 429 followed by the response to the retry
+#[tokio::test]
+async fn rate_limited_requests_get_the_next_recording() {
+    let topics = client().suggestions("java").await.unwrap();
+    assert_eq!(topics[0].title, "Java");
+}
+
+#[tokio::test]
+async fn categories() {
+    let tree = client().categories().await.unwrap();
+    let node = tree
+        .find("Computers & Electronics > Software > Operating Systems")
+        .unwrap();
+    assert_eq!(node.id, 317);
+}
+
+#[tokio::test]
+async fn daily_trends() {
+    let days = client().daily_trends("US", Utc.ymd(2021, 3, 1)).await.unwrap();
+    let dates: Vec<&str> = days.iter().map(|day| day.date.as_str()).collect();
+    assert_eq!(dates, ["20210301", "20210228"]);
+    assert_eq!(days[0].trending_searches[0].articles[0].source, "Example");
+}
+
+#[tokio::test]
+async fn trending_now() {
+    let trends = client().trending_now("US", 24, 0).await.unwrap();
+    assert_eq!(trends.len(), 2);
+    assert_eq!(trends[0].search_volume, 500_000);
+    assert_eq!(trends[0].ended, None);
+    assert_eq!(trends[1].ended, Some(Utc.timestamp(1_614_610_000, 0)));
+}
+
+async fn realtime_titles(client: &TrendsClient, geo: &str, limit: Option<usize>) -> Result<Vec<String>, Error> {
+    client
+        .realtime_trends_stream(geo, "all", limit)
+        .map_ok(|story| story.title)
+        .try_collect()
+        .await
+}
+
+#[tokio::test]
+async fn realtime_trends_stream_walks_every_page() {
+    let titles = realtime_titles(&client(), "US", None).await.unwrap();
+    assert_eq!(
+        titles,
+        [
+            "Kentucky Derby, Medina Spirit",
+            "SpaceX, Starship",
+            "Mother's Day",
+            "Bitcoin"
+        ]
+    );
+    let titles = realtime_titles(&client(), "US", Some(3)).await.unwrap();
+    assert_eq!(titles.len(), 3);
+}
+
+// the second page fails once a story dropped out, the walk goes on from a fresh list
+#[tokio::test]
+async fn realtime_trends_stream_refreshes_stale_ids() {
+    let titles = realtime_titles(&client(), "GB", None).await.unwrap();
+    assert_eq!(titles, ["FA Cup", "Local elections", "Eurovision"]);
+}
+
+#[tokio::test]
+async fn checkpointed_windows_are_not_fetched_again() {
+    let path = std::env::temp_dir().join(format!("google-trends-checkpoint-{}.json", std::process::id()));
+    let checkpoint = Arc::new(Checkpoint::open(&path).unwrap());
+    let client = client().with_checkpoint(checkpoint.clone());
+    let time = Timeframe::new(Utc.ymd(2021, 1, 1), Utc.ymd(2021, 3, 1));
+    let data = client
+        .interest_by_time(&query(), Source::Search, Category::All)
+        .await
+        .unwrap();
+    let digest = client
+        .rising_digest(Category::All, None, &time, &["rust"])
+        .await
+        .unwrap();
+    assert_eq!(checkpoint.len(), 2);
+
+    // resumed from the file by a client without any recordings
+    let offline = TrendsClient::builder()
+        .locale("en-US")
+        .cassette(Arc::new(Cassette::replay_interactions(vec![])))
+        .build()
+        .unwrap()
+        .with_checkpoint(Arc::new(Checkpoint::open(&path).unwrap()));
+    let resumed = offline
+        .interest_by_time(&query(), Source::Search, Category::All)
+        .await
+        .unwrap();
+    let values = |data: &TimeSeriesData| -> Vec<Vec<Option<u8>>> {
+        data.entries.iter().map(TimeSeriesEntry::raw_values).collect()
+    };
+    assert_eq!(values(&resumed), values(&data));
+    let resumed = offline
+        .rising_digest(Category::All, None, &time, &["rust"])
+        .await
+        .unwrap();
+    let queries = |digest: &[DigestEntry]| -> Vec<String> { digest.iter().map(|entry| entry.query.clone()).collect() };
+    assert_eq!(queries(&resumed), queries(&digest));
+
+    // other windows still go out
+    let err = offline
+        .related_topics(&query(), Source::Search, Category::All)
+        .await
+        .unwrap_err();
+    assert!(matches!(err.root(), Error::UnexpectedResponse(_)), "{:?}", err);
+
+    checkpoint.remove().unwrap();
+    assert!(!path.exists());
+}
+
+#[tokio::test]
+async fn unrecorded_request_fails() {
+    let err = client().daily_trends("US", Utc.ymd(2021, 3, 2)).await.unwrap_err();
+    assert!(matches!(err.root(), Error::UnexpectedResponse(_)), "{:?}", err);
+}
+
+// Records the requests of these tests against Google into target/endpoints.json:
+// `cargo test --features testing --test replay -- --ignored record`. The bundled fixtures aren't
+// recordings, real responses carry other tokens and values, so the assertions above would have to
+// follow them before the file could replace src/fixtures/endpoints.json.
+#[tokio::test]
+#[ignore]
+async fn record() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/target/endpoints.json");
+    let client = TrendsClient::builder()
+        .locale("en-US")
+        .cassette(Arc::new(Cassette::record(path)))
+        .build()
+        .unwrap();
+    assert!(client.ping().await.is_ok());
+    client.explore_widgets(&query()).await.unwrap();
+    client
+        .interest_by_time(&query(), Source::Search, Category::All)
+        .await
+        .unwrap();
+    client
+        .interest_by_region(&query(), Resolution::Country, Source::Search, Category::All, false)
+        .await
+        .unwrap();
+    client
+        .related_queries(&query(), Source::Search, Category::All)
+        .await
+        .unwrap();
+    client
+        .related_topics(&query(), Source::Search, Category::All)
+        .await
+        .unwrap();
+    client.suggestions("rust").await.unwrap();
+    client.categories().await.unwrap();
+    client.daily_trends("US", Utc.ymd(2021, 3, 1)).await.unwrap();
+    client.trending_now("US", 24, 0).await.unwrap();
+}
+
+#[cfg(feature = "strict")]
+mod strict {
+    use super::*;
+
+    fn strict_client(cassette: Cassette) -> TrendsClient {
+        TrendsClient::builder()
+            .locale("en-US")
+            .strict(true)
+            .cassette(Arc::new(cassette))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn recorded_responses_have_no_unknown_fields() {
+        let client = strict_client(Cassette::bundled());
+
+        client.explore_widgets(&query()).await.unwrap();
+        client
+            .interest_by_time(&query(), Source::Search, Category::All)
+            .await
+            .unwrap();
+        client.categories().await.unwrap();
+        client.daily_trends("US", Utc.ymd(2021, 3, 1)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn unknown_fields_are_reported() {
+        let mut interactions = Cassette::bundled().interactions();
+        for interaction in &mut interactions {
+            if interaction.url.contains("/explore/pickers/category") {
+                interaction.body = interaction.body.replacen("{", r#"{"isNew":true,"#, 1);
+            } else if interaction.url.contains("/explore?") {
+                interaction.body = interaction.body.replacen("{", r#"{"experiments":[],"#, 1);
+            }
+        }
+        let client = strict_client(Cassette::replay_interactions(interactions));
+
+        let err = client.categories().await.unwrap_err();
+        assert!(
+            matches!(err.root(), Error::SchemaDrift(fields) if fields.len() == 1),
+            "{:?}",
+            err
+        );
+        let err = client.explore_widgets(&query()).await.unwrap_err();
+        assert!(
+            matches!(err.root(), Error::SchemaDrift(fields) if fields.len() == 1),
+            "{:?}",
+            err
+        );
+    }
+}
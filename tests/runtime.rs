@@ -0,0 +1,25 @@
+#![cfg(feature = "testing")]
+
+use std::sync::Arc;
+
+use chrono::{TimeZone, Utc};
+use futures_executor::block_on;
+use google_trends::testing::Cassette;
+use google_trends::*;
+
+// The client doesn't need tokio, only the transport does. Without the network any executor will do.
+#[test]
+fn replayed_calls_run_without_tokio() {
+    let client = TrendsClient::builder()
+        .locale("en-US")
+        .cassette(Arc::new(Cassette::bundled()))
+        .build()
+        .unwrap();
+    let time = Timeframe::new(Utc.ymd(2021, 1, 1), Utc.ymd(2021, 3, 1));
+    let query = Query::by_keyword("rust".to_owned(), time);
+
+    let data = block_on(client.interest_by_time(&query, Source::Search, Category::All)).unwrap();
+    assert_eq!(data.entries.len(), 5);
+    let related = block_on(client.related_queries(&query, Source::Search, Category::All)).unwrap();
+    assert_eq!(related.top[0].query, "rust lang");
+}